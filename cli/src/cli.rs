@@ -1,5 +1,93 @@
 use clap::{App, AppSettings, Arg, SubCommand};
 
+fn format_arg() -> Arg<'static, 'static> {
+  Arg::with_name("format")
+    .long("format")
+    .value_name("format")
+    .number_of_values(1)
+    .possible_values(&["plain", "json"])
+    .default_value("plain")
+    .help("Output format")
+}
+
+fn secret_input_args(sub_command: App<'static, 'static>) -> App<'static, 'static> {
+  sub_command
+    .arg(
+      Arg::with_name("json")
+        .long("json")
+        .help("Read secret as json from stdin (all other input options are ignored)"),
+    )
+    .arg(
+      Arg::with_name("read-property")
+        .long("read-property")
+        .value_name("property")
+        .number_of_values(1)
+        .conflicts_with("json")
+        .help("Read the value of a property from stdin or prompt for it on a terminal"),
+    )
+    .arg(
+      Arg::with_name("name")
+        .long("name")
+        .value_name("name")
+        .number_of_values(1),
+    )
+    .arg(
+      Arg::with_name("type")
+        .long("type")
+        .value_name("type")
+        .number_of_values(1)
+        .possible_values(&[
+          "login", "note", "licence", "wlan", "password", "card", "identity", "other",
+        ]),
+    )
+    .arg(
+      Arg::with_name("tag")
+        .long("tag")
+        .short("t")
+        .value_name("tag")
+        .number_of_values(1)
+        .multiple(true),
+    )
+    .arg(
+      Arg::with_name("url")
+        .long("url")
+        .short("u")
+        .value_name("url")
+        .number_of_values(1)
+        .multiple(true),
+    )
+    .arg(
+      Arg::with_name("property")
+        .long("property")
+        .short("p")
+        .value_name("key=value")
+        .number_of_values(1)
+        .multiple(true),
+    )
+    .arg(
+      Arg::with_name("expires")
+        .long("expires")
+        .value_name("date")
+        .number_of_values(1)
+        .help("Expiry date as YYYY-MM-DD or RFC 3339 timestamp (empty to remove)"),
+    )
+    .arg(
+      Arg::with_name("rotate-every")
+        .long("rotate-every")
+        .value_name("days")
+        .number_of_values(1)
+        .help("Remind to change the secret every number of days (0 to remove)"),
+    )
+    .arg(
+      Arg::with_name("custom-type")
+        .long("custom-type")
+        .value_name("type-id")
+        .number_of_values(1)
+        .help("Id of a custom secret type (empty to remove)"),
+    )
+    .arg(format_arg())
+}
+
 pub fn app() -> App<'static, 'static> {
   App::new("t-rust-less")
     .version(&clap::crate_version!()[..])
@@ -26,6 +114,20 @@ pub fn app() -> App<'static, 'static> {
         .subcommand(SubCommand::with_name("list").alias("ls").about("List identities"))
         .subcommand(SubCommand::with_name("add").about("Add a new identity")),
     )
+    .subcommand(
+      SubCommand::with_name("types")
+        .about("Control custom secret types of a store")
+        .subcommand(
+          SubCommand::with_name("list")
+            .alias("ls")
+            .about("List custom secret types")
+            .arg(format_arg()),
+        )
+        .subcommand(
+          SubCommand::with_name("store")
+            .about("Add or replace a custom secret type (schema is read as json from stdin)"),
+        ),
+    )
     .subcommand(SubCommand::with_name("status").about("Show current status of the password store"))
     .subcommand(
       SubCommand::with_name("import")
@@ -35,19 +137,151 @@ pub fn app() -> App<'static, 'static> {
             .long("v1")
             .help("Import V1 format (from original trustless)"),
         )
-        .arg(Arg::with_name("file").help("File to import. If not set import will read from stdin")),
+        .arg(
+          Arg::with_name("keepass")
+            .long("keepass")
+            .conflicts_with("v1")
+            .help("Import KeePass database (KDBX 4) or KeePass XML export"),
+        )
+        .arg(
+          Arg::with_name("bitwarden")
+            .long("bitwarden")
+            .conflicts_with_all(&["v1", "keepass"])
+            .help("Import unencrypted json export of Bitwarden"),
+        )
+        .arg(
+          Arg::with_name("1password")
+            .long("1password")
+            .conflicts_with_all(&["v1", "keepass", "bitwarden"])
+            .help("Import 1PUX export of 1Password"),
+        )
+        .arg(
+          Arg::with_name("pass")
+            .long("pass")
+            .conflicts_with_all(&["v1", "keepass", "bitwarden", "1password"])
+            .help("Import pass (password-store) directory"),
+        )
+        .arg(
+          Arg::with_name("gpg")
+            .long("gpg")
+            .value_name("program")
+            .number_of_values(1)
+            .requires("pass")
+            .help("gpg program to decrypt the pass files (default: gpg)"),
+        )
+        .arg(
+          Arg::with_name("csv")
+            .long("csv")
+            .conflicts_with_all(&["v1", "keepass", "bitwarden", "1password", "pass"])
+            .help("Import password csv export of Chrome or Firefox"),
+        )
+        .arg(
+          Arg::with_name("dry-run")
+            .long("dry-run")
+            .conflicts_with("v1")
+            .help("Only report what would be imported"),
+        )
+        .arg(
+          Arg::with_name("file").help("File (or directory for pass) to import. If not set import will read from stdin"),
+        ),
+    )
+    .subcommand(
+      SubCommand::with_name("export")
+        .about("Export secrets of a store")
+        .arg(
+          Arg::with_name("format")
+            .long("format")
+            .short("f")
+            .value_name("format")
+            .number_of_values(1)
+            .possible_values(&["native", "keepass", "bitwarden", "csv"])
+            .default_value("native")
+            .help("Export format: native (can be imported via --v1), KeePass xml, Bitwarden json or csv (only Login secrets)"),
+        )
+        .arg(
+          Arg::with_name("tag")
+            .long("tag")
+            .short("t")
+            .value_name("tag-filter")
+            .number_of_values(1)
+            .help("Only export secrets with this tag"),
+        )
+        .arg(
+          Arg::with_name("type")
+            .long("type")
+            .value_name("type")
+            .number_of_values(1)
+            .possible_values(&["login", "note", "licence", "wlan", "password", "card", "identity", "other"])
+            .help("Only export secrets of this type"),
+        )
+        .arg(
+          Arg::with_name("include-deleted")
+            .long("include-deleted")
+            .help("Export deleted secrets as well"),
+        )
+        .arg(
+          Arg::with_name("output")
+            .long("output")
+            .short("o")
+            .value_name("file")
+            .number_of_values(1)
+            .help("Output file (only readable by the current user). If not set write to stdout"),
+        ),
+    )
+    .subcommand(
+      SubCommand::with_name("inject")
+        .about("Render a template with references like {{ trl://store/secret-name/property }}")
+        .arg(
+          Arg::with_name("output")
+            .long("output")
+            .short("o")
+            .value_name("file")
+            .number_of_values(1)
+            .help("Output file (only readable by the current user). If not set write to stdout"),
+        )
+        .arg(
+          Arg::with_name("strict")
+            .long("strict")
+            .help("Fail if any of the referenced secrets or properties does not exist"),
+        )
+        .arg(
+          Arg::with_name("fifo")
+            .long("fifo")
+            .requires("output")
+            .help("Write output as named pipe, so that secrets never hit the disk"),
+        )
+        .arg(Arg::with_name("template").help("Template to render. If not set read from stdin")),
     )
-    .subcommand(SubCommand::with_name("export").about("Export an entire store"))
     .subcommand(SubCommand::with_name("lock").about("Lock the store"))
-    .subcommand(SubCommand::with_name("unlock").about("Unlock the store"))
+    .subcommand(SubCommand::with_name("migrate").about("Rewrite secrets in the payload format configured for the store"))
+    .subcommand(
+      SubCommand::with_name("unlock")
+        .about("Unlock the store")
+        .arg(
+          Arg::with_name("pinentry")
+            .long("pinentry")
+            .value_name("program")
+            .number_of_values(1)
+            .conflicts_with("askpass")
+            .help("Ask for the passphrase via a pinentry program (e.g. pinentry-gtk-2)"),
+        )
+        .arg(
+          Arg::with_name("askpass")
+            .long("askpass")
+            .value_name("program")
+            .number_of_values(1)
+            .help("Ask for the passphrase via an SSH_ASKPASS style program"),
+        ),
+    )
     .subcommand(
       SubCommand::with_name("list")
         .alias("ls")
         .about("List secrets")
         .arg(
-          Arg::with_name("name")
-            .value_name("name-filter")
-            .help("Fuzzy name filter"),
+          Arg::with_name("query")
+            .value_name("query")
+            .multiple(true)
+            .help("Query like: tag:prod -tag:legacy (type:login OR type:password) url:example.com \"fuzzy name\""),
         )
         .arg(
           Arg::with_name("url")
@@ -63,7 +297,168 @@ pub fn app() -> App<'static, 'static> {
             .value_name("tag-filter")
             .number_of_values(1),
         )
-        .arg(Arg::with_name("deleted").long("deleted").help("List deleted items")),
+        .arg(Arg::with_name("deleted").long("deleted").help("List deleted items"))
+        .arg(
+          Arg::with_name("expired")
+            .long("expired")
+            .help("List only secrets that are expired or due for rotation"),
+        )
+        .arg(
+          Arg::with_name("expiring-within")
+            .long("expiring-within")
+            .value_name("days")
+            .number_of_values(1)
+            .conflicts_with("expired")
+            .help("List only secrets that expire or are due for rotation within number of days"),
+        )
+        .arg(
+          Arg::with_name("text")
+            .long("text")
+            .value_name("query")
+            .number_of_values(1)
+            .help("Full-text search in usernames, notes and other fields (if the full-text index is enabled)"),
+        )
+        .arg(
+          Arg::with_name("all-stores")
+            .long("all-stores")
+            .short("a")
+            .help("List the secrets of all unlocked stores"),
+        )
+        .arg(format_arg()),
+    )
+    .subcommand(
+      SubCommand::with_name("get")
+        .about("Get a secret")
+        .arg(
+          Arg::with_name("secret")
+            .value_name("id-or-name")
+            .required(true)
+            .help("Id or (exact) name of the secret"),
+        )
+        .arg(
+          Arg::with_name("property")
+            .long("property")
+            .short("p")
+            .value_name("property")
+            .number_of_values(1)
+            .help("Only get the value of a single property"),
+        )
+        .arg(
+          Arg::with_name("version")
+            .long("version")
+            .value_name("block-id")
+            .number_of_values(1)
+            .help("Get an older version, either by (a prefix of) its block id or relative like ~1"),
+        )
+        .arg(Arg::with_name("deleted").long("deleted").help("Get a deleted secret"))
+        .arg(format_arg()),
+    )
+    .subcommand(
+      SubCommand::with_name("health")
+        .about("Report weak, reused or old passwords and logins without one-time password")
+        .arg(
+          Arg::with_name("min-score")
+            .long("min-score")
+            .value_name("score")
+            .number_of_values(1)
+            .possible_values(&["1", "2", "3", "4"])
+            .help("Minimum password strength score (default: 3)"),
+        )
+        .arg(
+          Arg::with_name("max-age")
+            .long("max-age")
+            .value_name("days")
+            .number_of_values(1)
+            .help("Maximum age of passwords in days, 0 to disable (default: 365)"),
+        )
+        .arg(format_arg()),
+    )
+    .subcommand(
+      SubCommand::with_name("history")
+        .about("List all versions of a secret or show the changes between two of them")
+        .arg(
+          Arg::with_name("secret")
+            .value_name("id-or-name")
+            .required(true)
+            .help("Id or (exact) name of the secret"),
+        )
+        .arg(
+          Arg::with_name("diff")
+            .long("diff")
+            .value_name("block-id")
+            .number_of_values(1)
+            .help("Show the changes since a version, either by (a prefix of) its block id or relative like ~1"),
+        )
+        .arg(
+          Arg::with_name("to")
+            .long("to")
+            .value_name("block-id")
+            .number_of_values(1)
+            .requires("diff")
+            .help("Version to compare with (default: the current version)"),
+        )
+        .arg(
+          Arg::with_name("reveal")
+            .long("reveal")
+            .help("Show the values of passwords instead of masking them"),
+        )
+        .arg(Arg::with_name("deleted").long("deleted").help("Show a deleted secret"))
+        .arg(format_arg()),
+    )
+    .subcommand(
+      SubCommand::with_name("restore")
+        .about("Restore an old version of a secret (i.e. add a copy of it as new version)")
+        .arg(
+          Arg::with_name("block-id")
+            .value_name("block-id")
+            .required(true)
+            .help("Block id of the version to restore (see history)"),
+        )
+        .arg(format_arg()),
+    )
+    .subcommand(secret_input_args(
+      SubCommand::with_name("add").about("Add a new secret"),
+    ))
+    .subcommand(
+      secret_input_args(
+        SubCommand::with_name("edit")
+          .about("Edit a secret (i.e. add a new version of it)")
+          .arg(
+            Arg::with_name("secret")
+              .value_name("id-or-name")
+              .required(true)
+              .help("Id or (exact) name of the secret"),
+          ),
+      )
+      .arg(
+        Arg::with_name("remove-property")
+          .long("remove-property")
+          .value_name("property")
+          .number_of_values(1)
+          .multiple(true),
+      ),
+    )
+    .subcommand(
+      SubCommand::with_name("delete")
+        .about("Mark a secret as deleted")
+        .arg(
+          Arg::with_name("secret")
+            .value_name("id-or-name")
+            .required(true)
+            .help("Id or (exact) name of the secret"),
+        )
+        .arg(format_arg()),
+    )
+    .subcommand(
+      SubCommand::with_name("undelete")
+        .about("Restore a deleted secret")
+        .arg(
+          Arg::with_name("secret")
+            .value_name("id-or-name")
+            .required(true)
+            .help("Id or (exact) name of the secret"),
+        )
+        .arg(format_arg()),
     )
     .subcommand(
       SubCommand::with_name("generate")
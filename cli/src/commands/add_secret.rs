@@ -0,0 +1,109 @@
+use crate::commands::edit_secret::warn_breached_passwords;
+use crate::commands::open_unlocked_store;
+use crate::commands::tui::read_passphrase;
+use crate::error::{exit_with_error, ExtCodeResult, ExtResult};
+use crate::model::secret_input::SecretInput;
+use crate::output::{print_json, OutputFormat};
+use atty::Stream;
+use chrono::Utc;
+use clap::ArgMatches;
+use serde_json::json;
+use std::io::{self, stdin, BufRead};
+use std::process;
+use std::sync::Arc;
+use t_rust_less_lib::api::{SecretType, SecretVersion};
+use t_rust_less_lib::memguard::SecretBytes;
+use t_rust_less_lib::service::TrustlessService;
+
+pub fn add_secret(service: Arc<dyn TrustlessService>, store_name: String, input: SecretInput, format: OutputFormat) {
+  let name = match &input.name {
+    Some(name) if !name.is_empty() => name.clone(),
+    _ => exit_with_error("Invalid input: ", "name is required"),
+  };
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let secret_id = service.generate_id().ok_or_exit_with_code("Generate id: ");
+  let secret_type = input.secret_type.unwrap_or(match input.custom_type {
+    Some(_) => SecretType::Other,
+    None => SecretType::Login,
+  });
+  let mut version = SecretVersion::new(&secret_id, secret_type, &name, Utc::now().into());
+
+  input.apply_to(&mut version);
+
+  let block_id = secrets_store.add(version).ok_or_exit_with_code("Add secret: ");
+
+  warn_breached_passwords(&secrets_store, &secret_id);
+
+  match format {
+    OutputFormat::Plain => println!("{}", secret_id),
+    OutputFormat::Json => print_json(&json!({ "id": secret_id, "block_id": block_id })),
+  }
+}
+
+/// Read the input of `add` or `edit` either as json from stdin or from the command line.
+pub fn secret_input_from_args(args: &ArgMatches) -> SecretInput {
+  let mut input = if args.is_present("json") {
+    serde_json::from_reader(stdin()).ok_or_exit("Invalid json input: ")
+  } else {
+    SecretInput::from_args(args)
+  };
+
+  if let Some(property) = args.value_of("read-property") {
+    let value = read_property_value(property);
+
+    input.properties.set(property, value.borrow().as_str());
+  }
+
+  input
+}
+
+/// Read the value of a single property, so that it does not show up in the process list or shell history.
+///
+/// On a terminal the value is prompted for, otherwise the first line of stdin is taken.
+fn read_property_value(property: &str) -> SecretBytes {
+  if atty::is(Stream::Stdin) && atty::is(Stream::Stdout) {
+    read_passphrase(&format!("Value of {}", property)).unwrap_or_else(|| {
+      eprintln!("Cancelled");
+      process::exit(1);
+    })
+  } else {
+    read_first_line(stdin().lock()).ok_or_exit("Failed reading stdin: ")
+  }
+}
+
+fn read_first_line<R: BufRead>(mut reader: R) -> io::Result<SecretBytes> {
+  let mut line = String::new();
+
+  reader.read_line(&mut line)?;
+  let length = line.trim_end_matches(&['\r', '\n'][..]).len();
+  line.truncate(length);
+
+  Ok(SecretBytes::from(line))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use std::io::Cursor;
+
+  #[test]
+  fn test_read_first_line() {
+    assert_that(
+      &read_first_line(Cursor::new("secret\nsecond line\n"))
+        .unwrap()
+        .borrow()
+        .as_str(),
+    )
+    .is_equal_to("secret");
+    assert_that(
+      &read_first_line(Cursor::new("with space \r\n"))
+        .unwrap()
+        .borrow()
+        .as_str(),
+    )
+    .is_equal_to("with space ");
+    assert_that(&read_first_line(Cursor::new("no newline")).unwrap().borrow().as_str()).is_equal_to("no newline");
+    assert_that(&read_first_line(Cursor::new("")).unwrap().is_empty()).is_true();
+  }
+}
@@ -0,0 +1,21 @@
+use crate::commands::edit_secret::add_next_version;
+use crate::commands::{find_secret_entry, open_unlocked_store};
+use crate::output::OutputFormat;
+use std::sync::Arc;
+use t_rust_less_lib::service::TrustlessService;
+
+/// Mark a secret as deleted (or undo this).
+///
+/// Like any other change this just adds a new version, i.e. the secret itself remains in the store.
+pub fn delete_secret(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  id_or_name: &str,
+  deleted: bool,
+  format: OutputFormat,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let entry = find_secret_entry(&secrets_store, id_or_name, !deleted);
+
+  add_next_version(&secrets_store, &entry.id, format, |version| version.deleted = deleted);
+}
@@ -0,0 +1,70 @@
+use crate::commands::{find_secret_entry, open_unlocked_store};
+use crate::error::ExtCodeResult;
+use crate::model::secret_input::SecretInput;
+use crate::output::{print_json, OutputFormat};
+use chrono::Utc;
+use log::warn;
+use serde_json::json;
+use std::sync::Arc;
+use t_rust_less_lib::api::SecretVersion;
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::TrustlessService;
+
+pub fn edit_secret(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  id_or_name: &str,
+  input: SecretInput,
+  format: OutputFormat,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let entry = find_secret_entry(&secrets_store, id_or_name, false);
+
+  add_next_version(&secrets_store, &entry.id, format, |version| input.apply_to(version));
+}
+
+/// Write a new version of a secret based on its current version.
+///
+/// As a SecretVersion must never be modified, this is the only way to change a secret.
+pub(super) fn add_next_version<F>(
+  secrets_store: &Arc<dyn SecretsStore>,
+  secret_id: &str,
+  format: OutputFormat,
+  modify: F,
+) where
+  F: FnOnce(&mut SecretVersion),
+{
+  let secret = secrets_store.get(secret_id).ok_or_exit_with_code("Get secret: ");
+  let mut version = secret.current.clone();
+
+  modify(&mut version);
+  version.timestamp = Utc::now().into();
+  version.parent_block_id = Some(secret.current_block_id.clone());
+  version.merged_block_ids.clear();
+
+  let block_id = secrets_store.add(version).ok_or_exit_with_code("Add secret version: ");
+
+  warn_breached_passwords(secrets_store, secret_id);
+
+  match format {
+    OutputFormat::Plain => println!("{}", block_id),
+    OutputFormat::Json => print_json(&json!({ "id": secret.id, "block_id": block_id })),
+  }
+}
+
+/// Warn about passwords of a secret that appear in the breached passwords file of the daemon (if configured).
+pub(super) fn warn_breached_passwords(secrets_store: &Arc<dyn SecretsStore>, secret_id: &str) {
+  if let Ok(secret) = secrets_store.get(secret_id) {
+    if secret.current.deleted {
+      return;
+    }
+    for (property, strength) in secret.password_strengths.iter() {
+      if strength.breach_count > 0 {
+        warn!(
+          "{} appears {} times in breached passwords, consider changing it",
+          property, strength.breach_count
+        );
+      }
+    }
+  }
+}
@@ -1 +1,111 @@
+use crate::commands::{create_restricted, open_unlocked_store};
+use crate::error::ExtResult;
+use crate::model::export::{
+  AttachmentSource, BitwardenExporter, CsvExporter, Exporter, KeePassExporter, NativeExporter,
+};
+use std::io::{self, stdout, Write};
+use std::path::Path;
+use std::sync::Arc;
+use t_rust_less_lib::api::{SecretAttachment, SecretListFilter, SecretVersion};
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::TrustlessService;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+  Native,
+  KeePass,
+  Bitwarden,
+  Csv,
+}
+
+impl ExportFormat {
+  pub fn from_name(name: &str) -> Option<ExportFormat> {
+    match name {
+      "native" => Some(ExportFormat::Native),
+      "keepass" => Some(ExportFormat::KeePass),
+      "bitwarden" => Some(ExportFormat::Bitwarden),
+      "csv" => Some(ExportFormat::Csv),
+      _ => None,
+    }
+  }
+
+  fn exporter(self, store_name: &str) -> Box<dyn Exporter> {
+    match self {
+      ExportFormat::Native => Box::new(NativeExporter),
+      ExportFormat::KeePass => Box::new(KeePassExporter::new(store_name)),
+      ExportFormat::Bitwarden => Box::new(BitwardenExporter::new()),
+      ExportFormat::Csv => Box::new(CsvExporter),
+    }
+  }
+}
+
+pub fn export(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  format: ExportFormat,
+  filter: SecretListFilter,
+  include_deleted: bool,
+  maybe_output_file: Option<&str>,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let mut lists = vec![secrets_store.list(&filter).ok_or_exit("List secrets: ")];
+
+  if include_deleted {
+    let mut deleted_filter = filter.clone();
+    deleted_filter.deleted = true;
+    lists.push(secrets_store.list(&deleted_filter).ok_or_exit("List secrets: "));
+  }
+
+  let mut all_tags: Vec<String> = lists.iter().flat_map(|list| list.all_tags.iter().cloned()).collect();
+  all_tags.sort();
+  all_tags.dedup();
+
+  let mut out: Box<dyn Write> = match maybe_output_file {
+    Some(output_file) => Box::new(create_restricted(Path::new(output_file)).ok_or_exit("Create output: ")),
+    None => Box::new(stdout()),
+  };
+  let mut exporter = format.exporter(&store_name);
+
+  exporter.begin(&mut out, &all_tags).ok_or_exit("Write export: ");
+
+  for entry_match in lists.iter().flat_map(|list| list.entries.iter()) {
+    let secret = secrets_store.get(&entry_match.entry.id).ok_or_exit("Get secret: ");
+    let mut versions: Vec<SecretVersion> = Vec::with_capacity(secret.versions.len());
+
+    if exporter.with_history() {
+      // Versions are ordered newest first, the first being the current one
+      for version_ref in secret.versions.iter().skip(1).rev() {
+        versions.push(
+          secrets_store
+            .get_version(&version_ref.block_id)
+            .ok_or_exit("Get secret version: "),
+        );
+      }
+    }
+    versions.push(secret.current.clone());
+
+    exporter
+      .write_secret(
+        &mut out,
+        &secret.id,
+        &versions,
+        &StoreAttachments(secrets_store.as_ref()),
+      )
+      .ok_or_exit("Write export: ");
+  }
+
+  exporter.end(&mut out).ok_or_exit("Write export: ");
+  out.flush().ok_or_exit("Write export: ");
+}
+
+/// The content of attachments is streamed from the store chunk by chunk.
+struct StoreAttachments<'a>(&'a dyn SecretsStore);
+
+impl<'a> AttachmentSource for StoreAttachments<'a> {
+  fn write_content(&self, attachment: &SecretAttachment, out: &mut dyn Write) -> io::Result<()> {
+    self
+      .0
+      .get_attachment(attachment, out)
+      .map_err(|error| io::Error::other(format!("Get attachment {}: {}", attachment.name(), error)))
+  }
+}
@@ -0,0 +1,163 @@
+use crate::commands::{find_secret_entry, find_version_ref, open_unlocked_store};
+use crate::error::{exit_with_code, ExtCodeResult, ExtResult, EXIT_NOT_FOUND};
+use crate::output::{print_json, OutputFormat};
+use std::io::{self, Write};
+use std::sync::Arc;
+use t_rust_less_lib::api::Secret;
+use t_rust_less_lib::service::TrustlessService;
+
+pub fn get_secret(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  id_or_name: &str,
+  maybe_property: Option<&str>,
+  maybe_version: Option<&str>,
+  deleted: bool,
+  format: OutputFormat,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let entry = find_secret_entry(&secrets_store, id_or_name, deleted);
+  let mut secret = secrets_store.get(&entry.id).ok_or_exit_with_code("Get secret: ");
+
+  if let Some(version) = maybe_version {
+    let block_id = find_version_ref(&secret, version).block_id.clone();
+
+    if block_id != secret.current_block_id {
+      secret.current = secrets_store
+        .get_version(&block_id)
+        .ok_or_exit_with_code("Get secret version: ");
+      secret.current_block_id = block_id;
+      secret.password_strengths.clear();
+    }
+  }
+
+  match maybe_property {
+    Some(property) => match secret.current.properties.get(property) {
+      Some(value) => match format {
+        OutputFormat::Plain => println!("{}", value),
+        OutputFormat::Json => print_json(value),
+      },
+      None => exit_with_code(EXIT_NOT_FOUND, "Property not found: ", property),
+    },
+    None => match format {
+      OutputFormat::Plain => print_plain(&secret),
+      OutputFormat::Json => print_json(&secret),
+    },
+  }
+}
+
+fn print_plain(secret: &Secret) {
+  let stdout = io::stdout();
+
+  write_plain(&mut stdout.lock(), secret).ok_or_exit("Failed writing output: ");
+}
+
+fn write_plain<W: Write>(out: &mut W, secret: &Secret) -> io::Result<()> {
+  writeln!(out, "id: {}", secret.id)?;
+  writeln!(out, "name: {}", secret.current.name)?;
+  writeln!(out, "type: {}", secret.secret_type)?;
+  if let Some(custom_type) = &secret.current.custom_type {
+    writeln!(out, "custom_type: {}", custom_type)?;
+  }
+  writeln!(
+    out,
+    "timestamp: {}",
+    secret.current.timestamp.format("%Y-%m-%dT%H:%M:%SZ")
+  )?;
+  writeln!(out, "tags: {}", secret.current.tags.join(","))?;
+  writeln!(out, "urls: {}", secret.current.urls.join(","))?;
+  writeln!(out, "deleted: {}", secret.current.deleted)?;
+  if let Some(expires_at) = &secret.current.expires_at {
+    writeln!(out, "expires: {}", expires_at.format("%Y-%m-%dT%H:%M:%SZ"))?;
+  }
+  if let Some(rotate_every) = secret.current.rotate_every {
+    writeln!(out, "rotate_every: {}d", rotate_every / (24 * 3600))?;
+  }
+  if !secret.competing_versions.is_empty() {
+    let block_ids: Vec<&str> = secret.competing_versions.iter().map(|v| v.block_id.as_str()).collect();
+    writeln!(out, "conflicts: {}", block_ids.join(","))?;
+  }
+  for (property, strength) in secret.password_strengths.iter() {
+    if strength.breach_count > 0 {
+      writeln!(out, "breached: {} ({} times)", property, strength.breach_count)?;
+    }
+  }
+  for (property, value) in secret.current.properties.iter() {
+    // Multi-line values (e.g. notes) are indented so that the output remains parseable
+    writeln!(out, "{}: {}", property, value.replace('\n', "\n  "))?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use spectral::prelude::*;
+
+  fn secret() -> Secret {
+    serde_json::from_value(json!({
+      "id": "secret1",
+      "type": "login",
+      "current": {
+        "secret_id": "secret1",
+        "type": "login",
+        "timestamp": "2020-05-01T10:00:00Z",
+        "name": "Example",
+        "tags": ["web", "work"],
+        "urls": ["https://example.com"],
+        "properties": {
+          "username": "john",
+          "password": "secret",
+          "notes": "first\nsecond",
+        },
+        "rotate_every": 30 * 24 * 3600,
+      },
+      "current_block_id": "block1",
+      "versions": [],
+      "password_strengths": {},
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_plain_output() {
+    let mut out = Vec::new();
+
+    write_plain(&mut out, &secret()).unwrap();
+
+    assert_that(&String::from_utf8(out).unwrap()).is_equal_to(
+      r#"id: secret1
+name: Example
+type: Login
+timestamp: 2020-05-01T10:00:00Z
+tags: web,work
+urls: https://example.com
+deleted: false
+rotate_every: 30d
+notes: first
+  second
+password: secret
+username: john
+"#
+      .to_string(),
+    );
+  }
+
+  #[test]
+  fn test_json_output() {
+    let json = serde_json::to_value(secret()).unwrap();
+
+    assert_that(&json["id"]).is_equal_to(json!("secret1"));
+    assert_that(&json["type"]).is_equal_to(json!("login"));
+    assert_that(&json["current"]["name"]).is_equal_to(json!("Example"));
+    assert_that(&json["current"]["properties"]["password"]).is_equal_to(json!("secret"));
+    assert_that(&json["current"]["rotate_every"]).is_equal_to(json!(30 * 24 * 3600));
+    assert_that(&json["current"].get("expires_at")).is_none();
+
+    // The output of `get --format json` is accepted as input
+    let again: Secret = serde_json::from_value(json).unwrap();
+    assert_that(&again.current.properties.get("notes")).is_equal_to(Some(&"first\nsecond".to_string()));
+  }
+}
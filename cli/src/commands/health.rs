@@ -0,0 +1,35 @@
+use crate::commands::open_unlocked_store;
+use crate::error::ExtCodeResult;
+use crate::model::health::describe_issues;
+use crate::output::{print_json, OutputFormat};
+use std::sync::Arc;
+use t_rust_less_lib::api::HealthReportParams;
+use t_rust_less_lib::service::TrustlessService;
+
+/// Report weak, reused and old passwords as well as logins without one-time password.
+pub fn health_report(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  params: HealthReportParams,
+  format: OutputFormat,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let report = secrets_store
+    .health_report(&params)
+    .ok_or_exit_with_code("Health report: ");
+
+  match format {
+    OutputFormat::Plain => {
+      for health in report.secrets.iter() {
+        println!(
+          "{}\t{}\t{}",
+          health.secret_id,
+          health.name,
+          describe_issues(health, &report).join("; ")
+        );
+      }
+      eprintln!("{} of {} secrets with issues", report.secrets.len(), report.checked);
+    }
+    OutputFormat::Json => print_json(&report),
+  }
+}
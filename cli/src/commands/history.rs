@@ -0,0 +1,154 @@
+use crate::commands::edit_secret::add_next_version;
+use crate::commands::{find_secret_entry, find_version_ref, open_unlocked_store};
+use crate::error::ExtCodeResult;
+use crate::model::version_diff::{changed_fields, diff_versions};
+use crate::output::{print_json, OutputFormat};
+use serde_json::json;
+use std::sync::Arc;
+use t_rust_less_lib::api::{SecretVersion, SecretVersionRef};
+use t_rust_less_lib::service::TrustlessService;
+
+const TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// List all versions of a secret (newest first) or show the diff between two of them.
+///
+/// If no `to` version is given the diff is against the current version.
+pub fn history(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  id_or_name: &str,
+  deleted: bool,
+  maybe_diff: Option<(&str, Option<&str>)>,
+  reveal: bool,
+  format: OutputFormat,
+) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let entry = find_secret_entry(&secrets_store, id_or_name, deleted);
+  let secret = secrets_store.get(&entry.id).ok_or_exit_with_code("Get secret: ");
+  let get_version = |block_id: &str| -> SecretVersion {
+    if block_id == secret.current_block_id {
+      return secret.current.clone();
+    }
+    secrets_store
+      .get_version(block_id)
+      .ok_or_exit_with_code("Get secret version: ")
+  };
+
+  if let Some((from, maybe_to)) = maybe_diff {
+    let from_ref = find_version_ref(&secret, from);
+    let to_ref = find_version_ref(&secret, maybe_to.unwrap_or("~0"));
+    let changes = diff_versions(&get_version(&from_ref.block_id), &get_version(&to_ref.block_id), reveal);
+
+    match format {
+      OutputFormat::Plain => {
+        println!("--- {} {}", from_ref.block_id, from_ref.timestamp.format(TIME_FORMAT));
+        println!("+++ {} {}", to_ref.block_id, to_ref.timestamp.format(TIME_FORMAT));
+        for change in &changes {
+          // Multi-line values (e.g. notes) are indented so that the output remains parseable
+          println!("{}", change.to_string().replace('\n', "\n  "));
+        }
+      }
+      OutputFormat::Json => print_json(&json!({
+        "id": secret.id,
+        "from": from_ref.block_id,
+        "to": to_ref.block_id,
+        "changes": changes,
+      })),
+    }
+    return;
+  }
+
+  let versions: Vec<SecretVersion> = secret.versions.iter().map(|v| get_version(&v.block_id)).collect();
+  let mut history = Vec::with_capacity(versions.len());
+
+  for (idx, version_ref) in secret.versions.iter().enumerate() {
+    let changes = match previous_version_index(&secret.versions, idx) {
+      Some(previous_idx) => changed_fields(&versions[previous_idx], &versions[idx]),
+      None => vec!["(created)".to_string()],
+    };
+    let competing = secret
+      .competing_versions
+      .iter()
+      .any(|competing| competing.block_id == version_ref.block_id);
+    let marker = match (idx, competing) {
+      (0, _) => "(current) ",
+      (_, true) => "(competing) ",
+      _ => "",
+    };
+
+    match format {
+      OutputFormat::Plain => println!(
+        "{}\t{}\t{}{}",
+        version_ref.block_id,
+        version_ref.timestamp.format(TIME_FORMAT),
+        marker,
+        changes.join(", ")
+      ),
+      OutputFormat::Json => history.push(json!({
+        "block_id": version_ref.block_id,
+        "parent_block_id": version_ref.parent_block_id,
+        "timestamp": version_ref.timestamp.format(TIME_FORMAT),
+        "current": idx == 0,
+        "competing": competing,
+        "changes": changes,
+      })),
+    }
+  }
+
+  if format == OutputFormat::Json {
+    print_json(&json!({ "id": secret.id, "versions": history }));
+  }
+}
+
+/// Index of the version a version has been derived from.
+///
+/// Versions are ordered newest first. With concurrent edits the list neighbour is not necessarily the
+/// parent, so the successor in the list is only used if the parent is unknown (or no longer in the list).
+fn previous_version_index(version_refs: &[SecretVersionRef], idx: usize) -> Option<usize> {
+  version_refs[idx]
+    .parent_block_id
+    .as_ref()
+    .and_then(|parent_block_id| {
+      version_refs
+        .iter()
+        .position(|version_ref| &version_ref.block_id == parent_block_id)
+    })
+    .or_else(|| Some(idx + 1).filter(|&previous_idx| previous_idx < version_refs.len()))
+}
+
+/// Restore an old version of a secret.
+///
+/// Versions are immutable, so this adds a new version that is a copy of the old one.
+pub fn restore_version(service: Arc<dyn TrustlessService>, store_name: String, block_id: &str, format: OutputFormat) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let old_version = secrets_store
+    .get_version(block_id)
+    .ok_or_exit_with_code("Get secret version: ");
+  let secret_id = old_version.secret_id.clone();
+
+  add_next_version(&secrets_store, &secret_id, format, |version| *version = old_version);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+  use spectral::prelude::*;
+
+  #[test]
+  fn test_previous_version_index() {
+    // block3 and block2 have been derived from block1 concurrently, block0 does not know its parent
+    let version_refs: Vec<SecretVersionRef> = serde_json::from_value(json!([
+      { "block_id": "block3", "timestamp": "2020-05-04T10:00:00Z", "parent_block_id": "block1" },
+      { "block_id": "block2", "timestamp": "2020-05-03T10:00:00Z", "parent_block_id": "block1" },
+      { "block_id": "block1", "timestamp": "2020-05-02T10:00:00Z", "parent_block_id": "block_gone" },
+      { "block_id": "block0", "timestamp": "2020-05-01T10:00:00Z" },
+    ]))
+    .unwrap();
+
+    assert_that(&previous_version_index(&version_refs, 0)).is_equal_to(Some(2));
+    assert_that(&previous_version_index(&version_refs, 1)).is_equal_to(Some(2));
+    assert_that(&previous_version_index(&version_refs, 2)).is_equal_to(Some(3));
+    assert_that(&previous_version_index(&version_refs, 3)).is_equal_to(None);
+  }
+}
@@ -1,40 +1,301 @@
-use crate::commands::tui::create_tui;
-use crate::commands::unlock_store;
-use crate::error::ExtResult;
+use crate::commands::tui::{create_tui, read_passphrase};
+use crate::commands::{unlock_store, unlock_store_with_helper};
+use crate::error::{exit_with_error, ExtResult};
+use crate::model::import_set::{ImportSet, SkippedItem};
 use crate::model::import_v1::SecretV1;
+use crate::model::{bitwarden, browser_csv, keepass, onepassword, pass_store};
+use atty::Stream;
 use std::fs::File;
-use std::io::{stdin, BufRead, BufReader};
+use std::io::{stdin, BufRead, BufReader, Read};
+use std::path::Path;
 use std::process;
 use std::sync::Arc;
-use t_rust_less_lib::api::SecretVersion;
+use t_rust_less_lib::api::{SecretListFilter, SecretVersion};
+use t_rust_less_lib::memguard::SecretBytes;
+use t_rust_less_lib::pinentry::{PassphraseHelper, PassphrasePrompt, ASKPASS_ENV, PINENTRY_ENV};
+use t_rust_less_lib::secrets_store::{SecretStoreError, SecretsStore};
 use t_rust_less_lib::service::TrustlessService;
+use zeroize::Zeroizing;
 
-pub fn import_v1(service: Arc<dyn TrustlessService>, store_name: String, maybe_file_name: Option<&str>) {
+/// Open the store to import to and unlock it if necessary.
+///
+/// If the import is read from stdin, the passphrase has to come from a pinentry or askpass helper.
+fn open_store_for_import(
+  service: &Arc<dyn TrustlessService>,
+  store_name: &str,
+  from_stdin: bool,
+) -> Arc<dyn SecretsStore> {
   let secrets_store = service
-    .open_store(&store_name)
+    .open_store(store_name)
     .ok_or_exit(format!("Failed opening store {}: ", store_name));
 
   let status = secrets_store.status().ok_or_exit("Get status");
 
-  let import_stream: Box<dyn BufRead> = match maybe_file_name {
-    Some(file_name) => {
-      let file = File::open(file_name).ok_or_exit(format!("Failed opening {}", file_name));
-      Box::new(BufReader::new(file))
-    }
-    None => {
-      if status.locked {
-        eprintln!("Store is locked! Cannot unlock store when importing from stdin (duh).");
+  if status.locked {
+    if from_stdin {
+      let unlocked = match PassphraseHelper::from_env() {
+        Some(helper) => unlock_store_with_helper(service, &secrets_store, store_name, &helper),
+        None => {
+          eprintln!(
+            "Store is locked! Cannot unlock store when importing from stdin, unless {} or {} is set.",
+            PINENTRY_ENV, ASKPASS_ENV
+          );
+          process::exit(1);
+        }
+      };
+      if !unlocked {
+        eprintln!("Unlock cancelled");
         process::exit(1);
       }
-      Box::new(BufReader::new(stdin()))
+    } else {
+      let mut siv = create_tui();
+      unlock_store(&mut siv, &secrets_store, store_name);
     }
+  }
+
+  secrets_store
+}
+
+/// Read the passphrase of an (encrypted) file to import.
+fn read_import_passphrase(description: &str, from_stdin: bool) -> SecretBytes {
+  let maybe_passphrase = if !from_stdin && atty::is(Stream::Stdout) {
+    read_passphrase(description)
+  } else {
+    let helper = PassphraseHelper::from_env().unwrap_or_else(|| {
+      eprintln!(
+        "Passphrase required! Please use a terminal or set {} or {}.",
+        PINENTRY_ENV, ASKPASS_ENV
+      );
+      process::exit(1);
+    });
+    helper
+      .read_passphrase(&PassphrasePrompt {
+        title: "t-rust-less".to_string(),
+        description: description.to_string(),
+        prompt: "Passphrase:".to_string(),
+        error: None,
+      })
+      .ok_or_exit("Read passphrase: ")
   };
 
-  if status.locked {
-    let mut siv = create_tui();
-    unlock_store(&mut siv, &secrets_store, &store_name);
+  maybe_passphrase.unwrap_or_else(|| {
+    eprintln!("Import cancelled");
+    process::exit(1);
+  })
+}
+
+fn read_import_data(maybe_file_name: Option<&str>) -> Zeroizing<Vec<u8>> {
+  let mut data = Zeroizing::new(Vec::new());
+
+  match maybe_file_name {
+    Some(file_name) => File::open(file_name)
+      .and_then(|mut file| file.read_to_end(&mut data))
+      .ok_or_exit(format!("Failed reading {}: ", file_name)),
+    None => stdin().read_to_end(&mut data).ok_or_exit("Failed reading stdin: "),
+  };
+
+  data
+}
+
+pub fn import_keepass(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  maybe_file_name: Option<&str>,
+  dry_run: bool,
+) {
+  let data = read_import_data(maybe_file_name);
+  let maybe_passphrase = if keepass::is_kdbx(&data) {
+    Some(read_import_passphrase(
+      "Passphrase of the KeePass database",
+      maybe_file_name.is_none(),
+    ))
+  } else {
+    None
+  };
+  let entries = keepass::read_entries(&data, maybe_passphrase.as_ref()).ok_or_exit("Failed reading KeePass database: ");
+  let import_set = ImportSet {
+    secrets: entries.iter().map(|entry| entry.to_secret_versions("")).collect(),
+    skipped: vec![],
+  };
+
+  import_secrets(service, store_name, maybe_file_name.is_none(), import_set, dry_run);
+}
+
+pub fn import_bitwarden(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  maybe_file_name: Option<&str>,
+  dry_run: bool,
+) {
+  let data = read_import_data(maybe_file_name);
+  let import_set = bitwarden::read_export(&data).ok_or_exit("Failed reading Bitwarden export: ");
+
+  import_secrets(service, store_name, maybe_file_name.is_none(), import_set, dry_run);
+}
+
+pub fn import_1password(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  maybe_file_name: Option<&str>,
+  dry_run: bool,
+) {
+  let data = read_import_data(maybe_file_name);
+  let import_set = onepassword::read_export(&data).ok_or_exit("Failed reading 1Password export: ");
+
+  import_secrets(service, store_name, maybe_file_name.is_none(), import_set, dry_run);
+}
+
+pub fn import_pass(service: Arc<dyn TrustlessService>, store_name: String, store_dir: &str, gpg: &str, dry_run: bool) {
+  let import_set = pass_store::read_store(Path::new(store_dir), gpg).ok_or_exit("Failed reading password store: ");
+
+  import_secrets(service, store_name, false, import_set, dry_run);
+}
+
+pub fn import_browser_csv(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  maybe_file_name: Option<&str>,
+  dry_run: bool,
+) {
+  let data = read_import_data(maybe_file_name);
+  let import_set = browser_csv::read_export(&data).ok_or_exit("Failed reading csv export: ");
+
+  import_secrets(service, store_name, maybe_file_name.is_none(), import_set, dry_run);
+}
+
+fn import_secrets(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  from_stdin: bool,
+  mut import_set: ImportSet,
+  dry_run: bool,
+) {
+  let secrets_store = open_store_for_import(&service, &store_name, from_stdin);
+  let mut known: Vec<(String, Vec<String>)> = secrets_store
+    .list(&SecretListFilter::default())
+    .ok_or_exit("List secrets: ")
+    .entries
+    .iter()
+    .map(|entry_match| (entry_match.entry.name.clone(), entry_match.entry.urls.clone()))
+    .collect();
+  let mut secrets = Vec::with_capacity(import_set.secrets.len());
+
+  for versions in import_set.secrets.drain(..) {
+    let current = match versions.last() {
+      Some(current) => current,
+      None => continue,
+    };
+
+    if is_duplicate(&known, &current.name, &current.urls) {
+      import_set.skipped.push(SkippedItem {
+        name: current.name.clone(),
+        reason: "Already exists".to_string(),
+      });
+      continue;
+    }
+    known.push((current.name.clone(), current.urls.clone()));
+    secrets.push(versions);
+  }
+
+  if dry_run {
+    println!("Would import {} secrets:", secrets.len());
+    for versions in &secrets {
+      if let Some(current) = versions.last() {
+        println!(
+          "  {:<10} {} ({} versions){}{}",
+          current.secret_type.to_string(),
+          current.name,
+          versions.len(),
+          if current.tags.is_empty() {
+            "".to_string()
+          } else {
+            format!(" [{}]", current.tags.join(", "))
+          },
+          if current.deleted { " (deleted)" } else { "" },
+        );
+      }
+    }
+  } else {
+    let imported = add_current_versions(&service, secrets_store.as_ref(), secrets, &mut import_set.skipped);
+
+    // The history is only added once the index knows the current versions, so that a breached password in
+    // an old version does not get the version rejected
+    secrets_store.update_index().ok_or_exit("Index update");
+    for (secret_id, versions) in imported {
+      for mut version in versions {
+        version.secret_id = secret_id.clone();
+        secrets_store.add(version).ok_or_exit("Add secret version");
+      }
+    }
+    secrets_store.update_index().ok_or_exit("Index update");
   }
 
+  if !import_set.skipped.is_empty() {
+    println!("Skipped {} items:", import_set.skipped.len());
+    for skipped in &import_set.skipped {
+      println!("  {}: {}", skipped.name, skipped.reason);
+    }
+  }
+}
+
+/// Add the current version of each secret, secrets with a rejected current version are skipped as a whole.
+///
+/// Returns the ids of the imported secrets along with their remaining versions (i.e. the history).
+fn add_current_versions(
+  service: &Arc<dyn TrustlessService>,
+  secrets_store: &dyn SecretsStore,
+  secrets: Vec<Vec<SecretVersion>>,
+  skipped: &mut Vec<SkippedItem>,
+) -> Vec<(String, Vec<SecretVersion>)> {
+  let mut imported = Vec::with_capacity(secrets.len());
+
+  for mut versions in secrets {
+    let mut current = match versions.pop() {
+      Some(current) => current,
+      None => continue,
+    };
+    let secret_id = service.generate_id().ok_or_exit("Generate id");
+    let name = current.name.clone();
+
+    eprintln!("Importing secret {}", name);
+    current.secret_id = secret_id.clone();
+    match secrets_store.add(current) {
+      Ok(_) => imported.push((secret_id, versions)),
+      Err(error @ SecretStoreError::BreachedPassword(_)) => skipped.push(SkippedItem {
+        name,
+        reason: error.to_string(),
+      }),
+      Err(error) => exit_with_error("Add secret version", error),
+    }
+  }
+
+  imported
+}
+
+/// A secret is considered a duplicate if there already is one with the same name and a common url
+/// (or if both do not have any url at all).
+fn is_duplicate(known: &[(String, Vec<String>)], name: &str, urls: &[String]) -> bool {
+  let normalize = |url: &String| url.trim_end_matches('/').to_lowercase();
+
+  known.iter().any(|(known_name, known_urls)| {
+    known_name == name
+      && ((known_urls.is_empty() && urls.is_empty())
+        || known_urls
+          .iter()
+          .any(|known_url| urls.iter().any(|url| normalize(url) == normalize(known_url))))
+  })
+}
+
+pub fn import_v1(service: Arc<dyn TrustlessService>, store_name: String, maybe_file_name: Option<&str>) {
+  let import_stream: Box<dyn BufRead> = match maybe_file_name {
+    Some(file_name) => {
+      let file = File::open(file_name).ok_or_exit(format!("Failed opening {}", file_name));
+      Box::new(BufReader::new(file))
+    }
+    None => Box::new(BufReader::new(stdin())),
+  };
+  let secrets_store = open_store_for_import(&service, &store_name, maybe_file_name.is_none());
+
   for maybe_line in import_stream.lines() {
     let line = maybe_line.ok_or_exit("IO Error");
     let mut secret = serde_json::from_str::<SecretV1>(&line).ok_or_exit("Invalid format");
@@ -42,18 +303,13 @@ pub fn import_v1(service: Arc<dyn TrustlessService>, store_name: String, maybe_f
     eprintln!("Importing secret {}", secret.id);
 
     for v1_version in secret.versions.iter_mut() {
-      let version = SecretVersion {
-        secret_id: secret.id.to_string(),
-        secret_type: secret.secret_type,
-        timestamp: v1_version.timestamp,
-        name: v1_version.name.clone(),
-        tags: v1_version.tags.take().unwrap_or_default(),
-        urls: v1_version.urls.take().unwrap_or_default(),
-        attachments: v1_version.attachments.take().unwrap_or_default(),
-        properties: v1_version.properties.clone(),
-        deleted: v1_version.deleted,
-        recipients: vec![],
-      };
+      let mut version = SecretVersion::new(&secret.id, secret.secret_type, &v1_version.name, v1_version.timestamp);
+
+      version.tags = v1_version.tags.take().unwrap_or_default();
+      version.urls = v1_version.urls.take().unwrap_or_default();
+      version.attachments = v1_version.attachments.take().unwrap_or_default();
+      version.properties = v1_version.properties.clone();
+      version.deleted = v1_version.deleted;
 
       secrets_store.add(version).ok_or_exit("Add secret version");
     }
@@ -2,7 +2,7 @@ use std::process;
 
 use atty::Stream;
 use cursive::traits::{Boxable, Identifiable};
-use cursive::views::{Dialog, DummyView, EditView, LinearLayout, TextView};
+use cursive::views::{Checkbox, Dialog, DummyView, EditView, LinearLayout, TextView};
 use cursive::Cursive;
 
 use crate::commands::add_identity::add_identity_dialog;
@@ -25,28 +25,28 @@ pub fn init(service: Arc<dyn TrustlessService>, maybe_store_name: Option<String>
   let store_name = maybe_store_name.unwrap_or_else(|| "t-rust-less-store".to_string());
   let store_configs = match service.list_stores() {
     Ok(configs) => configs,
-    Err(err) => {
-      exit_with_error(
-        format!("Checking exsting configuration for store {}: ", store_name),
-        err,
-      );
-      unreachable!()
-    }
+    Err(err) => exit_with_error(
+      format!("Checking exsting configuration for store {}: ", store_name),
+      err,
+    ),
   };
   let maybe_config = store_configs
     .iter()
     .find(|config| config.name.as_str() == store_name.as_str());
   let store_path = match maybe_config {
-    Some(ref config) => match Url::parse(&config.store_url) {
+    Some(config) => match Url::parse(&config.store_url) {
       Ok(url) => url.path().to_string(),
       _ => default_store_dir(&store_name).to_string_lossy().to_string(),
     },
     _ => default_store_dir(&store_name).to_string_lossy().to_string(),
   };
   let autolock_timeout_secs = match maybe_config {
-    Some(ref config) => config.autolock_timeout_secs,
+    Some(config) => config.autolock_timeout_secs,
     _ => default_autolock_timeout().as_secs(),
   };
+  let full_text_index = maybe_config.map(|config| config.full_text_index).unwrap_or(false);
+  let compress_blocks = maybe_config.map(|config| config.compress_blocks).unwrap_or(false);
+  let capnp_payload = maybe_config.map(|config| config.capnp_payload).unwrap_or(false);
 
   let mut siv = create_tui();
 
@@ -72,6 +72,30 @@ pub fn init(service: Arc<dyn TrustlessService>, maybe_store_name: Option<String>
           EditView::new()
             .content(autolock_timeout_secs.to_string())
             .with_name("autolock_timeout"),
+        )
+        .child(DummyView {})
+        .child(
+          LinearLayout::horizontal()
+            .child(
+              Checkbox::new()
+                .with_checked(full_text_index)
+                .with_name("full_text_index"),
+            )
+            .child(TextView::new(" Full-text index (usernames, notes and other fields)")),
+        )
+        .child(
+          LinearLayout::horizontal()
+            .child(
+              Checkbox::new()
+                .with_checked(compress_blocks)
+                .with_name("compress_blocks"),
+            )
+            .child(TextView::new(" Compress secrets and index")),
+        )
+        .child(
+          LinearLayout::horizontal()
+            .child(Checkbox::new().with_checked(capnp_payload).with_name("capnp_payload"))
+            .child(TextView::new(" Binary secret format (not readable by older versions)")),
         ),
     )
     .button("Abort", Cursive::quit)
@@ -108,14 +132,18 @@ fn store_config(s: &mut Cursive) {
     s,
     "Autolock timeout has to be a positive integer:\n{}"
   );
+  let full_text_index = s.find_name::<Checkbox>("full_text_index").unwrap().is_checked();
+  let compress_blocks = s.find_name::<Checkbox>("compress_blocks").unwrap().is_checked();
+  let capnp_payload = s.find_name::<Checkbox>("capnp_payload").unwrap().is_checked();
   let store_configs = try_with_dialog!(service.list_stores(), s, "Failed reading existing configuration:\n{}");
-  let client_id = match store_configs
+  let previous = store_configs
     .iter()
-    .find(|config| config.name.as_str() == store_name.as_str())
-  {
+    .find(|config| config.name.as_str() == store_name.as_str());
+  let client_id = match previous {
     Some(previous) => previous.client_id.clone(),
     None => generate_id(64),
   };
+  let max_attachment_size = previous.and_then(|previous| previous.max_attachment_size);
 
   if store_path.is_empty() {
     s.add_layer(Dialog::info("Store directory must not be empty"));
@@ -124,13 +152,17 @@ fn store_config(s: &mut Cursive) {
   try_with_dialog!(fs::create_dir_all(&store_path), s, "Failed creating directory:\n{}");
 
   let store_url = Url::from_directory_path(store_path).unwrap();
-  let secrets_store_url = format!("multilane+{}", store_url.to_string());
+  let secrets_store_url = format!("multilane+{}", store_url);
   let config = StoreConfig {
     name: store_name.to_string(),
     client_id,
     store_url: secrets_store_url,
     autolock_timeout_secs,
     default_identity_id: None,
+    full_text_index,
+    max_attachment_size,
+    compress_blocks,
+    capnp_payload,
   };
 
   try_with_dialog!(service.upsert_store_config(config), s, "Failed to store config:\n{}");
@@ -0,0 +1,426 @@
+use crate::commands::tui::create_tui;
+use crate::commands::{create_restricted, unlock_store, unlock_store_with_helper};
+use crate::error::{exit_with_code, ExtResult, EXIT_FAILURE, EXIT_NOT_FOUND};
+use atty::Stream;
+use log::warn;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, stdin, stdout, Read, Write};
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use t_rust_less_lib::api::{Secret, SecretListFilter};
+use t_rust_less_lib::memguard::ZeroizeBytesBuffer;
+use t_rust_less_lib::pinentry::PassphraseHelper;
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::TrustlessService;
+use zeroize::Zeroize;
+
+const REFERENCE_SCHEME: &str = "trl://";
+
+/// Reference to a secret property inside a template, i.e. `{{ trl://store/secret-name/property }}`.
+///
+/// An empty store (`trl:///secret-name/property`) refers to the store selected on the command line.
+#[derive(Debug, PartialEq, Eq)]
+struct SecretReference {
+  store_name: String,
+  secret_name: String,
+  property: String,
+}
+
+impl SecretReference {
+  fn parse(reference: &str, default_store: &str) -> Option<SecretReference> {
+    let path = reference.strip_prefix(REFERENCE_SCHEME)?;
+    let store_end = path.find('/')?;
+    let property_start = path.rfind('/')?;
+
+    if property_start <= store_end + 1 || property_start + 1 >= path.len() {
+      return None;
+    }
+
+    let store_name = match &path[..store_end] {
+      "" => default_store,
+      store_name => store_name,
+    };
+
+    Some(SecretReference {
+      store_name: store_name.to_string(),
+      secret_name: path[store_end + 1..property_start].to_string(),
+      property: path[property_start + 1..].to_string(),
+    })
+  }
+}
+
+/// A part of a parsed template: Either plain text to be copied as is or a reference to a secret.
+#[derive(Debug, PartialEq, Eq)]
+enum TemplatePart<'a> {
+  Text(&'a str),
+  Reference(SecretReference),
+}
+
+/// Split a template into text and secret references.
+///
+/// Placeholders that do not start with `trl://` are left untouched, so that templates for other
+/// tools (helm, jinja ...) survive. A placeholder prefixed by a backslash (`\{{ trl://... }}`) is
+/// not resolved either, only the backslash is removed.
+fn parse_template<'a>(template: &'a str, default_store: &str) -> Vec<TemplatePart<'a>> {
+  let mut parts = Vec::new();
+  let mut remaining = template;
+
+  while let Some(start) = remaining.find("{{") {
+    if remaining[..start].ends_with('\\') {
+      if start > 1 {
+        parts.push(TemplatePart::Text(&remaining[..start - 1]));
+      }
+      parts.push(TemplatePart::Text("{{"));
+      remaining = &remaining[start + 2..];
+      continue;
+    }
+    let end = match remaining[start..].find("}}") {
+      Some(end) => start + end,
+      None => break,
+    };
+
+    match SecretReference::parse(remaining[start + 2..end].trim(), default_store) {
+      Some(reference) => {
+        if start > 0 {
+          parts.push(TemplatePart::Text(&remaining[..start]));
+        }
+        parts.push(TemplatePart::Reference(reference));
+      }
+      None => parts.push(TemplatePart::Text(&remaining[..end + 2])),
+    }
+    remaining = &remaining[end + 2..];
+  }
+  if !remaining.is_empty() {
+    parts.push(TemplatePart::Text(remaining));
+  }
+
+  parts
+}
+
+/// Lookup of the values of secret references.
+trait ResolveReference {
+  fn resolve(&mut self, reference: &SecretReference) -> Option<&str>;
+}
+
+/// Render a parsed template to `rendered`, the references that could not be resolved are returned
+/// (and replaced by an empty string).
+fn render_template<R: ResolveReference, W: Write>(
+  parts: Vec<TemplatePart>,
+  resolver: &mut R,
+  rendered: &mut W,
+) -> io::Result<Vec<String>> {
+  let mut missing = Vec::new();
+
+  for part in parts {
+    match part {
+      TemplatePart::Text(text) => rendered.write_all(text.as_bytes())?,
+      TemplatePart::Reference(reference) => match resolver.resolve(&reference) {
+        Some(value) => rendered.write_all(value.as_bytes())?,
+        None => missing.push(format!(
+          "{}{}/{}/{}",
+          REFERENCE_SCHEME, reference.store_name, reference.secret_name, reference.property
+        )),
+      },
+    }
+  }
+
+  Ok(missing)
+}
+
+/// An opened store with the ids of all its secrets by name (names are not necessarily unique).
+type StoreWithIds = (Arc<dyn SecretsStore>, HashMap<String, Vec<String>>);
+
+struct SecretResolver {
+  service: Arc<dyn TrustlessService>,
+  stores: HashMap<String, StoreWithIds>,
+  secrets: HashMap<(String, String), Secret>,
+}
+
+impl SecretResolver {
+  fn new(service: Arc<dyn TrustlessService>) -> SecretResolver {
+    SecretResolver {
+      service,
+      stores: HashMap::new(),
+      secrets: HashMap::new(),
+    }
+  }
+
+  fn open_store(&mut self, store_name: &str) -> &StoreWithIds {
+    let service = &self.service;
+
+    self.stores.entry(store_name.to_string()).or_insert_with(|| {
+      let secrets_store = service
+        .open_store(store_name)
+        .ok_or_exit(format!("Failed opening store {}: ", store_name));
+      let status = secrets_store.status().ok_or_exit("Get status");
+
+      if status.locked {
+        match PassphraseHelper::from_env() {
+          Some(helper) if !atty::is(Stream::Stdin) || !atty::is(Stream::Stdout) => {
+            if !unlock_store_with_helper(service, &secrets_store, store_name, &helper) {
+              eprintln!("Unlock cancelled");
+              process::exit(1);
+            }
+          }
+          _ => {
+            let mut siv = create_tui();
+            unlock_store(&mut siv, &secrets_store, store_name);
+          }
+        }
+      }
+
+      let list = secrets_store
+        .list(&SecretListFilter::default())
+        .ok_or_exit("List entries");
+      let mut ids_by_name: HashMap<String, Vec<String>> = HashMap::new();
+      for entry_match in &list.entries {
+        ids_by_name
+          .entry(entry_match.entry.name.clone())
+          .or_default()
+          .push(entry_match.entry.id.clone());
+      }
+
+      (secrets_store, ids_by_name)
+    })
+  }
+}
+
+impl ResolveReference for SecretResolver {
+  fn resolve(&mut self, reference: &SecretReference) -> Option<&str> {
+    let key = (reference.store_name.clone(), reference.secret_name.clone());
+
+    if !self.secrets.contains_key(&key) {
+      let (secrets_store, ids_by_name) = self.open_store(&reference.store_name);
+      let secret_id = match ids_by_name.get(&reference.secret_name)?.as_slice() {
+        [secret_id] => secret_id,
+        _ => exit_with_code(
+          EXIT_FAILURE,
+          "Secret name is ambiguous: ",
+          format!("{}{}/{}", REFERENCE_SCHEME, reference.store_name, reference.secret_name),
+        ),
+      };
+      let secret = secrets_store.get(secret_id).ok_or_exit("Get secret");
+
+      self.secrets.insert(key.clone(), secret);
+    }
+
+    self.secrets[&key]
+      .current
+      .properties
+      .get(&reference.property)
+      .map(String::as_str)
+  }
+}
+
+pub fn inject(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  maybe_template_file: Option<&str>,
+  maybe_output_file: Option<&str>,
+  strict: bool,
+  fifo: bool,
+) {
+  let mut template = String::new();
+
+  match maybe_template_file {
+    Some(template_file) => File::open(template_file)
+      .and_then(|mut file| file.read_to_string(&mut template))
+      .ok_or_exit(format!("Failed reading template {}: ", template_file)),
+    None => stdin()
+      .read_to_string(&mut template)
+      .ok_or_exit("Failed reading template: "),
+  };
+
+  let mut resolver = SecretResolver::new(service);
+  let mut rendered = ZeroizeBytesBuffer::with_capacity(template.len() * 2);
+  let missing =
+    render_template(parse_template(&template, &store_name), &mut resolver, &mut rendered).ok_or_exit("Render template");
+  template.zeroize();
+
+  if !missing.is_empty() {
+    if strict {
+      for reference in missing {
+        eprintln!("Missing secret: {}", reference);
+      }
+      process::exit(EXIT_NOT_FOUND);
+    }
+    for reference in missing {
+      warn!("Missing secret (replaced by empty string): {}", reference);
+    }
+  }
+
+  let result = match maybe_output_file {
+    Some(output_file) if fifo => write_fifo(Path::new(output_file), &rendered),
+    Some(output_file) => create_restricted(Path::new(output_file)).and_then(|mut file| file.write_all(&rendered)),
+    None => stdout().write_all(&rendered),
+  };
+  result.ok_or_exit("Write rendered template: ");
+}
+
+/// Write to a named pipe, so that the plaintext never hits the disk.
+///
+/// The pipe is created if necessary and removed once the content was consumed by the reader (a pipe
+/// that already existed is left as is).
+#[cfg(unix)]
+fn write_fifo(path: &Path, content: &[u8]) -> io::Result<()> {
+  use std::ffi::CString;
+  use std::os::unix::ffi::OsStrExt;
+  use std::os::unix::fs::FileTypeExt;
+
+  let created = match fs::metadata(path) {
+    Ok(metadata) if metadata.file_type().is_fifo() => false,
+    Ok(_) => {
+      return Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "Output exists and is not a fifo",
+      ))
+    }
+    Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+      let c_path = CString::new(path.as_os_str().as_bytes())?;
+
+      if unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) } != 0 {
+        return Err(io::Error::last_os_error());
+      }
+      true
+    }
+    Err(error) => return Err(error),
+  };
+
+  eprintln!("Waiting for reader on {}", path.to_string_lossy());
+  // Opening a fifo for writing blocks until there is a reader
+  let result = OpenOptions::new()
+    .write(true)
+    .open(path)
+    .and_then(|mut fifo| fifo.write_all(content));
+
+  if created {
+    fs::remove_file(path)?;
+  }
+
+  result
+}
+
+#[cfg(not(unix))]
+fn write_fifo(_: &Path, _: &[u8]) -> io::Result<()> {
+  Err(io::Error::new(
+    io::ErrorKind::Other,
+    "Named pipes are not supported on this platform",
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+
+  fn reference(store_name: &str, secret_name: &str, property: &str) -> SecretReference {
+    SecretReference {
+      store_name: store_name.to_string(),
+      secret_name: secret_name.to_string(),
+      property: property.to_string(),
+    }
+  }
+
+  struct TestResolver(HashMap<(String, String, String), String>);
+
+  impl ResolveReference for TestResolver {
+    fn resolve(&mut self, reference: &SecretReference) -> Option<&str> {
+      self
+        .0
+        .get(&(
+          reference.store_name.clone(),
+          reference.secret_name.clone(),
+          reference.property.clone(),
+        ))
+        .map(String::as_str)
+    }
+  }
+
+  #[test]
+  fn test_parse_reference() {
+    assert_that(&SecretReference::parse("trl://store/secret/password", "default"))
+      .is_equal_to(Some(reference("store", "secret", "password")));
+    assert_that(&SecretReference::parse("trl:///secret/password", "default"))
+      .is_equal_to(Some(reference("default", "secret", "password")));
+    assert_that(&SecretReference::parse(
+      "trl://store/path/to/secret/username",
+      "default",
+    ))
+    .is_equal_to(Some(reference("store", "path/to/secret", "username")));
+    assert_that(&SecretReference::parse(
+      "trl://store/secret/unknown_property",
+      "default",
+    ))
+    .is_equal_to(Some(reference("store", "secret", "unknown_property")));
+
+    assert_that(&SecretReference::parse("http://store/secret/password", "default")).is_none();
+    assert_that(&SecretReference::parse("trl://store/secret", "default")).is_none();
+    assert_that(&SecretReference::parse("trl://store//password", "default")).is_none();
+    assert_that(&SecretReference::parse("trl://store/secret/", "default")).is_none();
+    assert_that(&SecretReference::parse("trl://store", "default")).is_none();
+  }
+
+  #[test]
+  fn test_parse_template() {
+    assert_that(&parse_template(
+      "user={{ trl://store/db/username }}\npass={{trl:///db/password}}\n",
+      "default",
+    ))
+    .is_equal_to(vec![
+      TemplatePart::Text("user="),
+      TemplatePart::Reference(reference("store", "db", "username")),
+      TemplatePart::Text("\npass="),
+      TemplatePart::Reference(reference("default", "db", "password")),
+      TemplatePart::Text("\n"),
+    ]);
+    // Placeholders of other tools are kept as they are
+    assert_that(&parse_template("{{ .Values.name }}: {{ trl://s/n/p }}", "default")).is_equal_to(vec![
+      TemplatePart::Text("{{ .Values.name }}"),
+      TemplatePart::Text(": "),
+      TemplatePart::Reference(reference("s", "n", "p")),
+    ]);
+    // Escaped placeholders are not resolved
+    assert_that(&parse_template("a \\{{ trl://s/n/p }} b", "default")).is_equal_to(vec![
+      TemplatePart::Text("a "),
+      TemplatePart::Text("{{"),
+      TemplatePart::Text(" trl://s/n/p }} b"),
+    ]);
+    // Unterminated references are plain text
+    assert_that(&parse_template("a {{ trl://s/n/p", "default"))
+      .is_equal_to(vec![TemplatePart::Text("a {{ trl://s/n/p")]);
+    assert_that(&parse_template("{{ trl://s/n/p }} {{ trl://s/n/q", "default")).is_equal_to(vec![
+      TemplatePart::Reference(reference("s", "n", "p")),
+      TemplatePart::Text(" {{ trl://s/n/q"),
+    ]);
+  }
+
+  #[test]
+  fn test_render_template() {
+    let mut resolver = TestResolver(
+      vec![(
+        ("store".to_string(), "db".to_string(), "password".to_string()),
+        "secret".to_string(),
+      )]
+      .into_iter()
+      .collect(),
+    );
+    let mut rendered = Vec::new();
+    let missing = render_template(
+      parse_template(
+        "pass={{ trl:///db/password }} other={{ trl:///db/unknown_property }} {{ trl://other/db/password }}",
+        "store",
+      ),
+      &mut resolver,
+      &mut rendered,
+    )
+    .unwrap();
+
+    assert_that(&String::from_utf8(rendered).unwrap()).is_equal_to("pass=secret other= ".to_string());
+    assert_that(&missing).is_equal_to(vec![
+      "trl://store/db/unknown_property".to_string(),
+      "trl://other/db/password".to_string(),
+    ]);
+  }
+}
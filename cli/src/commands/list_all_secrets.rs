@@ -0,0 +1,278 @@
+use crate::commands::list_secrets::entry_list_item;
+use crate::commands::tui::create_tui;
+use crate::error::ExtResult;
+use crate::output::{print_json, OutputFormat};
+use crate::view::{show_copy_to_store_dialog, SecretView};
+use atty::Stream;
+use cursive::event::{Event, Key};
+use cursive::theme::{BaseColor, Color};
+use cursive::traits::{Boxable, Identifiable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::views::{EditView, LinearLayout, SelectView};
+use cursive::Cursive;
+use serde_derive::Serialize;
+use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use t_rust_less_lib::api::{
+  self, EventHandler, EventSubscription, SecretEntry, SecretListFilter, SecretQuery, StoreEntryMatch,
+  PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+};
+use t_rust_less_lib::service::TrustlessService;
+
+/// Secret of the combined list (i.e. tagged with the name of its store)
+#[derive(Serialize)]
+struct StoreEntry<'a> {
+  store: &'a str,
+  #[serde(flatten)]
+  entry: &'a SecretEntry,
+}
+
+/// List the secrets of all unlocked stores.
+pub fn list_all_secrets(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  filter: SecretListFilter,
+  format: OutputFormat,
+) {
+  if atty::is(Stream::Stdout) {
+    let mut siv = create_tui();
+
+    let config_changed = Arc::new(AtomicBool::new(false));
+    let config_subscription = service
+      .add_event_handler(Box::new(ConfigChangedHandler(config_changed.clone())))
+      .ok_or_exit("Add event handler");
+
+    list_all_secrets_ui(
+      &mut siv,
+      ListAllUIState {
+        service,
+        filter,
+        config_changed,
+        _config_subscription: config_subscription,
+      },
+      store_name,
+    );
+  } else {
+    let list = service.list_all(&filter).ok_or_exit("List entries");
+
+    match format {
+      OutputFormat::Plain => {
+        for store_match in list.entries.iter() {
+          let entry = &store_match.entry_match.entry;
+          println!("{}\t{}\t{}", store_match.store_name, entry.id, entry.name);
+        }
+      }
+      OutputFormat::Json => print_json(
+        &list
+          .entries
+          .iter()
+          .map(|m| StoreEntry {
+            store: &m.store_name,
+            entry: &m.entry_match.entry,
+          })
+          .collect::<Vec<_>>(),
+      ),
+    }
+  }
+}
+
+struct ListAllUIState {
+  service: Arc<dyn TrustlessService>,
+  filter: SecretListFilter,
+  config_changed: Arc<AtomicBool>,
+  _config_subscription: Box<dyn EventSubscription>,
+}
+
+/// Flag changes of the configuration (i.e. stores might have been added or removed).
+struct ConfigChangedHandler(Arc<AtomicBool>);
+
+impl EventHandler for ConfigChangedHandler {
+  fn handle(&self, event: api::Event) {
+    if let api::Event::ConfigChanged = event {
+      self.0.store(true, Ordering::Relaxed);
+    }
+  }
+}
+
+fn list_all_secrets_ui(siv: &mut Cursive, initial_state: ListAllUIState, store_name: String) {
+  let mut name_search = EditView::new();
+  if let Some(query) = &initial_state.filter.query {
+    name_search.set_content(query.to_string());
+  }
+  name_search.set_on_edit(update_query);
+
+  let list = initial_state
+    .service
+    .list_all(&initial_state.filter)
+    .ok_or_exit("List entries");
+  let store_width = store_column_width(&list.entries);
+  let mut entry_select = SelectView::new();
+  entry_select.add_all(
+    list
+      .entries
+      .iter()
+      .map(|store_match| store_list_item(store_match, store_width)),
+  );
+  entry_select.set_on_select(update_selection);
+
+  // The secret view is pointed to the store of the selected entry
+  let secrets_store = initial_state.service.open_store(&store_name).ok_or_exit("Open store");
+  let mut secret_view = SecretView::new(initial_state.service.clone(), store_name, secrets_store, None);
+  if let Some(first) = list.entries.first() {
+    let secrets_store = initial_state
+      .service
+      .open_store(&first.store_name)
+      .ok_or_exit("Open store");
+
+    secret_view.show_store_secret(&first.store_name, secrets_store, &first.entry_match.entry.id);
+  }
+  let screen_size = siv.screen_size();
+
+  siv.set_fps(2);
+  siv.add_global_callback(Key::Esc, Cursive::quit);
+  siv.add_global_callback(
+    Event::CtrlChar('a'),
+    secret_to_clipboard(&[PROPERTY_USERNAME, PROPERTY_PASSWORD, PROPERTY_TOTP_URL]),
+  );
+  siv.add_global_callback(Event::CtrlChar('u'), secret_to_clipboard(&[PROPERTY_USERNAME]));
+  siv.add_global_callback(Event::CtrlChar('p'), secret_to_clipboard(&[PROPERTY_PASSWORD]));
+  siv.add_global_callback(Event::CtrlChar('o'), secret_to_clipboard(&[PROPERTY_TOTP_URL]));
+  siv.add_global_callback(Event::CtrlChar('t'), copy_to_store);
+  siv.add_global_callback(Event::Refresh, |s| {
+    let config_changed = s
+      .with_user_data(|state: &mut ListAllUIState| {
+        state.service.check_autolock();
+        state.service.check_config();
+        state.config_changed.swap(false, Ordering::Relaxed)
+      })
+      .unwrap_or(false);
+
+    if config_changed {
+      update_list(s);
+    }
+  });
+  siv.add_fullscreen_layer(
+    LinearLayout::vertical()
+      .child(name_search.with_name("name_search").full_width())
+      .child(
+        LinearLayout::horizontal()
+          .child(
+            entry_select
+              .with_name("entry_list")
+              .scrollable()
+              .min_width(screen_size.x / 2),
+          )
+          .child(secret_view.with_name("secret_view").min_width(screen_size.x / 2))
+          .full_screen(),
+      ),
+  );
+  siv.set_user_data(initial_state);
+
+  siv.run();
+}
+
+fn store_column_width(entries: &[StoreEntryMatch]) -> usize {
+  entries
+    .iter()
+    .map(|store_match| store_match.store_name.chars().count())
+    .max()
+    .unwrap_or(0)
+}
+
+fn store_list_item(store_match: &StoreEntryMatch, store_width: usize) -> (StyledString, (String, SecretEntry)) {
+  let (styled_name, entry) = entry_list_item(store_match.entry_match.clone());
+  let mut label = StyledString::styled(
+    format!("{:width$} ", store_match.store_name, width = store_width),
+    Color::Light(BaseColor::Blue),
+  );
+
+  label.append(styled_name);
+
+  (label, (store_match.store_name.clone(), entry))
+}
+
+fn update_query(s: &mut Cursive, query: &str, _: usize) {
+  // Keep the current list while the query is incomplete (e.g. an unclosed quote)
+  if SecretQuery::parse(query).is_err() {
+    return;
+  }
+  {
+    let state = s.user_data::<ListAllUIState>().unwrap();
+    state.filter.query = if query.is_empty() {
+      None
+    } else {
+      Some(query.to_string())
+    };
+  }
+  update_list(s);
+}
+
+fn update_list(s: &mut Cursive) {
+  let list = {
+    let state = s.user_data::<ListAllUIState>().unwrap();
+
+    state.service.list_all(&state.filter).ok_or_exit("List entries")
+  };
+  let store_width = store_column_width(&list.entries);
+
+  match list.entries.first() {
+    Some(first) => update_selection(s, &(first.store_name.clone(), first.entry_match.entry.clone())),
+    None => {
+      let mut secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+      secret_view.clear();
+    }
+  }
+  let mut entry_select = s.find_name::<SelectView<(String, SecretEntry)>>("entry_list").unwrap();
+  entry_select.clear();
+  entry_select.add_all(
+    list
+      .entries
+      .iter()
+      .map(|store_match| store_list_item(store_match, store_width)),
+  );
+}
+
+fn update_selection(s: &mut Cursive, (store_name, entry): &(String, SecretEntry)) {
+  let secrets_store = {
+    let state = s.user_data::<ListAllUIState>().unwrap();
+    state.service.open_store(store_name).ok_or_exit("Open store")
+  };
+  let mut secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+
+  secret_view.show_store_secret(store_name, secrets_store, &entry.id);
+}
+
+fn secret_to_clipboard(properties: &'static [&'static str]) -> impl Fn(&mut Cursive) {
+  move |s: &mut Cursive| {
+    let (maybe_secret, store_name) = {
+      let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+      (secret_view.current_secret(), secret_view.store_name().to_string())
+    };
+    let state = s.user_data::<ListAllUIState>().unwrap();
+
+    if let Some(secret) = maybe_secret {
+      state
+        .service
+        .secret_to_clipboard(
+          &store_name,
+          &secret.current_block_id,
+          properties,
+          &env::var("DISPLAY").unwrap_or_else(|_| ":0".to_string()),
+        )
+        .ok_or_exit("Copy to clipboard");
+    }
+  }
+}
+
+fn copy_to_store(s: &mut Cursive) {
+  let (maybe_secret, store_name) = {
+    let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    (secret_view.current_secret(), secret_view.store_name().to_string())
+  };
+  let service = s.user_data::<ListAllUIState>().unwrap().service.clone();
+
+  if let Some(secret) = maybe_secret {
+    show_copy_to_store_dialog(s, service, &store_name, &secret);
+  }
+}
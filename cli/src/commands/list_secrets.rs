@@ -1,25 +1,35 @@
 use crate::commands::tui::create_tui;
 use crate::commands::unlock_store;
 use crate::error::ExtResult;
-use crate::view::{SecretView, StatusView};
+use crate::output::{print_json, OutputFormat};
+use crate::view::{
+  show_copy_to_store_dialog, show_edit_dialog, HealthReportView, SecretHistoryView, SecretView, StatusView,
+  HEALTH_REPORT_VIEW_NAME, SECRET_HISTORY_VIEW_NAME,
+};
 use atty::Stream;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use cursive::event::{Event, Key};
-use cursive::theme::Effect;
+use cursive::theme::{BaseColor, Color, Effect};
 use cursive::traits::{Boxable, Identifiable, Scrollable};
 use cursive::utils::markup::StyledString;
 use cursive::view::View;
-use cursive::views::{EditView, LinearLayout, ResizedView, SelectView, TextContent};
+use cursive::views::{Dialog, EditView, LinearLayout, ResizedView, SelectView, TextContent};
 use cursive::{Cursive, Vec2};
 use std::env;
 use std::sync::Arc;
 use t_rust_less_lib::api::{
-  SecretEntry, SecretEntryMatch, SecretListFilter, Status, PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+  Secret, SecretEntry, SecretEntryMatch, SecretListFilter, SecretQuery, Status, PROPERTY_PASSWORD, PROPERTY_TOTP_URL,
+  PROPERTY_USERNAME,
 };
 use t_rust_less_lib::secrets_store::SecretsStore;
 use t_rust_less_lib::service::TrustlessService;
 
-pub fn list_secrets(service: Arc<dyn TrustlessService>, store_name: String, filter: SecretListFilter) {
+pub fn list_secrets(
+  service: Arc<dyn TrustlessService>,
+  store_name: String,
+  filter: SecretListFilter,
+  format: OutputFormat,
+) {
   let secrets_store = service
     .open_store(&store_name)
     .ok_or_exit(format!("Failed opening store {}: ", store_name));
@@ -46,12 +56,24 @@ pub fn list_secrets(service: Arc<dyn TrustlessService>, store_name: String, filt
   } else {
     let list = secrets_store.list(&filter).ok_or_exit("List entries");
 
-    for entry in list.entries.iter() {
-      println!("{:?}", entry);
+    match format {
+      OutputFormat::Plain => {
+        for entry_match in list.entries.iter() {
+          print!("{}\t{}", entry_match.entry.id, entry_match.entry.name);
+          for text_match in entry_match.text_matches.iter() {
+            print!("\t{}: {}", text_match.property, text_match.text);
+          }
+          println!();
+        }
+      }
+      OutputFormat::Json => print_json(&list.entries.iter().map(|m| &m.entry).collect::<Vec<_>>()),
     }
   }
 }
 
+/// Secrets that expire (or are due for rotation) within this number of days are highlighted.
+const ROTATION_WARNING_DAYS: i64 = 7;
+
 struct ListUIState {
   service: Arc<dyn TrustlessService>,
   store_name: String,
@@ -63,10 +85,10 @@ struct ListUIState {
 
 fn list_secrets_ui(siv: &mut Cursive, initial_state: ListUIState, status: Status) {
   let mut name_search = EditView::new();
-  if let Some(name_filter) = &initial_state.filter.name {
-    name_search.set_content(name_filter.to_string());
+  if let Some(query) = &initial_state.filter.query {
+    name_search.set_content(query.to_string());
   }
-  name_search.set_on_edit(update_name_filter);
+  name_search.set_on_edit(update_query);
 
   let secrets_store = initial_state.secrets_store.clone();
 
@@ -79,6 +101,11 @@ fn list_secrets_ui(siv: &mut Cursive, initial_state: ListUIState, status: Status
   siv.add_global_callback(Event::CtrlChar('u'), secret_to_clipboard(&[PROPERTY_USERNAME]));
   siv.add_global_callback(Event::CtrlChar('p'), secret_to_clipboard(&[PROPERTY_PASSWORD]));
   siv.add_global_callback(Event::CtrlChar('o'), secret_to_clipboard(&[PROPERTY_TOTP_URL]));
+  siv.add_global_callback(Event::CtrlChar('r'), show_history);
+  siv.add_global_callback(Event::CtrlChar('k'), show_health_report);
+  siv.add_global_callback(Event::CtrlChar('n'), new_secret);
+  siv.add_global_callback(Event::CtrlChar('e'), edit_secret);
+  siv.add_global_callback(Event::CtrlChar('t'), copy_to_store);
   siv.add_global_callback(Event::Refresh, update_status);
   siv.add_global_callback(Event::WindowResize, on_event);
   siv.add_fullscreen_layer(
@@ -100,13 +127,17 @@ fn list_secrets_ui(siv: &mut Cursive, initial_state: ListUIState, status: Status
   siv.run();
 }
 
-fn update_name_filter(s: &mut Cursive, name_filter: &str, _: usize) {
+fn update_query(s: &mut Cursive, query: &str, _: usize) {
+  // Keep the current list while the query is incomplete (e.g. an unclosed quote)
+  if SecretQuery::parse(query).is_err() {
+    return;
+  }
   let next_entries: Vec<SecretEntryMatch> = {
     let state = s.user_data::<ListUIState>().unwrap();
-    state.filter.name = if name_filter.is_empty() {
+    state.filter.query = if query.is_empty() {
       None
     } else {
-      Some(name_filter.to_string())
+      Some(query.to_string())
     };
 
     let mut list = state.secrets_store.list(&state.filter).ok_or_exit("List entries");
@@ -129,22 +160,46 @@ fn update_selection(s: &mut Cursive, entry: &SecretEntry) {
   secret_view.show_secret(&entry.id);
 }
 
-fn entry_list_item(entry_match: SecretEntryMatch) -> (StyledString, SecretEntry) {
-  let name = &entry_match.entry.name;
-  let mut styled_name = StyledString::new();
+fn append_highlighted(styled: &mut StyledString, text: &str, highlights: &[usize]) {
   let mut last = 0usize;
 
-  for highlight in entry_match.name_highlights.iter() {
+  for highlight in highlights.iter() {
     if *highlight > last {
-      styled_name.append_plain(name.chars().skip(last).take(highlight - last).collect::<String>());
+      styled.append_plain(text.chars().skip(last).take(highlight - last).collect::<String>());
     }
-    styled_name.append_styled(
-      name.chars().skip(*highlight).take(1).collect::<String>(),
+    styled.append_styled(
+      text.chars().skip(*highlight).take(1).collect::<String>(),
       Effect::Reverse,
     );
     last = highlight + 1;
   }
-  styled_name.append_plain(name.chars().skip(last).collect::<String>());
+  styled.append_plain(text.chars().skip(last).collect::<String>());
+}
+
+pub(super) fn entry_list_item(entry_match: SecretEntryMatch) -> (StyledString, SecretEntry) {
+  let mut styled_name = StyledString::new();
+
+  append_highlighted(&mut styled_name, &entry_match.entry.name, &entry_match.name_highlights);
+  if let Some(text_match) = entry_match.text_matches.first() {
+    styled_name.append_plain(format!(" [{}: ", text_match.property));
+    append_highlighted(&mut styled_name, &text_match.text, &text_match.highlights);
+    styled_name.append_plain("]");
+  }
+  if entry_match.entry.conflicted {
+    styled_name.append_styled(" (conflict)", Color::Light(BaseColor::Red));
+  }
+  if let Some(due_at) = entry_match.entry.due_at() {
+    let now = Utc::now();
+
+    if due_at <= now.into() {
+      styled_name.append_styled(" (expired)", Color::Light(BaseColor::Red));
+    } else if due_at <= (now + Duration::days(ROTATION_WARNING_DAYS)).into() {
+      styled_name.append_styled(
+        format!(" (due {})", due_at.format("%Y-%m-%d")),
+        Color::Light(BaseColor::Yellow),
+      );
+    }
+  }
 
   (styled_name, entry_match.entry.clone())
 }
@@ -171,12 +226,126 @@ fn secret_to_clipboard(properties: &'static [&'static str]) -> impl Fn(&mut Curs
   }
 }
 
+fn show_health_report(s: &mut Cursive) {
+  let report = {
+    let state = s.user_data::<ListUIState>().unwrap();
+    state
+      .secrets_store
+      .health_report(&Default::default())
+      .ok_or_exit("Health report")
+  };
+
+  s.add_layer(
+    Dialog::around(HealthReportView::new(&report).with_name(HEALTH_REPORT_VIEW_NAME))
+      .title("Password health")
+      .dismiss_button("Close"),
+  );
+}
+
+fn new_secret(s: &mut Cursive) {
+  show_edit(s, None)
+}
+
+fn edit_secret(s: &mut Cursive) {
+  let maybe_secret = {
+    let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    secret_view.current_secret()
+  };
+
+  if maybe_secret.is_some() {
+    show_edit(s, maybe_secret)
+  }
+}
+
+fn copy_to_store(s: &mut Cursive) {
+  let maybe_secret = {
+    let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    secret_view.current_secret()
+  };
+  let (service, store_name) = {
+    let state = s.user_data::<ListUIState>().unwrap();
+    (state.service.clone(), state.store_name.clone())
+  };
+
+  if let Some(secret) = maybe_secret {
+    show_copy_to_store_dialog(s, service, &store_name, &secret);
+  }
+}
+
+fn show_edit(s: &mut Cursive, maybe_secret: Option<Secret>) {
+  let (service, secrets_store) = {
+    let state = s.user_data::<ListUIState>().unwrap();
+    (state.service.clone(), state.secrets_store.clone())
+  };
+
+  show_edit_dialog(s, service, secrets_store, maybe_secret, secret_saved);
+}
+
+/// Refresh the list (a new secret might match the current query) and show the saved secret.
+fn secret_saved(s: &mut Cursive, secret_id: &str) {
+  let query = {
+    let state = s.user_data::<ListUIState>().unwrap();
+    state.filter.query.clone().unwrap_or_default()
+  };
+
+  update_query(s, &query, 0);
+  let mut secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+  secret_view.show_secret(secret_id);
+}
+
+fn show_history(s: &mut Cursive) {
+  let maybe_history_view = {
+    let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    secret_view.history_view()
+  };
+
+  if let Some(history_view) = maybe_history_view {
+    s.add_layer(
+      Dialog::around(history_view.with_name(SECRET_HISTORY_VIEW_NAME))
+        .title("History")
+        .button("Restore", restore_version)
+        .dismiss_button("Close"),
+    );
+  }
+}
+
+/// Restore the version selected in the history, i.e. add a copy of it as new current version.
+fn restore_version(s: &mut Cursive) {
+  let maybe_version = s
+    .call_on_name(SECRET_HISTORY_VIEW_NAME, |view: &mut SecretHistoryView| {
+      view.from_version()
+    })
+    .flatten();
+  let maybe_current_block_id = {
+    let secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    secret_view
+      .current_secret()
+      .map(|secret| secret.current_block_id.clone())
+  };
+
+  if let (Some(mut version), Some(current_block_id)) = (maybe_version, maybe_current_block_id) {
+    let secret_id = version.secret_id.clone();
+    let state = s.user_data::<ListUIState>().unwrap();
+
+    version.timestamp = Utc::now().into();
+    version.parent_block_id = Some(current_block_id);
+    version.merged_block_ids.clear();
+    state.secrets_store.add(version).ok_or_exit("Add secret version");
+    state.secrets_store.update_index().ok_or_exit("Update index");
+
+    s.pop_layer();
+    let mut secret_view = s.find_name::<SecretView>("secret_view").unwrap();
+    secret_view.show_secret(&secret_id);
+  }
+}
+
 fn update_status(s: &mut Cursive) {
   let next_status = {
     let state = s.user_data::<ListUIState>().unwrap();
     let now = Utc::now();
     if state.last_update.is_none() || (now - state.last_update.unwrap()).num_milliseconds() > 400 {
       state.service.check_autolock();
+      state.service.check_config();
       state.last_update.replace(now);
       match state.secrets_store.status() {
         Ok(status) => {
@@ -213,7 +382,7 @@ fn create_list_view(screen_size: Vec2, state: &ListUIState) -> ResizedView<Linea
   let mut entry_select = SelectView::new();
   let mut list = state.secrets_store.list(&state.filter).ok_or_exit("List entries");
   let initial_selected = list.entries.first().map(|e| e.entry.id.clone());
-  entry_select.add_all(list.entries.drain(..).into_iter().map(entry_list_item));
+  entry_select.add_all(list.entries.drain(..).map(entry_list_item));
   entry_select.set_on_select(update_selection);
   let select_width = entry_select.required_size(Vec2::zero());
 
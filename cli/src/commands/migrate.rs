@@ -0,0 +1,14 @@
+use crate::commands::open_unlocked_store;
+use crate::error::ExtCodeResult;
+use std::sync::Arc;
+use t_rust_less_lib::service::TrustlessService;
+
+/// Rewrite all secrets that are not stored in the payload format configured for the store.
+pub fn migrate(service: Arc<dyn TrustlessService>, store_name: String) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let migrated = secrets_store
+    .migrate_versions()
+    .ok_or_exit_with_code("Migrate secrets: ");
+
+  eprintln!("{} secrets migrated", migrated);
+}
@@ -1,27 +1,58 @@
 mod add_identity;
+mod add_secret;
+mod delete_secret;
+mod edit_secret;
 mod export;
 mod generate;
+mod get_secret;
+mod health;
+mod history;
 mod import;
 mod init;
+mod inject;
+mod list_all_secrets;
 mod list_identities;
 mod list_secrets;
 mod lock;
+mod migrate;
 mod status;
 pub mod tui;
+mod types;
 mod unlock;
 
 pub use self::add_identity::*;
+pub use self::add_secret::*;
+pub use self::delete_secret::*;
+pub use self::edit_secret::*;
 pub use self::export::*;
 pub use self::generate::*;
+pub use self::get_secret::*;
+pub use self::health::*;
+pub use self::history::*;
 pub use self::import::*;
 pub use self::init::*;
+pub use self::inject::*;
+pub use self::list_all_secrets::*;
 pub use self::list_identities::*;
 pub use self::list_secrets::*;
 pub use self::lock::*;
+pub use self::migrate::*;
 pub use self::status::*;
+pub use self::types::*;
 pub use self::unlock::*;
 
+use crate::commands::tui::create_tui;
+use crate::error::{exit_with_code, ExtCodeResult, EXIT_FAILURE, EXIT_LOCKED, EXIT_NOT_FOUND};
+use atty::Stream;
 use rand::{distributions, thread_rng, Rng};
+use std::fs::{self, File, OpenOptions};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+use t_rust_less_lib::api::{Secret, SecretEntry, SecretListFilter, SecretVersionRef};
+use t_rust_less_lib::pinentry::PassphraseHelper;
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::TrustlessService;
 
 fn generate_id(length: usize) -> String {
   let rng = thread_rng();
@@ -31,3 +62,100 @@ fn generate_id(length: usize) -> String {
     .take(length)
     .collect::<String>()
 }
+
+/// Create (or truncate) a regular file that is only accessible by the current user.
+///
+/// An existing file is restricted before it is truncated, i.e. before anything is written to it.
+fn create_restricted(path: &Path) -> io::Result<File> {
+  let mut options = OpenOptions::new();
+
+  options.write(true).create(true).truncate(false);
+  #[cfg(unix)]
+  {
+    use std::os::unix::fs::OpenOptionsExt;
+
+    options.mode(0o600);
+  }
+
+  let file = options.open(path)?;
+  #[cfg(unix)]
+  {
+    use std::os::unix::fs::PermissionsExt;
+
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+  }
+  file.set_len(0)?;
+
+  Ok(file)
+}
+
+/// Open a store for one of the non-interactive commands.
+///
+/// A locked store can only be unlocked if we are attached to a terminal or a passphrase helper
+/// (pinentry or askpass) is configured in the environment, otherwise the process exits with `EXIT_LOCKED`.
+fn open_unlocked_store(service: &Arc<dyn TrustlessService>, store_name: &str) -> Arc<dyn SecretsStore> {
+  let secrets_store = service
+    .open_store(store_name)
+    .ok_or_exit_with_code(format!("Failed opening store {}: ", store_name));
+  let status = secrets_store.status().ok_or_exit_with_code("Get status: ");
+
+  if status.locked {
+    if !atty::is(Stream::Stdin) || !atty::is(Stream::Stdout) {
+      let unlocked = match PassphraseHelper::from_env() {
+        Some(helper) => unlock_store_with_helper(service, &secrets_store, store_name, &helper),
+        None => false,
+      };
+      if !unlocked {
+        exit_with_code(EXIT_LOCKED, "Store is locked: ", store_name);
+      }
+      return secrets_store;
+    }
+    let mut siv = create_tui();
+    unlock_store(&mut siv, &secrets_store, store_name);
+  }
+
+  secrets_store
+}
+
+/// Find a secret either by its id or its (exact) name.
+fn find_secret_entry(secrets_store: &Arc<dyn SecretsStore>, id_or_name: &str, deleted: bool) -> SecretEntry {
+  let mut filter = SecretListFilter::default();
+  filter.deleted = deleted;
+  let list = secrets_store.list(&filter).ok_or_exit_with_code("List entries: ");
+
+  if let Some(entry_match) = list.entries.iter().find(|m| m.entry.id == id_or_name) {
+    return entry_match.entry.clone();
+  }
+
+  let mut by_name = list.entries.iter().filter(|m| m.entry.name == id_or_name);
+
+  match (by_name.next(), by_name.next()) {
+    (Some(entry_match), None) => entry_match.entry.clone(),
+    (Some(_), Some(_)) => exit_with_code(EXIT_FAILURE, "Name is ambiguous, please use the id: ", id_or_name),
+    (None, _) => exit_with_code(EXIT_NOT_FOUND, "Secret not found: ", id_or_name),
+  }
+}
+
+/// Find a version of a secret either by (a unique prefix of) its block id or relative to the current
+/// version, i.e. `~1` is the version before the current one.
+fn find_version_ref<'a>(secret: &'a Secret, reference: &str) -> &'a SecretVersionRef {
+  if let Some(offset) = reference.strip_prefix('~') {
+    // Versions are ordered newest first, the first being the current one
+    match offset
+      .parse::<usize>()
+      .ok()
+      .and_then(|offset| secret.versions.get(offset))
+    {
+      Some(version_ref) => return version_ref,
+      None => exit_with_code(EXIT_NOT_FOUND, "Version not found: ", reference),
+    }
+  }
+
+  let mut by_prefix = secret.versions.iter().filter(|v| v.block_id.starts_with(reference));
+
+  match (by_prefix.next(), by_prefix.next()) {
+    (Some(version_ref), None) => version_ref,
+    (Some(_), Some(_)) => exit_with_code(EXIT_FAILURE, "Block id is ambiguous: ", reference),
+    (None, _) => exit_with_code(EXIT_NOT_FOUND, "Version not found: ", reference),
+  }
+}
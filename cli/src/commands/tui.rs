@@ -1,5 +1,10 @@
+use crate::view::PasswordView;
+use cursive::event::Key;
+use cursive::traits::Identifiable;
+use cursive::views::{Dialog, LinearLayout, TextView};
 use cursive::Cursive;
 use log::error;
+use t_rust_less_lib::memguard::SecretBytes;
 
 const THEME: &str = r##"
 shadow = false
@@ -31,3 +36,41 @@ pub fn create_tui() -> Cursive {
 
   siv
 }
+
+/// Ask for a passphrase that is not related to a store (e.g. of a file to import).
+///
+/// Returns `None` if aborted.
+pub fn read_passphrase(title: &str) -> Option<SecretBytes> {
+  let mut siv = create_tui();
+
+  siv.add_global_callback(Key::Esc, Cursive::quit);
+  siv.add_layer(
+    Dialog::around(
+      LinearLayout::vertical().child(TextView::new("Passphrase")).child(
+        PasswordView::new(100)
+          .on_submit(submit_passphrase)
+          .with_name("passphrase"),
+      ),
+    )
+    .title(title)
+    .button("Ok", submit_passphrase)
+    .button("Abort", Cursive::quit)
+    .padding_left(5)
+    .padding_right(5)
+    .padding_top(1)
+    .padding_bottom(1),
+  );
+
+  siv.focus_name("passphrase").unwrap();
+
+  siv.run();
+
+  siv.take_user_data::<SecretBytes>()
+}
+
+fn submit_passphrase(s: &mut Cursive) {
+  let passphrase = s.find_name::<PasswordView>("passphrase").unwrap().get_content();
+
+  s.set_user_data(passphrase);
+  s.quit();
+}
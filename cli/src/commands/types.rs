@@ -0,0 +1,43 @@
+use crate::commands::open_unlocked_store;
+use crate::error::{ExtCodeResult, ExtResult};
+use crate::output::{print_json, OutputFormat};
+use std::io::stdin;
+use std::sync::Arc;
+use t_rust_less_lib::api::SecretTypeSchema;
+use t_rust_less_lib::service::TrustlessService;
+
+pub fn list_types(service: Arc<dyn TrustlessService>, store_name: String, format: OutputFormat) {
+  let secrets_store = open_unlocked_store(&service, &store_name);
+  let schemas = secrets_store.list_schemas().ok_or_exit_with_code("List types: ");
+
+  match format {
+    OutputFormat::Plain => {
+      for schema in schemas {
+        let fields: Vec<String> = schema
+          .fields
+          .iter()
+          .map(|field| {
+            format!(
+              "{}:{}{}",
+              field.property,
+              field.kind,
+              if field.required { "!" } else { "" }
+            )
+          })
+          .collect();
+        println!("{}\t{}\t{}", schema.id, schema.name, fields.join(" "));
+      }
+    }
+    OutputFormat::Json => print_json(&schemas),
+  }
+}
+
+/// Add or replace a custom secret type, the schema is read as json from stdin.
+pub fn store_type(service: Arc<dyn TrustlessService>, store_name: String) {
+  let schema: SecretTypeSchema = serde_json::from_reader(stdin()).ok_or_exit("Invalid json input: ");
+  schema.check().ok_or_exit("Invalid schema: ");
+
+  let secrets_store = open_unlocked_store(&service, &store_name);
+
+  secrets_store.store_schema(schema).ok_or_exit_with_code("Store type: ");
+}
@@ -9,23 +9,63 @@ use cursive::Cursive;
 use std::process;
 use std::sync::Arc;
 use t_rust_less_lib::api::{Identity, Status};
+use t_rust_less_lib::pinentry::PassphraseHelper;
 use t_rust_less_lib::secrets_store::SecretsStore;
-use t_rust_less_lib::service::TrustlessService;
+use t_rust_less_lib::service::{unlock_with_helper, TrustlessService};
 
-pub fn unlock(service: Arc<dyn TrustlessService>, store_name: String) {
+pub fn unlock(service: Arc<dyn TrustlessService>, store_name: String, maybe_helper: Option<PassphraseHelper>) {
   let secrets_store = service
     .open_store(&store_name)
     .ok_or_exit(format!("Failed opening store {}: ", store_name));
 
   let status = secrets_store.status().ok_or_exit("Get status");
 
-  if status.locked {
-    let mut siv = create_tui();
+  if !status.locked {
+    return;
+  }
 
-    unlock_store(&mut siv, &secrets_store, &store_name);
+  let maybe_helper = maybe_helper.or_else(|| {
+    if atty::is(Stream::Stdout) {
+      None
+    } else {
+      PassphraseHelper::from_env()
+    }
+  });
+
+  match maybe_helper {
+    Some(helper) => {
+      if !unlock_store_with_helper(&service, &secrets_store, &store_name, &helper) {
+        eprintln!("Unlock cancelled");
+        process::exit(1);
+      }
+    }
+    None => {
+      let mut siv = create_tui();
+
+      unlock_store(&mut siv, &secrets_store, &store_name);
+    }
   }
 }
 
+/// Unlock a store without any terminal interaction, i.e. ask for the passphrase via pinentry or askpass.
+///
+/// The default identity of the store is used, if none is configured the first one.
+pub fn unlock_store_with_helper(
+  service: &Arc<dyn TrustlessService>,
+  secrets_store: &Arc<dyn SecretsStore>,
+  name: &str,
+  helper: &PassphraseHelper,
+) -> bool {
+  let default_identity_id = service
+    .list_stores()
+    .ok_or_exit("List stores")
+    .into_iter()
+    .find(|store_config| store_config.name == name)
+    .and_then(|store_config| store_config.default_identity_id.clone());
+
+  unlock_with_helper(secrets_store.as_ref(), name, default_identity_id.as_deref(), helper).ok_or_exit("Unlock failed: ")
+}
+
 pub fn unlock_store(siv: &mut Cursive, secrets_store: &Arc<dyn SecretsStore>, name: &str) -> Status {
   if !atty::is(Stream::Stdout) {
     println!("Please use a terminal");
@@ -1,14 +1,52 @@
 use log::error;
 use std::fmt;
 use std::process;
+use t_rust_less_lib::secrets_store::SecretStoreError;
+use t_rust_less_lib::service::ServiceError;
 
-pub fn exit_with_error<S: AsRef<str>, E>(prefix: S, error: E)
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_NOT_FOUND: i32 = 2;
+pub const EXIT_LOCKED: i32 = 3;
+
+pub fn exit_with_error<S: AsRef<str>, E>(prefix: S, error: E) -> !
+where
+  E: fmt::Display,
+{
+  exit_with_code(EXIT_FAILURE, prefix, error)
+}
+
+pub fn exit_with_code<S: AsRef<str>, E>(code: i32, prefix: S, error: E) -> !
 where
   E: fmt::Display,
 {
   error!("{}{}", prefix.as_ref(), error);
 
-  process::exit(1)
+  process::exit(code)
+}
+
+/// Errors that should be reported with a specific exit code (so that scripts can react on them).
+pub trait ErrorExitCode {
+  fn exit_code(&self) -> i32;
+}
+
+impl ErrorExitCode for SecretStoreError {
+  fn exit_code(&self) -> i32 {
+    match self {
+      SecretStoreError::NotFound => EXIT_NOT_FOUND,
+      SecretStoreError::Locked => EXIT_LOCKED,
+      _ => EXIT_FAILURE,
+    }
+  }
+}
+
+impl ErrorExitCode for ServiceError {
+  fn exit_code(&self) -> i32 {
+    match self {
+      ServiceError::SecretsStore(error) => error.exit_code(),
+      ServiceError::StoreNotFound(_) => EXIT_NOT_FOUND,
+      _ => EXIT_FAILURE,
+    }
+  }
 }
 
 pub trait ExtResult<T, E> {
@@ -22,10 +60,23 @@ where
   fn ok_or_exit<S: AsRef<str>>(self, prefix: S) -> T {
     match self {
       Ok(result) => result,
-      Err(error) => {
-        exit_with_error(prefix, error);
-        unreachable!()
-      }
+      Err(error) => exit_with_error(prefix, error),
+    }
+  }
+}
+
+pub trait ExtCodeResult<T, E> {
+  fn ok_or_exit_with_code<S: AsRef<str>>(self, prefix: S) -> T;
+}
+
+impl<T, E> ExtCodeResult<T, E> for Result<T, E>
+where
+  E: fmt::Display + ErrorExitCode,
+{
+  fn ok_or_exit_with_code<S: AsRef<str>>(self, prefix: S) -> T {
+    match self {
+      Ok(result) => result,
+      Err(error) => exit_with_code(error.exit_code(), prefix, error),
     }
   }
 }
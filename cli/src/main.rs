@@ -1,9 +1,13 @@
 use crate::error::ExtResult;
+use crate::model::pass_store::DEFAULT_GPG;
+use crate::model::secret_input::{parse_days, parse_secret_type};
+use crate::output::OutputFormat;
 use atty::Stream;
 use crossterm_style::{style, Color};
 use log::error;
 use std::process;
-use t_rust_less_lib::api::SecretListFilter;
+use t_rust_less_lib::api::{HealthReportParams, SecretListFilter, SecretQuery};
+use t_rust_less_lib::pinentry::PassphraseHelper;
 use t_rust_less_lib::service::{config_file, create_service};
 
 mod cli;
@@ -11,6 +15,7 @@ mod commands;
 mod config;
 mod error;
 pub mod model;
+mod output;
 pub mod view;
 
 fn uninitialized() {
@@ -67,16 +72,98 @@ fn main() {
   match matches.subcommand() {
     ("status", _) => commands::status(service, store_name),
     ("list", Some(sub_matches)) => {
+      let query = sub_matches
+        .values_of("query")
+        .map(|values| values.collect::<Vec<&str>>().join(" "));
+      if let Some(query) = &query {
+        SecretQuery::parse(query).ok_or_exit("Invalid query: ");
+      }
       let filter = SecretListFilter {
-        name: sub_matches.value_of("name").map(ToString::to_string),
+        name: None,
         tag: sub_matches.value_of("tag").map(ToString::to_string),
         url: sub_matches.value_of("url").map(ToString::to_string),
         deleted: sub_matches.is_present("deleted"),
+        expired: sub_matches.is_present("expired"),
+        expiring_within: sub_matches.value_of("expiring-within").map(parse_days),
+        text: sub_matches.value_of("text").map(ToString::to_string),
+        query,
         ..Default::default()
       };
 
-      commands::list_secrets(service, store_name, filter)
+      if sub_matches.is_present("all-stores") {
+        commands::list_all_secrets(service, store_name, filter, OutputFormat::from_args(sub_matches))
+      } else {
+        commands::list_secrets(service, store_name, filter, OutputFormat::from_args(sub_matches))
+      }
+    }
+    ("get", Some(sub_matches)) => commands::get_secret(
+      service,
+      store_name,
+      sub_matches.value_of("secret").unwrap(),
+      sub_matches.value_of("property"),
+      sub_matches.value_of("version"),
+      sub_matches.is_present("deleted"),
+      OutputFormat::from_args(sub_matches),
+    ),
+    ("health", Some(sub_matches)) => {
+      let mut params = HealthReportParams::default();
+
+      if let Some(min_score) = sub_matches.value_of("min-score") {
+        params.min_score = min_score.parse().ok_or_exit("Invalid score: ");
+      }
+      if let Some(max_age) = sub_matches.value_of("max-age") {
+        params.max_age_days = max_age.parse().ok_or_exit("Invalid number of days: ");
+      }
+
+      commands::health_report(service, store_name, params, OutputFormat::from_args(sub_matches))
+    }
+    ("history", Some(sub_matches)) => commands::history(
+      service,
+      store_name,
+      sub_matches.value_of("secret").unwrap(),
+      sub_matches.is_present("deleted"),
+      sub_matches
+        .value_of("diff")
+        .map(|from| (from, sub_matches.value_of("to"))),
+      sub_matches.is_present("reveal"),
+      OutputFormat::from_args(sub_matches),
+    ),
+    ("restore", Some(sub_matches)) => commands::restore_version(
+      service,
+      store_name,
+      sub_matches.value_of("block-id").unwrap(),
+      OutputFormat::from_args(sub_matches),
+    ),
+    ("add", Some(sub_matches)) => {
+      let input = commands::secret_input_from_args(sub_matches);
+
+      commands::add_secret(service, store_name, input, OutputFormat::from_args(sub_matches))
     }
+    ("edit", Some(sub_matches)) => {
+      let input = commands::secret_input_from_args(sub_matches);
+
+      commands::edit_secret(
+        service,
+        store_name,
+        sub_matches.value_of("secret").unwrap(),
+        input,
+        OutputFormat::from_args(sub_matches),
+      )
+    }
+    ("delete", Some(sub_matches)) => commands::delete_secret(
+      service,
+      store_name,
+      sub_matches.value_of("secret").unwrap(),
+      true,
+      OutputFormat::from_args(sub_matches),
+    ),
+    ("undelete", Some(sub_matches)) => commands::delete_secret(
+      service,
+      store_name,
+      sub_matches.value_of("secret").unwrap(),
+      false,
+      OutputFormat::from_args(sub_matches),
+    ),
     ("identities", Some(sub_matches)) => match sub_matches.subcommand() {
       ("add", _) => commands::add_identity(service, store_name),
       ("list", _) => commands::list_identities(service, store_name),
@@ -85,17 +172,81 @@ fn main() {
         process::exit(1)
       }
     },
+    ("types", Some(sub_matches)) => match sub_matches.subcommand() {
+      ("list", Some(list_matches)) => commands::list_types(service, store_name, OutputFormat::from_args(list_matches)),
+      ("store", _) => commands::store_type(service, store_name),
+      (command, _) => {
+        println!("Command {} not implemented", command);
+        process::exit(1)
+      }
+    },
     ("lock", _) => commands::lock(service, store_name),
-    ("unlock", _) => commands::unlock(service, store_name),
+    ("migrate", _) => commands::migrate(service, store_name),
+    ("unlock", Some(sub_matches)) => {
+      let maybe_helper = match (sub_matches.value_of("pinentry"), sub_matches.value_of("askpass")) {
+        (Some(program), _) => Some(PassphraseHelper::Pinentry {
+          program: program.to_string(),
+          args: vec![],
+        }),
+        (_, Some(program)) => Some(PassphraseHelper::Askpass {
+          program: program.to_string(),
+          args: vec![],
+        }),
+        _ => None,
+      };
+      commands::unlock(service, store_name, maybe_helper)
+    }
     ("import", Some(sub_matches)) => {
       let file_name = sub_matches.value_of("file");
+      let dry_run = sub_matches.is_present("dry-run");
       if sub_matches.is_present("v1") {
         commands::import_v1(service, store_name, file_name);
+      } else if sub_matches.is_present("keepass") {
+        commands::import_keepass(service, store_name, file_name, dry_run);
+      } else if sub_matches.is_present("bitwarden") {
+        commands::import_bitwarden(service, store_name, file_name, dry_run);
+      } else if sub_matches.is_present("1password") {
+        commands::import_1password(service, store_name, file_name, dry_run);
+      } else if sub_matches.is_present("pass") {
+        let store_dir = file_name.unwrap_or_else(|| {
+          eprintln!("Please specify the directory of the password store");
+          process::exit(1)
+        });
+        let gpg = sub_matches.value_of("gpg").unwrap_or(DEFAULT_GPG);
+        commands::import_pass(service, store_name, store_dir, gpg, dry_run);
+      } else if sub_matches.is_present("csv") {
+        commands::import_browser_csv(service, store_name, file_name, dry_run);
       } else {
-        println!("Only v1 import supported yet");
+        println!("Please specify the format to import");
         process::exit(1)
       }
     }
+    ("export", Some(sub_matches)) => {
+      let format = sub_matches
+        .value_of("format")
+        .and_then(commands::ExportFormat::from_name)
+        .unwrap_or(commands::ExportFormat::Native);
+      let mut filter = SecretListFilter::default();
+      filter.tag = sub_matches.value_of("tag").map(ToString::to_string);
+      filter.secret_type = sub_matches.value_of("type").and_then(parse_secret_type);
+
+      commands::export(
+        service,
+        store_name,
+        format,
+        filter,
+        sub_matches.is_present("include-deleted"),
+        sub_matches.value_of("output"),
+      )
+    }
+    ("inject", Some(sub_matches)) => commands::inject(
+      service,
+      store_name,
+      sub_matches.value_of("template"),
+      sub_matches.value_of("output"),
+      sub_matches.is_present("strict"),
+      sub_matches.is_present("fifo"),
+    ),
     ("generate", Some(sub_matches)) => {
       let param = commands::password_generate_param_from_args(sub_matches);
 
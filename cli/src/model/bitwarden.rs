@@ -0,0 +1,407 @@
+//! Import of the (unencrypted) json export of Bitwarden.
+use crate::model::import_set::{password_history_versions, ImportError, ImportResult, ImportSet};
+use crate::model::totp::totp_url;
+use chrono::{DateTime, Utc};
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use t_rust_less_lib::api::{
+  SecretProperties, SecretType, SecretVersion, PROPERTY_CARDHOLDER, PROPERTY_CARD_BRAND, PROPERTY_CARD_CODE,
+  PROPERTY_CARD_EXPIRY, PROPERTY_CARD_NUMBER, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_SSN, PROPERTY_TOTP_URL,
+  PROPERTY_USERNAME,
+};
+use zeroize::Zeroize;
+
+const ITEM_TYPE_LOGIN: u8 = 1;
+const ITEM_TYPE_SECURE_NOTE: u8 = 2;
+const ITEM_TYPE_CARD: u8 = 3;
+const ITEM_TYPE_IDENTITY: u8 = 4;
+
+const FIELD_TYPE_LINKED: u8 = 3;
+
+/// Properties of an Identity secret (named like the fields of a Bitwarden identity)
+pub const IDENTITY_PROPERTIES: &[&str] = &[
+  "title",
+  "firstName",
+  "middleName",
+  "lastName",
+  "address1",
+  "address2",
+  "address3",
+  "city",
+  "state",
+  "postalCode",
+  "country",
+  "company",
+  "email",
+  "phone",
+  PROPERTY_SSN,
+  PROPERTY_USERNAME,
+  "passportNumber",
+  "licenseNumber",
+];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Export {
+  #[serde(default)]
+  encrypted: bool,
+  #[serde(default)]
+  folders: Vec<Folder>,
+  #[serde(default)]
+  collections: Vec<Folder>,
+  #[serde(default)]
+  items: Vec<Item>,
+}
+
+#[derive(Deserialize)]
+struct Folder {
+  id: String,
+  name: String,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[serde(rename_all = "camelCase")]
+#[zeroize(drop)]
+struct Item {
+  #[serde(rename = "type")]
+  item_type: u8,
+  name: String,
+  notes: Option<String>,
+  folder_id: Option<String>,
+  #[serde(default)]
+  collection_ids: Option<Vec<String>>,
+  #[serde(default)]
+  fields: Option<Vec<Field>>,
+  login: Option<Login>,
+  card: Option<Card>,
+  identity: Option<Identity>,
+  #[serde(default)]
+  password_history: Option<Vec<PasswordHistory>>,
+  revision_date: Option<String>,
+  deleted_date: Option<String>,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[serde(rename_all = "camelCase")]
+#[zeroize(drop)]
+struct Field {
+  name: Option<String>,
+  value: Option<String>,
+  #[serde(rename = "type")]
+  field_type: u8,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[zeroize(drop)]
+struct Login {
+  username: Option<String>,
+  password: Option<String>,
+  totp: Option<String>,
+  #[serde(default)]
+  uris: Option<Vec<LoginUri>>,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[zeroize(drop)]
+struct LoginUri {
+  uri: Option<String>,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[serde(rename_all = "camelCase")]
+#[zeroize(drop)]
+struct Card {
+  cardholder_name: Option<String>,
+  brand: Option<String>,
+  number: Option<String>,
+  exp_month: Option<String>,
+  exp_year: Option<String>,
+  code: Option<String>,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[serde(rename_all = "camelCase")]
+#[zeroize(drop)]
+struct Identity {
+  title: Option<String>,
+  first_name: Option<String>,
+  middle_name: Option<String>,
+  last_name: Option<String>,
+  address1: Option<String>,
+  address2: Option<String>,
+  address3: Option<String>,
+  city: Option<String>,
+  state: Option<String>,
+  postal_code: Option<String>,
+  country: Option<String>,
+  company: Option<String>,
+  email: Option<String>,
+  phone: Option<String>,
+  ssn: Option<String>,
+  username: Option<String>,
+  passport_number: Option<String>,
+  license_number: Option<String>,
+}
+
+#[derive(Deserialize, Zeroize)]
+#[serde(rename_all = "camelCase")]
+#[zeroize(drop)]
+struct PasswordHistory {
+  last_used_date: Option<String>,
+  password: Option<String>,
+}
+
+/// Read a Bitwarden json export (as created by "Export vault" with file format ".json").
+pub fn read_export(data: &[u8]) -> ImportResult<ImportSet> {
+  let export: Export = serde_json::from_slice(data)?;
+
+  if export.encrypted {
+    return Err(ImportError::Unsupported(
+      "Encrypted exports can not be imported, please use an unencrypted json export".to_string(),
+    ));
+  }
+
+  let folders: HashMap<&str, &str> = export
+    .folders
+    .iter()
+    .chain(export.collections.iter())
+    .map(|folder| (folder.id.as_str(), folder.name.as_str()))
+    .collect();
+  let mut import_set = ImportSet::default();
+
+  for item in &export.items {
+    let (secret_type, properties, urls) = match item.item_type {
+      ITEM_TYPE_LOGIN => (SecretType::Login, login_properties(item), login_urls(item)),
+      ITEM_TYPE_SECURE_NOTE => (SecretType::Note, SecretProperties::default(), vec![]),
+      ITEM_TYPE_CARD => (SecretType::Card, card_properties(item), vec![]),
+      ITEM_TYPE_IDENTITY => (SecretType::Identity, identity_properties(item), vec![]),
+      item_type => {
+        import_set.skip(&item.name, format!("Unsupported item type {}", item_type));
+        continue;
+      }
+    };
+    let mut tags: Vec<String> = Vec::new();
+
+    for folder_id in item.folder_id.iter().chain(item.collection_ids.iter().flatten()) {
+      if let Some(name) = folders.get(folder_id.as_str()) {
+        if !tags.iter().any(|tag| tag == name) {
+          tags.push(name.to_string());
+        }
+      }
+    }
+
+    let timestamp = parse_date(&item.revision_date).unwrap_or_else(Utc::now);
+    let mut current = SecretVersion::new("", secret_type, &item.name, timestamp.into());
+
+    current.tags = tags;
+    current.urls = urls;
+    current.properties = properties;
+    current.deleted = item.deleted_date.is_some();
+
+    set_property(&mut current.properties, PROPERTY_NOTES, &item.notes);
+    for field in item.fields.iter().flatten() {
+      match &field.name {
+        Some(name) if field.field_type != FIELD_TYPE_LINKED && !name.is_empty() => {
+          set_property(&mut current.properties, name, &field.value)
+        }
+        _ => (),
+      }
+    }
+
+    let history = item
+      .password_history
+      .iter()
+      .flatten()
+      .filter_map(|entry| match (&entry.password, parse_date(&entry.last_used_date)) {
+        (Some(password), Some(timestamp)) => Some((password.clone(), timestamp)),
+        _ => None,
+      })
+      .collect();
+    let mut versions = password_history_versions(&current, history);
+
+    versions.push(current);
+    import_set.secrets.push(versions);
+  }
+
+  Ok(import_set)
+}
+
+fn login_properties(item: &Item) -> SecretProperties {
+  let mut properties = SecretProperties::default();
+
+  if let Some(login) = &item.login {
+    set_property(&mut properties, PROPERTY_USERNAME, &login.username);
+    set_property(&mut properties, PROPERTY_PASSWORD, &login.password);
+    if let Some(totp) = login.totp.as_ref().and_then(|totp| {
+      totp_url(
+        totp,
+        login.username.as_deref().unwrap_or_default(),
+        Some(&item.name),
+        None,
+        None,
+        None,
+      )
+    }) {
+      properties.set(PROPERTY_TOTP_URL, &totp);
+    }
+  }
+
+  properties
+}
+
+fn login_urls(item: &Item) -> Vec<String> {
+  item
+    .login
+    .iter()
+    .flat_map(|login| login.uris.iter().flatten())
+    .filter_map(|uri| uri.uri.clone())
+    .filter(|uri| !uri.is_empty())
+    .collect()
+}
+
+fn card_properties(item: &Item) -> SecretProperties {
+  let mut properties = SecretProperties::default();
+
+  if let Some(card) = &item.card {
+    set_property(&mut properties, PROPERTY_CARDHOLDER, &card.cardholder_name);
+    set_property(&mut properties, PROPERTY_CARD_BRAND, &card.brand);
+    set_property(&mut properties, PROPERTY_CARD_NUMBER, &card.number);
+    set_property(&mut properties, PROPERTY_CARD_CODE, &card.code);
+    if let (Some(month), Some(year)) = (&card.exp_month, &card.exp_year) {
+      properties.set(PROPERTY_CARD_EXPIRY, &format!("{:0>2}/{}", month, year));
+    }
+  }
+
+  properties
+}
+
+fn identity_properties(item: &Item) -> SecretProperties {
+  let mut properties = SecretProperties::default();
+
+  if let Some(identity) = &item.identity {
+    let values = [
+      &identity.title,
+      &identity.first_name,
+      &identity.middle_name,
+      &identity.last_name,
+      &identity.address1,
+      &identity.address2,
+      &identity.address3,
+      &identity.city,
+      &identity.state,
+      &identity.postal_code,
+      &identity.country,
+      &identity.company,
+      &identity.email,
+      &identity.phone,
+      &identity.ssn,
+      &identity.username,
+      &identity.passport_number,
+      &identity.license_number,
+    ];
+
+    for (name, value) in IDENTITY_PROPERTIES.iter().zip(values.iter()) {
+      set_property(&mut properties, name, value);
+    }
+  }
+
+  properties
+}
+
+fn set_property(properties: &mut SecretProperties, name: &str, value: &Option<String>) {
+  match value {
+    Some(value) if !value.is_empty() => properties.set(name, value),
+    _ => (),
+  }
+}
+
+fn parse_date(date: &Option<String>) -> Option<DateTime<Utc>> {
+  date
+    .as_ref()
+    .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+    .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use t_rust_less_lib::otp::OTPAuthUrl;
+
+  const EXPORT: &str = r#"{
+    "encrypted": false,
+    "folders": [{"id": "f1", "name": "Private"}],
+    "items": [
+      {
+        "type": 1,
+        "name": "Mail",
+        "notes": "Some notes",
+        "folderId": "f1",
+        "fields": [{"name": "PIN", "value": "1234", "type": 1}, {"name": "Link", "value": null, "type": 3}],
+        "login": {
+          "username": "john",
+          "password": "current",
+          "totp": "JBSW Y3DP EHPK 3PXP",
+          "uris": [{"uri": "https://mail.example.com"}, {"uri": ""}, {"uri": "https://webmail.example.com"}]
+        },
+        "passwordHistory": [{"lastUsedDate": "2020-01-01T00:00:00.000Z", "password": "previous"}],
+        "revisionDate": "2021-03-04T05:06:07.000Z",
+        "deletedDate": null
+      },
+      {
+        "type": 3,
+        "name": "Visa",
+        "folderId": null,
+        "card": {"cardholderName": "John Doe", "brand": "Visa", "number": "4111111111111111",
+                 "expMonth": "3", "expYear": "2030", "code": "123"},
+        "revisionDate": "2021-03-04T05:06:07.000Z"
+      },
+      {"type": 5, "name": "Unknown"}
+    ]
+  }"#;
+
+  #[test]
+  fn test_read_export() {
+    let import_set = read_export(EXPORT.as_bytes()).unwrap();
+
+    assert_that(&import_set.secrets.len()).is_equal_to(2);
+    assert_that(&import_set.skipped.len()).is_equal_to(1);
+    assert_that(&import_set.skipped[0].name.as_str()).is_equal_to("Unknown");
+
+    let mail = &import_set.secrets[0];
+    assert_that(&mail.len()).is_equal_to(2);
+    assert_that(&mail[0].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"previous".to_string()));
+
+    let current = &mail[1];
+    assert_that(&current.secret_type).is_equal_to(SecretType::Login);
+    assert_that(&current.name.as_str()).is_equal_to("Mail");
+    assert_that(&current.tags).is_equal_to(vec!["Private".to_string()]);
+    assert_that(&current.urls).is_equal_to(vec![
+      "https://mail.example.com".to_string(),
+      "https://webmail.example.com".to_string(),
+    ]);
+    assert_that(&current.properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&"john".to_string()));
+    assert_that(&current.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"current".to_string()));
+    assert_that(&current.properties.get(PROPERTY_NOTES)).is_equal_to(Some(&"Some notes".to_string()));
+    assert_that(&current.properties.get("PIN")).is_equal_to(Some(&"1234".to_string()));
+    assert_that(&current.properties.get("Link")).is_none();
+    assert_that(&current.deleted).is_false();
+    let totp_url = current.properties.get(PROPERTY_TOTP_URL).unwrap();
+    assert_that(&OTPAuthUrl::parse(totp_url).map(|otp_url| otp_url.account_name)).is_ok_containing("john".to_string());
+
+    let card = &import_set.secrets[1][0];
+    assert_that(&card.secret_type).is_equal_to(SecretType::Card);
+    assert_that(&card.tags).is_empty();
+    assert_that(&card.properties.get(PROPERTY_CARDHOLDER)).is_equal_to(Some(&"John Doe".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_NUMBER)).is_equal_to(Some(&"4111111111111111".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_EXPIRY)).is_equal_to(Some(&"03/2030".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_CODE)).is_equal_to(Some(&"123".to_string()));
+  }
+
+  #[test]
+  fn test_read_encrypted_export() {
+    let result = read_export(br#"{"encrypted": true, "items": []}"#).map(|import_set| import_set.secrets.len());
+
+    assert_that(&result).matches(|result| matches!(result, Err(ImportError::Unsupported(_))));
+  }
+}
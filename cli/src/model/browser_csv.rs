@@ -0,0 +1,161 @@
+//! Import of the password csv exports of Chrome (and Chromium based browsers) and Firefox.
+//!
+//! Chrome: `name,url,username,password[,note]`
+//! Firefox: `url,username,password,httpRealm,formActionOrigin,guid,timeCreated,timeLastUsed,timePasswordChanged`
+use crate::model::import_set::{ImportError, ImportResult, ImportSet};
+use chrono::{TimeZone, Utc};
+use csv::{ReaderBuilder, StringRecord};
+use t_rust_less_lib::api::{
+  SecretProperties, SecretType, SecretVersion, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_USERNAME,
+};
+use url::Url;
+
+const COLUMN_NAME: &str = "name";
+const COLUMN_URL: &str = "url";
+const COLUMN_USERNAME: &str = "username";
+const COLUMN_PASSWORD: &str = "password";
+const COLUMN_NOTE: &str = "note";
+const COLUMN_PASSWORD_CHANGED: &str = "timePasswordChanged";
+
+struct Columns {
+  name: Option<usize>,
+  url: usize,
+  username: usize,
+  password: usize,
+  note: Option<usize>,
+  password_changed: Option<usize>,
+}
+
+impl Columns {
+  fn from_headers(headers: &StringRecord) -> ImportResult<Columns> {
+    let find = |column: &str| headers.iter().position(|header| header.trim() == column);
+    let required = |column: &str| find(column).ok_or_else(|| ImportError::Format(format!("Missing column {}", column)));
+
+    Ok(Columns {
+      name: find(COLUMN_NAME),
+      url: required(COLUMN_URL)?,
+      username: required(COLUMN_USERNAME)?,
+      password: required(COLUMN_PASSWORD)?,
+      note: find(COLUMN_NOTE),
+      password_changed: find(COLUMN_PASSWORD_CHANGED),
+    })
+  }
+}
+
+/// Read a password csv export of a browser, every row becomes a Login secret.
+pub fn read_export(data: &[u8]) -> ImportResult<ImportSet> {
+  let mut reader = ReaderBuilder::new().flexible(true).from_reader(data);
+  let columns = Columns::from_headers(reader.headers()?)?;
+  let mut import_set = ImportSet::default();
+  let mut record = StringRecord::new();
+
+  while reader.read_record(&mut record)? {
+    let field = |idx: usize| record.get(idx).map(str::trim).unwrap_or_default();
+    let url = field(columns.url);
+    let name = match columns.name.map(field).filter(|name| !name.is_empty()) {
+      Some(name) => name.to_string(),
+      None => Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(ToString::to_string))
+        .unwrap_or_else(|| url.to_string()),
+    };
+
+    if name.is_empty() {
+      import_set.skip(
+        format!("line {}", record.position().map(|p| p.line()).unwrap_or_default()),
+        "No name or url",
+      );
+      continue;
+    }
+    if field(columns.password).is_empty() {
+      import_set.skip(name, "No password");
+      continue;
+    }
+
+    let mut properties = SecretProperties::default();
+
+    for (property, value) in &[
+      (PROPERTY_USERNAME, field(columns.username)),
+      (PROPERTY_PASSWORD, field(columns.password)),
+      (PROPERTY_NOTES, columns.note.map(field).unwrap_or_default()),
+    ] {
+      if !value.is_empty() {
+        properties.set(property, value);
+      }
+    }
+
+    // Firefox stores the timestamps in milliseconds
+    let timestamp = columns
+      .password_changed
+      .map(field)
+      .and_then(|millis| millis.parse::<i64>().ok())
+      .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+      .unwrap_or_else(Utc::now);
+    let mut version = SecretVersion::new("", SecretType::Login, &name, timestamp.into());
+
+    if !url.is_empty() {
+      version.urls.push(url.to_string());
+    }
+    version.properties = properties;
+    import_set.secrets.push(vec![version]);
+  }
+
+  Ok(import_set)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+
+  #[test]
+  fn test_read_chrome_export() {
+    let export = "name,url,username,password,note\n\
+                  Mail,https://mail.example.com/login,john,\"pass, \"\"word\"\"\",\"line 1\nline 2\"\n\
+                  ,https://www.example.com:8080/,jane,secret,\n\
+                  No password,https://nopass.example.com,jane,,\n\
+                  ,,jane,secret,\n";
+    let import_set = read_export(export.as_bytes()).unwrap();
+
+    assert_that(&import_set.secrets.len()).is_equal_to(2);
+
+    let mail = &import_set.secrets[0][0];
+    assert_that(&mail.secret_type).is_equal_to(SecretType::Login);
+    assert_that(&mail.name.as_str()).is_equal_to("Mail");
+    assert_that(&mail.urls).is_equal_to(vec!["https://mail.example.com/login".to_string()]);
+    assert_that(&mail.properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&"john".to_string()));
+    assert_that(&mail.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"pass, \"word\"".to_string()));
+    assert_that(&mail.properties.get(PROPERTY_NOTES)).is_equal_to(Some(&"line 1\nline 2".to_string()));
+
+    // Without a name the host of the url is used
+    assert_that(&import_set.secrets[1][0].name.as_str()).is_equal_to("www.example.com");
+
+    let skipped: Vec<(&str, &str)> = import_set
+      .skipped
+      .iter()
+      .map(|skipped| (skipped.name.as_str(), skipped.reason.as_str()))
+      .collect();
+    assert_that(&skipped).is_equal_to(vec![("No password", "No password"), ("line 6", "No name or url")]);
+  }
+
+  #[test]
+  fn test_read_firefox_export() {
+    let export = "\"url\",\"username\",\"password\",\"httpRealm\",\"formActionOrigin\",\"guid\",\"timeCreated\",\"timeLastUsed\",\"timePasswordChanged\"\n\
+                  \"https://example.com\",\"john\",\"secret\",,\"https://example.com\",\"{guid}\",\"1600000000000\",\"1600000000000\",\"1614834367000\"\n\
+                  \"https://other.example.com\",\"jane\",\"secret\",,,\"{guid}\",,,\"99999999999999999999\"\n";
+    let import_set = read_export(export.as_bytes()).unwrap();
+
+    assert_that(&import_set.secrets.len()).is_equal_to(2);
+    assert_that(&import_set.secrets[0][0].name.as_str()).is_equal_to("example.com");
+    assert_that(&import_set.secrets[0][0].timestamp.timestamp_millis()).is_equal_to(1_614_834_367_000);
+    // An out of range timestamp falls back to the time of the import
+    assert_that(&import_set.secrets[1][0].timestamp.timestamp_millis()).is_greater_than(1_614_834_367_000);
+  }
+
+  #[test]
+  fn test_read_invalid_export() {
+    let result = read_export(b"name,password\nMail,secret\n").map(|import_set| import_set.secrets.len());
+
+    assert_that(&result).matches(|result| matches!(result, Err(ImportError::Format(_))));
+  }
+}
@@ -0,0 +1,292 @@
+use super::{write_record, AttachmentSource, Exporter};
+use crate::model::bitwarden::IDENTITY_PROPERTIES;
+use data_encoding::HEXLOWER;
+use openssl::sha::sha256;
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use t_rust_less_lib::api::{
+  SecretType, SecretVersion, PROPERTY_CARDHOLDER, PROPERTY_CARD_BRAND, PROPERTY_CARD_CODE, PROPERTY_CARD_EXPIRY,
+  PROPERTY_CARD_NUMBER, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+};
+
+const TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3fZ";
+
+const ITEM_TYPE_LOGIN: u8 = 1;
+const ITEM_TYPE_SECURE_NOTE: u8 = 2;
+const ITEM_TYPE_CARD: u8 = 3;
+const ITEM_TYPE_IDENTITY: u8 = 4;
+
+const FIELD_TYPE_TEXT: u8 = 0;
+const FIELD_TYPE_HIDDEN: u8 = 1;
+
+#[derive(Serialize)]
+struct Folder<'a> {
+  id: String,
+  name: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Item<'a> {
+  id: &'a str,
+  folder_id: Option<String>,
+  #[serde(rename = "type")]
+  item_type: u8,
+  name: &'a str,
+  notes: Option<&'a str>,
+  favorite: bool,
+  fields: Vec<Field<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  login: Option<Login<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  secure_note: Option<SecureNote>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  card: Option<Card<'a>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  identity: Option<BTreeMap<&'a str, &'a str>>,
+  password_history: Vec<PasswordHistory<'a>>,
+  revision_date: String,
+  creation_date: String,
+  deleted_date: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Field<'a> {
+  name: &'a str,
+  value: &'a str,
+  #[serde(rename = "type")]
+  field_type: u8,
+}
+
+#[derive(Serialize)]
+struct Login<'a> {
+  uris: Vec<LoginUri<'a>>,
+  username: Option<&'a str>,
+  password: Option<&'a str>,
+  totp: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct LoginUri<'a> {
+  #[serde(rename = "match")]
+  uri_match: Option<u8>,
+  uri: &'a str,
+}
+
+#[derive(Serialize)]
+struct SecureNote {
+  #[serde(rename = "type")]
+  note_type: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Card<'a> {
+  cardholder_name: Option<&'a str>,
+  brand: Option<&'a str>,
+  number: Option<&'a str>,
+  exp_month: Option<&'a str>,
+  exp_year: Option<&'a str>,
+  code: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PasswordHistory<'a> {
+  last_used_date: String,
+  password: &'a str,
+}
+
+/// Unencrypted json export as created by Bitwarden itself.
+///
+/// Tags become folders (Bitwarden only supports one folder per item, so only the first tag
+/// of a secret is used), previous passwords end up in the password history.
+pub struct BitwardenExporter {
+  first_item: bool,
+}
+
+impl BitwardenExporter {
+  pub fn new() -> BitwardenExporter {
+    BitwardenExporter { first_item: true }
+  }
+}
+
+impl Default for BitwardenExporter {
+  fn default() -> Self {
+    BitwardenExporter::new()
+  }
+}
+
+impl Exporter for BitwardenExporter {
+  fn begin(&mut self, out: &mut dyn Write, all_tags: &[String]) -> io::Result<()> {
+    let folders: Vec<Folder> = all_tags
+      .iter()
+      .map(|tag| Folder {
+        id: folder_id(tag),
+        name: tag,
+      })
+      .collect();
+
+    out.write_all(b"{\"encrypted\":false,\"folders\":")?;
+    serde_json::to_writer(&mut *out, &folders)?;
+    out.write_all(b",\"items\":[\n")
+  }
+
+  fn write_secret(
+    &mut self,
+    out: &mut dyn Write,
+    secret_id: &str,
+    versions: &[SecretVersion],
+    _: &dyn AttachmentSource,
+  ) -> io::Result<()> {
+    let current = match versions.last() {
+      Some(current) => current,
+      None => return Ok(()),
+    };
+    let property = |name: &str| current.properties.get(name).map(String::as_str);
+    let item_type = match current.secret_type {
+      SecretType::Login | SecretType::Password | SecretType::Wlan => ITEM_TYPE_LOGIN,
+      SecretType::Card => ITEM_TYPE_CARD,
+      SecretType::Identity => ITEM_TYPE_IDENTITY,
+      SecretType::Note | SecretType::Licence | SecretType::Other => ITEM_TYPE_SECURE_NOTE,
+    };
+    let standard_properties: &[&str] = match item_type {
+      ITEM_TYPE_LOGIN => &[PROPERTY_NOTES, PROPERTY_USERNAME, PROPERTY_PASSWORD, PROPERTY_TOTP_URL],
+      ITEM_TYPE_CARD => &[
+        PROPERTY_NOTES,
+        PROPERTY_CARDHOLDER,
+        PROPERTY_CARD_BRAND,
+        PROPERTY_CARD_NUMBER,
+        PROPERTY_CARD_EXPIRY,
+        PROPERTY_CARD_CODE,
+      ],
+      ITEM_TYPE_IDENTITY => IDENTITY_PROPERTIES,
+      _ => &[PROPERTY_NOTES],
+    };
+    let password_properties = current.secret_type.password_properties();
+    let (exp_month, exp_year) = match property(PROPERTY_CARD_EXPIRY).map(|expiry| expiry.split('/')) {
+      Some(mut parts) => (parts.next(), parts.next()),
+      None => (None, None),
+    };
+    let item = Item {
+      id: secret_id,
+      folder_id: current.tags.first().map(|tag| folder_id(tag)),
+      item_type,
+      name: &current.name,
+      notes: property(PROPERTY_NOTES),
+      favorite: false,
+      fields: current
+        .properties
+        .iter()
+        .filter(|(name, _)| !standard_properties.contains(name))
+        .map(|(name, value)| Field {
+          name,
+          value,
+          field_type: if password_properties.contains(&name) {
+            FIELD_TYPE_HIDDEN
+          } else {
+            FIELD_TYPE_TEXT
+          },
+        })
+        .collect(),
+      login: if item_type == ITEM_TYPE_LOGIN {
+        Some(Login {
+          uris: current
+            .urls
+            .iter()
+            .map(|uri| LoginUri { uri_match: None, uri })
+            .collect(),
+          username: property(PROPERTY_USERNAME),
+          password: property(PROPERTY_PASSWORD),
+          totp: property(PROPERTY_TOTP_URL),
+        })
+      } else {
+        None
+      },
+      secure_note: if item_type == ITEM_TYPE_SECURE_NOTE {
+        Some(SecureNote { note_type: 0 })
+      } else {
+        None
+      },
+      card: if item_type == ITEM_TYPE_CARD {
+        Some(Card {
+          cardholder_name: property(PROPERTY_CARDHOLDER),
+          brand: property(PROPERTY_CARD_BRAND),
+          number: property(PROPERTY_CARD_NUMBER),
+          exp_month,
+          exp_year,
+          code: property(PROPERTY_CARD_CODE),
+        })
+      } else {
+        None
+      },
+      identity: if item_type == ITEM_TYPE_IDENTITY {
+        Some(
+          current
+            .properties
+            .iter()
+            .filter(|(name, _)| IDENTITY_PROPERTIES.contains(name))
+            .collect(),
+        )
+      } else {
+        None
+      },
+      password_history: password_history(versions),
+      revision_date: current.timestamp.format(TIME_FORMAT),
+      creation_date: versions[0].timestamp.format(TIME_FORMAT),
+      deleted_date: if current.deleted {
+        Some(current.timestamp.format(TIME_FORMAT))
+      } else {
+        None
+      },
+    };
+    let separator: &[u8] = if self.first_item { b"" } else { b",\n" };
+
+    self.first_item = false;
+    write_record(out, |record| {
+      record.write_all(separator)?;
+      serde_json::to_writer(&mut *record, &item)?;
+      Ok(())
+    })
+  }
+
+  fn end(&mut self, out: &mut dyn Write) -> io::Result<()> {
+    out.write_all(b"\n]}\n")
+  }
+}
+
+/// All previous passwords (newest first) with the time they were replaced.
+fn password_history(versions: &[SecretVersion]) -> Vec<PasswordHistory<'_>> {
+  let mut history: Vec<PasswordHistory> = versions
+    .windows(2)
+    .filter_map(|pair| {
+      let previous = pair[0].properties.get(PROPERTY_PASSWORD)?;
+
+      if pair[1].properties.get(PROPERTY_PASSWORD) == Some(previous) {
+        return None;
+      }
+      Some(PasswordHistory {
+        last_used_date: pair[1].timestamp.format(TIME_FORMAT),
+        password: previous,
+      })
+    })
+    .collect();
+
+  history.reverse();
+  history
+}
+
+/// Bitwarden expects uuids as folder ids, so these are derived from the tag name.
+fn folder_id(tag: &str) -> String {
+  let hex = HEXLOWER.encode(&sha256(tag.as_bytes())[..16]);
+
+  format!(
+    "{}-{}-{}-{}-{}",
+    &hex[0..8],
+    &hex[8..12],
+    &hex[12..16],
+    &hex[16..20],
+    &hex[20..32]
+  )
+}
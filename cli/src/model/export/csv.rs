@@ -0,0 +1,76 @@
+use super::{write_record, AttachmentSource, Exporter};
+use std::io::{self, Write};
+use t_rust_less_lib::api::{SecretType, SecretVersion, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_USERNAME};
+
+const HEADER: &[u8] = b"name,url,username,password,note\r\n";
+
+/// Plain csv of the current version of all Login secrets.
+///
+/// Uses the same columns as the password export of Chrome, which is understood by pretty much
+/// every password manager.
+pub struct CsvExporter;
+
+impl Exporter for CsvExporter {
+  fn begin(&mut self, out: &mut dyn Write, _: &[String]) -> io::Result<()> {
+    out.write_all(HEADER)
+  }
+
+  fn write_secret(
+    &mut self,
+    out: &mut dyn Write,
+    _: &str,
+    versions: &[SecretVersion],
+    _: &dyn AttachmentSource,
+  ) -> io::Result<()> {
+    let current = match versions.last() {
+      Some(current) if current.secret_type == SecretType::Login => current,
+      _ => return Ok(()),
+    };
+
+    write_record(out, |record| {
+      let columns = [
+        current.name.as_str(),
+        current.urls.first().map(String::as_str).unwrap_or_default(),
+        property(current, PROPERTY_USERNAME),
+        property(current, PROPERTY_PASSWORD),
+        property(current, PROPERTY_NOTES),
+      ];
+
+      for (idx, column) in columns.iter().enumerate() {
+        if idx > 0 {
+          record.write_all(b",")?;
+        }
+        write_field(record, column)?;
+      }
+      record.write_all(b"\r\n")
+    })
+  }
+
+  fn end(&mut self, _: &mut dyn Write) -> io::Result<()> {
+    Ok(())
+  }
+
+  fn with_history(&self) -> bool {
+    false
+  }
+}
+
+fn property<'a>(version: &'a SecretVersion, name: &str) -> &'a str {
+  version.properties.get(name).map(String::as_str).unwrap_or_default()
+}
+
+/// Write a field quoted according to RFC 4180 (if necessary).
+fn write_field<W: Write>(record: &mut W, field: &str) -> io::Result<()> {
+  if !field.contains(&[',', '"', '\r', '\n'][..]) {
+    return record.write_all(field.as_bytes());
+  }
+
+  record.write_all(b"\"")?;
+  for (idx, part) in field.split('"').enumerate() {
+    if idx > 0 {
+      record.write_all(b"\"\"")?;
+    }
+    record.write_all(part.as_bytes())?;
+  }
+  record.write_all(b"\"")
+}
@@ -0,0 +1,253 @@
+use super::{write_record, AttachmentSource, Exporter};
+use data_encoding::BASE64;
+use openssl::sha::sha256;
+use std::io::{self, Write};
+use t_rust_less_lib::api::{SecretVersion, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME};
+use zeroize::{Zeroize, Zeroizing};
+
+const TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+const RECYCLE_BIN_NAME: &str = "Recycle Bin";
+
+/// KeePass 2 xml, which can be imported by KeePass, KeePassXC and most other password managers.
+///
+/// The entire history of each secret is exported as well, deleted secrets end up in the recycle bin.
+pub struct KeePassExporter {
+  database_name: String,
+  in_recycle_bin: bool,
+}
+
+impl KeePassExporter {
+  pub fn new(database_name: &str) -> KeePassExporter {
+    KeePassExporter {
+      database_name: database_name.to_string(),
+      in_recycle_bin: false,
+    }
+  }
+}
+
+impl Exporter for KeePassExporter {
+  fn begin(&mut self, out: &mut dyn Write, _: &[String]) -> io::Result<()> {
+    out.write_all(b"<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>\n<KeePassFile>\n<Meta>\n")?;
+    write_element(out, "Generator", "t-rust-less")?;
+    write_element(out, "DatabaseName", &self.database_name)?;
+    write_element(out, "RecycleBinEnabled", "True")?;
+    write_element(out, "RecycleBinUUID", &uuid(RECYCLE_BIN_NAME))?;
+    out.write_all(b"</Meta>\n<Root>\n<Group>\n")?;
+    write_element(out, "UUID", &uuid(&self.database_name))?;
+    write_element(out, "Name", &self.database_name)
+  }
+
+  fn write_secret(
+    &mut self,
+    out: &mut dyn Write,
+    secret_id: &str,
+    versions: &[SecretVersion],
+    attachments: &dyn AttachmentSource,
+  ) -> io::Result<()> {
+    let current = match versions.last() {
+      Some(current) => current,
+      None => return Ok(()),
+    };
+
+    if current.deleted && !self.in_recycle_bin {
+      out.write_all(b"<Group>\n")?;
+      write_element(out, "UUID", &uuid(RECYCLE_BIN_NAME))?;
+      write_element(out, "Name", RECYCLE_BIN_NAME)?;
+      self.in_recycle_bin = true;
+    }
+
+    write_record(out, |record| {
+      let secret_uuid = uuid(secret_id);
+      let creation_time = versions[0].timestamp.format(TIME_FORMAT);
+
+      record.write_all(b"<Entry>\n")?;
+      write_entry_content(record, &secret_uuid, &creation_time, current, attachments)?;
+      record.write_all(b"<History>\n")?;
+      for version in &versions[..versions.len() - 1] {
+        record.write_all(b"<Entry>\n")?;
+        write_entry_content(record, &secret_uuid, &creation_time, version, attachments)?;
+        record.write_all(b"</Entry>\n")?;
+      }
+      record.write_all(b"</History>\n</Entry>\n")
+    })
+  }
+
+  fn end(&mut self, out: &mut dyn Write) -> io::Result<()> {
+    if self.in_recycle_bin {
+      out.write_all(b"</Group>\n")?;
+    }
+    out.write_all(b"</Group>\n</Root>\n</KeePassFile>\n")
+  }
+}
+
+fn write_entry_content<W: Write>(
+  record: &mut W,
+  secret_uuid: &str,
+  creation_time: &str,
+  version: &SecretVersion,
+  attachments: &dyn AttachmentSource,
+) -> io::Result<()> {
+  let password_properties = version.secret_type.password_properties();
+
+  write_element(record, "UUID", secret_uuid)?;
+  write_element(record, "Tags", &version.tags.join(";"))?;
+  record.write_all(b"<Times>\n")?;
+  write_element(record, "CreationTime", creation_time)?;
+  write_element(record, "LastModificationTime", &version.timestamp.format(TIME_FORMAT))?;
+  record.write_all(b"</Times>\n")?;
+
+  write_string(record, "Title", &version.name, false)?;
+  for (idx, url) in version.urls.iter().enumerate() {
+    match idx {
+      0 => write_string(record, "URL", url, false)?,
+      idx => write_string(record, &format!("KP2A_URL_{}", idx), url, false)?,
+    }
+  }
+  for (name, value) in version.properties.iter() {
+    let key = match name {
+      PROPERTY_USERNAME => "UserName",
+      PROPERTY_PASSWORD => "Password",
+      PROPERTY_NOTES => "Notes",
+      PROPERTY_TOTP_URL => "otp",
+      name => name,
+    };
+
+    write_string(
+      record,
+      key,
+      value,
+      name == PROPERTY_TOTP_URL || password_properties.contains(&name),
+    )?;
+  }
+  for attachment in &version.attachments {
+    record.write_all(b"<Binary><Key>")?;
+    write_escaped(record, attachment.name())?;
+    record.write_all(b"</Key><Value>")?;
+    let mut encoder = Base64Writer::new(&mut *record);
+    attachments.write_content(attachment, &mut encoder)?;
+    encoder.finish()?;
+    record.write_all(b"</Value></Binary>\n")?;
+  }
+
+  Ok(())
+}
+
+fn write_string<W: Write>(record: &mut W, key: &str, value: &str, protected: bool) -> io::Result<()> {
+  record.write_all(b"<String><Key>")?;
+  write_escaped(record, key)?;
+  if protected {
+    record.write_all(b"</Key><Value ProtectInMemory=\"True\">")?;
+  } else {
+    record.write_all(b"</Key><Value>")?;
+  }
+  write_escaped(record, value)?;
+  record.write_all(b"</Value></String>\n")
+}
+
+fn write_element<W: Write + ?Sized>(out: &mut W, name: &str, content: &str) -> io::Result<()> {
+  write!(out, "<{}>", name)?;
+  write_escaped(out, content)?;
+  writeln!(out, "</{}>", name)
+}
+
+/// Escape xml special chars without creating a copy of the content.
+fn write_escaped<W: Write + ?Sized>(out: &mut W, content: &str) -> io::Result<()> {
+  let mut start = 0;
+
+  for (idx, ch) in content.char_indices() {
+    let escaped: &[u8] = match ch {
+      '&' => b"&amp;",
+      '<' => b"&lt;",
+      '>' => b"&gt;",
+      '"' => b"&quot;",
+      '\'' => b"&apos;",
+      _ => continue,
+    };
+    out.write_all(&content.as_bytes()[start..idx])?;
+    out.write_all(escaped)?;
+    start = idx + 1;
+  }
+
+  out.write_all(&content.as_bytes()[start..])
+}
+
+/// Base64 encodes the content written to it on the fly.
+///
+/// Only the remainder of an incomplete group of three bytes is kept until the next write.
+struct Base64Writer<W: Write> {
+  out: W,
+  pending: [u8; 3],
+  pending_len: usize,
+}
+
+impl<W: Write> Base64Writer<W> {
+  fn new(out: W) -> Self {
+    Base64Writer {
+      out,
+      pending: [0; 3],
+      pending_len: 0,
+    }
+  }
+
+  /// Write the remainder (including the padding).
+  fn finish(mut self) -> io::Result<()> {
+    let mut encoded = [0u8; 4];
+
+    if self.pending_len > 0 {
+      BASE64.encode_mut(&self.pending[..self.pending_len], &mut encoded);
+      self.out.write_all(&encoded)?;
+    }
+    encoded.zeroize();
+
+    Ok(())
+  }
+}
+
+impl<W: Write> Write for Base64Writer<W> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    let mut input = buf;
+
+    if self.pending_len > 0 {
+      let missing = (3 - self.pending_len).min(input.len());
+
+      self.pending[self.pending_len..self.pending_len + missing].copy_from_slice(&input[..missing]);
+      self.pending_len += missing;
+      input = &input[missing..];
+      if self.pending_len < 3 {
+        return Ok(buf.len());
+      }
+      let mut encoded = [0u8; 4];
+
+      BASE64.encode_mut(&self.pending, &mut encoded);
+      self.pending_len = 0;
+      let result = self.out.write_all(&encoded);
+      encoded.zeroize();
+      result?;
+    }
+
+    let complete = input.len() / 3 * 3;
+    let mut encoded = Zeroizing::new(vec![0u8; BASE64.encode_len(complete)]);
+
+    BASE64.encode_mut(&input[..complete], &mut encoded);
+    self.out.write_all(&encoded)?;
+    self.pending_len = input.len() - complete;
+    self.pending[..self.pending_len].copy_from_slice(&input[complete..]);
+
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.out.flush()
+  }
+}
+
+impl<W: Write> Drop for Base64Writer<W> {
+  fn drop(&mut self) {
+    self.pending.zeroize();
+  }
+}
+
+/// KeePass requires 16 byte uuids, which are derived from the (arbitrary) ids of the secrets.
+fn uuid(id: &str) -> String {
+  BASE64.encode(&sha256(id.as_bytes())[..16])
+}
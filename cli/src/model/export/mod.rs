@@ -0,0 +1,65 @@
+//! Export of secrets to formats that other password managers are able to import.
+//!
+//! All exporters write one record (i.e. secret) at a time via a `ZeroizeBytesBuffer`, so that
+//! the plaintext of an export never accumulates in regular heap memory. This includes the content of
+//! attachments, which is streamed into the record.
+use std::io::{self, Write};
+use t_rust_less_lib::api::{SecretAttachment, SecretVersion};
+use t_rust_less_lib::memguard::ZeroizeBytesBuffer;
+
+mod bitwarden;
+mod csv;
+mod keepass;
+mod native;
+#[cfg(test)]
+mod tests;
+
+pub use self::bitwarden::BitwardenExporter;
+pub use self::csv::CsvExporter;
+pub use self::keepass::KeePassExporter;
+pub use self::native::NativeExporter;
+
+const RECORD_CAPACITY: usize = 4096;
+
+/// Source of the content of attachments (e.g. the store the versions have been read from).
+pub trait AttachmentSource {
+  /// Write the entire content of an attachment.
+  fn write_content(&self, attachment: &SecretAttachment, out: &mut dyn Write) -> io::Result<()>;
+}
+
+pub trait Exporter {
+  /// Write everything required before the first secret
+  fn begin(&mut self, out: &mut dyn Write, all_tags: &[String]) -> io::Result<()>;
+
+  /// Write a single secret.
+  ///
+  /// `versions` contains all versions of the secret (oldest first), i.e. the last one is the current version.
+  /// Deleted secrets are always written after all the regular ones. The content of attachments is
+  /// obtained from `attachments` while the record is rendered.
+  fn write_secret(
+    &mut self,
+    out: &mut dyn Write,
+    secret_id: &str,
+    versions: &[SecretVersion],
+    attachments: &dyn AttachmentSource,
+  ) -> io::Result<()>;
+
+  /// Write everything required after the last secret
+  fn end(&mut self, out: &mut dyn Write) -> io::Result<()>;
+
+  /// Whether the exporter makes use of the previous versions of a secret
+  fn with_history(&self) -> bool {
+    true
+  }
+}
+
+/// Render a record to a zeroizing buffer and write it to `out` as a whole.
+fn write_record<F>(out: &mut dyn Write, render: F) -> io::Result<()>
+where
+  F: FnOnce(&mut ZeroizeBytesBuffer) -> io::Result<()>,
+{
+  let mut record = ZeroizeBytesBuffer::with_capacity(RECORD_CAPACITY);
+
+  render(&mut record)?;
+  out.write_all(&record)
+}
@@ -0,0 +1,131 @@
+use super::{write_record, AttachmentSource, Exporter};
+use serde::ser::{self, SerializeSeq, SerializeStruct, Serializer};
+use serde_derive::Serialize;
+use std::io::{self, Write};
+use t_rust_less_lib::api::{SecretAttachment, SecretProperties, SecretType, SecretVersion, ZeroizeDateTime};
+
+/// Native format: One json object per line and secret, which can be imported again via `import --v1`.
+pub struct NativeExporter;
+
+impl Exporter for NativeExporter {
+  fn begin(&mut self, _: &mut dyn Write, _: &[String]) -> io::Result<()> {
+    Ok(())
+  }
+
+  fn write_secret(
+    &mut self,
+    out: &mut dyn Write,
+    secret_id: &str,
+    versions: &[SecretVersion],
+    attachments: &dyn AttachmentSource,
+  ) -> io::Result<()> {
+    let secret = NativeSecret {
+      id: secret_id,
+      secret_type: versions
+        .last()
+        .map(|version| version.secret_type)
+        .unwrap_or(SecretType::Other),
+      versions: versions
+        .iter()
+        .map(|version| NativeVersion {
+          timestamp: &version.timestamp,
+          name: &version.name,
+          tags: &version.tags,
+          urls: &version.urls,
+          properties: &version.properties,
+          attachments: version
+            .attachments
+            .iter()
+            .map(|attachment| NativeAttachment {
+              attachment,
+              attachments,
+            })
+            .collect(),
+          deleted: version.deleted,
+        })
+        .collect(),
+    };
+
+    write_record(out, |record| {
+      serde_json::to_writer(&mut *record, &secret)?;
+      record.write_all(b"\n")
+    })
+  }
+
+  fn end(&mut self, _: &mut dyn Write) -> io::Result<()> {
+    Ok(())
+  }
+}
+
+/// Borrowed counterpart of `SecretV1`, so that nothing has to be copied.
+#[derive(Serialize)]
+struct NativeSecret<'a> {
+  id: &'a str,
+  #[serde(rename = "type")]
+  secret_type: SecretType,
+  versions: Vec<NativeVersion<'a>>,
+}
+
+/// Borrowed counterpart of `SecretVersionV1`.
+#[derive(Serialize)]
+struct NativeVersion<'a> {
+  timestamp: &'a ZeroizeDateTime,
+  name: &'a str,
+  tags: &'a [String],
+  urls: &'a [String],
+  properties: &'a SecretProperties,
+  attachments: Vec<NativeAttachment<'a>>,
+  deleted: bool,
+}
+
+/// Attachment with inline content (as `import --v1` expects it), which is streamed from its source.
+struct NativeAttachment<'a> {
+  attachment: &'a SecretAttachment,
+  attachments: &'a dyn AttachmentSource,
+}
+
+impl<'a> serde::Serialize for NativeAttachment<'a> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("SecretAttachment", 4)?;
+
+    state.serialize_field("name", self.attachment.name())?;
+    state.serialize_field("mime_type", self.attachment.mime_type())?;
+    state.serialize_field("content", &StreamedContent(self))?;
+    state.serialize_field("size", &self.attachment.size())?;
+    state.end()
+  }
+}
+
+struct StreamedContent<'a>(&'a NativeAttachment<'a>);
+
+impl<'a> serde::Serialize for StreamedContent<'a> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(self.0.attachment.size() as usize))?;
+
+    self
+      .0
+      .attachments
+      .write_content(self.0.attachment, &mut SeqWriter(&mut seq))
+      .map_err(ser::Error::custom)?;
+    seq.end()
+  }
+}
+
+/// Serializes every byte written to it as an element of a sequence.
+struct SeqWriter<'a, S: SerializeSeq>(&'a mut S);
+
+impl<'a, S: SerializeSeq> Write for SeqWriter<'a, S> {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    for byte in buf {
+      self
+        .0
+        .serialize_element(byte)
+        .map_err(|error| io::Error::other(error.to_string()))?;
+    }
+    Ok(buf.len())
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    Ok(())
+  }
+}
@@ -0,0 +1,149 @@
+use super::*;
+use crate::model::import_v1::SecretV1;
+use crate::model::{bitwarden, browser_csv, keepass};
+use chrono::DateTime;
+use spectral::prelude::*;
+use t_rust_less_lib::api::{
+  SecretAttachment, SecretProperties, SecretType, ZeroizeDateTime, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_USERNAME,
+};
+
+const NAME: &str = "Mail \"work\", <&> 'all'";
+const URL: &str = "https://mail.example.com/?a=1&b=\"2\"";
+const USERNAME: &str = "john, \"doe\"";
+const PASSWORD: &str = "p<a&s>s, \"word\"\r\nline 2";
+const NOTES: &str = "first line\nsecond, \"quoted\" <line> & more";
+const ATTACHMENT: &[u8] = b"attachment <&>\x00\xff\n";
+
+/// Writes the (inline) content in small pieces, like a store does chunk by chunk.
+struct InlineAttachments;
+
+impl AttachmentSource for InlineAttachments {
+  fn write_content(&self, attachment: &SecretAttachment, out: &mut dyn Write) -> io::Result<()> {
+    for piece in attachment.content().chunks(2) {
+      out.write_all(piece)?;
+    }
+    Ok(())
+  }
+}
+
+fn timestamp(date: &str) -> ZeroizeDateTime {
+  DateTime::parse_from_rfc3339(date)
+    .unwrap()
+    .with_timezone(&chrono::Utc)
+    .into()
+}
+
+fn version(password: &str, timestamp: ZeroizeDateTime) -> SecretVersion {
+  let mut properties = SecretProperties::default();
+
+  properties.set(PROPERTY_USERNAME, USERNAME);
+  properties.set(PROPERTY_PASSWORD, password);
+  properties.set(PROPERTY_NOTES, NOTES);
+
+  let mut version = SecretVersion::new("secret1", SecretType::Login, NAME, timestamp);
+
+  version.tags.push("Work & <Private>".to_string());
+  version.urls.push(URL.to_string());
+  version.properties = properties;
+  version
+}
+
+fn versions() -> Vec<SecretVersion> {
+  let mut current = version(PASSWORD, timestamp("2021-03-04T05:06:07Z"));
+
+  current.attachments.push(SecretAttachment::new(
+    "file.bin".to_string(),
+    "application/octet-stream".to_string(),
+    ATTACHMENT.to_vec(),
+  ));
+
+  vec![version("previous, \"one\"", timestamp("2020-01-02T03:04:05Z")), current]
+}
+
+fn export<E: Exporter>(mut exporter: E) -> Vec<u8> {
+  let mut out = Vec::new();
+
+  exporter.begin(&mut out, &["Work & <Private>".to_string()]).unwrap();
+  exporter
+    .write_secret(&mut out, "secret1", &versions(), &InlineAttachments)
+    .unwrap();
+  exporter.end(&mut out).unwrap();
+
+  out
+}
+
+fn assert_current(version: &SecretVersion) {
+  assert_that(&version.name.as_str()).is_equal_to(NAME);
+  assert_that(&version.urls).is_equal_to(vec![URL.to_string()]);
+  assert_that(&version.properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&USERNAME.to_string()));
+  assert_that(&version.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&PASSWORD.to_string()));
+  assert_that(&version.properties.get(PROPERTY_NOTES)).is_equal_to(Some(&NOTES.to_string()));
+}
+
+#[test]
+fn test_csv_round_trip() {
+  let exported = export(CsvExporter);
+  let import_set = browser_csv::read_export(&exported).unwrap();
+
+  assert_that(&import_set.skipped).is_empty();
+  assert_that(&import_set.secrets.len()).is_equal_to(1);
+  // The csv only contains the current version
+  assert_that(&import_set.secrets[0].len()).is_equal_to(1);
+  assert_current(&import_set.secrets[0][0]);
+}
+
+#[test]
+fn test_keepass_round_trip() {
+  let exported = export(KeePassExporter::new("Export <&>"));
+  let entries = keepass::read_entries(&exported, None).unwrap();
+
+  assert_that(&entries.len()).is_equal_to(1);
+
+  let versions = entries[0].to_secret_versions("secret1");
+  assert_that(&versions.len()).is_equal_to(2);
+  assert_that(&versions[0].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"previous, \"one\"".to_string()));
+  assert_that(&versions[0].timestamp).is_equal_to(timestamp("2020-01-02T03:04:05Z"));
+  assert_that(&versions[1].timestamp).is_equal_to(timestamp("2021-03-04T05:06:07Z"));
+  assert_that(&versions[1].tags).is_equal_to(vec!["Work & <Private>".to_string()]);
+  assert_that(&versions[0].attachments).is_empty();
+  assert_that(&versions[1].attachments.len()).is_equal_to(1);
+  assert_that(&versions[1].attachments[0].content()).is_equal_to(ATTACHMENT);
+  assert_current(&versions[1]);
+}
+
+#[test]
+fn test_native_round_trip() {
+  let exported = export(NativeExporter);
+  let secret: SecretV1 = serde_json::from_slice(&exported).unwrap();
+
+  assert_that(&secret.id.as_str()).is_equal_to("secret1");
+  assert_that(&secret.versions.len()).is_equal_to(2);
+  assert_that(&secret.versions[0].properties.get(PROPERTY_PASSWORD))
+    .is_equal_to(Some(&"previous, \"one\"".to_string()));
+
+  let current = &secret.versions[1];
+  let attachments = current.attachments.as_ref().unwrap();
+
+  assert_that(&current.name.as_str()).is_equal_to(NAME);
+  assert_that(&current.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&PASSWORD.to_string()));
+  assert_that(&attachments.len()).is_equal_to(1);
+  assert_that(&attachments[0].name()).is_equal_to("file.bin");
+  assert_that(&attachments[0].content()).is_equal_to(ATTACHMENT);
+  assert_that(&attachments[0].is_stored()).is_false();
+}
+
+#[test]
+fn test_bitwarden_round_trip() {
+  let exported = export(BitwardenExporter::new());
+  let import_set = bitwarden::read_export(&exported).unwrap();
+
+  assert_that(&import_set.skipped).is_empty();
+  assert_that(&import_set.secrets.len()).is_equal_to(1);
+
+  let versions = &import_set.secrets[0];
+  assert_that(&versions.len()).is_equal_to(2);
+  assert_that(&versions[0].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"previous, \"one\"".to_string()));
+  assert_that(&versions[1].timestamp).is_equal_to(timestamp("2021-03-04T05:06:07Z"));
+  assert_that(&versions[1].tags).is_equal_to(vec!["Work & <Private>".to_string()]);
+  assert_current(&versions[1]);
+}
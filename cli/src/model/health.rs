@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use t_rust_less_lib::api::{HealthReport, SecretHealth};
+
+/// Human readable descriptions of the issues of a secret in a health report.
+///
+/// Reused passwords are described by the names of the other secrets (as far as they are part of the report).
+pub fn describe_issues(health: &SecretHealth, report: &HealthReport) -> Vec<String> {
+  let names: HashMap<&str, &str> = report
+    .secrets
+    .iter()
+    .map(|other| (other.secret_id.as_str(), other.name.as_str()))
+    .collect();
+  let mut issues = Vec::new();
+
+  if health.breached {
+    issues.push("password appears in breached passwords".to_string());
+  }
+  if health.weak {
+    issues.push(format!("weak password (score {})", health.score));
+  }
+  if !health.reused_with.is_empty() {
+    let others: Vec<&str> = health
+      .reused_with
+      .iter()
+      .map(|secret_id| names.get(secret_id.as_str()).copied().unwrap_or(secret_id.as_str()))
+      .collect();
+    issues.push(format!("password reused in {}", others.join(", ")));
+  }
+  if let Some(changed_at) = &health.password_changed_at {
+    issues.push(format!("password unchanged since {}", changed_at.format("%Y-%m-%d")));
+  }
+  if health.missing_totp {
+    issues.push("no one-time password".to_string());
+  }
+
+  issues
+}
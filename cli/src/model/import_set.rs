@@ -0,0 +1,100 @@
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+use std::io;
+use t_rust_less_lib::api::{SecretVersion, ZeroizeDateTime, PROPERTY_PASSWORD};
+
+#[derive(Debug)]
+pub enum ImportError {
+  IO(String),
+  Format(String),
+  Unsupported(String),
+}
+
+impl fmt::Display for ImportError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      ImportError::IO(error) => write!(f, "IO: {}", error),
+      ImportError::Format(error) => write!(f, "Invalid format: {}", error),
+      ImportError::Unsupported(error) => write!(f, "Unsupported: {}", error),
+    }
+  }
+}
+
+pub type ImportResult<T> = Result<T, ImportError>;
+
+impl From<io::Error> for ImportError {
+  fn from(error: io::Error) -> Self {
+    ImportError::IO(format!("{}", error))
+  }
+}
+
+impl From<serde_json::Error> for ImportError {
+  fn from(error: serde_json::Error) -> Self {
+    ImportError::Format(format!("{}", error))
+  }
+}
+
+impl From<csv::Error> for ImportError {
+  fn from(error: csv::Error) -> Self {
+    ImportError::Format(format!("{}", error))
+  }
+}
+
+impl From<zip::result::ZipError> for ImportError {
+  fn from(error: zip::result::ZipError) -> Self {
+    ImportError::Format(format!("{}", error))
+  }
+}
+
+/// An item of the import file that will not be imported.
+pub struct SkippedItem {
+  pub name: String,
+  pub reason: String,
+}
+
+/// Result of reading an export of another password manager.
+///
+/// The versions of each secret are ordered oldest first, the `secret_id` of the versions is not
+/// set yet, since it has to be generated by the service.
+#[derive(Default)]
+pub struct ImportSet {
+  pub secrets: Vec<Vec<SecretVersion>>,
+  pub skipped: Vec<SkippedItem>,
+}
+
+impl ImportSet {
+  pub fn skip<S: Into<String>, R: Into<String>>(&mut self, name: S, reason: R) {
+    self.skipped.push(SkippedItem {
+      name: name.into(),
+      reason: reason.into(),
+    })
+  }
+}
+
+/// Create the previous versions of a secret from the password history of an entry (i.e. every
+/// previous version differs from the current one just by its password).
+///
+/// The timestamp of a history entry is the time the password was replaced, which usually is the
+/// timestamp of the current version as well. Such a version is moved just before the current one.
+pub fn password_history_versions(
+  current: &SecretVersion,
+  mut history: Vec<(String, DateTime<Utc>)>,
+) -> Vec<SecretVersion> {
+  let latest = current.timestamp + Duration::milliseconds(-1);
+
+  history.sort_by_key(|(_, timestamp)| *timestamp);
+
+  history
+    .into_iter()
+    .map(|(password, timestamp)| (password, ZeroizeDateTime::from(timestamp)))
+    .filter(|(_, timestamp)| *timestamp <= current.timestamp)
+    .map(|(password, timestamp)| {
+      let mut version = current.clone();
+
+      version.timestamp = timestamp.min(latest);
+      version.properties.set(PROPERTY_PASSWORD, &password);
+      version.deleted = false;
+      version
+    })
+    .collect()
+}
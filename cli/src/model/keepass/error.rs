@@ -0,0 +1,64 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum KeePassError {
+  IO(String),
+  Format(String),
+  Unsupported(String),
+  Cipher(String),
+  InvalidPassphrase,
+  Corrupted,
+}
+
+impl fmt::Display for KeePassError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      KeePassError::IO(error) => write!(f, "IO: {}", error)?,
+      KeePassError::Format(error) => write!(f, "Invalid format: {}", error)?,
+      KeePassError::Unsupported(feature) => write!(f, "Not supported: {}", feature)?,
+      KeePassError::Cipher(error) => write!(f, "Cipher: {}", error)?,
+      KeePassError::InvalidPassphrase => write!(f, "Invalid passphrase")?,
+      KeePassError::Corrupted => write!(f, "Database is corrupted")?,
+    }
+
+    Ok(())
+  }
+}
+
+pub type KeePassResult<T> = Result<T, KeePassError>;
+
+impl From<std::io::Error> for KeePassError {
+  fn from(error: std::io::Error) -> Self {
+    KeePassError::IO(format!("{}", error))
+  }
+}
+
+impl From<xml::reader::Error> for KeePassError {
+  fn from(error: xml::reader::Error) -> Self {
+    KeePassError::Format(format!("{}", error))
+  }
+}
+
+impl From<data_encoding::DecodeError> for KeePassError {
+  fn from(error: data_encoding::DecodeError) -> Self {
+    KeePassError::Format(format!("{}", error))
+  }
+}
+
+impl From<std::string::FromUtf8Error> for KeePassError {
+  fn from(error: std::string::FromUtf8Error) -> Self {
+    KeePassError::Format(format!("{}", error))
+  }
+}
+
+impl From<openssl::error::ErrorStack> for KeePassError {
+  fn from(error: openssl::error::ErrorStack) -> Self {
+    KeePassError::Cipher(format!("{}", error))
+  }
+}
+
+impl From<argon2::Error> for KeePassError {
+  fn from(error: argon2::Error) -> Self {
+    KeePassError::Cipher(format!("{}", error))
+  }
+}
@@ -0,0 +1,400 @@
+//! Decryption of KDBX 4 databases.
+//!
+//! See https://keepass.info/help/kb/kdbx_4.html for the format specification.
+use super::{KeePassError, KeePassResult};
+use argon2::{Config, ThreadMode, Variant, Version};
+use flate2::read::GzDecoder;
+use openssl::hash::{Hasher, MessageDigest};
+use openssl::memcmp;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use openssl::symm::{self, Cipher, Crypter, Mode};
+use std::collections::HashMap;
+use std::io::Read;
+use t_rust_less_lib::memguard::SecretBytes;
+use zeroize::Zeroizing;
+
+const SIGNATURE_1: u32 = 0x9AA2_D903;
+const SIGNATURE_2: u32 = 0xB54B_FB67;
+
+const CIPHER_AES256: [u8; 16] = [
+  0x31, 0xc1, 0xf2, 0xe6, 0xbf, 0x71, 0x43, 0x50, 0xbe, 0x58, 0x05, 0x21, 0x6a, 0xfc, 0x5a, 0xff,
+];
+const CIPHER_CHACHA20: [u8; 16] = [
+  0xd6, 0x03, 0x8a, 0x2b, 0x8b, 0x6f, 0x4c, 0xb5, 0xa5, 0x24, 0x33, 0x9a, 0x31, 0xdb, 0xb5, 0x9a,
+];
+
+const KDF_AES: [u8; 16] = [
+  0xc9, 0xd9, 0xf3, 0x9a, 0x62, 0x8a, 0x44, 0x60, 0xbf, 0x74, 0x0d, 0x08, 0xc1, 0x8a, 0x4f, 0xea,
+];
+const KDF_ARGON2D: [u8; 16] = [
+  0xef, 0x63, 0x6d, 0xdf, 0x8c, 0x29, 0x44, 0x4b, 0x91, 0xf7, 0xa9, 0xa4, 0x03, 0xe3, 0x0a, 0x0c,
+];
+const KDF_ARGON2ID: [u8; 16] = [
+  0x9e, 0x29, 0x8b, 0x19, 0x56, 0xdb, 0x47, 0x73, 0xb2, 0x3d, 0xfc, 0x3e, 0xc6, 0xf0, 0xa1, 0xe6,
+];
+
+const INNER_STREAM_CHACHA20: u32 = 3;
+
+/// Check if some data looks like a KDBX database (of any version)
+pub fn is_kdbx(data: &[u8]) -> bool {
+  let mut reader = ByteReader::new(data);
+
+  matches!((reader.u32(), reader.u32()), (Ok(SIGNATURE_1), Ok(SIGNATURE_2)))
+}
+
+/// Decrypted content of a KDBX database
+pub struct KdbxContent {
+  pub xml: Zeroizing<Vec<u8>>,
+  pub inner_stream: Option<InnerStream>,
+  pub binaries: Vec<Zeroizing<Vec<u8>>>,
+}
+
+pub fn decrypt(data: &[u8], passphrase: &SecretBytes) -> KeePassResult<KdbxContent> {
+  let mut reader = ByteReader::new(data);
+
+  if reader.u32()? != SIGNATURE_1 || reader.u32()? != SIGNATURE_2 {
+    return Err(KeePassError::Format("Not a KeePass database".to_string()));
+  }
+  let major_version = reader.u32()? >> 16;
+  if major_version != 4 {
+    return Err(KeePassError::Unsupported(format!(
+      "KDBX version {} (please convert to KDBX 4 or export as XML)",
+      major_version
+    )));
+  }
+
+  let mut cipher_id = None;
+  let mut compressed = false;
+  let mut master_seed = None;
+  let mut encryption_iv = None;
+  let mut kdf_parameters = None;
+
+  loop {
+    let field_id = reader.u8()?;
+    let size = reader.u32()? as usize;
+    let value = reader.take(size)?;
+
+    match field_id {
+      0 => break,
+      2 => cipher_id = Some(value),
+      3 => compressed = ByteReader::new(value).u32()? == 1,
+      4 => master_seed = Some(value),
+      7 => encryption_iv = Some(value),
+      11 => kdf_parameters = Some(read_variant_dictionary(value)?),
+      _ => (),
+    }
+  }
+
+  let header = &data[..reader.pos];
+  let header_hash = reader.take(32)?;
+  let header_hmac = reader.take(32)?;
+
+  if !memcmp::eq(&digest(MessageDigest::sha256(), &[header])?, header_hash) {
+    return Err(KeePassError::Corrupted);
+  }
+
+  let master_seed = master_seed.ok_or_else(|| missing_header("master seed"))?;
+  let transformed_key = transform_key(
+    &kdf_parameters.ok_or_else(|| missing_header("kdf parameters"))?,
+    passphrase,
+  )?;
+  let master_key = digest(MessageDigest::sha256(), &[master_seed, &transformed_key[..]])?;
+  let hmac_base_key = digest(
+    MessageDigest::sha512(),
+    &[master_seed, &transformed_key[..], &[1u8][..]],
+  )?;
+
+  if !memcmp::eq(
+    &hmac_sha256(&block_hmac_key(u64::MAX, &hmac_base_key)?, &[header])?,
+    header_hmac,
+  ) {
+    return Err(KeePassError::InvalidPassphrase);
+  }
+
+  let mut encrypted = Vec::with_capacity(data.len());
+  for block_index in 0u64.. {
+    let block_hmac = reader.take(32)?;
+    let size = reader.u32()?;
+    let block = reader.take(size as usize)?;
+    let expected = hmac_sha256(
+      &block_hmac_key(block_index, &hmac_base_key)?,
+      &[&block_index.to_le_bytes()[..], &size.to_le_bytes()[..], block],
+    )?;
+
+    if !memcmp::eq(&expected, block_hmac) {
+      return Err(KeePassError::Corrupted);
+    }
+    if size == 0 {
+      break;
+    }
+    encrypted.extend_from_slice(block);
+  }
+
+  let encryption_iv = encryption_iv.ok_or_else(|| missing_header("encryption iv"))?;
+  let decrypted = match cipher_id {
+    Some(cipher_id) if cipher_id[..] == CIPHER_AES256[..] => Zeroizing::new(symm::decrypt(
+      Cipher::aes_256_cbc(),
+      &master_key,
+      Some(encryption_iv),
+      &encrypted,
+    )?),
+    Some(cipher_id) if cipher_id[..] == CIPHER_CHACHA20[..] => {
+      Zeroizing::new(chacha20(&master_key, encryption_iv, &encrypted)?)
+    }
+    _ => {
+      return Err(KeePassError::Unsupported(
+        "Cipher (only AES256 and ChaCha20)".to_string(),
+      ))
+    }
+  };
+  let payload = if compressed {
+    let mut decompressed = Zeroizing::new(Vec::with_capacity(decrypted.len() * 4));
+    GzDecoder::new(&decrypted[..]).read_to_end(&mut decompressed)?;
+    decompressed
+  } else {
+    decrypted
+  };
+
+  read_inner(&payload)
+}
+
+fn read_inner(payload: &[u8]) -> KeePassResult<KdbxContent> {
+  let mut reader = ByteReader::new(payload);
+  let mut inner_stream_id = 0;
+  let mut inner_stream_key = None;
+  let mut binaries = Vec::new();
+
+  loop {
+    let field_id = reader.u8()?;
+    let size = reader.u32()? as usize;
+    let value = reader.take(size)?;
+
+    match field_id {
+      0 => break,
+      1 => inner_stream_id = ByteReader::new(value).u32()?,
+      2 => inner_stream_key = Some(value),
+      // First byte are flags (i.e. if the binary should be protected in memory)
+      3 if !value.is_empty() => binaries.push(Zeroizing::new(value[1..].to_vec())),
+      _ => (),
+    }
+  }
+
+  let inner_stream = match (inner_stream_id, inner_stream_key) {
+    (0, _) => None,
+    (INNER_STREAM_CHACHA20, Some(key)) => Some(InnerStream::new(key)?),
+    _ => {
+      return Err(KeePassError::Unsupported(
+        "Inner random stream (only ChaCha20)".to_string(),
+      ))
+    }
+  };
+
+  Ok(KdbxContent {
+    xml: Zeroizing::new(payload[reader.pos..].to_vec()),
+    inner_stream,
+    binaries,
+  })
+}
+
+/// Stream cipher for the protected values inside the xml.
+///
+/// The key stream is shared by all protected values in the order of their appearance.
+pub struct InnerStream {
+  crypter: Crypter,
+}
+
+impl InnerStream {
+  fn new(key: &[u8]) -> KeePassResult<InnerStream> {
+    let key_hash = digest(MessageDigest::sha512(), &[key])?;
+    let mut iv = [0u8; 16];
+
+    // openssl expects the 32-bit block counter in front of the 96-bit nonce
+    iv[4..].copy_from_slice(&key_hash[32..44]);
+
+    Ok(InnerStream {
+      crypter: Crypter::new(Cipher::chacha20(), Mode::Decrypt, &key_hash[..32], Some(&iv))?,
+    })
+  }
+
+  pub fn unprotect(&mut self, protected: &[u8]) -> KeePassResult<String> {
+    let mut plain = vec![0u8; protected.len() + Cipher::chacha20().block_size()];
+    let count = self.crypter.update(protected, &mut plain)?;
+
+    plain.truncate(count);
+
+    Ok(String::from_utf8(plain)?)
+  }
+}
+
+fn chacha20(key: &[u8], nonce: &[u8], data: &[u8]) -> KeePassResult<Vec<u8>> {
+  if nonce.len() != 12 {
+    return Err(KeePassError::Format("Invalid ChaCha20 nonce".to_string()));
+  }
+  let mut iv = [0u8; 16];
+
+  iv[4..].copy_from_slice(nonce);
+
+  Ok(symm::decrypt(Cipher::chacha20(), key, Some(&iv), data)?)
+}
+
+fn transform_key(
+  kdf_parameters: &HashMap<String, Vec<u8>>,
+  passphrase: &SecretBytes,
+) -> KeePassResult<Zeroizing<Vec<u8>>> {
+  // Only a passphrase is supported as key, so the composite key is just a hash of a hash
+  let composite_key = digest(
+    MessageDigest::sha256(),
+    &[&digest(MessageDigest::sha256(), &[passphrase.borrow().as_bytes()])?[..]],
+  )?;
+  let kdf_id = kdf_parameters.get("$UUID").ok_or_else(|| missing_header("kdf uuid"))?;
+  let salt = kdf_parameters.get("S").ok_or_else(|| missing_header("kdf salt"))?;
+
+  if kdf_id[..] == KDF_AES[..] {
+    let rounds = variant_u64(kdf_parameters, "R")?;
+    let mut crypter = Crypter::new(Cipher::aes_256_ecb(), Mode::Encrypt, salt, None)?;
+    let mut key = Zeroizing::new(composite_key.to_vec());
+    let mut next = Zeroizing::new(vec![0u8; 64]);
+
+    crypter.pad(false);
+    for _ in 0..rounds {
+      let count = crypter.update(&key, &mut next)?;
+      key.copy_from_slice(&next[..count]);
+    }
+
+    return digest(MessageDigest::sha256(), &[&key[..]]);
+  }
+
+  let variant = if kdf_id[..] == KDF_ARGON2D[..] {
+    Variant::Argon2d
+  } else if kdf_id[..] == KDF_ARGON2ID[..] {
+    Variant::Argon2id
+  } else {
+    return Err(KeePassError::Unsupported(
+      "Key derivation (only Argon2 and AES-KDF)".to_string(),
+    ));
+  };
+  let lanes = variant_u64(kdf_parameters, "P")? as u32;
+  let config = Config {
+    ad: &[],
+    hash_length: 32,
+    lanes,
+    mem_cost: (variant_u64(kdf_parameters, "M")? / 1024) as u32,
+    secret: &[],
+    thread_mode: ThreadMode::from_threads(lanes),
+    time_cost: variant_u64(kdf_parameters, "I")? as u32,
+    version: Version::from_u32(variant_u64(kdf_parameters, "V")? as u32)?,
+    variant,
+  };
+
+  Ok(Zeroizing::new(argon2::hash_raw(&composite_key, salt, &config)?))
+}
+
+/// KDF parameters are stored as a VariantDictionary, we are only interested in the raw values.
+fn read_variant_dictionary(data: &[u8]) -> KeePassResult<HashMap<String, Vec<u8>>> {
+  let mut reader = ByteReader::new(data);
+  let mut result = HashMap::new();
+
+  if reader.u16()? >> 8 != 1 {
+    return Err(KeePassError::Unsupported("VariantDictionary version".to_string()));
+  }
+
+  loop {
+    let value_type = reader.u8()?;
+    if value_type == 0 {
+      break;
+    }
+    let name_length = reader.u32()? as usize;
+    let name = String::from_utf8(reader.take(name_length)?.to_vec())?;
+    let value_length = reader.u32()? as usize;
+    let value = reader.take(value_length)?.to_vec();
+
+    result.insert(name, value);
+  }
+
+  Ok(result)
+}
+
+fn variant_u64(parameters: &HashMap<String, Vec<u8>>, name: &str) -> KeePassResult<u64> {
+  let value = parameters
+    .get(name)
+    .ok_or_else(|| missing_header(&format!("kdf parameter {}", name)))?;
+  let mut bytes = [0u8; 8];
+
+  if value.len() > 8 {
+    return Err(KeePassError::Format(format!("Invalid kdf parameter {}", name)));
+  }
+  bytes[..value.len()].copy_from_slice(value);
+
+  Ok(u64::from_le_bytes(bytes))
+}
+
+fn block_hmac_key(block_index: u64, hmac_base_key: &[u8]) -> KeePassResult<Zeroizing<Vec<u8>>> {
+  digest(
+    MessageDigest::sha512(),
+    &[&block_index.to_le_bytes()[..], hmac_base_key],
+  )
+}
+
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> KeePassResult<Vec<u8>> {
+  let key = PKey::hmac(key)?;
+  let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+
+  for part in parts {
+    signer.update(part)?;
+  }
+
+  Ok(signer.sign_to_vec()?)
+}
+
+fn digest(digest: MessageDigest, parts: &[&[u8]]) -> KeePassResult<Zeroizing<Vec<u8>>> {
+  let mut hasher = Hasher::new(digest)?;
+
+  for part in parts {
+    hasher.update(part)?;
+  }
+
+  Ok(Zeroizing::new(hasher.finish()?.to_vec()))
+}
+
+fn missing_header(name: &str) -> KeePassError {
+  KeePassError::Format(format!("Missing {}", name))
+}
+
+struct ByteReader<'a> {
+  data: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+  fn new(data: &'a [u8]) -> ByteReader<'a> {
+    ByteReader { data, pos: 0 }
+  }
+
+  fn take(&mut self, length: usize) -> KeePassResult<&'a [u8]> {
+    if self.pos + length > self.data.len() {
+      return Err(KeePassError::Format("Unexpected end of data".to_string()));
+    }
+    let result = &self.data[self.pos..self.pos + length];
+
+    self.pos += length;
+
+    Ok(result)
+  }
+
+  fn u8(&mut self) -> KeePassResult<u8> {
+    Ok(self.take(1)?[0])
+  }
+
+  fn u16(&mut self) -> KeePassResult<u16> {
+    let mut bytes = [0u8; 2];
+    bytes.copy_from_slice(self.take(2)?);
+    Ok(u16::from_le_bytes(bytes))
+  }
+
+  fn u32(&mut self) -> KeePassResult<u32> {
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(self.take(4)?);
+    Ok(u32::from_le_bytes(bytes))
+  }
+}
@@ -0,0 +1,213 @@
+use crate::model::totp::totp_url;
+use chrono::Utc;
+use t_rust_less_lib::api::{
+  SecretAttachment, SecretProperties, SecretType, SecretVersion, ZeroizeDateTime, PROPERTY_NOTES, PROPERTY_PASSWORD,
+  PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+};
+use t_rust_less_lib::memguard::SecretBytes;
+use zeroize::Zeroize;
+
+mod error;
+mod kdbx;
+mod xml;
+
+#[cfg(test)]
+mod tests;
+
+pub use self::error::*;
+pub use self::kdbx::is_kdbx;
+
+const FIELD_TITLE: &str = "Title";
+const FIELD_USERNAME: &str = "UserName";
+const FIELD_PASSWORD: &str = "Password";
+const FIELD_URL: &str = "URL";
+const FIELD_NOTES: &str = "Notes";
+/// Additional urls as used by KeePass2Android and KeePassXC
+const FIELD_ADDITIONAL_URL_PREFIX: &str = "KP2A_URL";
+/// KeePassXC stores a complete otpauth url
+const FIELD_OTP: &str = "otp";
+/// KeePass 2.47+ native TOTP fields
+const FIELD_TIME_OTP_SECRET: &str = "TimeOtp-Secret-Base32";
+const FIELD_TIME_OTP_PERIOD: &str = "TimeOtp-Period";
+const FIELD_TIME_OTP_LENGTH: &str = "TimeOtp-Length";
+const FIELD_TIME_OTP_ALGORITHM: &str = "TimeOtp-Algorithm";
+/// Legacy KeePassXC (and KeeTrayTOTP) fields
+const FIELD_TOTP_SEED: &str = "TOTP Seed";
+const FIELD_TOTP_SETTINGS: &str = "TOTP Settings";
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct KeePassString {
+  pub key: String,
+  pub value: String,
+}
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct KeePassBinary {
+  pub name: String,
+  pub content: Vec<u8>,
+}
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+pub struct KeePassEntry {
+  pub uuid: String,
+  /// Names of all the (parent) groups of the entry
+  pub group_path: Vec<String>,
+  pub tags: Vec<String>,
+  pub last_modification: Option<ZeroizeDateTime>,
+  pub strings: Vec<KeePassString>,
+  pub binaries: Vec<KeePassBinary>,
+  /// Previous versions of the entry (oldest first)
+  pub history: Vec<KeePassEntry>,
+  /// Entry is in the recycle bin
+  pub deleted: bool,
+}
+
+impl KeePassEntry {
+  pub fn title(&self) -> &str {
+    self.string(FIELD_TITLE).unwrap_or_default()
+  }
+
+  fn string(&self, key: &str) -> Option<&str> {
+    self
+      .strings
+      .iter()
+      .find(|string| string.key == key)
+      .map(|string| string.value.as_str())
+  }
+
+  /// All versions of the entry including its history, the current version is the last one.
+  pub fn to_secret_versions(&self, secret_id: &str) -> Vec<SecretVersion> {
+    let mut versions: Vec<SecretVersion> = self
+      .history
+      .iter()
+      .map(|history_entry| history_entry.to_secret_version(secret_id, &self.group_path, false))
+      .collect();
+
+    versions.push(self.to_secret_version(secret_id, &self.group_path, self.deleted));
+
+    versions
+  }
+
+  fn to_secret_version(&self, secret_id: &str, group_path: &[String], deleted: bool) -> SecretVersion {
+    let mut properties = SecretProperties::default();
+    let mut tags: Vec<String> = group_path.to_vec();
+    let mut urls = Vec::new();
+
+    for tag in &self.tags {
+      if !tags.contains(tag) {
+        tags.push(tag.clone());
+      }
+    }
+
+    for string in &self.strings {
+      if string.value.is_empty() {
+        continue;
+      }
+      match string.key.as_str() {
+        FIELD_TITLE
+        | FIELD_OTP
+        | FIELD_TIME_OTP_SECRET
+        | FIELD_TIME_OTP_PERIOD
+        | FIELD_TIME_OTP_LENGTH
+        | FIELD_TIME_OTP_ALGORITHM
+        | FIELD_TOTP_SEED
+        | FIELD_TOTP_SETTINGS => (),
+        FIELD_USERNAME => properties.set(PROPERTY_USERNAME, &string.value),
+        FIELD_PASSWORD => properties.set(PROPERTY_PASSWORD, &string.value),
+        FIELD_NOTES => properties.set(PROPERTY_NOTES, &string.value),
+        FIELD_URL => urls.push(string.value.clone()),
+        key if key.starts_with(FIELD_ADDITIONAL_URL_PREFIX) => urls.push(string.value.clone()),
+        key => properties.set(key, &string.value),
+      }
+    }
+
+    if let Some(totp_url) = self.totp_url() {
+      properties.set(PROPERTY_TOTP_URL, &totp_url);
+    }
+
+    let timestamp = self.last_modification.unwrap_or_else(|| Utc::now().into());
+    let mut version = SecretVersion::new(secret_id, SecretType::Login, self.title(), timestamp);
+
+    version.tags = tags;
+    version.urls = urls;
+    version.properties = properties;
+    version.attachments = self
+      .binaries
+      .iter()
+      .map(|binary| {
+        SecretAttachment::new(
+          binary.name.clone(),
+          guess_mime_type(&binary.name).to_string(),
+          binary.content.clone(),
+        )
+      })
+      .collect();
+    version.deleted = deleted;
+    version
+  }
+
+  fn totp_url(&self) -> Option<String> {
+    let account_name = self.string(FIELD_USERNAME).unwrap_or_default();
+    let issuer = Some(self.title());
+
+    if let Some(otp) = self.string(FIELD_OTP) {
+      return totp_url(otp, account_name, issuer, None, None, None);
+    }
+    if let Some(secret) = self.string(FIELD_TIME_OTP_SECRET) {
+      return totp_url(
+        secret,
+        account_name,
+        issuer,
+        self
+          .string(FIELD_TIME_OTP_PERIOD)
+          .and_then(|period| period.parse().ok()),
+        self
+          .string(FIELD_TIME_OTP_LENGTH)
+          .and_then(|digits| digits.parse().ok()),
+        self.string(FIELD_TIME_OTP_ALGORITHM),
+      );
+    }
+    if let Some(seed) = self.string(FIELD_TOTP_SEED) {
+      // Settings are "<period>;<digits>"
+      let mut settings = self.string(FIELD_TOTP_SETTINGS).unwrap_or_default().split(';');
+      let period = settings.next().and_then(|period| period.parse().ok());
+      let digits = settings.next().and_then(|digits| digits.parse().ok());
+
+      return totp_url(seed, account_name, issuer, period, digits, None);
+    }
+
+    None
+  }
+}
+
+/// Read all entries of a KeePass database, either a KDBX 4 file (requires the passphrase) or an xml export.
+pub fn read_entries(data: &[u8], maybe_passphrase: Option<&SecretBytes>) -> KeePassResult<Vec<KeePassEntry>> {
+  if kdbx::is_kdbx(data) {
+    let passphrase = maybe_passphrase.ok_or(KeePassError::InvalidPassphrase)?;
+    let content = kdbx::decrypt(data, passphrase)?;
+
+    xml::parse_xml(&content.xml[..], content.inner_stream, &content.binaries)
+  } else {
+    xml::parse_xml(data, None, &[])
+  }
+}
+
+pub fn guess_mime_type(name: &str) -> &'static str {
+  let extension = name.rsplit('.').next().unwrap_or_default().to_lowercase();
+
+  match extension.as_str() {
+    "txt" => "text/plain",
+    "pdf" => "application/pdf",
+    "png" => "image/png",
+    "jpg" | "jpeg" => "image/jpeg",
+    "gif" => "image/gif",
+    "json" => "application/json",
+    "xml" => "application/xml",
+    "zip" => "application/zip",
+    "pem" | "crt" | "key" => "application/x-pem-file",
+    _ => "application/octet-stream",
+  }
+}
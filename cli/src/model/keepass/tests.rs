@@ -0,0 +1,175 @@
+use super::*;
+use spectral::prelude::*;
+use t_rust_less_lib::otp::OTPAuthUrl;
+
+/// Both fixtures contain the same entries, the passphrase is "test passphrase"
+const AES_KDF_FIXTURE: &[u8] = include_bytes!("fixtures/aes-kdf.kdbx");
+const ARGON2ID_CHACHA20_FIXTURE: &[u8] = include_bytes!("fixtures/argon2id-chacha20.kdbx");
+const FIXTURE_PASSPHRASE: &str = "test passphrase";
+
+fn passphrase(passphrase: &str) -> SecretBytes {
+  SecretBytes::from(passphrase.to_string())
+}
+
+fn read_error(data: &[u8], maybe_passphrase: Option<&str>) -> Option<KeePassError> {
+  read_entries(data, maybe_passphrase.map(passphrase).as_ref()).err()
+}
+
+fn string<'a>(entry: &'a KeePassEntry, key: &str) -> Option<&'a str> {
+  entry.string(key)
+}
+
+fn check_fixture_entries(entries: &[KeePassEntry]) {
+  assert_that(&entries.len()).is_equal_to(3);
+
+  let mail = &entries[0];
+  assert_that(&mail.title()).is_equal_to("Mail & Calendar");
+  assert_that(&mail.group_path).is_empty();
+  assert_that(&mail.tags).is_equal_to(vec!["private".to_string(), "mail".to_string()]);
+  assert_that(&mail.deleted).is_false();
+  assert_that(&mail.last_modification.as_ref().map(ZeroizeDateTime::timestamp_millis))
+    .is_equal_to(Some(1_614_834_367_000));
+  // Protected fields have to be decrypted in order of appearance
+  assert_that(&string(mail, FIELD_PASSWORD)).is_equal_to(Some("correct horse & battery <staple>"));
+  assert_that(&string(mail, FIELD_OTP))
+    .is_equal_to(Some("otpauth://totp/Mail:john?secret=JBSWY3DPEHPK3PXP&issuer=Mail"));
+  assert_that(&string(mail, "PIN")).is_equal_to(Some("1234"));
+  assert_that(&string(mail, FIELD_NOTES)).is_equal_to(Some("line 1\nline 2"));
+
+  let server = &entries[1];
+  assert_that(&server.title()).is_equal_to("Server");
+  assert_that(&server.group_path).is_equal_to(vec!["Work".to_string()]);
+  assert_that(&string(server, FIELD_PASSWORD)).is_equal_to(Some("current"));
+  assert_that(&server.binaries.len()).is_equal_to(1);
+  assert_that(&server.binaries[0].name.as_str()).is_equal_to("id_ed25519.pub");
+  assert_that(&server.binaries[0].content).is_equal_to(b"ssh-ed25519 AAAA fixture\n".to_vec());
+  assert_that(&server.history.len()).is_equal_to(1);
+  assert_that(&string(&server.history[0], FIELD_PASSWORD)).is_equal_to(Some("previous"));
+
+  let old = &entries[2];
+  assert_that(&old.title()).is_equal_to("Old");
+  assert_that(&old.group_path).is_empty();
+  assert_that(&old.deleted).is_true();
+  assert_that(&string(old, FIELD_PASSWORD)).is_equal_to(Some("deleted"));
+}
+
+#[test]
+fn test_import_kdbx() {
+  assert_that(&is_kdbx(AES_KDF_FIXTURE)).is_true();
+  assert_that(&is_kdbx(b"<?xml version=\"1.0\"?>")).is_false();
+
+  for fixture in &[AES_KDF_FIXTURE, ARGON2ID_CHACHA20_FIXTURE] {
+    let entries = read_entries(fixture, Some(&passphrase(FIXTURE_PASSPHRASE))).unwrap();
+
+    check_fixture_entries(&entries);
+  }
+}
+
+#[test]
+fn test_kdbx_to_secret_versions() {
+  let entries = read_entries(AES_KDF_FIXTURE, Some(&passphrase(FIXTURE_PASSPHRASE))).unwrap();
+
+  let mail = entries[0].to_secret_versions("mail");
+  assert_that(&mail.len()).is_equal_to(1);
+  assert_that(&mail[0].name.as_str()).is_equal_to("Mail & Calendar");
+  assert_that(&mail[0].urls).is_equal_to(vec![
+    "https://mail.example.com".to_string(),
+    "https://calendar.example.com".to_string(),
+  ]);
+  assert_that(&mail[0].properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&"john".to_string()));
+  assert_that(&mail[0].properties.get(PROPERTY_PASSWORD))
+    .is_equal_to(Some(&"correct horse & battery <staple>".to_string()));
+  assert_that(&mail[0].properties.get("PIN")).is_equal_to(Some(&"1234".to_string()));
+  assert_that(&mail[0].properties.get(FIELD_OTP)).is_none();
+  let totp_url = mail[0].properties.get(PROPERTY_TOTP_URL).unwrap();
+  assert_that(&OTPAuthUrl::parse(totp_url).map(|_| ())).is_ok();
+
+  let server = entries[1].to_secret_versions("server");
+  assert_that(&server.len()).is_equal_to(2);
+  assert_that(&server[0].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"previous".to_string()));
+  assert_that(&server[1].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"current".to_string()));
+  assert_that(&server[1].tags).is_equal_to(vec!["Work".to_string()]);
+  assert_that(&server[1].attachments.len()).is_equal_to(1);
+
+  let old = entries[2].to_secret_versions("old");
+  assert_that(&old[0].deleted).is_true();
+}
+
+#[test]
+fn test_kdbx_wrong_passphrase() {
+  for fixture in &[AES_KDF_FIXTURE, ARGON2ID_CHACHA20_FIXTURE] {
+    assert_that(&read_error(fixture, Some("wrong passphrase")))
+      .matches(|error| matches!(error, Some(KeePassError::InvalidPassphrase)));
+  }
+  assert_that(&read_error(AES_KDF_FIXTURE, None))
+    .matches(|error| matches!(error, Some(KeePassError::InvalidPassphrase)));
+}
+
+#[test]
+fn test_kdbx_tampered() {
+  // The last 36 bytes are the (empty) final block, i.e. this is the last byte of the data block
+  let mut tampered_block = AES_KDF_FIXTURE.to_vec();
+  let last_data_byte = tampered_block.len() - 37;
+  tampered_block[last_data_byte] ^= 1;
+
+  assert_that(&read_error(&tampered_block, Some(FIXTURE_PASSPHRASE)))
+    .matches(|error| matches!(error, Some(KeePassError::Corrupted)));
+
+  // Byte 20 is part of the cipher id, which is covered by the header hash
+  let mut tampered_header = AES_KDF_FIXTURE.to_vec();
+  tampered_header[20] ^= 1;
+
+  assert_that(&read_error(&tampered_header, Some(FIXTURE_PASSPHRASE)))
+    .matches(|error| matches!(error, Some(KeePassError::Corrupted)));
+
+  let truncated = &AES_KDF_FIXTURE[..AES_KDF_FIXTURE.len() - 36];
+
+  assert_that(&read_error(truncated, Some(FIXTURE_PASSPHRASE)))
+    .matches(|error| matches!(error, Some(KeePassError::Format(_))));
+}
+
+#[test]
+fn test_import_xml() {
+  let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<KeePassFile>
+  <Root>
+    <Group>
+      <Name>Export</Name>
+      <Entry>
+        <Times><LastModificationTime>2021-03-04T05:06:07Z</LastModificationTime></Times>
+        <String><Key>Title</Key><Value>Exported &lt;entry&gt;</Value></String>
+        <String><Key>Password</Key><Value ProtectInMemory="True">plain &amp; simple</Value></String>
+        <Binary><Key>note.txt</Key><Value>aGVsbG8=</Value></Binary>
+      </Entry>
+    </Group>
+  </Root>
+</KeePassFile>"#;
+  let entries = read_entries(xml.as_bytes(), None).unwrap();
+
+  assert_that(&entries.len()).is_equal_to(1);
+  assert_that(&entries[0].title()).is_equal_to("Exported <entry>");
+  assert_that(&string(&entries[0], FIELD_PASSWORD)).is_equal_to(Some("plain & simple"));
+  assert_that(&entries[0].binaries[0].content).is_equal_to(b"hello".to_vec());
+  assert_that(
+    &entries[0]
+      .last_modification
+      .as_ref()
+      .map(ZeroizeDateTime::timestamp_millis),
+  )
+  .is_equal_to(Some(1_614_834_367_000));
+
+  // Protected values require the inner stream of a KDBX database
+  let protected = xml.replace("ProtectInMemory", "Protected");
+  assert_that(&read_error(protected.as_bytes(), None)).is_some();
+}
+
+#[test]
+fn test_invalid_timestamp() {
+  // i64::MAX seconds since 0001-01-01 is way out of range
+  let xml = r#"<KeePassFile><Root><Group><Entry>
+    <Times><LastModificationTime>/////////38=</LastModificationTime></Times>
+    <String><Key>Title</Key><Value>Entry</Value></String>
+  </Entry></Group></Root></KeePassFile>"#;
+
+  assert_that(&read_error(xml.as_bytes(), None)).matches(|error| matches!(error, Some(KeePassError::Format(_))));
+}
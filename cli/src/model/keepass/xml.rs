@@ -0,0 +1,270 @@
+//! Parsing of the KeePass xml format, which is used as inner format of KDBX databases as
+//! well as for KeePass XML exports.
+use super::kdbx::InnerStream;
+use super::{KeePassBinary, KeePassEntry, KeePassError, KeePassResult, KeePassString};
+use chrono::{DateTime, TimeZone, Utc};
+use data_encoding::BASE64;
+use flate2::read::GzDecoder;
+use std::collections::HashMap;
+use std::io::Read;
+use xml::reader::{EventReader, XmlEvent};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Seconds between 0001-01-01 (KDBX 4 epoch) and 1970-01-01
+const KDBX_EPOCH_OFFSET: i64 = 62_135_596_800;
+/// The all-zero uuid is used as "not set"
+const EMPTY_UUID: &str = "AAAAAAAAAAAAAAAAAAAAAA==";
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+struct Element {
+  name: String,
+  attributes: Vec<Attribute>,
+  text: String,
+  children: Vec<Element>,
+}
+
+#[derive(Zeroize)]
+#[zeroize(drop)]
+struct Attribute {
+  name: String,
+  value: String,
+}
+
+impl Element {
+  fn attribute(&self, name: &str) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find(|attribute| attribute.name == name)
+      .map(|attribute| attribute.value.as_str())
+  }
+
+  fn child(&self, name: &str) -> Option<&Element> {
+    self.children.iter().find(|child| child.name == name)
+  }
+
+  fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+    self.children.iter().filter(move |child| child.name == name)
+  }
+
+  fn child_text(&self, name: &str) -> Option<&str> {
+    self.child(name).map(|child| child.text.as_str())
+  }
+}
+
+/// Parse the xml content of a KeePass database.
+///
+/// `binaries` are the attachments stored in the inner header of a KDBX 4 database, for plain xml
+/// exports these are part of the xml itself.
+pub fn parse_xml<R: Read>(
+  input: R,
+  inner_stream: Option<InnerStream>,
+  binaries: &[Zeroizing<Vec<u8>>],
+) -> KeePassResult<Vec<KeePassEntry>> {
+  let document = read_document(input, inner_stream)?;
+  let meta = document.child("Meta");
+  let recycle_bin_uuid = meta
+    .and_then(|meta| meta.child_text("RecycleBinUUID"))
+    .filter(|uuid| !uuid.is_empty() && *uuid != EMPTY_UUID);
+  let mut binary_pool: HashMap<String, Zeroizing<Vec<u8>>> = binaries
+    .iter()
+    .enumerate()
+    .map(|(idx, binary)| (idx.to_string(), binary.clone()))
+    .collect();
+
+  if let Some(meta_binaries) = meta.and_then(|meta| meta.child("Binaries")) {
+    for binary in meta_binaries.children("Binary") {
+      let id = binary.attribute("ID").unwrap_or_default().to_string();
+      let mut content = Zeroizing::new(BASE64.decode(binary.text.trim().as_bytes())?);
+
+      if binary.attribute("Compressed") == Some("True") {
+        let mut decompressed = Zeroizing::new(Vec::with_capacity(content.len() * 4));
+        GzDecoder::new(&content[..]).read_to_end(&mut decompressed)?;
+        content = decompressed;
+      }
+      binary_pool.insert(id, content);
+    }
+  }
+
+  let root_group = document
+    .child("Root")
+    .and_then(|root| root.child("Group"))
+    .ok_or_else(|| KeePassError::Format("Missing root group".to_string()))?;
+  let mut entries = Vec::new();
+
+  // The name of the root group is just the name of the database, so it is not used as tag
+  read_group(root_group, &[], false, recycle_bin_uuid, &binary_pool, &mut entries)?;
+
+  Ok(entries)
+}
+
+fn read_document<R: Read>(input: R, mut inner_stream: Option<InnerStream>) -> KeePassResult<Element> {
+  let mut stack: Vec<Element> = Vec::new();
+
+  for event in EventReader::new(input) {
+    match event? {
+      XmlEvent::StartElement { name, attributes, .. } => stack.push(Element {
+        name: name.local_name,
+        attributes: attributes
+          .into_iter()
+          .map(|attribute| Attribute {
+            name: attribute.name.local_name,
+            value: attribute.value,
+          })
+          .collect(),
+        text: String::new(),
+        children: Vec::new(),
+      }),
+      XmlEvent::Characters(text) | XmlEvent::CData(text) | XmlEvent::Whitespace(text) => {
+        if let Some(element) = stack.last_mut() {
+          element.text.push_str(&text);
+        }
+      }
+      XmlEvent::EndElement { .. } => {
+        let mut element = stack
+          .pop()
+          .ok_or_else(|| KeePassError::Format("Unbalanced xml".to_string()))?;
+
+        // Protected values have to be decrypted in order of appearance
+        if element.attribute("Protected") == Some("True") && !element.text.is_empty() {
+          let stream = inner_stream
+            .as_mut()
+            .ok_or_else(|| KeePassError::Format("Protected value without inner stream".to_string()))?;
+          let protected = Zeroizing::new(BASE64.decode(element.text.as_bytes())?);
+
+          element.text.zeroize();
+          element.text = stream.unprotect(&protected)?;
+        }
+
+        match stack.last_mut() {
+          Some(parent) => parent.children.push(element),
+          None => return Ok(element),
+        }
+      }
+      _ => (),
+    }
+  }
+
+  Err(KeePassError::Format("Unexpected end of xml".to_string()))
+}
+
+fn read_group(
+  group: &Element,
+  path: &[String],
+  in_recycle_bin: bool,
+  recycle_bin_uuid: Option<&str>,
+  binary_pool: &HashMap<String, Zeroizing<Vec<u8>>>,
+  entries: &mut Vec<KeePassEntry>,
+) -> KeePassResult<()> {
+  for entry in group.children("Entry") {
+    let mut entry = read_entry(entry, binary_pool)?;
+
+    entry.group_path = path.to_vec();
+    entry.deleted = in_recycle_bin;
+    entries.push(entry);
+  }
+
+  for sub_group in group.children("Group") {
+    let is_recycle_bin = recycle_bin_uuid.is_some() && sub_group.child_text("UUID") == recycle_bin_uuid;
+    let mut sub_path = path.to_vec();
+
+    if !is_recycle_bin {
+      sub_path.push(sub_group.child_text("Name").unwrap_or_default().to_string());
+    }
+    read_group(
+      sub_group,
+      &sub_path,
+      in_recycle_bin || is_recycle_bin,
+      recycle_bin_uuid,
+      binary_pool,
+      entries,
+    )?;
+  }
+
+  Ok(())
+}
+
+fn read_entry(entry: &Element, binary_pool: &HashMap<String, Zeroizing<Vec<u8>>>) -> KeePassResult<KeePassEntry> {
+  let mut result = KeePassEntry {
+    uuid: entry.child_text("UUID").unwrap_or_default().to_string(),
+    group_path: Vec::new(),
+    tags: entry
+      .child_text("Tags")
+      .unwrap_or_default()
+      .split(&[';', ','][..])
+      .map(str::trim)
+      .filter(|tag| !tag.is_empty())
+      .map(ToString::to_string)
+      .collect(),
+    last_modification: entry
+      .child("Times")
+      .and_then(|times| times.child_text("LastModificationTime"))
+      .map(parse_time)
+      .transpose()?
+      .map(Into::into),
+    strings: Vec::new(),
+    binaries: Vec::new(),
+    history: Vec::new(),
+    deleted: false,
+  };
+
+  for string in entry.children("String") {
+    if let (Some(key), Some(value)) = (string.child_text("Key"), string.child_text("Value")) {
+      result.strings.push(KeePassString {
+        key: key.to_string(),
+        value: value.to_string(),
+      });
+    }
+  }
+
+  for binary in entry.children("Binary") {
+    let name = binary.child_text("Key").unwrap_or_default();
+    let value = match binary.child("Value") {
+      Some(value) => value,
+      None => continue,
+    };
+    // Older xml exports (and our own) contain the binaries inline
+    let content = match value.attribute("Ref") {
+      Some(reference) => binary_pool.get(reference).map(|content| content.to_vec()),
+      None if !value.text.trim().is_empty() => Some(BASE64.decode(value.text.trim().as_bytes())?),
+      None => None,
+    };
+
+    if let Some(content) = content {
+      result.binaries.push(KeePassBinary {
+        name: name.to_string(),
+        content,
+      });
+    }
+  }
+
+  if let Some(history) = entry.child("History") {
+    for history_entry in history.children("Entry") {
+      result.history.push(read_entry(history_entry, binary_pool)?);
+    }
+  }
+
+  Ok(result)
+}
+
+/// KDBX 4 stores timestamps as base64 encoded seconds since 0001-01-01, xml exports use ISO 8601.
+fn parse_time(time: &str) -> KeePassResult<DateTime<Utc>> {
+  if time.contains('-') {
+    return DateTime::parse_from_rfc3339(time)
+      .map(|time| time.with_timezone(&Utc))
+      .map_err(|error| KeePassError::Format(format!("Invalid timestamp {}: {}", time, error)));
+  }
+
+  let bytes = BASE64.decode(time.as_bytes())?;
+  if bytes.len() != 8 {
+    return Err(KeePassError::Format(format!("Invalid timestamp {}", time)));
+  }
+  let mut seconds = [0u8; 8];
+  seconds.copy_from_slice(&bytes);
+
+  i64::from_le_bytes(seconds)
+    .checked_sub(KDBX_EPOCH_OFFSET)
+    .and_then(|seconds| Utc.timestamp_opt(seconds, 0).single())
+    .ok_or_else(|| KeePassError::Format(format!("Invalid timestamp {}", time)))
+}
@@ -1 +1,12 @@
+pub mod bitwarden;
+pub mod browser_csv;
+pub mod export;
+pub mod health;
+pub mod import_set;
 pub mod import_v1;
+pub mod keepass;
+pub mod onepassword;
+pub mod pass_store;
+pub mod secret_input;
+pub mod totp;
+pub mod version_diff;
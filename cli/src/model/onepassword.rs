@@ -0,0 +1,475 @@
+//! Import of the 1Password unencrypted export format (1PUX).
+//!
+//! A 1PUX file is a zip archive containing all the items in `export.data` and the attached
+//! documents in `files/`.
+use crate::model::import_set::{password_history_versions, ImportResult, ImportSet};
+use crate::model::keepass::guess_mime_type;
+use crate::model::totp::totp_url;
+use chrono::{TimeZone, Utc};
+use serde_derive::Deserialize;
+use serde_json::Value;
+use std::io::{Cursor, Read};
+use t_rust_less_lib::api::{
+  SecretAttachment, SecretType, SecretVersion, PROPERTY_CARDHOLDER, PROPERTY_CARD_BRAND, PROPERTY_CARD_CODE,
+  PROPERTY_CARD_EXPIRY, PROPERTY_CARD_NUMBER, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+};
+use zeroize::{Zeroize, Zeroizing};
+use zip::ZipArchive;
+
+const EXPORT_DATA: &str = "export.data";
+
+const STATE_ARCHIVED: &str = "archived";
+
+#[derive(Deserialize)]
+struct Export {
+  #[serde(default)]
+  accounts: Vec<Account>,
+}
+
+#[derive(Deserialize)]
+struct Account {
+  #[serde(default)]
+  vaults: Vec<Vault>,
+}
+
+#[derive(Deserialize)]
+struct Vault {
+  attrs: VaultAttributes,
+  #[serde(default)]
+  items: Vec<Item>,
+}
+
+#[derive(Deserialize)]
+struct VaultAttributes {
+  name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Item {
+  category_uuid: String,
+  #[serde(default)]
+  state: String,
+  updated_at: Option<i64>,
+  details: Details,
+  overview: Overview,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Details {
+  #[serde(default)]
+  login_fields: Vec<LoginField>,
+  notes_plain: Option<String>,
+  password: Option<String>,
+  #[serde(default)]
+  sections: Vec<Section>,
+  #[serde(default)]
+  password_history: Vec<PasswordHistory>,
+  document_attributes: Option<Document>,
+}
+
+#[derive(Deserialize)]
+struct LoginField {
+  #[serde(default)]
+  value: String,
+  #[serde(default)]
+  name: String,
+  designation: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Section {
+  #[serde(default)]
+  fields: Vec<SectionField>,
+}
+
+#[derive(Deserialize)]
+struct SectionField {
+  #[serde(default)]
+  title: String,
+  #[serde(default)]
+  id: String,
+  /// An object with a single key denoting the type of the value, e.g. `{"concealed": "..."}`
+  #[serde(default)]
+  value: Value,
+}
+
+#[derive(Deserialize)]
+struct PasswordHistory {
+  value: String,
+  time: i64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Document {
+  file_name: String,
+  document_id: String,
+}
+
+#[derive(Deserialize)]
+struct Overview {
+  #[serde(default)]
+  title: String,
+  url: Option<String>,
+  #[serde(default)]
+  urls: Vec<OverviewUrl>,
+  #[serde(default)]
+  tags: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct OverviewUrl {
+  url: String,
+}
+
+impl Drop for Item {
+  fn drop(&mut self) {
+    for field in &mut self.details.login_fields {
+      field.value.zeroize();
+    }
+    for field in self
+      .details
+      .sections
+      .iter_mut()
+      .flat_map(|section| section.fields.iter_mut())
+    {
+      zeroize_value(&mut field.value);
+    }
+    for entry in &mut self.details.password_history {
+      entry.value.zeroize();
+    }
+    self.details.notes_plain.zeroize();
+    self.details.password.zeroize();
+  }
+}
+
+fn zeroize_value(value: &mut Value) {
+  match value {
+    Value::String(value) => value.zeroize(),
+    Value::Array(values) => values.iter_mut().for_each(zeroize_value),
+    Value::Object(values) => values.values_mut().for_each(zeroize_value),
+    _ => (),
+  }
+}
+
+/// Read a 1PUX export of 1Password.
+pub fn read_export(data: &[u8]) -> ImportResult<ImportSet> {
+  let mut archive = ZipArchive::new(Cursor::new(data))?;
+  let mut export_data = Zeroizing::new(Vec::new());
+
+  archive.by_name(EXPORT_DATA)?.read_to_end(&mut export_data)?;
+
+  let export: Export = serde_json::from_slice(&export_data)?;
+  let mut import_set = ImportSet::default();
+
+  for vault in export.accounts.iter().flat_map(|account| account.vaults.iter()) {
+    for item in &vault.items {
+      let secret_type = match item.category_uuid.as_str() {
+        "001" => SecretType::Login,
+        "002" => SecretType::Card,
+        "003" | "006" => SecretType::Note,
+        "004" => SecretType::Identity,
+        "005" => SecretType::Password,
+        "100" => SecretType::Licence,
+        "109" => SecretType::Wlan,
+        _ => SecretType::Other,
+      };
+      let mut tags = vec![vault.attrs.name.clone()];
+
+      for tag in &item.overview.tags {
+        if !tags.contains(tag) {
+          tags.push(tag.clone());
+        }
+      }
+
+      let timestamp = item
+        .updated_at
+        .and_then(|updated_at| Utc.timestamp_opt(updated_at, 0).single())
+        .unwrap_or_else(Utc::now);
+      let mut current = SecretVersion::new("", secret_type, &item.overview.title, timestamp.into());
+
+      current.tags = tags;
+      current.urls = item
+        .overview
+        .urls
+        .iter()
+        .map(|url| url.url.clone())
+        .chain(item.overview.url.iter().cloned())
+        .filter(|url| !url.is_empty())
+        .fold(Vec::new(), |mut urls, url| {
+          if !urls.contains(&url) {
+            urls.push(url);
+          }
+          urls
+        });
+      current.deleted = item.state == STATE_ARCHIVED;
+
+      if let Err(reason) = read_details(item, &mut current, &mut archive) {
+        import_set.skip(&item.overview.title, reason);
+        continue;
+      }
+
+      let history = item
+        .details
+        .password_history
+        .iter()
+        .filter_map(|entry| {
+          Utc
+            .timestamp_opt(entry.time, 0)
+            .single()
+            .map(|timestamp| (entry.value.clone(), timestamp))
+        })
+        .collect();
+      let mut versions = password_history_versions(&current, history);
+
+      versions.push(current);
+      import_set.secrets.push(versions);
+    }
+  }
+
+  Ok(import_set)
+}
+
+fn read_details(
+  item: &Item,
+  version: &mut SecretVersion,
+  archive: &mut ZipArchive<Cursor<&[u8]>>,
+) -> Result<(), String> {
+  let details = &item.details;
+
+  for field in &details.login_fields {
+    if field.value.is_empty() {
+      continue;
+    }
+    match field.designation.as_deref() {
+      Some("username") => version.properties.set(PROPERTY_USERNAME, &field.value),
+      Some("password") => version.properties.set(PROPERTY_PASSWORD, &field.value),
+      _ if !field.name.is_empty() => version.properties.set(&field.name, &field.value),
+      _ => (),
+    }
+  }
+  if let Some(password) = details.password.as_ref().filter(|password| !password.is_empty()) {
+    version.properties.set(PROPERTY_PASSWORD, password);
+  }
+  if let Some(notes) = details.notes_plain.as_ref().filter(|notes| !notes.is_empty()) {
+    version.properties.set(PROPERTY_NOTES, notes);
+  }
+  if let Some(document) = &details.document_attributes {
+    version.attachments.push(read_document(document, archive)?);
+  }
+
+  for field in details.sections.iter().flat_map(|section| section.fields.iter()) {
+    let (kind, value) = match field.value.as_object().and_then(|value| value.iter().next()) {
+      Some(entry) => entry,
+      None => continue,
+    };
+    let name = match (field.title.as_str(), field.id.as_str()) {
+      ("", id) => id,
+      (title, _) => title,
+    };
+
+    match (kind.as_str(), value) {
+      ("totp", Value::String(totp)) if !totp.is_empty() => {
+        let account_name = version.properties.get(PROPERTY_USERNAME).cloned().unwrap_or_default();
+
+        match totp_url(totp, &account_name, Some(&item.overview.title), None, None, None) {
+          Some(totp_url) => version.properties.set(PROPERTY_TOTP_URL, &totp_url),
+          None => return Err(format!("Invalid one-time password in field {}", name)),
+        }
+      }
+      ("file", Value::Object(_)) => {
+        let document: Document =
+          serde_json::from_value(value.clone()).map_err(|error| format!("Invalid file field {}: {}", name, error))?;
+
+        version.attachments.push(read_document(&document, archive)?);
+      }
+      (kind, value) => {
+        if let Some(value) = field_value_to_string(kind, value).filter(|value| !value.is_empty()) {
+          match (kind, version.secret_type) {
+            ("creditCardNumber", SecretType::Card) => version.properties.set(PROPERTY_CARD_NUMBER, &value),
+            ("creditCardType", SecretType::Card) => version.properties.set(PROPERTY_CARD_BRAND, &value),
+            ("monthYear", SecretType::Card) if field.id == "expiry" => {
+              version.properties.set(PROPERTY_CARD_EXPIRY, &value)
+            }
+            (_, SecretType::Card) if field.id == "cardholder" => version.properties.set(PROPERTY_CARDHOLDER, &value),
+            (_, SecretType::Card) if field.id == "cvv" => version.properties.set(PROPERTY_CARD_CODE, &value),
+            ("concealed", SecretType::Wlan) if field.id == "wireless_password" => {
+              version.properties.set(PROPERTY_PASSWORD, &value)
+            }
+            _ => version.properties.set(name, &value),
+          }
+        }
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn read_document(document: &Document, archive: &mut ZipArchive<Cursor<&[u8]>>) -> Result<SecretAttachment, String> {
+  let prefix = format!("files/{}", document.document_id);
+  let file_name = archive
+    .file_names()
+    .find(|file_name| file_name.starts_with(&prefix))
+    .map(ToString::to_string)
+    .ok_or_else(|| format!("Missing attachment {}", document.file_name))?;
+  let mut file = archive
+    .by_name(&file_name)
+    .map_err(|error| format!("Unreadable attachment {}: {}", document.file_name, error))?;
+  let mut content = Vec::with_capacity(file.size() as usize);
+
+  file
+    .read_to_end(&mut content)
+    .map_err(|error| format!("Unreadable attachment {}: {}", document.file_name, error))?;
+
+  Ok(SecretAttachment::new(
+    document.file_name.clone(),
+    guess_mime_type(&document.file_name).to_string(),
+    content,
+  ))
+}
+
+fn field_value_to_string(kind: &str, value: &Value) -> Option<String> {
+  match (kind, value) {
+    (_, Value::String(value)) => Some(value.clone()),
+    ("monthYear", Value::Number(month_year)) => month_year
+      .as_u64()
+      .map(|month_year| format!("{:02}/{}", month_year % 100, month_year / 100)),
+    ("date", Value::Number(date)) => date
+      .as_i64()
+      .and_then(|date| Utc.timestamp_opt(date, 0).single())
+      .map(|date| date.format("%Y-%m-%d").to_string()),
+    (_, Value::Number(number)) => Some(number.to_string()),
+    ("email", Value::Object(email)) => email
+      .get("email_address")
+      .and_then(Value::as_str)
+      .map(ToString::to_string),
+    ("address", Value::Object(address)) => Some(
+      ["street", "city", "state", "zip", "country"]
+        .iter()
+        .filter_map(|key| address.get(*key).and_then(Value::as_str))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<&str>>()
+        .join(", "),
+    ),
+    ("sshKey", Value::Object(ssh_key)) => ssh_key
+      .get("privateKey")
+      .and_then(Value::as_str)
+      .map(ToString::to_string),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use std::io::Write;
+  use t_rust_less_lib::otp::OTPAuthUrl;
+  use zip::write::FileOptions;
+  use zip::{CompressionMethod, ZipWriter};
+
+  const EXPORT_DATA_JSON: &str = r#"{
+    "accounts": [{
+      "vaults": [{
+        "attrs": {"name": "Personal"},
+        "items": [
+          {
+            "categoryUuid": "001",
+            "state": "active",
+            "updatedAt": 1614834367,
+            "details": {
+              "loginFields": [
+                {"value": "john", "name": "username", "designation": "username"},
+                {"value": "current", "name": "password", "designation": "password"}
+              ],
+              "notesPlain": "Some notes",
+              "sections": [{
+                "fields": [
+                  {"title": "one-time password", "id": "otp", "value": {"totp": "JBSWY3DPEHPK3PXP"}},
+                  {"title": "", "id": "birthday", "value": {"date": 946684800}},
+                  {"title": "key", "id": "key", "value": {"file": {"fileName": "key.txt", "documentId": "doc1"}}}
+                ]
+              }],
+              "passwordHistory": [{"value": "previous", "time": 1577836800}]
+            },
+            "overview": {
+              "title": "Mail",
+              "url": "https://mail.example.com",
+              "urls": [{"url": "https://mail.example.com"}, {"url": "https://webmail.example.com"}],
+              "tags": ["mail", "Personal"]
+            }
+          },
+          {
+            "categoryUuid": "002",
+            "state": "archived",
+            "details": {
+              "sections": [{
+                "fields": [
+                  {"title": "cardholder name", "id": "cardholder", "value": {"string": "John Doe"}},
+                  {"title": "number", "id": "ccnum", "value": {"creditCardNumber": "4111111111111111"}},
+                  {"title": "expiry date", "id": "expiry", "value": {"monthYear": 203003}},
+                  {"title": "verification number", "id": "cvv", "value": {"concealed": "123"}}
+                ]
+              }]
+            },
+            "overview": {"title": "Visa"}
+          }
+        ]
+      }]
+    }]
+  }"#;
+
+  fn export() -> Vec<u8> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+
+    zip.start_file(EXPORT_DATA, options).unwrap();
+    zip.write_all(EXPORT_DATA_JSON.as_bytes()).unwrap();
+    zip.start_file("files/doc1__key.txt", options).unwrap();
+    zip.write_all(b"key content").unwrap();
+
+    zip.finish().unwrap().into_inner()
+  }
+
+  #[test]
+  fn test_read_export() {
+    let data = export();
+    let import_set = read_export(&data).unwrap();
+
+    assert_that(&import_set.secrets.len()).is_equal_to(2);
+    assert_that(&import_set.skipped).is_empty();
+
+    let mail = &import_set.secrets[0];
+    assert_that(&mail.len()).is_equal_to(2);
+    assert_that(&mail[0].properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"previous".to_string()));
+
+    let current = &mail[1];
+    assert_that(&current.secret_type).is_equal_to(SecretType::Login);
+    assert_that(&current.name.as_str()).is_equal_to("Mail");
+    assert_that(&current.tags).is_equal_to(vec!["Personal".to_string(), "mail".to_string()]);
+    assert_that(&current.urls).is_equal_to(vec![
+      "https://mail.example.com".to_string(),
+      "https://webmail.example.com".to_string(),
+    ]);
+    assert_that(&current.timestamp.timestamp_millis()).is_equal_to(1_614_834_367_000);
+    assert_that(&current.properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&"john".to_string()));
+    assert_that(&current.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"current".to_string()));
+    assert_that(&current.properties.get(PROPERTY_NOTES)).is_equal_to(Some(&"Some notes".to_string()));
+    assert_that(&current.properties.get("birthday")).is_equal_to(Some(&"2000-01-01".to_string()));
+    let totp_url = current.properties.get(PROPERTY_TOTP_URL).unwrap();
+    assert_that(&OTPAuthUrl::parse(totp_url).map(|otp_url| otp_url.account_name)).is_ok_containing("john".to_string());
+    assert_that(&current.attachments.len()).is_equal_to(1);
+
+    let card = &import_set.secrets[1][0];
+    assert_that(&card.secret_type).is_equal_to(SecretType::Card);
+    assert_that(&card.deleted).is_true();
+    assert_that(&card.tags).is_equal_to(vec!["Personal".to_string()]);
+    assert_that(&card.properties.get(PROPERTY_CARDHOLDER)).is_equal_to(Some(&"John Doe".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_NUMBER)).is_equal_to(Some(&"4111111111111111".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_EXPIRY)).is_equal_to(Some(&"03/2030".to_string()));
+    assert_that(&card.properties.get(PROPERTY_CARD_CODE)).is_equal_to(Some(&"123".to_string()));
+  }
+}
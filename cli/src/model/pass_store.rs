@@ -0,0 +1,257 @@
+//! Import of a `pass` (https://www.passwordstore.org) password store.
+//!
+//! A password store is just a directory tree of gpg encrypted files, by convention the
+//! first line of each file is the password, all other lines are free form (though
+//! commonly `key: value`).
+use crate::model::import_set::{ImportError, ImportResult, ImportSet};
+use crate::model::totp::totp_url;
+use chrono::{TimeZone, Utc};
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::UNIX_EPOCH;
+use t_rust_less_lib::api::{
+  SecretProperties, SecretType, SecretVersion, ZeroizeDateTime, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL,
+  PROPERTY_USERNAME,
+};
+use zeroize::Zeroizing;
+
+pub const DEFAULT_GPG: &str = "gpg";
+
+const GPG_EXTENSION: &str = "gpg";
+
+/// Read all entries of a password store by decrypting them with `gpg`.
+///
+/// Decryption relies on a running gpg-agent (with a pinentry) for the passphrase of the secret key.
+pub fn read_store(store_dir: &Path, gpg: &str) -> ImportResult<ImportSet> {
+  if !store_dir.is_dir() {
+    return Err(ImportError::IO(format!("{} is not a directory", store_dir.display())));
+  }
+
+  let mut import_set = ImportSet::default();
+
+  read_dir(store_dir, &[], gpg, &mut import_set)?;
+
+  Ok(import_set)
+}
+
+fn read_dir(dir: &Path, path: &[String], gpg: &str, import_set: &mut ImportSet) -> ImportResult<()> {
+  let mut dir_entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+
+  dir_entries.sort_by_key(|dir_entry| dir_entry.file_name());
+
+  for dir_entry in dir_entries {
+    let file_path = dir_entry.path();
+    let file_name = dir_entry.file_name().to_string_lossy().to_string();
+
+    // .git, .gpg-id, .extensions ... are not part of the secrets
+    if file_name.starts_with('.') {
+      continue;
+    }
+    if file_path.is_dir() {
+      let mut sub_path = path.to_vec();
+
+      sub_path.push(file_name);
+      read_dir(&file_path, &sub_path, gpg, import_set)?;
+    } else if file_path.extension().and_then(|extension| extension.to_str()) == Some(GPG_EXTENSION) {
+      // Entries are identified by their path inside the store (like `pass show` does)
+      let name = path
+        .iter()
+        .map(String::as_str)
+        .chain(Some(file_name.trim_end_matches(".gpg")))
+        .collect::<Vec<&str>>()
+        .join("/");
+
+      match decrypt(&file_path, gpg) {
+        Ok(content) => {
+          let timestamp = modified_at(&file_path).unwrap_or_else(|| Utc::now().into());
+
+          import_set
+            .secrets
+            .push(vec![to_secret_version(&name, path, timestamp, &content)]);
+        }
+        Err(error) => import_set.skip(name, error),
+      }
+    }
+  }
+
+  Ok(())
+}
+
+fn modified_at(file_path: &Path) -> Option<ZeroizeDateTime> {
+  let modified = fs::metadata(file_path).and_then(|metadata| metadata.modified()).ok()?;
+  let millis = modified.duration_since(UNIX_EPOCH).ok()?.as_millis();
+
+  Utc
+    .timestamp_millis_opt(i64::try_from(millis).ok()?)
+    .single()
+    .map(ZeroizeDateTime::from)
+}
+
+fn decrypt(file_path: &Path, gpg: &str) -> Result<Zeroizing<String>, String> {
+  let output = Command::new(gpg)
+    .args(["--quiet", "--decrypt"])
+    .arg(file_path)
+    .stdin(Stdio::null())
+    .output()
+    .map_err(|error| format!("Unable to run {}: {}", gpg, error))?;
+  let stdout = Zeroizing::new(output.stdout);
+
+  if !output.status.success() {
+    return Err(format!(
+      "Decryption failed: {}",
+      String::from_utf8_lossy(&output.stderr).trim()
+    ));
+  }
+
+  match std::str::from_utf8(&stdout) {
+    Ok(content) => Ok(Zeroizing::new(content.to_string())),
+    Err(error) => Err(format!("Invalid content: {}", error)),
+  }
+}
+
+/// Convert the decrypted content of a password file.
+///
+/// The first line is the password, `key: value` lines become properties, an `otpauth://` line
+/// (as used by pass-otp) becomes the `totpUrl` and everything else is kept as notes.
+fn to_secret_version(name: &str, path: &[String], timestamp: ZeroizeDateTime, content: &str) -> SecretVersion {
+  let mut lines = content.lines();
+  let mut properties = SecretProperties::default();
+  let mut urls = Vec::new();
+  let mut notes = Zeroizing::new(String::new());
+
+  if let Some(password) = lines.next().filter(|password| !password.is_empty()) {
+    properties.set(PROPERTY_PASSWORD, password);
+  }
+
+  for line in lines {
+    if line.starts_with("otpauth://") {
+      if let Some(totp_url) = totp_url(line, "", Some(name), None, None, None) {
+        properties.set(PROPERTY_TOTP_URL, &totp_url);
+        continue;
+      }
+    }
+    let mut parts = line.splitn(2, ':');
+
+    match (parts.next().map(str::trim), parts.next().map(str::trim)) {
+      (Some(key), Some(value))
+        if !key.is_empty() && !key.contains(' ') && !value.is_empty() && !value.starts_with("//") =>
+      {
+        match key.to_lowercase().as_str() {
+          "user" | "username" | "login" => properties.set(PROPERTY_USERNAME, value),
+          "url" | "website" => urls.push(value.to_string()),
+          "password" if properties.get(PROPERTY_PASSWORD).is_none() => properties.set(PROPERTY_PASSWORD, value),
+          _ => properties.set(key, value),
+        }
+      }
+      _ => {
+        if !notes.is_empty() || !line.is_empty() {
+          notes.push_str(line);
+          notes.push('\n');
+        }
+      }
+    }
+  }
+
+  let trimmed_notes = notes.trim_end();
+  if !trimmed_notes.is_empty() {
+    properties.set(PROPERTY_NOTES, trimmed_notes);
+  }
+
+  let secret_type = if properties.get(PROPERTY_USERNAME).is_some() || !urls.is_empty() {
+    SecretType::Login
+  } else {
+    SecretType::Password
+  };
+  let mut version = SecretVersion::new("", secret_type, name, timestamp);
+
+  version.tags = path.to_vec();
+  version.urls = urls;
+  version.properties = properties;
+  version
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use std::os::unix::fs::PermissionsExt;
+  use tempdir::TempDir;
+
+  /// Stands in for `gpg --quiet --decrypt <file>`, the "encrypted" files are plain text
+  const FAKE_GPG: &str = r#"#!/bin/sh
+case "$3" in
+  *broken*) echo "gpg: decryption failed: No secret key" >&2; exit 2 ;;
+esac
+exec cat "$3"
+"#;
+
+  fn write_file(dir: &Path, name: &str, content: &str) {
+    let file_path = dir.join(name);
+
+    fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+    fs::write(file_path, content).unwrap();
+  }
+
+  #[test]
+  fn test_read_store() {
+    let dir = TempDir::new("t-rust-less-pass").unwrap();
+    let gpg = dir.path().join("gpg.sh");
+    let store_dir = dir.path().join("store");
+
+    fs::write(&gpg, FAKE_GPG).unwrap();
+    fs::set_permissions(&gpg, fs::Permissions::from_mode(0o700)).unwrap();
+    write_file(&store_dir, ".gpg-id", "john@example.com\n");
+    write_file(&store_dir, ".git/mail.gpg", "not a secret\n");
+    write_file(&store_dir, "mail.gpg", "private\n");
+    write_file(
+      &store_dir,
+      "Work/mail.gpg",
+      "work password\nuser: john\nurl: https://mail.example.com\notpauth://totp/Mail:john?secret=JBSWY3DPEHPK3PXP\n\nSome notes\nhttps://not.a.property\n",
+    );
+    write_file(&store_dir, "Work/server/root.gpg", "root password\nPIN: 1234\n");
+    write_file(&store_dir, "Work/broken.gpg", "");
+    write_file(&store_dir, "readme.txt", "not a secret\n");
+
+    let import_set = read_store(&store_dir, &gpg.to_string_lossy()).unwrap();
+    let names: Vec<&str> = import_set
+      .secrets
+      .iter()
+      .map(|versions| versions[0].name.as_str())
+      .collect();
+
+    assert_that(&names).is_equal_to(vec!["Work/mail", "Work/server/root", "mail"]);
+    assert_that(&import_set.skipped.len()).is_equal_to(1);
+    assert_that(&import_set.skipped[0].name.as_str()).is_equal_to("Work/broken");
+    assert_that(&import_set.skipped[0].reason.as_str())
+      .is_equal_to("Decryption failed: gpg: decryption failed: No secret key");
+
+    let work_mail = &import_set.secrets[0][0];
+    assert_that(&work_mail.secret_type).is_equal_to(SecretType::Login);
+    assert_that(&work_mail.tags).is_equal_to(vec!["Work".to_string()]);
+    assert_that(&work_mail.urls).is_equal_to(vec!["https://mail.example.com".to_string()]);
+    assert_that(&work_mail.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"work password".to_string()));
+    assert_that(&work_mail.properties.get(PROPERTY_USERNAME)).is_equal_to(Some(&"john".to_string()));
+    assert_that(&work_mail.properties.get(PROPERTY_TOTP_URL)).is_some();
+    assert_that(&work_mail.properties.get(PROPERTY_NOTES))
+      .is_equal_to(Some(&"Some notes\nhttps://not.a.property".to_string()));
+
+    let root = &import_set.secrets[1][0];
+    assert_that(&root.secret_type).is_equal_to(SecretType::Password);
+    assert_that(&root.tags).is_equal_to(vec!["Work".to_string(), "server".to_string()]);
+    assert_that(&root.properties.get("PIN")).is_equal_to(Some(&"1234".to_string()));
+
+    let mail = &import_set.secrets[2][0];
+    assert_that(&mail.tags).is_empty();
+    assert_that(&mail.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"private".to_string()));
+  }
+
+  #[test]
+  fn test_read_store_missing() {
+    let dir = TempDir::new("t-rust-less-pass").unwrap();
+
+    assert_that(&read_store(&dir.path().join("missing"), DEFAULT_GPG).map(|import_set| import_set.secrets.len()))
+      .is_err();
+  }
+}
@@ -0,0 +1,251 @@
+use crate::error::ExtResult;
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use clap::ArgMatches;
+use serde_derive::{Deserialize, Serialize};
+use t_rust_less_lib::api::{SecretProperties, SecretType, SecretVersion, ZeroizeDateTime};
+use zeroize::Zeroize;
+
+const SECONDS_PER_DAY: u64 = 24 * 3600;
+/// Longest accepted interval in days (roughly 100 years).
+const MAX_DAYS: u64 = 36_525;
+
+/// Input of the `add` and `edit` commands, either assembled from command line arguments or
+/// read as json from stdin.
+///
+/// All fields are optional, when editing a secret only the fields present are changed.
+/// A property with an empty value is removed.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretInput {
+  pub name: Option<String>,
+  #[serde(rename = "type")]
+  pub secret_type: Option<SecretType>,
+  pub tags: Option<Vec<String>>,
+  pub urls: Option<Vec<String>>,
+  #[serde(default)]
+  pub properties: SecretProperties,
+  /// Expiry date (`YYYY-MM-DD` or RFC 3339), an empty value removes the expiry.
+  pub expires: Option<String>,
+  /// Rotation interval in days, 0 removes the rotation reminder.
+  pub rotate_every: Option<u64>,
+  /// Id of a custom secret type, an empty value removes the custom type.
+  pub custom_type: Option<String>,
+}
+
+impl SecretInput {
+  pub fn from_args(args: &ArgMatches) -> SecretInput {
+    let mut properties = SecretProperties::default();
+
+    for property in args.values_of("property").into_iter().flatten() {
+      match property.find('=') {
+        Some(idx) => properties.set(&property[..idx], &property[idx + 1..]),
+        None => properties.set(property, ""),
+      }
+    }
+    for property in args.values_of("remove-property").into_iter().flatten() {
+      properties.set(property, "");
+    }
+
+    SecretInput {
+      name: args.value_of("name").map(ToString::to_string),
+      secret_type: args.value_of("type").and_then(parse_secret_type),
+      tags: args
+        .values_of("tag")
+        .map(|tags| tags.map(ToString::to_string).collect()),
+      urls: args
+        .values_of("url")
+        .map(|urls| urls.map(ToString::to_string).collect()),
+      properties,
+      expires: args.value_of("expires").map(ToString::to_string),
+      rotate_every: args
+        .value_of("rotate-every")
+        .map(|days| days.parse().ok_or_exit("Invalid number of days: ")),
+      custom_type: args.value_of("custom-type").map(ToString::to_string),
+    }
+  }
+
+  /// Apply all the changes to a secret version.
+  pub fn apply_to(&self, version: &mut SecretVersion) {
+    if let Some(name) = &self.name {
+      version.name = name.clone();
+    }
+    if let Some(secret_type) = self.secret_type {
+      version.secret_type = secret_type;
+    }
+    if let Some(tags) = &self.tags {
+      version.tags = tags.clone();
+    }
+    if let Some(urls) = &self.urls {
+      version.urls = urls.clone();
+    }
+    for (key, value) in self.properties.iter() {
+      if value.is_empty() {
+        version.properties.remove(key);
+      } else {
+        version.properties.set(key, value);
+      }
+    }
+    if let Some(expires) = &self.expires {
+      version.expires_at = parse_expiry_date(expires).ok_or_exit("Invalid expiry date: ");
+    }
+    if let Some(days) = self.rotate_every {
+      version.rotate_every =
+        Some(days_to_seconds(days).ok_or_exit("Invalid rotation interval: ")).filter(|seconds| *seconds > 0);
+    }
+    if let Some(custom_type) = &self.custom_type {
+      version.custom_type = Some(custom_type.clone()).filter(|custom_type| !custom_type.is_empty());
+    }
+  }
+}
+
+/// Parse an expiry date, which is either a plain date (i.e. midnight UTC) or a RFC 3339 timestamp.
+pub fn parse_expiry_date(date: &str) -> Result<Option<ZeroizeDateTime>, chrono::ParseError> {
+  if date.is_empty() {
+    return Ok(None);
+  }
+  match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+    Ok(date) => Ok(
+      date
+        .and_hms_opt(0, 0, 0)
+        .map(|midnight| Utc.from_utc_datetime(&midnight).into()),
+    ),
+    Err(_) => Ok(Some(DateTime::parse_from_rfc3339(date)?.with_timezone(&Utc).into())),
+  }
+}
+
+/// Parse a number of days (from the command line) to seconds.
+pub fn parse_days(days: &str) -> u64 {
+  let days = days.parse::<u64>().ok_or_exit("Invalid number of days: ");
+
+  days_to_seconds(days).ok_or_exit("Invalid number of days: ")
+}
+
+fn days_to_seconds(days: u64) -> Result<u64, String> {
+  match days {
+    days if days > MAX_DAYS => Err(format!("{} is more than {}", days, MAX_DAYS)),
+    days => Ok(days * SECONDS_PER_DAY),
+  }
+}
+
+pub fn parse_secret_type(secret_type: &str) -> Option<SecretType> {
+  serde_json::from_value(serde_json::Value::String(secret_type.to_lowercase())).ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::cli::app;
+  use serde_json::json;
+  use spectral::prelude::*;
+
+  fn timestamp(date: &str) -> i64 {
+    DateTime::parse_from_rfc3339(date).unwrap().timestamp_millis()
+  }
+
+  fn version() -> SecretVersion {
+    serde_json::from_value(json!({
+      "secret_id": "secret1",
+      "type": "login",
+      "timestamp": "2020-05-01T10:00:00Z",
+      "name": "Example",
+      "tags": ["web"],
+      "properties": {
+        "username": "john",
+        "password": "secret",
+      },
+      "custom_type": "server",
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_json_input() {
+    let input: SecretInput = serde_json::from_value(json!({
+      "name": "Renamed",
+      "urls": ["https://example.com"],
+      "properties": {
+        "password": "changed",
+        "username": "",
+      },
+      "expires": "2030-01-31",
+      "rotate_every": 90,
+      "custom_type": "",
+    }))
+    .unwrap();
+    let mut version = version();
+
+    input.apply_to(&mut version);
+
+    assert_that(&version.name).is_equal_to("Renamed".to_string());
+    assert_that(&version.secret_type).is_equal_to(SecretType::Login);
+    assert_that(&version.tags).is_equal_to(vec!["web".to_string()]);
+    assert_that(&version.urls).is_equal_to(vec!["https://example.com".to_string()]);
+    assert_that(&version.properties.get("password")).is_equal_to(Some(&"changed".to_string()));
+    assert_that(&version.properties.get("username")).is_none();
+    assert_that(
+      &version
+        .expires_at
+        .as_ref()
+        .map(|expires_at| expires_at.timestamp_millis()),
+    )
+    .is_equal_to(Some(timestamp("2030-01-31T00:00:00Z")));
+    assert_that(&version.rotate_every).is_equal_to(Some(90 * SECONDS_PER_DAY));
+    assert_that(&version.custom_type).is_none();
+
+    assert_that(&serde_json::from_value::<SecretInput>(json!({"rotate_every": "never"}))).is_err();
+  }
+
+  #[test]
+  fn test_args_input() {
+    let matches = app().get_matches_from(vec![
+      "t-rust-less",
+      "edit",
+      "Example",
+      "--type",
+      "note",
+      "--tag",
+      "a",
+      "--tag",
+      "b",
+      "--property",
+      "notes=x=y",
+      "--remove-property",
+      "password",
+      "--rotate-every",
+      "0",
+    ]);
+    let input = SecretInput::from_args(matches.subcommand_matches("edit").unwrap());
+    let mut version = version();
+
+    input.apply_to(&mut version);
+
+    assert_that(&version.name).is_equal_to("Example".to_string());
+    assert_that(&version.secret_type).is_equal_to(SecretType::Note);
+    assert_that(&version.tags).is_equal_to(vec!["a".to_string(), "b".to_string()]);
+    assert_that(&version.properties.get("notes")).is_equal_to(Some(&"x=y".to_string()));
+    assert_that(&version.properties.get("password")).is_none();
+    assert_that(&version.properties.get("username")).is_equal_to(Some(&"john".to_string()));
+    assert_that(&version.rotate_every).is_none();
+    assert_that(&version.custom_type).is_equal_to(Some("server".to_string()));
+  }
+
+  #[test]
+  fn test_parse_expiry_date() {
+    assert_that(&parse_expiry_date("").unwrap()).is_none();
+    assert_that(
+      &parse_expiry_date("2030-01-31T12:00:00+01:00")
+        .unwrap()
+        .map(|date| date.timestamp_millis()),
+    )
+    .is_equal_to(Some(timestamp("2030-01-31T11:00:00Z")));
+    assert_that(&parse_expiry_date("tomorrow")).is_err();
+  }
+
+  #[test]
+  fn test_days_to_seconds() {
+    assert_that(&days_to_seconds(0)).is_ok_containing(0);
+    assert_that(&days_to_seconds(30)).is_ok_containing(30 * SECONDS_PER_DAY);
+    assert_that(&days_to_seconds(MAX_DAYS)).is_ok();
+    assert_that(&days_to_seconds(u64::MAX)).is_err();
+  }
+}
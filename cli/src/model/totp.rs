@@ -0,0 +1,47 @@
+use std::str::FromStr;
+use t_rust_less_lib::otp::{OTPAlgorithm, OTPAuthUrl, OTPSecret, OTPType};
+
+/// Normalize the various ways other password managers store TOTP settings into an `otpauth://` url
+/// (as expected by the `totpUrl` property).
+///
+/// `secret_or_url` may either be an url already or a plain base32 encoded seed.
+pub fn totp_url(
+  secret_or_url: &str,
+  account_name: &str,
+  issuer: Option<&str>,
+  period: Option<u32>,
+  digits: Option<u8>,
+  algorithm: Option<&str>,
+) -> Option<String> {
+  let secret_or_url = secret_or_url.trim();
+
+  if secret_or_url.starts_with("otpauth://") {
+    return OTPAuthUrl::parse(secret_or_url).ok().map(|otp_url| otp_url.to_url());
+  }
+
+  let seed: String = secret_or_url
+    .chars()
+    .filter(|c| !c.is_whitespace() && *c != '=' && *c != '-')
+    .map(|c| c.to_ascii_uppercase())
+    .collect();
+  let algorithm = match algorithm.map(|algorithm| algorithm.to_uppercase().replace("-", "")) {
+    Some(ref algorithm) if algorithm.ends_with("SHA256") => OTPAlgorithm::SHA256,
+    Some(ref algorithm) if algorithm.ends_with("SHA512") => OTPAlgorithm::SHA512,
+    _ => OTPAlgorithm::SHA1,
+  };
+  let otp_url = OTPAuthUrl {
+    otp_type: OTPType::TOTP {
+      period: period.unwrap_or(30),
+    },
+    algorithm,
+    digits: digits.unwrap_or(6),
+    account_name: match account_name {
+      "" => issuer.unwrap_or_default().to_string(),
+      account_name => account_name.to_string(),
+    },
+    issuer: issuer.filter(|issuer| !issuer.is_empty()).map(ToString::to_string),
+    secret: OTPSecret::from_str(&seed).ok()?,
+  };
+
+  Some(otp_url.to_url())
+}
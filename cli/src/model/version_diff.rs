@@ -0,0 +1,322 @@
+use serde_derive::Serialize;
+use std::fmt;
+use t_rust_less_lib::api::{SecretVersion, PROPERTY_TOTP_URL};
+use zeroize::Zeroize;
+
+/// Replacement of secret values (i.e. passwords) unless explicitly revealed.
+pub const MASK: &str = "********";
+
+/// Change of a single field between two versions of a secret.
+///
+/// Fields are either properties (by name) or one of `(name)`, `(type)`, `(tags)`, `(urls)`, `(deleted)`
+/// or `(attachment <name>)`.
+#[derive(Clone, Debug, Serialize, Zeroize)]
+#[zeroize(drop)]
+pub struct FieldChange {
+  pub field: String,
+  pub old: Option<String>,
+  pub new: Option<String>,
+}
+
+impl fmt::Display for FieldChange {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match (&self.old, &self.new) {
+      (None, Some(new)) => write!(f, "+ {}: {}", self.field, new),
+      (Some(old), None) => write!(f, "- {}: {}", self.field, old),
+      (Some(old), Some(new)) => write!(f, "~ {}: {} -> {}", self.field, old, new),
+      (None, None) => write!(f, "  {}", self.field),
+    }
+  }
+}
+
+/// Property-level diff between two versions of a secret.
+///
+/// Values of password properties and one-time password urls are masked unless `reveal` is set.
+pub fn diff_versions(old: &SecretVersion, new: &SecretVersion, reveal: bool) -> Vec<FieldChange> {
+  let mut changes = Vec::new();
+
+  push_change(&mut changes, "(name)", Some(&old.name), Some(&new.name));
+  push_change(
+    &mut changes,
+    "(type)",
+    Some(&old.secret_type.to_string()),
+    Some(&new.secret_type.to_string()),
+  );
+  push_change(
+    &mut changes,
+    "(tags)",
+    Some(&old.tags.join(", ")),
+    Some(&new.tags.join(", ")),
+  );
+  push_change(
+    &mut changes,
+    "(urls)",
+    Some(&old.urls.join(", ")),
+    Some(&new.urls.join(", ")),
+  );
+  push_change(
+    &mut changes,
+    "(deleted)",
+    Some(&old.deleted.to_string()),
+    Some(&new.deleted.to_string()),
+  );
+
+  let mut property_names: Vec<&str> = old
+    .properties
+    .iter()
+    .chain(new.properties.iter())
+    .map(|(name, _)| name)
+    .collect();
+  property_names.sort();
+  property_names.dedup();
+
+  for name in property_names {
+    let old_value = old.properties.get(name);
+    let new_value = new.properties.get(name);
+
+    if old_value == new_value {
+      continue;
+    }
+    if !reveal && (is_secret_property(old, name) || is_secret_property(new, name)) {
+      changes.push(FieldChange {
+        field: name.to_string(),
+        old: old_value.map(|_| MASK.to_string()),
+        new: new_value.map(|_| MASK.to_string()),
+      });
+    } else {
+      push_change(&mut changes, name, old_value, new_value);
+    }
+  }
+
+  for attachment in &old.attachments {
+    let field = format!("(attachment {})", attachment.name());
+
+    match new.attachments.iter().find(|other| other.name() == attachment.name()) {
+      Some(other) if other.same_content(attachment) && other.mime_type() == attachment.mime_type() => (),
+      Some(other) => changes.push(FieldChange {
+        field,
+        old: Some(format!("{} bytes", attachment.size())),
+        new: Some(format!("{} bytes", other.size())),
+      }),
+      None => changes.push(FieldChange {
+        field,
+        old: Some(format!("{} bytes", attachment.size())),
+        new: None,
+      }),
+    }
+  }
+  for attachment in &new.attachments {
+    if !old.attachments.iter().any(|other| other.name() == attachment.name()) {
+      changes.push(FieldChange {
+        field: format!("(attachment {})", attachment.name()),
+        old: None,
+        new: Some(format!("{} bytes", attachment.size())),
+      });
+    }
+  }
+
+  changes
+}
+
+/// Names of all the fields that differ between two versions (without any values).
+pub fn changed_fields(old: &SecretVersion, new: &SecretVersion) -> Vec<String> {
+  diff_versions(old, new, false)
+    .iter()
+    .map(|change| change.field.clone())
+    .collect()
+}
+
+/// Take over a field (as named by `diff_versions`) from another version, e.g. to merge competing versions.
+pub fn take_field(target: &mut SecretVersion, source: &SecretVersion, field: &str) {
+  match field {
+    "(name)" => target.name = source.name.clone(),
+    "(type)" => target.secret_type = source.secret_type,
+    "(tags)" => target.tags = source.tags.clone(),
+    "(urls)" => target.urls = source.urls.clone(),
+    "(deleted)" => target.deleted = source.deleted,
+    attachment if attachment.starts_with("(attachment ") && attachment.ends_with(')') => {
+      let name = &attachment[12..attachment.len() - 1];
+
+      target.attachments.retain(|a| a.name() != name);
+      if let Some(source_attachment) = source.attachments.iter().find(|a| a.name() == name) {
+        target.attachments.push(source_attachment.clone());
+      }
+    }
+    property => match source.properties.get(property) {
+      Some(value) => target.properties.set(property, value),
+      None => target.properties.remove(property),
+    },
+  }
+}
+
+fn is_secret_property(version: &SecretVersion, name: &str) -> bool {
+  name == PROPERTY_TOTP_URL || version.secret_type.password_properties().contains(&name)
+}
+
+fn push_change(changes: &mut Vec<FieldChange>, field: &str, old: Option<&String>, new: Option<&String>) {
+  let old = old.filter(|value| !value.is_empty());
+  let new = new.filter(|value| !value.is_empty());
+
+  if old != new {
+    changes.push(FieldChange {
+      field: field.to_string(),
+      old: old.cloned(),
+      new: new.cloned(),
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::Utc;
+  use spectral::prelude::*;
+  use t_rust_less_lib::api::{SecretAttachment, SecretType, PROPERTY_PASSWORD, PROPERTY_USERNAME};
+
+  fn version(properties: &[(&str, &str)], tags: &[&str], urls: &[&str]) -> SecretVersion {
+    let mut version = SecretVersion::new("secret", SecretType::Login, "Mail", Utc::now().into());
+
+    version.tags = tags.iter().map(ToString::to_string).collect();
+    version.urls = urls.iter().map(ToString::to_string).collect();
+
+    for (name, value) in properties {
+      version.properties.set(name, value);
+    }
+    version
+  }
+
+  fn change(field: &str, old: Option<&str>, new: Option<&str>) -> (String, Option<String>, Option<String>) {
+    (
+      field.to_string(),
+      old.map(ToString::to_string),
+      new.map(ToString::to_string),
+    )
+  }
+
+  fn changes(old: &SecretVersion, new: &SecretVersion, reveal: bool) -> Vec<(String, Option<String>, Option<String>)> {
+    diff_versions(old, new, reveal)
+      .iter()
+      .map(|change| (change.field.clone(), change.old.clone(), change.new.clone()))
+      .collect()
+  }
+
+  #[test]
+  fn test_diff_properties() {
+    let old = version(
+      &[(PROPERTY_USERNAME, "john"), ("pin", "1234"), ("old", "gone")],
+      &[],
+      &[],
+    );
+    let new = version(
+      &[(PROPERTY_USERNAME, "jane"), ("pin", "1234"), ("new", "added")],
+      &[],
+      &[],
+    );
+
+    assert_that(&changes(&old, &old, false)).is_empty();
+    assert_that(&changes(&old, &new, false)).is_equal_to(vec![
+      change("new", None, Some("added")),
+      change("old", Some("gone"), None),
+      change(PROPERTY_USERNAME, Some("john"), Some("jane")),
+    ]);
+    assert_that(&changed_fields(&old, &new)).is_equal_to(vec![
+      "new".to_string(),
+      "old".to_string(),
+      PROPERTY_USERNAME.to_string(),
+    ]);
+  }
+
+  #[test]
+  fn test_diff_masks_secrets() {
+    let old = version(&[(PROPERTY_PASSWORD, "secret")], &[], &[]);
+    let new = version(
+      &[
+        (PROPERTY_PASSWORD, "more secret"),
+        (PROPERTY_TOTP_URL, "otpauth://totp/Mail?secret=JBSWY3DPEHPK3PXP"),
+      ],
+      &[],
+      &[],
+    );
+
+    assert_that(&changes(&old, &new, false)).is_equal_to(vec![
+      change(PROPERTY_PASSWORD, Some(MASK), Some(MASK)),
+      change(PROPERTY_TOTP_URL, None, Some(MASK)),
+    ]);
+    assert_that(&changes(&old, &new, true)).is_equal_to(vec![
+      change(PROPERTY_PASSWORD, Some("secret"), Some("more secret")),
+      change(
+        PROPERTY_TOTP_URL,
+        None,
+        Some("otpauth://totp/Mail?secret=JBSWY3DPEHPK3PXP"),
+      ),
+    ]);
+  }
+
+  #[test]
+  fn test_diff_tags_and_urls() {
+    let old = version(&[], &["private"], &[]);
+    let new = version(&[], &["private", "mail"], &["https://mail.example.com"]);
+    let untagged = version(&[], &[], &["https://mail.example.com"]);
+
+    assert_that(&changes(&old, &new, false)).is_equal_to(vec![
+      change("(tags)", Some("private"), Some("private, mail")),
+      change("(urls)", None, Some("https://mail.example.com")),
+    ]);
+    assert_that(&changes(&new, &untagged, false)).is_equal_to(vec![change("(tags)", Some("private, mail"), None)]);
+  }
+
+  #[test]
+  fn test_diff_attachments() {
+    let mut old = version(&[], &[], &[]);
+    let mut new = version(&[], &[], &[]);
+
+    old.attachments.push(SecretAttachment::new(
+      "same.txt".to_string(),
+      "text/plain".to_string(),
+      b"same".to_vec(),
+    ));
+    old.attachments.push(SecretAttachment::new(
+      "changed.txt".to_string(),
+      "text/plain".to_string(),
+      b"old".to_vec(),
+    ));
+    old.attachments.push(SecretAttachment::new(
+      "removed.txt".to_string(),
+      "text/plain".to_string(),
+      b"gone".to_vec(),
+    ));
+    new.attachments.push(SecretAttachment::new(
+      "same.txt".to_string(),
+      "text/plain".to_string(),
+      b"same".to_vec(),
+    ));
+    new.attachments.push(SecretAttachment::new(
+      "changed.txt".to_string(),
+      "text/plain".to_string(),
+      b"new!".to_vec(),
+    ));
+    new.attachments.push(SecretAttachment::new(
+      "added.txt".to_string(),
+      "text/plain".to_string(),
+      b"added".to_vec(),
+    ));
+
+    assert_that(&changes(&old, &new, false)).is_equal_to(vec![
+      change("(attachment changed.txt)", Some("3 bytes"), Some("4 bytes")),
+      change("(attachment removed.txt)", Some("4 bytes"), None),
+      change("(attachment added.txt)", None, Some("5 bytes")),
+    ]);
+  }
+
+  #[test]
+  fn test_take_field() {
+    let mut target = version(&[(PROPERTY_USERNAME, "john"), ("pin", "1234")], &["private"], &[]);
+    let source = version(&[(PROPERTY_USERNAME, "jane")], &["work"], &["https://mail.example.com"]);
+
+    for field in &[PROPERTY_USERNAME, "pin", "(tags)"] {
+      take_field(&mut target, &source, field);
+    }
+
+    assert_that(&changed_fields(&target, &source)).is_equal_to(vec!["(urls)".to_string()]);
+  }
+}
@@ -0,0 +1,28 @@
+use crate::error::ExtResult;
+use clap::ArgMatches;
+use serde::Serialize;
+use std::io;
+
+/// Output format of the non-interactive commands.
+///
+/// `plain` is supposed to be easily consumable by shell scripts, `json` is for everything else.
+/// Both formats should be considered stable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+  Plain,
+  Json,
+}
+
+impl OutputFormat {
+  pub fn from_args(args: &ArgMatches) -> OutputFormat {
+    match args.value_of("format") {
+      Some("json") => OutputFormat::Json,
+      _ => OutputFormat::Plain,
+    }
+  }
+}
+
+pub fn print_json<T: Serialize>(value: &T) {
+  serde_json::to_writer(io::stdout(), value).ok_or_exit("Failed writing json: ");
+  println!();
+}
@@ -0,0 +1,63 @@
+use crate::model::health::describe_issues;
+use crate::view::SecretView;
+use cursive::traits::Scrollable;
+use cursive::view::ViewWrapper;
+use cursive::views::{DummyView, LinearLayout, SelectView, TextView};
+use cursive::Cursive;
+use t_rust_less_lib::api::HealthReport;
+
+pub const HEALTH_REPORT_VIEW_NAME: &str = "health_report";
+
+/// All secrets with password issues, selecting one of them shows it in the secret view.
+pub struct HealthReportView {
+  base_view: LinearLayout,
+}
+
+impl HealthReportView {
+  pub fn new(report: &HealthReport) -> Self {
+    let items: Vec<(String, String)> = report
+      .secrets
+      .iter()
+      .map(|health| {
+        (
+          format!("{}: {}", health.name, describe_issues(health, report).join(", ")),
+          health.secret_id.clone(),
+        )
+      })
+      .collect();
+    let select = SelectView::new().with_all(items).on_submit(show_secret);
+    let base_view = LinearLayout::vertical()
+      .child(TextView::new(format!(
+        "{} of {} secrets with issues",
+        report.secrets.len(),
+        report.checked
+      )))
+      .child(DummyView {})
+      .child(select.scrollable());
+
+    HealthReportView { base_view }
+  }
+}
+
+fn show_secret(s: &mut Cursive, secret_id: &str) {
+  s.pop_layer();
+  s.call_on_name("secret_view", |view: &mut SecretView| view.show_secret(secret_id));
+}
+
+impl ViewWrapper for HealthReportView {
+  type V = LinearLayout;
+
+  fn with_view<F, R>(&self, f: F) -> Option<R>
+  where
+    F: FnOnce(&Self::V) -> R,
+  {
+    Some(f(&self.base_view))
+  }
+
+  fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+  where
+    F: FnOnce(&mut Self::V) -> R,
+  {
+    Some(f(&mut self.base_view))
+  }
+}
@@ -1,5 +1,10 @@
+mod health_report_view;
 mod password_view;
+mod secret_copy_to_store_view;
 mod secret_copy_view;
+mod secret_edit_view;
+mod secret_history_view;
+mod secret_merge_view;
 mod secret_note_view;
 mod secret_simple_view;
 mod secret_totp_view;
@@ -7,8 +12,13 @@ mod secret_type_view;
 mod secret_view;
 mod status_view;
 
+pub use self::health_report_view::*;
 pub use self::password_view::*;
+pub use self::secret_copy_to_store_view::*;
 pub use self::secret_copy_view::*;
+pub use self::secret_edit_view::*;
+pub use self::secret_history_view::*;
+pub use self::secret_merge_view::*;
 pub use self::secret_note_view::*;
 pub use self::secret_simple_view::*;
 pub use self::secret_totp_view::*;
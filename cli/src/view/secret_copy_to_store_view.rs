@@ -0,0 +1,91 @@
+use crate::error::ExtResult;
+use cursive::traits::{Boxable, Identifiable};
+use cursive::views::{Checkbox, Dialog, DummyView, LinearLayout, SelectView, TextView};
+use cursive::Cursive;
+use std::sync::Arc;
+use t_rust_less_lib::api::Secret;
+use t_rust_less_lib::secrets_store::{copy_secret, SecretStoreError};
+use t_rust_less_lib::service::TrustlessService;
+
+/// Ask for another store a secret should be copied to.
+///
+/// The target store has to be unlocked already, the copy is readable by all identities of the
+/// target store.
+pub fn show_copy_to_store_dialog(
+  s: &mut Cursive,
+  service: Arc<dyn TrustlessService>,
+  store_name: &str,
+  secret: &Secret,
+) {
+  let target_names: Vec<String> = service
+    .list_stores()
+    .ok_or_exit("List stores")
+    .into_iter()
+    .map(|store_config| store_config.name.clone())
+    .filter(|name| name != store_name)
+    .collect();
+
+  if target_names.is_empty() {
+    s.add_layer(Dialog::info("There are no other stores"));
+    return;
+  }
+  let source_name = store_name.to_string();
+  let secret_id = secret.id.clone();
+
+  s.add_layer(
+    Dialog::around(
+      LinearLayout::vertical()
+        .child(TextView::new("Target store"))
+        .child(
+          SelectView::new()
+            .with_all_str(target_names)
+            .with_name("copy_target_store")
+            .fixed_width(50),
+        )
+        .child(DummyView {})
+        .child(
+          LinearLayout::horizontal()
+            .child(Checkbox::new().with_name("copy_with_history"))
+            .child(DummyView {})
+            .child(TextView::new("Include history")),
+        ),
+    )
+    .title(format!("Copy {} to store", secret.current.name))
+    .button("Copy", move |s| copy_to_store(s, &service, &source_name, &secret_id))
+    .dismiss_button("Cancel"),
+  );
+}
+
+fn copy_to_store(s: &mut Cursive, service: &Arc<dyn TrustlessService>, store_name: &str, secret_id: &str) {
+  let maybe_target_name = s.find_name::<SelectView>("copy_target_store").unwrap().selection();
+  let with_history = s.find_name::<Checkbox>("copy_with_history").unwrap().is_checked();
+  let target_name = match maybe_target_name {
+    Some(name) => name,
+    _ => {
+      s.add_layer(Dialog::info("No store selected"));
+      return;
+    }
+  };
+  let source = service
+    .open_store(store_name)
+    .ok_or_exit(format!("Failed opening store {}: ", store_name));
+  let target = service
+    .open_store(&target_name)
+    .ok_or_exit(format!("Failed opening store {}: ", target_name));
+
+  if target.status().ok_or_exit("Get status").locked {
+    s.add_layer(Dialog::info(format!("Store {} is locked", target_name)));
+    return;
+  }
+
+  match copy_secret(source.as_ref(), target.as_ref(), secret_id, with_history) {
+    Ok(_) => {
+      s.pop_layer();
+      s.add_layer(Dialog::info(format!("Copied to store {}", target_name)));
+    }
+    Err(SecretStoreError::Conflict) => {
+      s.add_layer(Dialog::info(format!("Secret already exists in store {}", target_name)))
+    }
+    Err(error) => s.add_layer(Dialog::info(format!("Unable to copy secret:\n{}", error))),
+  }
+}
@@ -0,0 +1,632 @@
+use crate::error::ExtResult;
+use crate::model::bitwarden::IDENTITY_PROPERTIES;
+use chrono::Utc;
+use cursive::event::{EventResult, Key};
+use cursive::theme::{BaseColor, Color};
+use cursive::traits::{Boxable, Identifiable, Scrollable};
+use cursive::utils::markup::StyledString;
+use cursive::view::{Finder, ViewWrapper};
+use cursive::views::{
+  Button, Dialog, DummyView, EditView, LinearLayout, NamedView, OnEventView, SelectView, TextArea, TextView,
+};
+use cursive::Cursive;
+use std::rc::Rc;
+use std::sync::Arc;
+use t_rust_less_lib::api::{
+  FieldKind, PasswordGeneratorCharsParam, PasswordGeneratorParam, Secret, SecretListFilter, SecretType,
+  SecretTypeSchema, SecretVersion, PROPERTY_CARDHOLDER, PROPERTY_CARD_BRAND, PROPERTY_CARD_CODE, PROPERTY_CARD_EXPIRY,
+  PROPERTY_CARD_NUMBER, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL, PROPERTY_USERNAME,
+};
+use t_rust_less_lib::secrets_store::estimate::{PasswordEstimator, ZxcvbnEstimator};
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::TrustlessService;
+
+pub const SECRET_EDIT_VIEW_NAME: &str = "secret_edit";
+
+const NAME_FIELD: &str = "secret_edit_name";
+const TAGS_FIELD: &str = "secret_edit_tags";
+const TAG_SUGGESTIONS: &str = "secret_edit_tag_suggestions";
+const URLS_FIELD: &str = "secret_edit_urls";
+
+const SECRET_TYPES: &[SecretType] = &[
+  SecretType::Login,
+  SecretType::Note,
+  SecretType::Licence,
+  SecretType::Wlan,
+  SecretType::Password,
+  SecretType::Card,
+  SecretType::Identity,
+  SecretType::Other,
+];
+
+/// Built-in type or id of a custom type.
+type TypeChoice = (SecretType, Option<String>);
+
+/// Properties that are offered for a new secret of a (built-in) type.
+fn template(secret_type: SecretType) -> Vec<(&'static str, FieldKind)> {
+  let properties: &[(&str, FieldKind)] = match secret_type {
+    SecretType::Login => &[
+      (PROPERTY_USERNAME, FieldKind::Text),
+      (PROPERTY_PASSWORD, FieldKind::Secret),
+      (PROPERTY_TOTP_URL, FieldKind::Totp),
+    ],
+    SecretType::Wlan => &[
+      ("ssid", FieldKind::Text),
+      (PROPERTY_PASSWORD, FieldKind::Secret),
+      ("security", FieldKind::Text),
+    ],
+    SecretType::Licence => &[("key", FieldKind::Text), ("owner", FieldKind::Text)],
+    SecretType::Password => &[(PROPERTY_PASSWORD, FieldKind::Secret)],
+    SecretType::Card => &[
+      (PROPERTY_CARDHOLDER, FieldKind::Text),
+      (PROPERTY_CARD_BRAND, FieldKind::Text),
+      (PROPERTY_CARD_NUMBER, FieldKind::Secret),
+      (PROPERTY_CARD_EXPIRY, FieldKind::Text),
+      (PROPERTY_CARD_CODE, FieldKind::Secret),
+    ],
+    // Same properties as an identity imported from Bitwarden
+    SecretType::Identity => {
+      return IDENTITY_PROPERTIES
+        .iter()
+        .map(|property| {
+          if secret_type.password_properties().contains(property) {
+            (*property, FieldKind::Secret)
+          } else {
+            (*property, FieldKind::Text)
+          }
+        })
+        .chain(std::iter::once((PROPERTY_NOTES, FieldKind::Multiline)))
+        .collect();
+    }
+    SecretType::Note | SecretType::Other => &[],
+  };
+
+  properties
+    .iter()
+    .cloned()
+    .chain(std::iter::once((PROPERTY_NOTES, FieldKind::Multiline)))
+    .collect()
+}
+
+/// Fields of the form: the schema of a custom type or the template of the type followed by all
+/// other properties the secret already has.
+fn form_fields(version: &SecretVersion, schemas: &[SecretTypeSchema]) -> Vec<(String, FieldKind)> {
+  let mut fields: Vec<(String, FieldKind)> = match version.schema(schemas) {
+    Some(schema) => schema
+      .fields
+      .iter()
+      .map(|field| (field.property.clone(), field.kind))
+      .collect(),
+    None => template(version.secret_type)
+      .iter()
+      .map(|(property, kind)| (property.to_string(), *kind))
+      .collect(),
+  };
+  let password_properties = version.password_properties(schemas);
+
+  for (property, value) in version.properties.iter() {
+    if fields.iter().any(|(field, _)| field == property) {
+      continue;
+    }
+    let kind = match property {
+      _ if password_properties.contains(&property) => FieldKind::Secret,
+      PROPERTY_TOTP_URL => FieldKind::Totp,
+      _ if property == PROPERTY_NOTES || value.contains('\n') => FieldKind::Multiline,
+      _ => FieldKind::Text,
+    };
+    fields.push((property.to_string(), kind));
+  }
+
+  fields
+}
+
+fn field_name(property: &str) -> String {
+  format!("secret_edit_property_{}", property)
+}
+
+fn strength_name(property: &str) -> String {
+  format!("secret_edit_strength_{}", property)
+}
+
+/// Form to create a new secret or edit (i.e. add a new version of) an existing one.
+pub struct SecretEditView {
+  service: Arc<dyn TrustlessService>,
+  schemas: Vec<SecretTypeSchema>,
+  version: SecretVersion,
+  fields: Vec<(String, FieldKind)>,
+  base_view: LinearLayout,
+}
+
+impl SecretEditView {
+  pub fn new(
+    service: Arc<dyn TrustlessService>,
+    secrets_store: &Arc<dyn SecretsStore>,
+    maybe_secret: Option<&Secret>,
+  ) -> Self {
+    let schemas = secrets_store.list_schemas().ok_or_exit("List types");
+    let all_tags = Rc::new(
+      secrets_store
+        .list(&SecretListFilter::default())
+        .ok_or_exit("List entries")
+        .all_tags
+        .clone(),
+    );
+    let version = match maybe_secret {
+      Some(secret) => {
+        let mut version = secret.current.clone();
+        version.parent_block_id = Some(secret.current_block_id.clone());
+        version.merged_block_ids.clear();
+        version
+      }
+      None => SecretVersion::new(
+        &service.generate_id().ok_or_exit("Generate id"),
+        SecretType::Login,
+        "",
+        Utc::now().into(),
+      ),
+    };
+    let fields = form_fields(&version, &schemas);
+
+    let mut type_select = SelectView::<TypeChoice>::new().popup();
+    for secret_type in SECRET_TYPES {
+      type_select.add_item(secret_type.to_string(), (*secret_type, None));
+    }
+    for schema in schemas.iter() {
+      type_select.add_item(schema.name.clone(), (SecretType::Other, Some(schema.id.clone())));
+    }
+    let selected = type_select
+      .iter()
+      .position(|(_, (secret_type, custom_type))| {
+        *secret_type == version.secret_type && *custom_type == version.custom_type
+      })
+      .unwrap_or_default();
+    type_select = type_select.selected(selected);
+    type_select.set_on_submit(|s, choice: &TypeChoice| {
+      let choice = choice.clone();
+      s.call_on_name(SECRET_EDIT_VIEW_NAME, move |view: &mut SecretEditView| {
+        view.change_type(choice)
+      });
+    });
+
+    let suggestion_tags = all_tags.clone();
+    let tags_field = EditView::new()
+      .content(version.tags.join(", "))
+      .on_edit(move |s, content, _| {
+        let suggestions = tag_candidates(&suggestion_tags, content).join(", ");
+        s.call_on_name(TAG_SUGGESTIONS, |view: &mut TextView| view.set_content(suggestions));
+      })
+      .with_name(TAGS_FIELD);
+    let tags_field =
+      OnEventView::new(tags_field).on_pre_event_inner(Key::Tab, move |view: &mut NamedView<EditView>, _| {
+        let mut edit_view = view.get_mut();
+        let completed = complete_tag(&all_tags, &edit_view.get_content())?;
+
+        Some(EventResult::Consumed(Some(edit_view.set_content(completed))))
+      });
+
+    let mut view = SecretEditView {
+      service,
+      schemas,
+      version,
+      fields,
+      base_view: LinearLayout::vertical(),
+    };
+    let header = LinearLayout::vertical()
+      .child(TextView::new("Name"))
+      .child(
+        EditView::new()
+          .content(view.version.name.clone())
+          .with_name(NAME_FIELD)
+          .fixed_width(50),
+      )
+      .child(DummyView {})
+      .child(TextView::new("Type"))
+      .child(type_select)
+      .child(DummyView {})
+      .child(TextView::new("Tags (comma separated, Tab to complete)"))
+      .child(tags_field.fixed_width(50))
+      .child(TextView::new("").with_name(TAG_SUGGESTIONS))
+      .child(DummyView {})
+      .child(TextView::new("URLs (comma separated)"))
+      .child(
+        EditView::new()
+          .content(view.version.urls.join(", "))
+          .with_name(URLS_FIELD)
+          .fixed_width(50),
+      )
+      .child(DummyView {});
+    let properties = view.properties_view();
+
+    view.base_view.add_child(header);
+    view.base_view.add_child(properties);
+    view
+  }
+
+  /// The new version of the secret with all the changes of the form.
+  pub fn edited_version(&mut self) -> Result<SecretVersion, String> {
+    self.collect();
+
+    if self.version.name.is_empty() {
+      return Err("Name must not be empty".to_string());
+    }
+    if let Some(schema) = self.version.schema(&self.schemas) {
+      schema.validate(&self.version).map_err(|error| error.to_string())?;
+    }
+
+    let mut version = self.version.clone();
+    version.timestamp = Utc::now().into();
+
+    Ok(version)
+  }
+
+  fn change_type(&mut self, (secret_type, custom_type): TypeChoice) {
+    self.collect();
+    self.version.secret_type = secret_type;
+    self.version.custom_type = custom_type;
+    self.fields = form_fields(&self.version, &self.schemas);
+
+    let properties = self.properties_view();
+    self.base_view.remove_child(1);
+    self.base_view.add_child(properties);
+  }
+
+  /// Take over the current content of all input fields.
+  fn collect(&mut self) {
+    if let Some(name) = self
+      .base_view
+      .call_on_name(NAME_FIELD, |view: &mut EditView| view.get_content())
+    {
+      self.version.name = name.trim().to_string();
+    }
+    if let Some(tags) = self
+      .base_view
+      .call_on_name(TAGS_FIELD, |view: &mut EditView| view.get_content())
+    {
+      self.version.tags = split_list(&tags);
+    }
+    if let Some(urls) = self
+      .base_view
+      .call_on_name(URLS_FIELD, |view: &mut EditView| view.get_content())
+    {
+      self.version.urls = split_list(&urls);
+    }
+    for (property, kind) in self.fields.iter() {
+      let maybe_value = match kind {
+        FieldKind::Multiline => self
+          .base_view
+          .call_on_name(&field_name(property), |view: &mut TextArea| {
+            view.get_content().to_string()
+          }),
+        _ => self
+          .base_view
+          .call_on_name(&field_name(property), |view: &mut EditView| {
+            view.get_content().to_string()
+          }),
+      };
+      match maybe_value {
+        Some(value) if value.is_empty() => {
+          self.version.properties.remove(property);
+        }
+        Some(value) => self.version.properties.set(property, &value),
+        None => (),
+      }
+    }
+  }
+
+  fn properties_view(&self) -> LinearLayout {
+    let mut layout = LinearLayout::vertical();
+
+    for (property, kind) in self.fields.iter() {
+      let value = self.version.properties.get(property).cloned().unwrap_or_default();
+
+      layout.add_child(TextView::new(property.as_str()));
+      match kind {
+        FieldKind::Multiline => layout.add_child(
+          TextArea::new()
+            .content(value)
+            .with_name(field_name(property))
+            .fixed_width(50)
+            .min_height(3),
+        ),
+        FieldKind::Secret => {
+          let strength = strength_name(property);
+          let field = field_name(property);
+          let service = self.service.clone();
+
+          layout.add_child(
+            LinearLayout::horizontal()
+              .child(
+                EditView::new()
+                  .secret()
+                  .content(value.as_str())
+                  .on_edit(move |s, content, _| {
+                    let text = strength_text(content);
+                    s.call_on_name(&strength, |view: &mut TextView| view.set_content(text));
+                  })
+                  .with_name(field.clone())
+                  .fixed_width(50),
+              )
+              .child(Button::new("Generate", move |s| {
+                let password = match service.generate_password(default_generator_param()) {
+                  Ok(password) => password,
+                  Err(error) => {
+                    s.add_layer(Dialog::info(format!("Unable to generate password:\n{}", error)));
+                    return;
+                  }
+                };
+                // Setting the content triggers the strength feedback
+                if let Some(callback) = s.call_on_name(&field, |view: &mut EditView| view.set_content(password)) {
+                  callback(s);
+                }
+              })),
+          );
+          layout.add_child(TextView::new(strength_text(&value)).with_name(strength_name(property)));
+        }
+        _ => layout.add_child(
+          EditView::new()
+            .content(value)
+            .with_name(field_name(property))
+            .fixed_width(50),
+        ),
+      }
+    }
+
+    layout
+  }
+}
+
+impl ViewWrapper for SecretEditView {
+  type V = LinearLayout;
+
+  fn with_view<F, R>(&self, f: F) -> Option<R>
+  where
+    F: FnOnce(&Self::V) -> R,
+  {
+    Some(f(&self.base_view))
+  }
+
+  fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+  where
+    F: FnOnce(&mut Self::V) -> R,
+  {
+    Some(f(&mut self.base_view))
+  }
+}
+
+/// Open the form for a new secret (or a new version of an existing one).
+///
+/// `on_saved` is called with the id of the secret once the new version has been added to the store.
+pub fn show_edit_dialog<F>(
+  s: &mut Cursive,
+  service: Arc<dyn TrustlessService>,
+  secrets_store: Arc<dyn SecretsStore>,
+  maybe_secret: Option<Secret>,
+  on_saved: F,
+) where
+  F: Fn(&mut Cursive, &str) + 'static,
+{
+  let edit_view = SecretEditView::new(service, &secrets_store, maybe_secret.as_ref());
+  let title = match maybe_secret {
+    Some(secret) => format!("Edit {}", secret.current.name),
+    None => "New secret".to_string(),
+  };
+
+  s.add_layer(
+    Dialog::around(edit_view.with_name(SECRET_EDIT_VIEW_NAME).scrollable())
+      .title(title)
+      .button("Save", move |s| save(s, &secrets_store, &on_saved))
+      .dismiss_button("Cancel"),
+  );
+}
+
+fn save<F>(s: &mut Cursive, secrets_store: &Arc<dyn SecretsStore>, on_saved: &F)
+where
+  F: Fn(&mut Cursive, &str),
+{
+  let maybe_version = s.call_on_name(SECRET_EDIT_VIEW_NAME, |view: &mut SecretEditView| view.edited_version());
+
+  match maybe_version {
+    Some(Ok(version)) => {
+      let secret_id = version.secret_id.clone();
+
+      // The form stays open, so that nothing is lost
+      if let Err(error) = secrets_store.add(version) {
+        s.add_layer(Dialog::info(format!("Unable to save secret:\n{}", error)));
+        return;
+      }
+      s.pop_layer();
+      match secrets_store.update_index() {
+        Ok(_) => on_saved(s, &secret_id),
+        Err(error) => s.add_layer(Dialog::info(format!("Unable to update index:\n{}", error))),
+      }
+    }
+    Some(Err(error)) => s.add_layer(Dialog::info(error)),
+    None => (),
+  }
+}
+
+fn default_generator_param() -> PasswordGeneratorParam {
+  PasswordGeneratorParam::Chars(PasswordGeneratorCharsParam {
+    num_chars: 16,
+    include_uppers: true,
+    include_numbers: true,
+    include_symbols: true,
+    require_upper: false,
+    require_number: false,
+    require_symbol: false,
+    exclude_ambiguous: true,
+    exlcude_similar: true,
+  })
+}
+
+fn strength_text(password: &str) -> StyledString {
+  if password.is_empty() {
+    return StyledString::new();
+  }
+  let strength = ZxcvbnEstimator::estimate_strength(password, &[]);
+  let text = format!(
+    "Strength {}/4, cracked in {}",
+    strength.score, strength.crack_time_display
+  );
+
+  if strength.score < 3 {
+    StyledString::styled(text, Color::Light(BaseColor::Red))
+  } else {
+    StyledString::styled(text, Color::Light(BaseColor::Green))
+  }
+}
+
+/// Split a comma separated list (of tags or urls).
+fn split_list(content: &str) -> Vec<String> {
+  content
+    .split(',')
+    .map(str::trim)
+    .filter(|item| !item.is_empty())
+    .map(ToString::to_string)
+    .collect()
+}
+
+/// Split the input of the tags field into the tags that have already been entered and the
+/// (incomplete) tag that is currently typed.
+fn split_partial_tag(content: &str) -> (&str, &str) {
+  match content.rfind(',') {
+    Some(idx) => (&content[..idx], content[idx + 1..].trim_start()),
+    None => ("", content.trim_start()),
+  }
+}
+
+/// Known tags that start with the tag that is currently typed.
+fn tag_candidates<'a>(all_tags: &'a [String], content: &str) -> Vec<&'a str> {
+  let (entered, partial) = split_partial_tag(content);
+  if partial.is_empty() {
+    return vec![];
+  }
+  let entered: Vec<&str> = entered.split(',').map(str::trim).collect();
+
+  all_tags
+    .iter()
+    .map(String::as_str)
+    .filter(|tag| tag.starts_with(partial) && *tag != partial && !entered.contains(tag))
+    .collect()
+}
+
+fn complete_tag(all_tags: &[String], content: &str) -> Option<String> {
+  let candidate = *tag_candidates(all_tags, content).first()?;
+  let (entered, _) = split_partial_tag(content);
+
+  if content.contains(',') {
+    Some(format!("{}, {}, ", entered, candidate))
+  } else {
+    Some(format!("{}, ", candidate))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use t_rust_less_lib::api::{SchemaField, PROPERTY_SSN};
+
+  fn tags() -> Vec<String> {
+    vec!["private".to_string(), "project".to_string(), "work".to_string()]
+  }
+
+  fn property_names(fields: &[(String, FieldKind)]) -> Vec<&str> {
+    fields.iter().map(|(property, _)| property.as_str()).collect()
+  }
+
+  #[test]
+  fn test_split_list() {
+    assert_that(&split_list("")).is_empty();
+    assert_that(&split_list(" , ,")).is_empty();
+    assert_that(&split_list("private, work ,, mail")).is_equal_to(vec![
+      "private".to_string(),
+      "work".to_string(),
+      "mail".to_string(),
+    ]);
+  }
+
+  #[test]
+  fn test_tag_candidates() {
+    let all_tags = tags();
+
+    assert_that(&tag_candidates(&all_tags, "")).is_empty();
+    assert_that(&tag_candidates(&all_tags, "p")).is_equal_to(vec!["private", "project"]);
+    assert_that(&tag_candidates(&all_tags, "pro")).is_equal_to(vec!["project"]);
+    // Complete tags and tags that have already been entered are not offered again
+    assert_that(&tag_candidates(&all_tags, "work")).is_empty();
+    assert_that(&tag_candidates(&all_tags, "private, p")).is_equal_to(vec!["project"]);
+    assert_that(&tag_candidates(&all_tags, "private, ")).is_empty();
+  }
+
+  #[test]
+  fn test_complete_tag() {
+    let all_tags = tags();
+
+    assert_that(&complete_tag(&all_tags, "w")).is_equal_to(Some("work, ".to_string()));
+    assert_that(&complete_tag(&all_tags, "work, p")).is_equal_to(Some("work, private, ".to_string()));
+    assert_that(&complete_tag(&all_tags, "work, private, p")).is_equal_to(Some("work, private, project, ".to_string()));
+    assert_that(&complete_tag(&all_tags, "mail")).is_none();
+  }
+
+  #[test]
+  fn test_form_fields() {
+    let mut version = SecretVersion::new("secret", SecretType::Login, "Mail", Utc::now().into());
+
+    assert_that(&property_names(&form_fields(&version, &[]))).is_equal_to(vec![
+      PROPERTY_USERNAME,
+      PROPERTY_PASSWORD,
+      PROPERTY_TOTP_URL,
+      PROPERTY_NOTES,
+    ]);
+
+    // Properties without a field in the template are appended
+    version.properties.set("pin", "1234");
+    version.properties.set("comment", "line 1\nline 2");
+    let fields = form_fields(&version, &[]);
+
+    assert_that(&fields.len()).is_equal_to(6);
+    assert_that(&fields[4..].contains(&("pin".to_string(), FieldKind::Text))).is_true();
+    assert_that(&fields[4..].contains(&("comment".to_string(), FieldKind::Multiline))).is_true();
+
+    version.secret_type = SecretType::Card;
+    version.properties = Default::default();
+    let fields = form_fields(&version, &[]);
+
+    assert_that(&fields.contains(&(PROPERTY_CARD_NUMBER.to_string(), FieldKind::Secret))).is_true();
+    assert_that(&fields.contains(&(PROPERTY_CARD_EXPIRY.to_string(), FieldKind::Text))).is_true();
+
+    version.secret_type = SecretType::Identity;
+    let fields = form_fields(&version, &[]);
+
+    assert_that(&fields.len()).is_equal_to(IDENTITY_PROPERTIES.len() + 1);
+    assert_that(&fields.contains(&(PROPERTY_SSN.to_string(), FieldKind::Secret))).is_true();
+
+    // The schema of a custom type replaces the template
+    let schema = SecretTypeSchema {
+      id: "database".to_string(),
+      name: "Database".to_string(),
+      fields: vec![
+        SchemaField {
+          property: "host".to_string(),
+          kind: FieldKind::Text,
+          required: true,
+        },
+        SchemaField {
+          property: "adminPassword".to_string(),
+          kind: FieldKind::Secret,
+          required: false,
+        },
+      ],
+    };
+    version.secret_type = SecretType::Other;
+    version.custom_type = Some("database".to_string());
+    version.properties.set("legacyPassword", "secret");
+
+    assert_that(&form_fields(&version, &[schema])).is_equal_to(vec![
+      ("host".to_string(), FieldKind::Text),
+      ("adminPassword".to_string(), FieldKind::Secret),
+      ("legacyPassword".to_string(), FieldKind::Text),
+    ]);
+  }
+}
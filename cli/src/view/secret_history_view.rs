@@ -0,0 +1,137 @@
+use crate::error::ExtResult;
+use crate::model::version_diff::diff_versions;
+use cursive::traits::Scrollable;
+use cursive::view::ViewWrapper;
+use cursive::views::{Checkbox, DummyView, LinearLayout, Panel, SelectView, TextContent, TextView};
+use cursive::Cursive;
+use std::sync::Arc;
+use t_rust_less_lib::api::{Secret, SecretVersion};
+use t_rust_less_lib::secrets_store::SecretsStore;
+
+pub const SECRET_HISTORY_VIEW_NAME: &str = "secret_history";
+
+/// All versions of a secret with the changes between two selectable versions.
+///
+/// Password values are masked until revealed.
+pub struct SecretHistoryView {
+  versions: Vec<SecretVersion>,
+  from: usize,
+  to: usize,
+  reveal: bool,
+  changes: TextContent,
+  base_view: LinearLayout,
+}
+
+impl SecretHistoryView {
+  pub fn new(secrets_store: &Arc<dyn SecretsStore>, secret: &Secret) -> Self {
+    // Versions are ordered newest first, the first being the current one
+    let versions: Vec<SecretVersion> = secret
+      .versions
+      .iter()
+      .map(|version_ref| {
+        if version_ref.block_id == secret.current_block_id {
+          secret.current.clone()
+        } else {
+          secrets_store
+            .get_version(&version_ref.block_id)
+            .ok_or_exit("Get secret version")
+        }
+      })
+      .collect();
+    let from = if versions.len() > 1 { 1 } else { 0 };
+    let labels: Vec<(String, usize)> = secret
+      .versions
+      .iter()
+      .enumerate()
+      .map(|(idx, version_ref)| {
+        let timestamp = version_ref.timestamp.format("%Y-%m-%d %H:%M:%S");
+        match idx {
+          0 => (format!("{} (current)", timestamp), idx),
+          _ => (timestamp, idx),
+        }
+      })
+      .collect();
+    let from_select = SelectView::new()
+      .with_all(labels.clone())
+      .selected(from)
+      .on_select(|s, idx| Self::update(s, |view| view.from = *idx));
+    let to_select = SelectView::new()
+      .with_all(labels)
+      .on_select(|s, idx| Self::update(s, |view| view.to = *idx));
+    let changes = TextContent::new("");
+    let base_view = LinearLayout::vertical()
+      .child(
+        LinearLayout::horizontal()
+          .child(Panel::new(from_select.scrollable()).title("From"))
+          .child(Panel::new(to_select.scrollable()).title("To")),
+      )
+      .child(
+        LinearLayout::horizontal()
+          .child(Checkbox::new().on_change(|s, checked| Self::update(s, |view| view.reveal = checked)))
+          .child(DummyView {})
+          .child(TextView::new("Reveal passwords")),
+      )
+      .child(Panel::new(TextView::new_with_content(changes.clone()).scrollable()).title("Changes"));
+
+    let mut view = SecretHistoryView {
+      versions,
+      from,
+      to: 0,
+      reveal: false,
+      changes,
+      base_view,
+    };
+    view.update_changes();
+    view
+  }
+
+  /// The version selected in the `From` list, which is the one to restore.
+  pub fn from_version(&self) -> Option<SecretVersion> {
+    self.versions.get(self.from).cloned()
+  }
+
+  fn update<F>(s: &mut Cursive, modify: F)
+  where
+    F: FnOnce(&mut SecretHistoryView),
+  {
+    s.call_on_name(SECRET_HISTORY_VIEW_NAME, |view: &mut SecretHistoryView| {
+      modify(view);
+      view.update_changes();
+    });
+  }
+
+  fn update_changes(&mut self) {
+    let text = match (self.versions.get(self.from), self.versions.get(self.to)) {
+      (Some(from), Some(to)) => {
+        let changes = diff_versions(from, to, self.reveal);
+
+        if changes.is_empty() {
+          "No changes".to_string()
+        } else {
+          changes.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n")
+        }
+      }
+      _ => String::new(),
+    };
+
+    self.changes.set_content(text);
+  }
+}
+
+impl ViewWrapper for SecretHistoryView {
+  type V = LinearLayout;
+
+  fn with_view<F, R>(&self, f: F) -> Option<R>
+  where
+    F: FnOnce(&Self::V) -> R,
+  {
+    Some(f(&self.base_view))
+  }
+
+  fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+  where
+    F: FnOnce(&mut Self::V) -> R,
+  {
+    Some(f(&mut self.base_view))
+  }
+}
@@ -0,0 +1,138 @@
+use crate::error::ExtResult;
+use crate::model::version_diff::{diff_versions, take_field};
+use crate::view::SecretView;
+use chrono::Utc;
+use cursive::traits::{Identifiable, Scrollable};
+use cursive::view::ViewWrapper;
+use cursive::views::{Checkbox, Dialog, DummyView, LinearLayout, TextView};
+use cursive::Cursive;
+use std::sync::Arc;
+use t_rust_less_lib::api::{Secret, SecretVersion};
+use t_rust_less_lib::secrets_store::SecretsStore;
+
+pub const SECRET_MERGE_VIEW_NAME: &str = "secret_merge";
+
+/// Merge a competing version (i.e. a concurrent change) into the current version of a secret.
+///
+/// Every field that differs can be taken over from the competing version, all other fields remain
+/// as they are in the current version. If there are several competing versions they are merged one
+/// at a time.
+pub struct SecretMergeView {
+  current_block_id: String,
+  current: SecretVersion,
+  competing_block_id: String,
+  competing: SecretVersion,
+  fields: Vec<String>,
+  take_over: Vec<bool>,
+  base_view: LinearLayout,
+}
+
+impl SecretMergeView {
+  pub fn new(secrets_store: &Arc<dyn SecretsStore>, secret: &Secret) -> Option<Self> {
+    let competing_ref = secret.competing_versions.first()?;
+    let competing = secrets_store
+      .get_version(&competing_ref.block_id)
+      .ok_or_exit("Get secret version");
+    let changes = diff_versions(&secret.current, &competing, false);
+    let mut base_view = LinearLayout::vertical()
+      .child(TextView::new(format!(
+        "Competing change from {} ({} of {}).\nSelect the changes to take over:",
+        competing_ref,
+        1,
+        secret.competing_versions.len()
+      )))
+      .child(DummyView {});
+
+    for (idx, change) in changes.iter().enumerate() {
+      base_view.add_child(
+        LinearLayout::horizontal()
+          .child(Checkbox::new().on_change(move |s, checked| {
+            s.call_on_name(SECRET_MERGE_VIEW_NAME, |view: &mut SecretMergeView| {
+              view.take_over[idx] = checked
+            });
+          }))
+          .child(DummyView {})
+          .child(TextView::new(change.to_string())),
+      );
+    }
+    if changes.is_empty() {
+      base_view.add_child(TextView::new("Both versions are identical"));
+    }
+
+    Some(SecretMergeView {
+      current_block_id: secret.current_block_id.clone(),
+      current: secret.current.clone(),
+      competing_block_id: competing_ref.block_id.clone(),
+      competing,
+      take_over: vec![false; changes.len()],
+      fields: changes.iter().map(|change| change.field.clone()).collect(),
+      base_view,
+    })
+  }
+
+  /// New version of the secret replacing both the current and the competing version.
+  pub fn merged_version(&self) -> SecretVersion {
+    let mut merged = self.current.clone();
+
+    for (field, _) in self.fields.iter().zip(self.take_over.iter()).filter(|(_, take)| **take) {
+      take_field(&mut merged, &self.competing, field);
+    }
+    merged.timestamp = Utc::now().into();
+    merged.parent_block_id = Some(self.current_block_id.clone());
+    merged.merged_block_ids = vec![self.competing_block_id.clone()];
+
+    merged
+  }
+}
+
+impl ViewWrapper for SecretMergeView {
+  type V = LinearLayout;
+
+  fn with_view<F, R>(&self, f: F) -> Option<R>
+  where
+    F: FnOnce(&Self::V) -> R,
+  {
+    Some(f(&self.base_view))
+  }
+
+  fn with_view_mut<F, R>(&mut self, f: F) -> Option<R>
+  where
+    F: FnOnce(&mut Self::V) -> R,
+  {
+    Some(f(&mut self.base_view))
+  }
+}
+
+/// Open the merge dialog for a (conflicted) secret.
+pub fn show_merge_dialog(secrets_store: Arc<dyn SecretsStore>, secret_id: String) -> impl Fn(&mut Cursive) {
+  move |s: &mut Cursive| {
+    let secret = secrets_store.get(&secret_id).ok_or_exit("Get secret");
+
+    if let Some(merge_view) = SecretMergeView::new(&secrets_store, &secret) {
+      let secrets_store = secrets_store.clone();
+
+      s.add_layer(
+        Dialog::around(merge_view.with_name(SECRET_MERGE_VIEW_NAME).scrollable())
+          .title("Merge conflicting changes")
+          .button("Merge", move |s| merge(s, &secrets_store))
+          .dismiss_button("Cancel"),
+      );
+    }
+  }
+}
+
+fn merge(s: &mut Cursive, secrets_store: &Arc<dyn SecretsStore>) {
+  let maybe_merged = s.call_on_name(SECRET_MERGE_VIEW_NAME, |view: &mut SecretMergeView| {
+    view.merged_version()
+  });
+
+  if let Some(merged) = maybe_merged {
+    let secret_id = merged.secret_id.clone();
+
+    secrets_store.add(merged).ok_or_exit("Add secret version");
+    secrets_store.update_index().ok_or_exit("Update index");
+
+    s.pop_layer();
+    s.call_on_name("secret_view", |view: &mut SecretView| view.show_secret(&secret_id));
+  }
+}
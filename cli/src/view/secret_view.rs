@@ -1,11 +1,16 @@
 use crate::error::ExtResult;
-use crate::view::{SecretCopyView, SecretNodeView, SecretSimpleView, SecretTOTPView, SecretTypeView};
+use crate::view::{
+  show_merge_dialog, SecretCopyView, SecretHistoryView, SecretNodeView, SecretSimpleView, SecretTOTPView,
+  SecretTypeView,
+};
+use cursive::theme::{BaseColor, Color};
+use cursive::utils::markup::StyledString;
 use cursive::view::ViewWrapper;
-use cursive::views::{DummyView, LinearLayout};
+use cursive::views::{Button, DummyView, LinearLayout, TextView};
 use cursive::Cursive;
 use std::env;
 use std::sync::Arc;
-use t_rust_less_lib::api::{Secret, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL};
+use t_rust_less_lib::api::{FieldKind, Secret, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_TOTP_URL};
 use t_rust_less_lib::secrets_store::SecretsStore;
 use t_rust_less_lib::service::TrustlessService;
 
@@ -46,39 +51,104 @@ impl SecretView {
     self.current_secret.clone()
   }
 
+  /// Name of the store the currently shown secret belongs to.
+  pub fn store_name(&self) -> &str {
+    &self.store_name
+  }
+
+  /// History of the currently shown secret.
+  pub fn history_view(&self) -> Option<SecretHistoryView> {
+    self
+      .current_secret
+      .as_ref()
+      .map(|secret| SecretHistoryView::new(&self.secrets_store, secret))
+  }
+
+  /// Show a secret of another store.
+  pub fn show_store_secret(&mut self, store_name: &str, secrets_store: Arc<dyn SecretsStore>, secret_id: &str) {
+    self.store_name = store_name.to_string();
+    self.secrets_store = secrets_store;
+    self.show_secret(secret_id)
+  }
+
   pub fn show_secret(&mut self, secret_id: &str) {
     match self.secrets_store.get(secret_id) {
       Ok(secret) => {
-        let mut layout = LinearLayout::vertical()
-          .child(SecretSimpleView::new("Name", &secret.current.name))
-          .child(SecretTypeView::new(secret.current.secret_type))
-          .child(DummyView {});
+        let schemas = match secret.current.custom_type {
+          Some(_) => self.secrets_store.list_schemas().unwrap_or_default(),
+          None => vec![],
+        };
+        let schema = secret.current.schema(&schemas);
+        let mut layout = LinearLayout::vertical();
 
+        if !secret.competing_versions.is_empty() {
+          layout = layout
+            .child(
+              LinearLayout::horizontal()
+                .child(TextView::new(StyledString::styled(
+                  "Conflicting changes ",
+                  Color::Light(BaseColor::Red),
+                )))
+                .child(Button::new(
+                  "Merge",
+                  show_merge_dialog(self.secrets_store.clone(), secret_id.to_string()),
+                )),
+            )
+            .child(DummyView {});
+        }
+        layout = layout.child(SecretSimpleView::new("Name", &secret.current.name));
+        layout = match schema {
+          Some(schema) => layout.child(SecretSimpleView::new("Type", &schema.name)),
+          None => layout.child(SecretTypeView::new(secret.current.secret_type)),
+        };
+        if let Some(expires_at) = &secret.current.expires_at {
+          layout = layout.child(SecretSimpleView::new("Expires", &expires_at.format("%Y-%m-%d %H:%M")));
+        }
+        if let Some(rotate_every) = secret.current.rotate_every {
+          layout = layout.child(SecretSimpleView::new(
+            "Rotate",
+            &format!("every {} days", rotate_every / (24 * 3600)),
+          ));
+        }
+        for (property, strength) in secret.password_strengths.iter() {
+          if strength.breach_count > 0 {
+            layout = layout.child(TextView::new(StyledString::styled(
+              format!(
+                "{} appears {} times in breached passwords",
+                property, strength.breach_count
+              ),
+              Color::Light(BaseColor::Red),
+            )));
+          }
+        }
+        layout = layout.child(DummyView {});
+
+        // Fields of a custom type come first (in the order of the schema)
+        let fields: Vec<(&str, FieldKind)> = schema
+          .map(|schema| {
+            schema
+              .fields
+              .iter()
+              .map(|field| (field.property.as_str(), field.kind))
+              .collect()
+          })
+          .unwrap_or_default();
+        for (property, kind) in fields.iter() {
+          if let Some(value) = secret.current.properties.get(property) {
+            layout = self.add_property_view(layout, secret_id, property, value, *kind);
+          }
+        }
         for (property, value) in secret.current.properties.iter() {
-          match property {
-            PROPERTY_PASSWORD => (),
-            PROPERTY_NOTES => {
-              layout = layout.child(SecretNodeView::new(
-                property,
-                value,
-                self.copy_to_clipboard(secret_id, property),
-              ))
-            }
-            PROPERTY_TOTP_URL => {
-              layout = layout.child(SecretTOTPView::new(
-                property,
-                value,
-                self.copy_to_clipboard(secret_id, property),
-              ))
-            }
-            _ => {
-              layout = layout.child(SecretCopyView::new(
-                property,
-                value,
-                self.copy_to_clipboard(secret_id, property),
-              ))
-            }
+          if fields.iter().any(|(field, _)| *field == property) {
+            continue;
           }
+          let kind = match property {
+            PROPERTY_PASSWORD => continue,
+            PROPERTY_NOTES => FieldKind::Multiline,
+            PROPERTY_TOTP_URL => FieldKind::Totp,
+            _ => FieldKind::Text,
+          };
+          layout = self.add_property_view(layout, secret_id, property, value, kind);
         }
 
         self.base_view = Some(layout);
@@ -91,6 +161,38 @@ impl SecretView {
     }
   }
 
+  fn add_property_view(
+    &self,
+    layout: LinearLayout,
+    secret_id: &str,
+    property: &str,
+    value: &str,
+    kind: FieldKind,
+  ) -> LinearLayout {
+    match kind {
+      FieldKind::Secret => layout.child(SecretCopyView::new(
+        property,
+        "********",
+        self.copy_to_clipboard(secret_id, property),
+      )),
+      FieldKind::Multiline => layout.child(SecretNodeView::new(
+        property,
+        value,
+        self.copy_to_clipboard(secret_id, property),
+      )),
+      FieldKind::Totp => layout.child(SecretTOTPView::new(
+        property,
+        value,
+        self.copy_to_clipboard(secret_id, property),
+      )),
+      _ => layout.child(SecretCopyView::new(
+        property,
+        value,
+        self.copy_to_clipboard(secret_id, property),
+      )),
+    }
+  }
+
   fn copy_to_clipboard(&self, secret_id: &str, property: &str) -> impl Fn(&mut Cursive) {
     let service = self.service.clone();
     let store_name = self.store_name.clone();
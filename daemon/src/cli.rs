@@ -1,10 +1,30 @@
 use clap::{App, Arg};
 
 pub fn app() -> App<'static, 'static> {
-  App::new("t-rust-less").version("0.1").about("Manages passwords").arg(
-    Arg::with_name("debug")
-      .short("D")
-      .long("debug")
-      .help("Enable debug logs"),
-  )
+  App::new("t-rust-less")
+    .version("0.1")
+    .about("Manages passwords")
+    .arg(
+      Arg::with_name("debug")
+        .short("D")
+        .long("debug")
+        .help("Enable debug logs"),
+    )
+    .arg(
+      Arg::with_name("logind")
+        .long("logind")
+        .help("Lock all stores when the session is locked or the system goes to sleep (linux only)"),
+    )
+    .arg(
+      Arg::with_name("idle-exit")
+        .long("idle-exit")
+        .value_name("minutes")
+        .number_of_values(1)
+        .help("Exit after all stores have been locked for a number of minutes (e.g. with socket activation)"),
+    )
+    .arg(
+      Arg::with_name("json-rpc")
+        .long("json-rpc")
+        .help("Also serve JSON-RPC 2.0 on a socket of its own (for clients that can not use capnp)"),
+    )
 }
@@ -0,0 +1,292 @@
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use t_rust_less_lib::api::{
+  Event, HealthReportParams, Identity, PasswordGeneratorParam, SecretListFilter, SecretTypeSchema, SecretVersion,
+};
+use t_rust_less_lib::memguard::SecretBytes;
+use t_rust_less_lib::service::{ServiceError, StoreConfig};
+
+pub const JSON_RPC_VERSION: &str = "2.0";
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// Error reported by the service or a store, the `ServiceError` itself is the `data` of the error.
+pub const SERVICE_ERROR: i64 = -32000;
+
+/// All methods of the `TrustlessService` and `SecretsStore` (params are always by name).
+///
+/// Attachments are transferred chunk by chunk, the content of a chunk is base64 encoded.
+#[derive(Debug, Deserialize)]
+#[allow(clippy::large_enum_variant)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Method {
+  ListStores,
+  UpsertStoreConfig(StoreConfig),
+  DeleteStoreConfig {
+    store_name: String,
+  },
+  GetDefaultStore,
+  SetDefaultStore {
+    store_name: String,
+  },
+  LockAll,
+  ListAll {
+    #[serde(default)]
+    filter: SecretListFilter,
+  },
+  SecretToClipboard {
+    store_name: String,
+    block_id: String,
+    properties: Vec<String>,
+    display_name: String,
+  },
+  GenerateId,
+  GeneratePassword(PasswordGeneratorParam),
+  SubscribeEvents,
+  UnsubscribeEvents,
+
+  Status {
+    store_name: String,
+  },
+  Lock {
+    store_name: String,
+  },
+  Unlock {
+    store_name: String,
+    identity_id: String,
+    passphrase: SecretBytes,
+  },
+  ListIdentities {
+    store_name: String,
+  },
+  AddIdentity {
+    store_name: String,
+    identity: Identity,
+    passphrase: SecretBytes,
+  },
+  ChangePassphrase {
+    store_name: String,
+    passphrase: SecretBytes,
+  },
+  ListSecrets {
+    store_name: String,
+    #[serde(default)]
+    filter: SecretListFilter,
+  },
+  UpdateIndex {
+    store_name: String,
+  },
+  MigrateVersions {
+    store_name: String,
+  },
+  AddSecret {
+    store_name: String,
+    version: SecretVersion,
+  },
+  GetSecret {
+    store_name: String,
+    secret_id: String,
+  },
+  GetSecretVersion {
+    store_name: String,
+    block_id: String,
+  },
+  HealthReport {
+    store_name: String,
+    params: HealthReportParams,
+  },
+  ListSchemas {
+    store_name: String,
+  },
+  StoreSchema {
+    store_name: String,
+    schema: SecretTypeSchema,
+  },
+  /// `chunk` is the base64 encoded content
+  AddAttachmentChunk {
+    store_name: String,
+    recipients: Vec<String>,
+    chunk: SecretBytes,
+  },
+  /// The result is the base64 encoded content
+  GetAttachmentChunk {
+    store_name: String,
+    block_id: String,
+  },
+
+  ClipboardIsDone,
+  ClipboardCurrentlyProviding,
+  ClipboardProvideNext,
+  ClipboardDestroy,
+
+  #[serde(other)]
+  Unknown,
+}
+
+impl Method {
+  pub fn parse(method: &str, params: Option<Value>) -> Result<Method, ErrorObject> {
+    // Methods without params are accepted whatever params are sent (some clients always send `{}` or `[]`)
+    match serde_json::from_value(json!({ "method": method })) {
+      Ok(Method::Unknown) => Err(ErrorObject::new(
+        METHOD_NOT_FOUND,
+        format!("Method not found: {}", method),
+      )),
+      Ok(method) => Ok(method),
+      Err(_) => serde_json::from_value(json!({ "method": method, "params": params.unwrap_or(Value::Null) }))
+        .map_err(|error| ErrorObject::new(INVALID_PARAMS, format!("Invalid params: {}", error))),
+    }
+  }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Request {
+  pub jsonrpc: String,
+  /// Requests without id are notifications, i.e. there is no response
+  #[serde(default)]
+  pub id: Option<Value>,
+  pub method: String,
+  #[serde(default)]
+  pub params: Option<Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Response {
+  pub jsonrpc: &'static str,
+  pub id: Value,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub result: Option<Value>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<ErrorObject>,
+}
+
+impl Response {
+  pub fn result(id: Value, result: Value) -> Response {
+    Response {
+      jsonrpc: JSON_RPC_VERSION,
+      id,
+      result: Some(result),
+      error: None,
+    }
+  }
+
+  pub fn error(id: Value, error: ErrorObject) -> Response {
+    Response {
+      jsonrpc: JSON_RPC_VERSION,
+      id,
+      result: None,
+      error: Some(error),
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorObject {
+  pub code: i64,
+  pub message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub data: Option<Value>,
+}
+
+impl ErrorObject {
+  pub fn new<S: Into<String>>(code: i64, message: S) -> ErrorObject {
+    ErrorObject {
+      code,
+      message: message.into(),
+      data: None,
+    }
+  }
+}
+
+impl From<ServiceError> for ErrorObject {
+  fn from(error: ServiceError) -> Self {
+    ErrorObject {
+      code: SERVICE_ERROR,
+      message: format!("{}", error),
+      data: serde_json::to_value(&error).ok(),
+    }
+  }
+}
+
+/// Server-push of an event to all clients that subscribed to events.
+#[derive(Debug, Serialize)]
+pub struct Notification<'a> {
+  pub jsonrpc: &'static str,
+  pub method: &'static str,
+  pub params: &'a Event,
+}
+
+impl<'a> Notification<'a> {
+  pub fn event(event: &'a Event) -> Notification<'a> {
+    Notification {
+      jsonrpc: JSON_RPC_VERSION,
+      method: "event",
+      params: event,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+
+  #[test]
+  fn test_parse_method() {
+    assert_that(&Method::parse("list_stores", None)).matches(|method| matches!(method, Ok(Method::ListStores)));
+    assert_that(&Method::parse("lock_all", Some(json!({})))).matches(|method| matches!(method, Ok(Method::LockAll)));
+    assert_that(&Method::parse("status", Some(json!({"store_name": "bla"}))))
+      .matches(|method| matches!(method, Ok(Method::Status { store_name }) if store_name == "bla"));
+    assert_that(&Method::parse("list_secrets", Some(json!({"store_name": "bla"}))))
+      .matches(|method| matches!(method, Ok(Method::ListSecrets { filter, .. }) if filter.query.is_none()));
+    assert_that(&Method::parse(
+      "unlock",
+      Some(json!({"store_name": "bla", "identity_id": "me", "passphrase": "secret"})),
+    ))
+    .matches(|method| matches!(method, Ok(Method::Unlock { passphrase, .. }) if &*passphrase.borrow() == b"secret"));
+    assert_that(&Method::parse("status", None)).matches(|method| {
+      matches!(
+        method,
+        Err(ErrorObject {
+          code: INVALID_PARAMS,
+          ..
+        })
+      )
+    });
+    assert_that(&Method::parse("status", Some(json!(["bla"])))).matches(|method| {
+      matches!(
+        method,
+        Err(ErrorObject {
+          code: INVALID_PARAMS,
+          ..
+        })
+      )
+    });
+    assert_that(&Method::parse("does_not_exist", Some(json!({"store_name": "bla"})))).matches(|method| {
+      matches!(
+        method,
+        Err(ErrorObject {
+          code: METHOD_NOT_FOUND,
+          ..
+        })
+      )
+    });
+  }
+
+  #[test]
+  fn test_serialize() {
+    let response = Response::result(json!(12), json!(null));
+    let error = Response::error(json!("abc"), ServiceError::StoreNotFound("bla".to_string()).into());
+
+    assert_that(&serde_json::to_string(&response).unwrap())
+      .is_equal_to(r#"{"jsonrpc":"2.0","id":12,"result":null}"#.to_string());
+    assert_that(&serde_json::to_string(&error).unwrap()).is_equal_to(
+      r#"{"jsonrpc":"2.0","id":"abc","error":{"code":-32000,"message":"Store with name bla not found","data":{"StoreNotFound":"bla"}}}"#
+        .to_string(),
+    );
+    assert_that(&serde_json::to_string(&Notification::event(&Event::ConfigChanged)).unwrap())
+      .is_equal_to(r#"{"jsonrpc":"2.0","method":"event","params":"ConfigChanged"}"#.to_string());
+  }
+}
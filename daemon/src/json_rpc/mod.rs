@@ -0,0 +1,142 @@
+//! JSON-RPC 2.0 for clients that can not speak capnp-rpc (e.g. scripts).
+//!
+//! Every message (request, response or notification) is a single line of JSON. Events are pushed as
+//! `event` notifications once the client has called `subscribe_events`.
+
+mod messages;
+mod processor;
+
+use self::processor::Processor;
+use log::error;
+use std::io::Write;
+use std::sync::Arc;
+use t_rust_less_lib::memguard::ZeroizeBytesBuffer;
+use t_rust_less_lib::service::local::LocalTrustlessService;
+use tokio::io::{self, AsyncReadExt, AsyncWriteExt};
+use tokio::net::UnixStream;
+use tokio::sync::mpsc;
+use zeroize::{Zeroize, Zeroizing};
+
+const READ_CHUNK_SIZE: usize = 4096;
+const LINE_CAPACITY: usize = 4096;
+
+/// Handle all requests of a connection until it is closed by the client.
+pub async fn serve_connection(stream: UnixStream, service: Arc<LocalTrustlessService>) {
+  let (mut reader, mut writer) = io::split(stream);
+  let (message_sender, mut message_receiver) = mpsc::unbounded_channel::<String>();
+  let mut processor = Processor::new(service, message_sender.clone());
+
+  let read_requests = async move {
+    // Requests might contain passphrases, so the received data does not stay around in memory
+    let mut chunk = Zeroizing::new([0u8; READ_CHUNK_SIZE]);
+    let mut line = ZeroizeBytesBuffer::with_capacity(LINE_CAPACITY);
+
+    loop {
+      let len = reader.read(&mut chunk[..]).await?;
+
+      if len == 0 {
+        break;
+      }
+      let mut parts = chunk[..len].split(|b| *b == b'\n').peekable();
+
+      while let Some(part) = parts.next() {
+        line.write_all(part)?;
+        // Only the last part is not terminated by a newline
+        if parts.peek().is_some() {
+          process_line(&mut processor, &line, &message_sender);
+          line.zeroize();
+        }
+      }
+    }
+    process_line(&mut processor, &line, &message_sender);
+    // Dropping the processor (and its event subscription) closes the message queue
+    Ok::<(), io::Error>(())
+  };
+  let write_messages = async move {
+    while let Some(message) = message_receiver.recv().await {
+      writer.write_all(message.as_bytes()).await?;
+      writer.write_all(b"\n").await?;
+    }
+    Ok::<(), io::Error>(())
+  };
+
+  let (read_result, write_result) = futures::future::join(read_requests, write_messages).await;
+
+  if let Err(error) = read_result.and(write_result) {
+    error!("JSON-RPC connection failed: {}", error);
+  }
+}
+
+fn process_line(processor: &mut Processor, line: &[u8], message_sender: &mpsc::UnboundedSender<String>) {
+  if line.iter().all(u8::is_ascii_whitespace) {
+    return;
+  }
+  if let Some(response) = processor.process_message(line) {
+    message_sender.send(response).ok();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::Value;
+  use spectral::prelude::*;
+  use std::env;
+  use std::net::Shutdown;
+  use std::sync::Once;
+  use tempdir::TempDir;
+  use tokio::runtime::Builder;
+
+  static ISOLATE_CONFIG: Once = Once::new();
+
+  /// Local service that does not touch the configuration of the user.
+  pub fn local_service() -> Arc<LocalTrustlessService> {
+    ISOLATE_CONFIG.call_once(|| {
+      let config_dir = TempDir::new("t-rust-less-json-rpc").unwrap().into_path();
+
+      env::set_var("HOME", &config_dir);
+      env::set_var("XDG_CONFIG_HOME", &config_dir);
+    });
+
+    Arc::new(LocalTrustlessService::new().unwrap())
+  }
+
+  #[test]
+  fn test_serve_connection() {
+    let mut rt = Builder::new().basic_scheduler().enable_all().build().unwrap();
+    let (server, mut client) = rt.enter(UnixStream::pair).unwrap();
+    let client_requests = async move {
+      // Requests are split across reads and the last one is not terminated by a newline
+      client
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"generate_id\"}\n\n{\"jsonrpc\":\"2.0\",")
+        .await?;
+      client.write_all(b"\"id\":2,\"method\":\"unknown\"}\n").await?;
+      client
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":3,\"method\":\"generate_id\"}")
+        .await?;
+      client.shutdown(Shutdown::Write)?;
+
+      let mut responses = String::new();
+
+      client.read_to_string(&mut responses).await?;
+      Ok::<String, io::Error>(responses)
+    };
+
+    let (_, responses) = rt.block_on(futures::future::join(
+      serve_connection(server, local_service()),
+      client_requests,
+    ));
+    let responses: Vec<Value> = responses
+      .unwrap()
+      .lines()
+      .map(|line| serde_json::from_str(line).unwrap())
+      .collect();
+
+    assert_that(&responses).has_length(3);
+    assert_that(&responses[0]["id"]).is_equal_to(Value::from(1));
+    assert_that(&responses[0]["result"].is_string()).is_true();
+    assert_that(&responses[1]["id"]).is_equal_to(Value::from(2));
+    assert_that(&responses[1]["error"]["code"]).is_equal_to(Value::from(messages::METHOD_NOT_FOUND));
+    assert_that(&responses[2]["id"]).is_equal_to(Value::from(3));
+  }
+}
@@ -0,0 +1,489 @@
+use super::messages::{
+  ErrorObject, Method, Notification, Request, Response, INTERNAL_ERROR, INVALID_PARAMS, INVALID_REQUEST,
+  JSON_RPC_VERSION, METHOD_NOT_FOUND, PARSE_ERROR,
+};
+use data_encoding::{DecodeError, BASE64};
+use log::error;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+use t_rust_less_lib::api::{Event, EventHandler, EventSubscription};
+use t_rust_less_lib::secrets_store::SecretsStore;
+use t_rust_less_lib::service::local::LocalTrustlessService;
+use t_rust_less_lib::service::{ClipboardControl, ServiceResult, TrustlessService};
+use tokio::sync::mpsc::UnboundedSender;
+use zeroize::Zeroizing;
+
+/// Processor of the JSON-RPC requests of a single connection.
+pub struct Processor {
+  service: Arc<LocalTrustlessService>,
+  messages: UnboundedSender<String>,
+  current_clipboard: Option<Arc<dyn ClipboardControl>>,
+  event_subscription: Option<Box<dyn EventSubscription>>,
+}
+
+impl Processor {
+  /// All messages to the client besides the responses (i.e. event notifications) are queued to `messages`.
+  pub fn new(service: Arc<LocalTrustlessService>, messages: UnboundedSender<String>) -> Processor {
+    Processor {
+      service,
+      messages,
+      current_clipboard: None,
+      event_subscription: None,
+    }
+  }
+
+  /// Process a single message (i.e. a request or a batch of requests) and get the encoded response (if any).
+  pub fn process_message(&mut self, message: &[u8]) -> Option<String> {
+    let value = match serde_json::from_slice::<Value>(message) {
+      Ok(value) => value,
+      Err(error) => {
+        let error = ErrorObject::new(PARSE_ERROR, format!("Parse error: {}", error));
+        return encode(&Response::error(Value::Null, error));
+      }
+    };
+
+    match value {
+      Value::Array(requests) if requests.is_empty() => encode(&Response::error(
+        Value::Null,
+        ErrorObject::new(INVALID_REQUEST, "Invalid request: Empty batch"),
+      )),
+      Value::Array(requests) => {
+        let responses: Vec<Response> = requests
+          .into_iter()
+          .filter_map(|request| self.process_request(request))
+          .collect();
+
+        if responses.is_empty() {
+          None
+        } else {
+          encode(&responses)
+        }
+      }
+      request => self.process_request(request).and_then(|response| encode(&response)),
+    }
+  }
+
+  fn process_request(&mut self, value: Value) -> Option<Response> {
+    let request = match serde_json::from_value::<Request>(value) {
+      Ok(request) if request.jsonrpc == JSON_RPC_VERSION => request,
+      Ok(_) => {
+        let error = ErrorObject::new(INVALID_REQUEST, "Invalid request: Only JSON-RPC 2.0 is supported");
+        return Some(Response::error(Value::Null, error));
+      }
+      Err(error) => {
+        let error = ErrorObject::new(INVALID_REQUEST, format!("Invalid request: {}", error));
+        return Some(Response::error(Value::Null, error));
+      }
+    };
+    let result = Method::parse(&request.method, request.params).and_then(|method| self.call(method));
+
+    // Notifications do not get any response, not even on error
+    let id = request.id?;
+
+    Some(match result {
+      Ok(result) => Response::result(id, result),
+      Err(error) => Response::error(id, error),
+    })
+  }
+
+  fn call(&mut self, method: Method) -> Result<Value, ErrorObject> {
+    match method {
+      Method::ListStores => to_value(self.service.list_stores()),
+      Method::UpsertStoreConfig(store_config) => to_value(self.service.upsert_store_config(store_config)),
+      Method::DeleteStoreConfig { store_name } => to_value(self.service.delete_store_config(&store_name)),
+      Method::GetDefaultStore => to_value(self.service.get_default_store()),
+      Method::SetDefaultStore { store_name } => to_value(self.service.set_default_store(&store_name)),
+      Method::LockAll => to_value(self.service.lock_all()),
+      Method::ListAll { filter } => to_value(self.service.list_all(&filter)),
+      Method::SecretToClipboard {
+        store_name,
+        block_id,
+        properties,
+        display_name,
+      } => {
+        let clipboard = self.service.secret_to_clipboard(
+          &store_name,
+          &block_id,
+          &properties.iter().map(String::as_str).collect::<Vec<&str>>(),
+          &display_name,
+        )?;
+        self.current_clipboard.replace(clipboard);
+
+        Ok(Value::Null)
+      }
+      Method::GenerateId => to_value(self.service.generate_id()),
+      Method::GeneratePassword(param) => to_value(self.service.generate_password(param)),
+      Method::SubscribeEvents => {
+        if self.event_subscription.is_none() {
+          let subscription = self
+            .service
+            .add_event_handler(Box::new(EventNotifier(self.messages.clone())))?;
+          self.event_subscription.replace(subscription);
+        }
+
+        Ok(Value::Null)
+      }
+      Method::UnsubscribeEvents => {
+        self.event_subscription.take();
+
+        Ok(Value::Null)
+      }
+
+      Method::Status { store_name } => to_value(self.open_store(&store_name).and_then(|store| Ok(store.status()?))),
+      Method::Lock { store_name } => to_value(self.open_store(&store_name).and_then(|store| Ok(store.lock()?))),
+      Method::Unlock {
+        store_name,
+        identity_id,
+        passphrase,
+      } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(move |store| Ok(store.unlock(&identity_id, passphrase)?)),
+      ),
+      Method::ListIdentities { store_name } => {
+        to_value(self.open_store(&store_name).and_then(|store| Ok(store.identities()?)))
+      }
+      Method::AddIdentity {
+        store_name,
+        identity,
+        passphrase,
+      } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(move |store| Ok(store.add_identity(identity, passphrase)?)),
+      ),
+      Method::ChangePassphrase { store_name, passphrase } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(move |store| Ok(store.change_passphrase(passphrase)?)),
+      ),
+      Method::ListSecrets { store_name, filter } => {
+        to_value(self.open_store(&store_name).and_then(|store| Ok(store.list(&filter)?)))
+      }
+      Method::UpdateIndex { store_name } => {
+        to_value(self.open_store(&store_name).and_then(|store| Ok(store.update_index()?)))
+      }
+      Method::MigrateVersions { store_name } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(|store| Ok(store.migrate_versions()?)),
+      ),
+      Method::AddSecret { store_name, version } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(move |store| Ok(store.add(version)?)),
+      ),
+      Method::GetSecret { store_name, secret_id } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(|store| Ok(store.get(&secret_id)?)),
+      ),
+      Method::GetSecretVersion { store_name, block_id } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(|store| Ok(store.get_version(&block_id)?)),
+      ),
+      Method::HealthReport { store_name, params } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(|store| Ok(store.health_report(&params)?)),
+      ),
+      Method::ListSchemas { store_name } => {
+        to_value(self.open_store(&store_name).and_then(|store| Ok(store.list_schemas()?)))
+      }
+      Method::StoreSchema { store_name, schema } => to_value(
+        self
+          .open_store(&store_name)
+          .and_then(move |store| Ok(store.store_schema(schema)?)),
+      ),
+      Method::AddAttachmentChunk {
+        store_name,
+        recipients,
+        chunk,
+      } => {
+        let chunk = decode_base64(&chunk.borrow())?;
+
+        to_value(
+          self
+            .open_store(&store_name)
+            .and_then(|store| Ok(store.add_attachment_chunk(&recipients, &chunk)?)),
+        )
+      }
+      Method::GetAttachmentChunk { store_name, block_id } => to_value(self.open_store(&store_name).and_then(|store| {
+        let chunk = store.get_attachment_chunk(&block_id)?;
+        let encoded = BASE64.encode(&chunk.borrow());
+
+        Ok(encoded)
+      })),
+
+      Method::ClipboardIsDone => match &self.current_clipboard {
+        Some(clipboard) => to_value(clipboard.is_done()),
+        None => Ok(Value::Bool(true)),
+      },
+      Method::ClipboardCurrentlyProviding => match &self.current_clipboard {
+        Some(clipboard) => to_value(clipboard.currently_providing()),
+        None => Ok(Value::Null),
+      },
+      Method::ClipboardProvideNext => match &self.current_clipboard {
+        Some(clipboard) => to_value(clipboard.provide_next()),
+        None => Ok(Value::Null),
+      },
+      Method::ClipboardDestroy => match self.current_clipboard.take() {
+        Some(clipboard) => to_value(clipboard.destroy()),
+        None => Ok(Value::Null),
+      },
+
+      Method::Unknown => Err(ErrorObject::new(METHOD_NOT_FOUND, "Method not found")),
+    }
+  }
+
+  fn open_store(&self, store_name: &str) -> ServiceResult<Arc<dyn SecretsStore>> {
+    self.service.open_store(store_name)
+  }
+}
+
+fn to_value<T: Serialize>(result: ServiceResult<T>) -> Result<Value, ErrorObject> {
+  serde_json::to_value(result?)
+    .map_err(|error| ErrorObject::new(INTERNAL_ERROR, format!("Encoding result failed: {}", error)))
+}
+
+fn decode_base64(encoded: &[u8]) -> Result<Zeroizing<Vec<u8>>, ErrorObject> {
+  let mut decoded = Zeroizing::new(vec![0u8; BASE64.decode_len(encoded.len()).map_err(invalid_base64)?]);
+  let len = BASE64
+    .decode_mut(encoded, &mut decoded)
+    .map_err(|error| invalid_base64(error.error))?;
+
+  decoded.truncate(len);
+  Ok(decoded)
+}
+
+fn invalid_base64(error: DecodeError) -> ErrorObject {
+  ErrorObject::new(INVALID_PARAMS, format!("Invalid params: Invalid base64: {}", error))
+}
+
+fn encode<T: Serialize>(message: &T) -> Option<String> {
+  match serde_json::to_string(message) {
+    Ok(encoded) => Some(encoded),
+    Err(error) => {
+      error!("Encoding message failed: {}", error);
+      None
+    }
+  }
+}
+
+/// Forward all events as notifications to the client.
+///
+/// Events might be sent from any thread (e.g. the clipboard), so they are only queued here.
+struct EventNotifier(UnboundedSender<String>);
+
+impl EventHandler for EventNotifier {
+  fn handle(&self, event: Event) {
+    if let Some(notification) = encode(&Notification::event(&event)) {
+      // The connection might just be closing
+      self.0.send(notification).ok();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::json_rpc::tests::local_service;
+  use serde_json::json;
+  use spectral::prelude::*;
+  use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+  fn processor() -> (Processor, UnboundedReceiver<String>) {
+    let (message_sender, message_receiver) = mpsc::unbounded_channel();
+
+    (Processor::new(local_service(), message_sender), message_receiver)
+  }
+
+  fn process(processor: &mut Processor, message: Value) -> Option<Value> {
+    processor
+      .process_message(message.to_string().as_bytes())
+      .map(|response| serde_json::from_str(&response).unwrap())
+  }
+
+  #[test]
+  fn test_parse_error() {
+    let (mut processor, _) = processor();
+    let response: Value = serde_json::from_str(&processor.process_message(b"{\"jsonrpc\":").unwrap()).unwrap();
+
+    assert_that(&response["id"]).is_equal_to(&Value::Null);
+    assert_that(&response["error"]["code"]).is_equal_to(json!(PARSE_ERROR));
+  }
+
+  #[test]
+  fn test_invalid_request() {
+    let (mut processor, _) = processor();
+
+    for request in [
+      json!(42),
+      json!({"jsonrpc": "1.0", "method": "list_stores", "id": 1}),
+      json!({"jsonrpc": "2.0", "id": 1}),
+      json!([]),
+    ] {
+      let response = process(&mut processor, request).unwrap();
+
+      assert_that(&response["id"]).is_equal_to(&Value::Null);
+      assert_that(&response["error"]["code"]).is_equal_to(json!(INVALID_REQUEST));
+    }
+  }
+
+  #[test]
+  fn test_unknown_method() {
+    let (mut processor, _) = processor();
+    let response = process(&mut processor, json!({"jsonrpc": "2.0", "method": "nope", "id": "abc"})).unwrap();
+
+    assert_that(&response["id"]).is_equal_to(json!("abc"));
+    assert_that(&response["error"]["code"]).is_equal_to(json!(METHOD_NOT_FOUND));
+
+    // Notifications do not get a response, not even on error
+    assert_that(&process(&mut processor, json!({"jsonrpc": "2.0", "method": "nope"}))).is_none();
+  }
+
+  #[test]
+  fn test_batch() {
+    let (mut processor, _) = processor();
+
+    assert_that(&process(
+      &mut processor,
+      json!([
+        {"jsonrpc": "2.0", "method": "generate_id"},
+        {"jsonrpc": "2.0", "method": "lock_all"},
+      ]),
+    ))
+    .is_none();
+
+    let responses = process(
+      &mut processor,
+      json!([
+        {"jsonrpc": "2.0", "method": "generate_id", "id": 1},
+        {"jsonrpc": "2.0", "method": "lock_all"},
+        {"jsonrpc": "2.0", "method": "nope", "id": 2},
+      ]),
+    )
+    .unwrap();
+
+    assert_that(&responses.as_array().map(Vec::len)).is_equal_to(Some(2));
+    assert_that(&responses[0]["id"]).is_equal_to(json!(1));
+    assert_that(&responses[0]["result"].is_string()).is_true();
+    assert_that(&responses[1]["id"]).is_equal_to(json!(2));
+    assert_that(&responses[1]["error"]["code"]).is_equal_to(json!(METHOD_NOT_FOUND));
+  }
+
+  #[test]
+  fn test_subscribe_events() {
+    let (mut processor, mut messages) = processor();
+
+    assert_that(&process(
+      &mut processor,
+      json!({"jsonrpc": "2.0", "method": "subscribe_events", "id": 1}),
+    ))
+    .is_equal_to(Some(json!({"jsonrpc": "2.0", "id": 1, "result": null})));
+    assert_that(&messages.try_recv().is_err()).is_true();
+
+    let response = process(
+      &mut processor,
+      json!({
+        "jsonrpc": "2.0",
+        "method": "upsert_store_config",
+        "params": {
+          "name": "json-rpc-events",
+          "store_url": "multilane+memory://",
+          "client_id": "json-rpc-events",
+          "autolock_timeout_secs": 300,
+          "default_identity_id": null,
+        },
+        "id": 2,
+      }),
+    )
+    .unwrap();
+
+    assert_that(&response["error"]).is_equal_to(&Value::Null);
+    assert_that(
+      &messages
+        .try_recv()
+        .map(|message| serde_json::from_str::<Value>(&message).unwrap()),
+    )
+    .is_ok_containing(json!({"jsonrpc": "2.0", "method": "event", "params": "ConfigChanged"}));
+
+    process(
+      &mut processor,
+      json!({"jsonrpc": "2.0", "method": "unsubscribe_events", "id": 3}),
+    );
+    process(
+      &mut processor,
+      json!({"jsonrpc": "2.0", "method": "delete_store_config", "params": {"store_name": "json-rpc-events"}}),
+    );
+    assert_that(&messages.try_recv().is_err()).is_true();
+  }
+
+  #[test]
+  fn test_invalid_attachment_chunk() {
+    let (mut processor, _) = processor();
+    let response = process(
+      &mut processor,
+      json!({"jsonrpc": "2.0", "method": "add_attachment_chunk", "id": 1, "params": {
+        "store_name": "chunks",
+        "recipients": [],
+        "chunk": "not base64!",
+      }}),
+    )
+    .unwrap();
+
+    assert_that(&response["error"]["code"]).is_equal_to(json!(INVALID_PARAMS));
+  }
+
+  #[test]
+  #[cfg_attr(debug_assertions, ignore)]
+  fn test_attachment_chunks() {
+    let (mut processor, _) = processor();
+    let mut call = |method: &str, params: Value| {
+      let response = process(
+        &mut processor,
+        json!({"jsonrpc": "2.0", "method": method, "id": 1, "params": params}),
+      )
+      .unwrap();
+
+      assert_that(&response["error"]).is_equal_to(Value::Null);
+      response["result"].clone()
+    };
+
+    call(
+      "upsert_store_config",
+      json!({
+        "name": "chunks",
+        "store_url": "multilane+memory://",
+        "client_id": "node1",
+        "autolock_timeout_secs": 300,
+        "default_identity_id": null,
+      }),
+    );
+    call(
+      "add_identity",
+      json!({
+        "store_name": "chunks",
+        "identity": {"id": "identity1", "name": "Name", "email": "Email", "hidden": false},
+        "passphrase": "Passphrase1",
+      }),
+    );
+    call(
+      "unlock",
+      json!({"store_name": "chunks", "identity_id": "identity1", "passphrase": "Passphrase1"}),
+    );
+
+    let block_id = call(
+      "add_attachment_chunk",
+      json!({"store_name": "chunks", "recipients": [], "chunk": "AAECA/8="}),
+    );
+
+    assert_that(&call(
+      "get_attachment_chunk",
+      json!({"store_name": "chunks", "block_id": block_id}),
+    ))
+    .is_equal_to(json!("AAECA/8="));
+  }
+}
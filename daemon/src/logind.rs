@@ -0,0 +1,301 @@
+use crate::unix::LockRequest;
+use dbus::arg::OwnedFd;
+use dbus::blocking::Connection;
+use dbus::message::MatchRule;
+use dbus::Path;
+use log::{error, info, warn};
+use std::env;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+
+const LOGIND_DESTINATION: &str = "org.freedesktop.login1";
+const LOGIND_PATH: &str = "/org/freedesktop/login1";
+const LOGIND_MANAGER_INTERFACE: &str = "org.freedesktop.login1.Manager";
+const LOGIND_SESSION_INTERFACE: &str = "org.freedesktop.login1.Session";
+const METHOD_CALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Listen to logind on the system bus in a thread of its own.
+///
+/// A lock request is sent whenever the session of the daemon is locked (e.g. by the screen saver or
+/// `loginctl lock-sessions`) or the system is about to go to sleep (e.g. the lid is closed).
+pub fn spawn_listener(lock_requests: UnboundedSender<LockRequest>) {
+  thread::spawn(move || {
+    let result = Connection::new_system().and_then(|connection| listen(&connection, lock_requests));
+
+    if let Err(error) = result {
+      error!("Listening to logind failed: {}", error);
+    }
+  });
+}
+
+/// Forward the logind signals of a connection as lock requests.
+///
+/// The system does not go to sleep before the lock request has been processed, i.e. a delay inhibitor
+/// is passed along with the request.
+///
+/// This only returns on error.
+pub fn listen(connection: &Connection, lock_requests: UnboundedSender<LockRequest>) -> Result<(), dbus::Error> {
+  let sleep_inhibitor: Arc<Mutex<Option<OwnedFd>>> = Arc::new(Mutex::new(None));
+  let sleep_lock_requests = lock_requests.clone();
+  let sleep_inhibitor_cloned = sleep_inhibitor.clone();
+
+  connection.add_match(
+    MatchRule::new_signal(LOGIND_MANAGER_INTERFACE, "PrepareForSleep")
+      .with_sender(LOGIND_DESTINATION)
+      .with_path(LOGIND_PATH),
+    move |(start,): (bool,), connection, _| {
+      let mut sleep_inhibitor = match sleep_inhibitor_cloned.lock() {
+        Ok(sleep_inhibitor) => sleep_inhibitor,
+        Err(_) => return false,
+      };
+      if !start {
+        info!("System has resumed");
+        *sleep_inhibitor = take_sleep_inhibitor(connection);
+        return true;
+      }
+      info!("System is going to sleep");
+      sleep_lock_requests
+        .send(LockRequest {
+          guard: sleep_inhibitor.take().map(|fd| Box::new(fd) as Box<dyn Send>),
+        })
+        .is_ok()
+    },
+  )?;
+  match own_session_path(connection) {
+    Ok(session_path) => {
+      connection.add_match(
+        MatchRule::new_signal(LOGIND_SESSION_INTERFACE, "Lock")
+          .with_sender(LOGIND_DESTINATION)
+          .with_path(session_path),
+        move |_: (), _, _| {
+          info!("Session is locked");
+          lock_requests.send(LockRequest { guard: None }).is_ok()
+        },
+      )?;
+    }
+    Err(error) => warn!("Unable to find login session, session locks are ignored: {}", error),
+  }
+  if let Ok(mut inhibitor) = sleep_inhibitor.lock() {
+    *inhibitor = take_sleep_inhibitor(connection);
+  }
+
+  loop {
+    connection.process(Duration::from_secs(60))?;
+  }
+}
+
+/// Object path of the login session the daemon belongs to.
+///
+/// The daemon might be started outside of a session (e.g. as systemd user service), in which case
+/// the session is taken from the environment.
+fn own_session_path(connection: &Connection) -> Result<Path<'static>, dbus::Error> {
+  let proxy = connection.with_proxy(LOGIND_DESTINATION, LOGIND_PATH, METHOD_CALL_TIMEOUT);
+  let (session_path,): (Path<'static>,) = match env::var("XDG_SESSION_ID") {
+    Ok(session_id) => proxy.method_call(LOGIND_MANAGER_INTERFACE, "GetSession", (session_id,))?,
+    Err(_) => proxy.method_call(LOGIND_MANAGER_INTERFACE, "GetSessionByPID", (process::id(),))?,
+  };
+
+  Ok(session_path)
+}
+
+/// Delay the next sleep until the returned file descriptor is closed.
+fn take_sleep_inhibitor(connection: &Connection) -> Option<OwnedFd> {
+  let proxy = connection.with_proxy(LOGIND_DESTINATION, LOGIND_PATH, METHOD_CALL_TIMEOUT);
+  let result: Result<(OwnedFd,), dbus::Error> = proxy.method_call(
+    LOGIND_MANAGER_INTERFACE,
+    "Inhibit",
+    ("sleep", "t-rust-less", "Lock all stores", "delay"),
+  );
+
+  match result {
+    Ok((fd,)) => Some(fd),
+    Err(error) => {
+      warn!(
+        "Unable to delay sleep, stores might be locked after the system went to sleep: {}",
+        error
+      );
+      None
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use dbus::channel::{Channel, MatchingReceiver, Sender};
+  use dbus::Message;
+  use spectral::prelude::*;
+  use std::fs;
+  use std::io::{BufRead, BufReader};
+  use std::os::unix::io::{AsRawFd, FromRawFd};
+  use std::process::{Child, Command, Stdio};
+  use std::time::Instant;
+  use tokio::sync::mpsc;
+
+  const SESSION_PATH: &str = "/org/freedesktop/login1/session/_1";
+  const OTHER_SESSION_PATH: &str = "/org/freedesktop/login1/session/_2";
+
+  struct PrivateBus {
+    daemon: Child,
+    address: String,
+  }
+
+  impl PrivateBus {
+    fn start() -> PrivateBus {
+      let config_path = env::temp_dir().join(format!("t-rust-less-bus-{}.conf", process::id()));
+      fs::write(
+        &config_path,
+        r#"<busconfig>
+  <type>session</type>
+  <listen>unix:tmpdir=/tmp</listen>
+  <auth>EXTERNAL</auth>
+  <policy context="default">
+    <allow send_destination="*"/>
+    <allow receive_sender="*"/>
+    <allow own="*"/>
+  </policy>
+</busconfig>"#,
+      )
+      .unwrap();
+      let mut daemon = Command::new("dbus-daemon")
+        .arg(format!("--config-file={}", config_path.display()))
+        .args(["--nofork", "--print-address"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("dbus-daemon is required to test the logind listener");
+      let mut address = String::new();
+
+      BufReader::new(daemon.stdout.take().unwrap())
+        .read_line(&mut address)
+        .unwrap();
+      fs::remove_file(config_path).unwrap();
+
+      PrivateBus {
+        daemon,
+        address: address.trim().to_string(),
+      }
+    }
+
+    fn connect(&self) -> Connection {
+      let mut channel = Channel::open_private(&self.address).unwrap();
+
+      channel.register().unwrap();
+      Connection::from(channel)
+    }
+  }
+
+  impl Drop for PrivateBus {
+    fn drop(&mut self) {
+      self.daemon.kill().ok();
+      self.daemon.wait().ok();
+    }
+  }
+
+  fn pipe() -> (OwnedFd, OwnedFd) {
+    let mut fds = [0; 2];
+
+    assert_that(&unsafe { libc::pipe(fds.as_mut_ptr()) }).is_equal_to(0);
+    unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) }
+  }
+
+  fn is_closed(read_end: &OwnedFd) -> bool {
+    let mut buffer = [0u8; 1];
+
+    unsafe {
+      libc::fcntl(read_end.as_raw_fd(), libc::F_SETFL, libc::O_NONBLOCK);
+      libc::read(read_end.as_raw_fd(), buffer.as_mut_ptr() as *mut libc::c_void, 1) == 0
+    }
+  }
+
+  fn process_until<F: FnMut() -> bool>(connection: &Connection, mut condition: F) {
+    let start = Instant::now();
+
+    while !condition() {
+      assert_that(&(start.elapsed() < Duration::from_secs(10))).is_true();
+      connection.process(Duration::from_millis(100)).unwrap();
+    }
+  }
+
+  fn signal(path: &str, interface: &str, member: &str) -> Message {
+    Message::new_signal(path, interface, member).unwrap()
+  }
+
+  #[test]
+  fn test_listen() {
+    let bus = PrivateBus::start();
+    // Acts as logind on the private bus
+    let logind = bus.connect();
+    // Read ends of the inhibitors handed out, a read end is closed once the inhibitor is released
+    let inhibitors = Arc::new(Mutex::new(Vec::<OwnedFd>::new()));
+    let inhibitors_cloned = inhibitors.clone();
+
+    logind.request_name(LOGIND_DESTINATION, false, true, true).unwrap();
+    logind.start_receive(
+      MatchRule::new_method_call().with_interface(LOGIND_MANAGER_INTERFACE),
+      Box::new(move |message, connection| {
+        let reply = match message.member().as_deref() {
+          Some("GetSession") | Some("GetSessionByPID") => message.method_return().append1(Path::from(SESSION_PATH)),
+          Some("Inhibit") => {
+            let (read_end, write_end) = pipe();
+
+            inhibitors_cloned.lock().unwrap().push(read_end);
+            message.method_return().append1(write_end)
+          }
+          _ => return true,
+        };
+        connection.send(reply).is_ok()
+      }),
+    );
+
+    let (lock_sender, mut lock_receiver) = mpsc::unbounded_channel::<LockRequest>();
+    let address = bus.address.clone();
+
+    thread::spawn(move || {
+      let mut channel = Channel::open_private(&address).unwrap();
+
+      channel.register().unwrap();
+      listen(&Connection::from(channel), lock_sender).ok();
+    });
+
+    // The inhibitor is taken after all signals have been matched
+    process_until(&logind, || inhibitors.lock().unwrap().len() == 1);
+
+    logind
+      .send(signal(OTHER_SESSION_PATH, LOGIND_SESSION_INTERFACE, "Lock"))
+      .unwrap();
+    logind
+      .send(signal(SESSION_PATH, LOGIND_SESSION_INTERFACE, "Lock"))
+      .unwrap();
+    logind
+      .send(signal(LOGIND_PATH, LOGIND_MANAGER_INTERFACE, "PrepareForSleep").append1(true))
+      .unwrap();
+
+    let mut requests = vec![];
+
+    process_until(&logind, || {
+      if let Ok(request) = lock_receiver.try_recv() {
+        requests.push(request);
+      }
+      requests.len() == 2
+    });
+
+    assert_that(&requests[0].guard.is_none()).is_true();
+    assert_that(&requests[1].guard.is_some()).is_true();
+    assert_that(&is_closed(&inhibitors.lock().unwrap()[0])).is_false();
+
+    requests.clear();
+
+    assert_that(&is_closed(&inhibitors.lock().unwrap()[0])).is_true();
+
+    // A new inhibitor is taken on resume
+    logind
+      .send(signal(LOGIND_PATH, LOGIND_MANAGER_INTERFACE, "PrepareForSleep").append1(false))
+      .unwrap();
+    process_until(&logind, || inhibitors.lock().unwrap().len() == 2);
+    assert_that(&lock_receiver.try_recv().is_err()).is_true();
+  }
+}
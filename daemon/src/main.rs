@@ -5,17 +5,22 @@ pub mod macros;
 mod clipboard_control_impl;
 mod error;
 mod event_handler_impl;
+#[cfg(unix)]
+mod json_rpc;
+#[cfg(all(target_os = "linux", feature = "with_logind"))]
+mod logind;
 mod secrets_store_impl;
 mod service_impl;
 
 #[cfg(unix)]
 mod unix;
 #[cfg(unix)]
-use unix::run_server;
+use unix::{run_server, ServerOptions};
 
 use crate::error::ExtResult;
-use log::info;
+use log::{error, info};
 use std::sync::Arc;
+use std::time::Duration;
 use t_rust_less_lib::api_capnp::service;
 use t_rust_less_lib::service::local::LocalTrustlessService;
 use t_rust_less_lib::service::TrustlessService;
@@ -34,6 +39,13 @@ fn main() {
   log_builder.init();
 
   let service = Arc::new(LocalTrustlessService::new().ok_or_exit("Open local store"));
+  let options = ServerOptions {
+    listen_logind: matches.is_present("logind"),
+    idle_exit: matches
+      .value_of("idle-exit")
+      .map(|minutes| Duration::from_secs(60 * minutes.parse::<u64>().ok_or_exit("Invalid idle-exit"))),
+    json_rpc: matches.is_present("json-rpc"),
+  };
 
   run_server(
     {
@@ -45,6 +57,33 @@ fn main() {
         service_server.client
       }
     },
-    move || service.check_autolock(),
+    {
+      let cloned = service.clone();
+      move |stream| {
+        info!("New JSON-RPC client connection");
+        json_rpc::serve_connection(stream, cloned.clone())
+      }
+    },
+    {
+      let cloned = service.clone();
+      move || {
+        cloned.check_autolock();
+        cloned.check_config();
+      }
+    },
+    {
+      let cloned = service.clone();
+      move || cloned.check_expiring()
+    },
+    {
+      let cloned = service.clone();
+      move || {
+        if let Err(error) = cloned.lock_all() {
+          error!("Lock all failed: {}", error);
+        }
+      }
+    },
+    move || service.all_locked().unwrap_or(false),
+    options,
   );
 }
@@ -1,6 +1,6 @@
 use capnp::capability::Promise;
 use std::sync::Arc;
-use t_rust_less_lib::api::{Identity, SecretListFilter, SecretVersion};
+use t_rust_less_lib::api::{HealthReportParams, Identity, SecretListFilter, SecretTypeSchema, SecretVersion};
 use t_rust_less_lib::api_capnp::secrets_store;
 use t_rust_less_lib::memguard::SecretBytes;
 use t_rust_less_lib::secrets_store::SecretsStore;
@@ -124,6 +124,18 @@ impl secrets_store::Server for SecretsStoreImpl {
     Promise::ok(())
   }
 
+  fn migrate_versions(
+    &mut self,
+    _: secrets_store::MigrateVersionsParams,
+    mut results: secrets_store::MigrateVersionsResults,
+  ) -> Promise<(), capnp::Error> {
+    let migrated = stry!(self.secrets_store.migrate_versions());
+
+    results.get().set_migrated(migrated as u32);
+
+    Promise::ok(())
+  }
+
   fn add(
     &mut self,
     params: secrets_store::AddParams,
@@ -170,4 +182,91 @@ impl secrets_store::Server for SecretsStoreImpl {
 
     Promise::ok(())
   }
+
+  fn health_report(
+    &mut self,
+    params: secrets_store::HealthReportParams,
+    mut results: secrets_store::HealthReportResults,
+  ) -> Promise<(), capnp::Error> {
+    let params = stry!(params
+      .get()
+      .and_then(secrets_store::health_report_params::Reader::get_params)
+      .and_then(HealthReportParams::from_reader));
+    let report = stry!(self.secrets_store.health_report(&params));
+
+    stry!(report.to_builder(results.get().init_report()));
+
+    Promise::ok(())
+  }
+
+  fn list_schemas(
+    &mut self,
+    _: secrets_store::ListSchemasParams,
+    mut results: secrets_store::ListSchemasResults,
+  ) -> Promise<(), capnp::Error> {
+    let schemas = stry!(self.secrets_store.list_schemas());
+    let mut result = results.get().init_schemas(schemas.len() as u32);
+
+    for (idx, schema) in schemas.iter().enumerate() {
+      schema.to_builder(result.reborrow().get(idx as u32));
+    }
+
+    Promise::ok(())
+  }
+
+  fn store_schema(
+    &mut self,
+    params: secrets_store::StoreSchemaParams,
+    _: secrets_store::StoreSchemaResults,
+  ) -> Promise<(), capnp::Error> {
+    let schema = stry!(params
+      .get()
+      .and_then(secrets_store::store_schema_params::Reader::get_schema)
+      .and_then(SecretTypeSchema::from_reader));
+
+    stry!(self.secrets_store.store_schema(schema));
+
+    Promise::ok(())
+  }
+
+  fn add_attachment_chunk(
+    &mut self,
+    params: secrets_store::AddAttachmentChunkParams,
+    mut results: secrets_store::AddAttachmentChunkResults,
+  ) -> Promise<(), capnp::Error> {
+    let recipients = stry!(params
+      .get()
+      .and_then(secrets_store::add_attachment_chunk_params::Reader::get_recipients)
+      .and_then(|recipients| {
+        recipients
+          .iter()
+          .map(|recipient| recipient.map(str::to_string))
+          .collect::<capnp::Result<Vec<String>>>()
+      }));
+    let chunk = stry!(params
+      .get()
+      .and_then(secrets_store::add_attachment_chunk_params::Reader::get_chunk));
+
+    let block_id = stry!(self.secrets_store.add_attachment_chunk(&recipients, chunk));
+
+    results.get().set_block_id(&block_id);
+
+    Promise::ok(())
+  }
+
+  fn get_attachment_chunk(
+    &mut self,
+    params: secrets_store::GetAttachmentChunkParams,
+    mut results: secrets_store::GetAttachmentChunkResults,
+  ) -> Promise<(), capnp::Error> {
+    let block_id = stry!(params
+      .get()
+      .and_then(secrets_store::get_attachment_chunk_params::Reader::get_block_id));
+
+    let chunk = stry!(self.secrets_store.get_attachment_chunk(block_id));
+
+    results.get().set_chunk(&chunk.borrow());
+
+    Promise::ok(())
+  }
 }
@@ -6,7 +6,7 @@ use std::sync::Arc;
 use t_rust_less_lib::api_capnp::service;
 use t_rust_less_lib::service::local::LocalTrustlessService;
 use t_rust_less_lib::{
-  api::PasswordGeneratorParam,
+  api::{PasswordGeneratorParam, SecretListFilter},
   service::{StoreConfig, TrustlessService},
 };
 
@@ -114,6 +114,28 @@ impl service::Server for ServiceImpl {
     Promise::ok(())
   }
 
+  fn lock_all(&mut self, _: service::LockAllParams, _: service::LockAllResults) -> Promise<(), capnp::Error> {
+    stry!(self.service.lock_all());
+
+    Promise::ok(())
+  }
+
+  fn list_all(
+    &mut self,
+    params: service::ListAllParams,
+    mut results: service::ListAllResults,
+  ) -> Promise<(), capnp::Error> {
+    let filter = stry!(params
+      .get()
+      .and_then(service::list_all_params::Reader::get_filter)
+      .and_then(SecretListFilter::from_reader));
+    let list = stry!(self.service.list_all(&filter));
+
+    stry!(results.get().get_list().and_then(|l| list.to_builder(l)));
+
+    Promise::ok(())
+  }
+
   fn secret_to_clipboard(
     &mut self,
     params: service::SecretToClipboardParams,
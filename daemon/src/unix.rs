@@ -1,38 +1,99 @@
 use crate::error::ExtResult;
 use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
 use futures::{future, AsyncReadExt, FutureExt, StreamExt};
-use log::{error, info};
+use log::{error, info, warn};
+use std::cell::Cell;
+use std::env;
 use std::fs;
-use std::time::Duration;
-use t_rust_less_lib::service::unix::daemon_socket_path;
-use tokio::net::UnixListener;
+use std::future::Future;
+use std::io;
+use std::mem;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::io::{FromRawFd, RawFd};
+use std::os::unix::net;
+use std::path::Path;
+use std::process;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use t_rust_less_lib::service::unix::{daemon_socket_path, json_rpc_socket_path};
+use tokio::net::{UnixListener, UnixStream};
 use tokio::runtime::Builder;
 use tokio::signal;
 use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::mpsc;
 use tokio::task::{self, LocalSet};
 use tokio::time::interval;
 
-pub fn run_server<F, A>(handler_factory: F, check_autolock: A)
-where
+/// Interval of the expiry check, which is a lot less time critical than the autolock.
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// First file descriptor passed by systemd socket activation (see `sd_listen_fds(3)`).
+const SD_LISTEN_FDS_START: RawFd = 3;
+
+/// Request to lock all stores.
+///
+/// The guard (e.g. a sleep inhibitor of logind) is only released once all stores are locked.
+pub struct LockRequest {
+  pub guard: Option<Box<dyn Send>>,
+}
+
+pub struct ServerOptions {
+  /// Lock all stores when the session is locked or the system goes to sleep
+  pub listen_logind: bool,
+  /// Exit once all stores have been locked (and no client is connected) for this long
+  pub idle_exit: Option<Duration>,
+  /// Also listen for JSON-RPC clients on a socket of its own
+  pub json_rpc: bool,
+}
+
+pub fn run_server<F, J, T, A, E, L, I>(
+  handler_factory: F,
+  json_rpc_handler: J,
+  check_autolock: A,
+  check_expiring: E,
+  lock_all: L,
+  all_locked: I,
+  options: ServerOptions,
+) where
   F: Fn() -> capnp::capability::Client,
+  J: Fn(UnixStream) -> T,
+  T: Future<Output = ()> + 'static,
   A: Fn(),
+  E: Fn(),
+  L: Fn(),
+  I: Fn() -> bool,
 {
   let socket_path = daemon_socket_path();
+  let json_rpc_enabled = options.json_rpc;
   let socket_path_cloned = socket_path.clone();
-
-  info!("Listening on socket {}", socket_path.to_string_lossy());
+  let json_rpc_socket_path = json_rpc_socket_path();
+  let json_rpc_socket_path_cloned = json_rpc_socket_path.clone();
 
   let mut rt = Builder::new().basic_scheduler().enable_all().build().unwrap();
   let local_set = LocalSet::new();
-  let result: Result<(), Box<dyn std::error::Error>> = local_set.block_on(&mut rt, async move {
-    let prev_mask = unsafe {
-      // Dirty little trick to set permissions on the socket
-      libc::umask(0o177)
+  let lock_all_ref = &lock_all;
+  let result: Result<bool, Box<dyn std::error::Error>> = local_set.block_on(&mut rt, async move {
+    let (mut socket, activated) = match activated_socket()? {
+      Some(socket) => {
+        info!("Listening on socket passed by systemd");
+        (socket, true)
+      }
+      None => {
+        info!("Listening on socket {}", socket_path_cloned.to_string_lossy());
+        (bind_socket(&socket_path_cloned)?, false)
+      }
     };
-    let mut socket = UnixListener::bind(&socket_path_cloned)?;
-    unsafe {
-      libc::umask(prev_mask);
-    }
+    let json_rpc_socket = if options.json_rpc {
+      info!(
+        "Listening for JSON-RPC on socket {}",
+        json_rpc_socket_path_cloned.to_string_lossy()
+      );
+      Some(bind_socket(&json_rpc_socket_path_cloned)?)
+    } else {
+      None
+    };
+    let connections = Rc::new(Cell::new(0usize));
+    let connections_cloned = connections.clone();
+    let json_rpc_connections = connections.clone();
 
     let handle_incoming = async move {
       while let Ok((stream, _)) = socket.accept().await {
@@ -40,8 +101,30 @@ where
 
         let network = twoparty::VatNetwork::new(reader, writer, rpc_twoparty_capnp::Side::Server, Default::default());
         let rpc_system = RpcSystem::new(Box::new(network), Some(handler_factory()));
+        let connections = connections_cloned.clone();
+
+        connections.set(connections.get() + 1);
+        task::spawn_local(Box::pin(
+          rpc_system.map(move |_| connections.set(connections.get() - 1)),
+        ));
+      }
+
+      Ok::<(), Box<dyn std::error::Error>>(())
+    };
+
+    let handle_json_rpc_incoming = async move {
+      let mut socket = match json_rpc_socket {
+        Some(socket) => socket,
+        None => return future::pending().await,
+      };
 
-        task::spawn_local(Box::pin(rpc_system.map(|_| ())));
+      while let Ok((stream, _)) = socket.accept().await {
+        let connections = json_rpc_connections.clone();
+
+        connections.set(connections.get() + 1);
+        task::spawn_local(Box::pin(
+          json_rpc_handler(stream).map(move |_| connections.set(connections.get() - 1)),
+        ));
       }
 
       Ok::<(), Box<dyn std::error::Error>>(())
@@ -54,25 +137,292 @@ where
       })
       .map(|_| Ok::<(), Box<dyn std::error::Error>>(()));
 
+    let expiry_checker = interval(EXPIRY_CHECK_INTERVAL)
+      .for_each(|_| {
+        check_expiring();
+        future::ready(())
+      })
+      .map(|_| Ok::<(), Box<dyn std::error::Error>>(()));
+
+    // Lock requests might come from other threads (e.g. the logind listener)
+    let (lock_sender, lock_receiver) = mpsc::unbounded_channel::<LockRequest>();
+    #[cfg(all(target_os = "linux", feature = "with_logind"))]
+    {
+      if options.listen_logind {
+        crate::logind::spawn_listener(lock_sender.clone());
+      }
+    }
+    #[cfg(not(all(target_os = "linux", feature = "with_logind")))]
+    {
+      if options.listen_logind {
+        info!("Listening to logind requires linux and the with_logind feature");
+      }
+    }
+    let lock_requests = lock_receiver
+      .for_each(|request| {
+        info!("Locking all stores");
+        lock_all_ref();
+        drop(request.guard);
+        future::ready(())
+      })
+      .map(|_| Ok::<(), Box<dyn std::error::Error>>(()));
+
+    let idle_exit = async move {
+      let timeout = match options.idle_exit {
+        Some(timeout) => timeout,
+        None => return future::pending().await,
+      };
+      let mut ticks = interval(Duration::from_secs(1));
+      let mut idle_since: Option<Instant> = None;
+
+      loop {
+        ticks.tick().await;
+        if connections.get() > 0 || !all_locked() {
+          idle_since = None;
+          continue;
+        }
+        if idle_since.get_or_insert_with(Instant::now).elapsed() >= timeout {
+          info!("Idle for {} seconds", timeout.as_secs());
+          return;
+        }
+      }
+    };
+
     future::select(
       future::select(
-        Box::pin(signal::ctrl_c().map(|_| Ok::<(), Box<dyn std::error::Error>>(()))),
-        Box::pin(
-          signal(SignalKind::terminate())?
-            .recv()
-            .map(|_| Ok::<(), Box<dyn std::error::Error>>(())),
+        future::select(
+          Box::pin(signal::ctrl_c().map(|_| Ok::<(), Box<dyn std::error::Error>>(()))),
+          Box::pin(
+            signal(SignalKind::terminate())?
+              .recv()
+              .map(|_| Ok::<(), Box<dyn std::error::Error>>(())),
+          ),
         ),
+        Box::pin(idle_exit),
       ),
-      Box::pin(future::try_join(handle_incoming, autolocker)),
+      Box::pin(future::try_join5(
+        handle_incoming,
+        handle_json_rpc_incoming,
+        autolocker,
+        expiry_checker,
+        lock_requests,
+      )),
     )
     .await;
-    Ok(())
+    drop(lock_sender);
+    Ok(activated)
   });
 
-  result.ok_or_exit(format!("Listen to {} failed", socket_path.to_string_lossy()));
+  let activated = result.ok_or_exit(format!("Listen to {} failed", socket_path.to_string_lossy()));
+
+  info!("Shutting down");
+  // Do not leave it to the process exit, locking zeroizes all the keys
+  lock_all();
+
+  // A socket passed by systemd is none of our business
+  if !activated {
+    if let Err(error) = fs::remove_file(&socket_path) {
+      error!("Cleanup of {} failed: {}", socket_path.to_string_lossy(), error)
+    }
+  }
+  if json_rpc_enabled {
+    if let Err(error) = fs::remove_file(&json_rpc_socket_path) {
+      error!(
+        "Cleanup of {} failed: {}",
+        json_rpc_socket_path.to_string_lossy(),
+        error
+      )
+    }
+  }
+}
+
+/// Bind a socket that is only accessible by the current user.
+///
+/// A socket left behind by a daemon that died is removed.
+fn bind_socket(path: &Path) -> io::Result<UnixListener> {
+  let socket = bind_std_socket(path)?;
+
+  socket.set_nonblocking(true)?;
+
+  UnixListener::from_std(socket)
+}
+
+/// Bind a socket only accessible by the current user.
+///
+/// An existing socket is only replaced if nobody is listening on it anymore (e.g. the previous daemon has
+/// been killed), i.e. a running daemon is never hijacked.
+fn bind_std_socket(path: &Path) -> io::Result<net::UnixListener> {
+  match fs::symlink_metadata(path) {
+    Ok(metadata) if !metadata.file_type().is_socket() => {
+      return Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("{} is not a socket", path.to_string_lossy()),
+      ))
+    }
+    Ok(_) => (),
+    Err(error) if error.kind() == io::ErrorKind::NotFound => (),
+    Err(error) => return Err(error),
+  }
+  if path.exists() {
+    match net::UnixStream::connect(path) {
+      Ok(_) => {
+        return Err(io::Error::new(
+          io::ErrorKind::AddrInUse,
+          format!("{} is in use by another process", path.to_string_lossy()),
+        ))
+      }
+      Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => {
+        warn!("Removing stale socket {}", path.to_string_lossy());
+        fs::remove_file(path)?;
+      }
+      Err(error) => return Err(error),
+    }
+  }
+  let prev_mask = unsafe {
+    // Dirty little trick to set permissions on the socket
+    libc::umask(0o177)
+  };
+  let result = net::UnixListener::bind(path);
+  unsafe {
+    libc::umask(prev_mask);
+  }
+
+  result
+}
+
+/// Socket passed via systemd socket activation (if any).
+fn activated_socket() -> io::Result<Option<UnixListener>> {
+  let listen_pid = env::var("LISTEN_PID").ok().and_then(|pid| pid.parse::<u32>().ok());
+  let listen_fds = env::var("LISTEN_FDS")
+    .ok()
+    .and_then(|fds| fds.parse::<u32>().ok())
+    .unwrap_or(0);
+
+  // These must not be inherited by child processes (e.g. the passphrase helper)
+  env::remove_var("LISTEN_PID");
+  env::remove_var("LISTEN_FDS");
+  env::remove_var("LISTEN_FDNAMES");
+
+  if listen_pid != Some(process::id()) || listen_fds == 0 {
+    return Ok(None);
+  }
+  if listen_fds > 1 {
+    warn!("Only the first of {} sockets passed by systemd is used", listen_fds);
+  }
+  check_unix_stream_socket(SD_LISTEN_FDS_START)?;
+  let socket = unsafe {
+    libc::fcntl(SD_LISTEN_FDS_START, libc::F_SETFD, libc::FD_CLOEXEC);
+    net::UnixListener::from_raw_fd(SD_LISTEN_FDS_START)
+  };
+  socket.set_nonblocking(true)?;
+
+  Ok(Some(UnixListener::from_std(socket)?))
+}
+
+/// Check that a file descriptor is a unix stream socket (e.g. systemd might be configured to pass
+/// a TCP or datagram socket instead).
+fn check_unix_stream_socket(fd: RawFd) -> io::Result<()> {
+  let mut stat: libc::stat = unsafe { mem::zeroed() };
+
+  if unsafe { libc::fstat(fd, &mut stat) } < 0 {
+    return Err(io::Error::last_os_error());
+  }
+  if stat.st_mode & libc::S_IFMT != libc::S_IFSOCK {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      "Passed file descriptor is not a socket",
+    ));
+  }
+
+  let mut address: libc::sockaddr_storage = unsafe { mem::zeroed() };
+  let mut address_len = mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+
+  if unsafe { libc::getsockname(fd, &mut address as *mut _ as *mut libc::sockaddr, &mut address_len) } < 0 {
+    return Err(io::Error::last_os_error());
+  }
+  if i32::from(address.ss_family) != libc::AF_UNIX {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      "Passed socket is not a unix socket",
+    ));
+  }
+
+  let mut socket_type: libc::c_int = 0;
+  let mut socket_type_len = mem::size_of::<libc::c_int>() as libc::socklen_t;
+
+  if unsafe {
+    libc::getsockopt(
+      fd,
+      libc::SOL_SOCKET,
+      libc::SO_TYPE,
+      &mut socket_type as *mut _ as *mut libc::c_void,
+      &mut socket_type_len,
+    )
+  } < 0
+  {
+    return Err(io::Error::last_os_error());
+  }
+  if socket_type != libc::SOCK_STREAM {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidInput,
+      "Passed socket is not a stream socket",
+    ));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use std::net::UdpSocket;
+  use std::os::unix::fs::PermissionsExt;
+  use std::os::unix::io::AsRawFd;
+  use tempdir::TempDir;
+
+  #[test]
+  fn test_bind_socket() {
+    let tmp_dir = TempDir::new("t-rust-less-daemon").unwrap();
+    let socket_path = tmp_dir.path().join("daemon.socket");
+    let listener = bind_std_socket(&socket_path).unwrap();
+
+    assert_that(&(fs::metadata(&socket_path).unwrap().permissions().mode() & 0o777)).is_equal_to(0o600);
+    // A running daemon must not be replaced
+    assert_that(&bind_std_socket(&socket_path).map(|_| ()).map_err(|error| error.kind()))
+      .is_err_containing(io::ErrorKind::AddrInUse);
+    assert_that(&net::UnixStream::connect(&socket_path).map(|_| ())).is_ok();
+
+    drop(listener);
+
+    let listener = bind_std_socket(&socket_path).unwrap();
+
+    assert_that(&net::UnixStream::connect(&socket_path).map(|_| ())).is_ok();
+    drop(listener);
+
+    // Anything else than a stale socket is not removed
+    let file_path = tmp_dir.path().join("some.file");
+
+    fs::write(&file_path, b"no socket").unwrap();
+    assert_that(&bind_std_socket(&file_path).map(|_| ()).map_err(|error| error.kind()))
+      .is_err_containing(io::ErrorKind::AlreadyExists);
+    assert_that(&file_path.is_file()).is_true();
+  }
+
+  #[test]
+  fn test_check_unix_stream_socket() {
+    let tmp_dir = TempDir::new("t-rust-less-daemon").unwrap();
+    let stream_socket = net::UnixListener::bind(tmp_dir.path().join("stream.socket")).unwrap();
+    let datagram_socket = net::UnixDatagram::bind(tmp_dir.path().join("datagram.socket")).unwrap();
+    let udp_socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+    let file = fs::File::create(tmp_dir.path().join("some.file")).unwrap();
 
-  info!("Cleaning up");
-  if let Err(error) = fs::remove_file(&socket_path) {
-    error!("Cleanup of {} failed: {}", socket_path.to_string_lossy(), error)
+    assert_that(&check_unix_stream_socket(stream_socket.as_raw_fd()).map_err(|error| error.kind())).is_ok();
+    assert_that(&check_unix_stream_socket(datagram_socket.as_raw_fd()).map_err(|error| error.kind()))
+      .is_err_containing(io::ErrorKind::InvalidInput);
+    assert_that(&check_unix_stream_socket(udp_socket.as_raw_fd()).map_err(|error| error.kind()))
+      .is_err_containing(io::ErrorKind::InvalidInput);
+    assert_that(&check_unix_stream_socket(file.as_raw_fd()).map_err(|error| error.kind()))
+      .is_err_containing(io::ErrorKind::InvalidInput);
   }
 }
@@ -1,6 +1,6 @@
-systemctl --user stop t-rust-less
+systemctl --user stop t-rust-less.socket t-rust-less
 
-cp conf/t-rust-less.service $HOME/.config/systemd/user
+cp conf/t-rust-less.service conf/t-rust-less.socket $HOME/.config/systemd/user
 
 systemctl --user daemon-reload
 
@@ -8,4 +8,4 @@ cargo install --path cli --force
 cargo install --path daemon --force
 cargo install --path native --force
 
-systemctl --user start t-rust-less
+systemctl --user start t-rust-less.socket
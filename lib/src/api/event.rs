@@ -1,5 +1,6 @@
-use crate::api::Identity;
+use crate::api::{Identity, ZeroizeDateTime};
 use crate::api_capnp::{event, EventType};
+use chrono::{TimeZone, Utc};
 use serde_derive::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
@@ -33,6 +34,14 @@ pub enum Event {
     property: String,
   },
   ClipboardDone,
+  /// A secret expires or is due for rotation soon (or already is).
+  SecretExpiring {
+    store_name: String,
+    secret_id: String,
+    expires_at: ZeroizeDateTime,
+  },
+  /// The configuration has been changed (e.g. stores have been added or removed).
+  ConfigChanged,
 }
 
 impl Event {
@@ -65,6 +74,16 @@ impl Event {
         property: reader.get_property()?.to_string(),
       }),
       EventType::ClipboardDone => Ok(Event::ClipboardDone),
+      EventType::SecretExpiring => Ok(Event::SecretExpiring {
+        store_name: reader.get_store_name()?.to_string(),
+        secret_id: reader.get_secret_id()?.to_string(),
+        expires_at: Utc
+          .timestamp_millis_opt(reader.get_expires_at())
+          .single()
+          .ok_or_else(|| capnp::Error::failed("Invalid expiry timestamp".to_string()))?
+          .into(),
+      }),
+      EventType::ConfigChanged => Ok(Event::ConfigChanged),
     }
   }
 
@@ -117,6 +136,19 @@ impl Event {
       Event::ClipboardDone => {
         builder.set_type(EventType::ClipboardDone);
       }
+      Event::SecretExpiring {
+        store_name,
+        secret_id,
+        expires_at,
+      } => {
+        builder.set_type(EventType::SecretExpiring);
+        builder.set_store_name(store_name);
+        builder.set_secret_id(secret_id);
+        builder.set_expires_at(expires_at.timestamp_millis());
+      }
+      Event::ConfigChanged => {
+        builder.set_type(EventType::ConfigChanged);
+      }
     }
     Ok(())
   }
@@ -1,6 +1,7 @@
 use crate::api_capnp::{
-  self, identity, option, password_generator_param, password_strength, secret, secret_entry, secret_entry_match,
-  secret_list, secret_list_filter, secret_version, status,
+  self, health_report, health_report_params, identity, option, password_generator_param, password_strength, secret,
+  secret_entry, secret_entry_match, secret_health, secret_list, secret_list_filter, secret_version, status,
+  store_entry_match, store_secret_list, text_match,
 };
 use capnp::{struct_list, text_list};
 use chrono::{TimeZone, Utc};
@@ -12,15 +13,30 @@ use std::fmt;
 use zeroize::Zeroize;
 
 mod event;
+mod query;
+mod schema;
 mod zeroize_datetime;
 
+#[cfg(test)]
+mod query_tests;
+#[cfg(test)]
+mod schema_tests;
+
 pub use event::*;
+pub use query::*;
+pub use schema::*;
 pub use zeroize_datetime::*;
 
 pub const PROPERTY_USERNAME: &str = "username";
 pub const PROPERTY_PASSWORD: &str = "password";
 pub const PROPERTY_TOTP_URL: &str = "totpUrl";
 pub const PROPERTY_NOTES: &str = "notes";
+pub const PROPERTY_CARDHOLDER: &str = "cardholder";
+pub const PROPERTY_CARD_BRAND: &str = "brand";
+pub const PROPERTY_CARD_NUMBER: &str = "number";
+pub const PROPERTY_CARD_EXPIRY: &str = "expiry";
+pub const PROPERTY_CARD_CODE: &str = "code";
+pub const PROPERTY_SSN: &str = "ssn";
 
 /// Status information of a secrets store
 ///
@@ -46,7 +62,7 @@ impl Status {
         if autolock_at == std::i64::MIN {
           None
         } else {
-          Some(Utc.timestamp_millis(autolock_at).into())
+          Utc.timestamp_millis_opt(autolock_at).single().map(Into::into)
         }
       },
       version: reader.get_version()?.to_string(),
@@ -119,6 +135,8 @@ pub enum SecretType {
   Licence,
   Wlan,
   Password,
+  Card,
+  Identity,
   #[serde(other)]
   Other,
 }
@@ -133,13 +151,15 @@ impl SecretType {
   /// Get the commonly used property name that may contain a password.
   ///
   /// The values of these properties are automatically estimated for the strengths.
-  pub fn password_properties(&self) -> &[&str] {
+  pub fn password_properties(&self) -> &'static [&'static str] {
     match self {
       SecretType::Login => &[PROPERTY_PASSWORD],
       SecretType::Note => &[],
       SecretType::Licence => &[],
       SecretType::Wlan => &[PROPERTY_PASSWORD],
       SecretType::Password => &[PROPERTY_PASSWORD],
+      SecretType::Card => &[PROPERTY_CARD_NUMBER, PROPERTY_CARD_CODE],
+      SecretType::Identity => &[PROPERTY_SSN],
       SecretType::Other => &[],
     }
   }
@@ -151,6 +171,8 @@ impl SecretType {
       api_capnp::SecretType::Wlan => SecretType::Wlan,
       api_capnp::SecretType::Note => SecretType::Note,
       api_capnp::SecretType::Password => SecretType::Password,
+      api_capnp::SecretType::Card => SecretType::Card,
+      api_capnp::SecretType::Identity => SecretType::Identity,
       api_capnp::SecretType::Other => SecretType::Other,
     }
   }
@@ -162,6 +184,8 @@ impl SecretType {
       SecretType::Note => api_capnp::SecretType::Note,
       SecretType::Wlan => api_capnp::SecretType::Wlan,
       SecretType::Password => api_capnp::SecretType::Password,
+      SecretType::Card => api_capnp::SecretType::Card,
+      SecretType::Identity => api_capnp::SecretType::Identity,
       SecretType::Other => api_capnp::SecretType::Other,
     }
   }
@@ -175,6 +199,8 @@ impl fmt::Display for SecretType {
       SecretType::Licence => write!(f, "Licence"),
       SecretType::Wlan => write!(f, "WLAN"),
       SecretType::Password => write!(f, "Password"),
+      SecretType::Card => write!(f, "Card"),
+      SecretType::Identity => write!(f, "Identity"),
       SecretType::Other => write!(f, "Other"),
     }
   }
@@ -196,6 +222,19 @@ pub struct SecretListFilter {
   pub name: Option<String>,
   #[serde(default)]
   pub deleted: bool,
+  /// Only secrets that are past their expiry or rotation date.
+  #[serde(default)]
+  pub expired: bool,
+  /// Only secrets that expire or are due for rotation within the given number of seconds (this includes
+  /// all the secrets that are already expired).
+  #[serde(default)]
+  pub expiring_within: Option<u64>,
+  /// Full-text search in the non-secret properties (only if the full-text index of the store is enabled).
+  #[serde(default)]
+  pub text: Option<String>,
+  /// Query in the syntax of `SecretQuery`, which is combined with all the other filters.
+  #[serde(default)]
+  pub query: Option<String>,
 }
 
 impl SecretListFilter {
@@ -209,6 +248,13 @@ impl SecretListFilter {
       },
       name: read_option(reader.get_name()?)?.map(ToString::to_string),
       deleted: reader.get_deleted(),
+      expired: reader.get_expired(),
+      expiring_within: match reader.get_expiring_within() {
+        0 => None,
+        seconds => Some(seconds),
+      },
+      text: read_option(reader.get_text()?)?.map(ToString::to_string),
+      query: read_option(reader.get_query()?)?.map(ToString::to_string),
     })
   }
 
@@ -239,6 +285,22 @@ impl SecretListFilter {
       None => builder.reborrow().init_name().set_none(()),
     }
     builder.set_deleted(self.deleted);
+    builder.set_expired(self.expired);
+    builder.set_expiring_within(self.expiring_within.unwrap_or(0));
+    match &self.text {
+      Some(text) => builder
+        .reborrow()
+        .init_text()
+        .set_some(capnp::text::new_reader(text.as_bytes())?)?,
+      None => builder.reborrow().init_text().set_none(()),
+    }
+    match &self.query {
+      Some(query) => builder
+        .reborrow()
+        .init_query()
+        .set_some(capnp::text::new_reader(query.as_bytes())?)?,
+      None => builder.reborrow().init_query().set_none(()),
+    }
 
     Ok(())
   }
@@ -263,13 +325,32 @@ pub struct SecretEntry {
   pub urls: Vec<String>,
   pub timestamp: ZeroizeDateTime,
   pub deleted: bool,
+  /// There are competing versions of the secret (i.e. concurrent changes), see `Secret::competing_versions`.
+  #[serde(default)]
+  pub conflicted: bool,
+  #[serde(default)]
+  pub expires_at: Option<ZeroizeDateTime>,
+  #[serde(default)]
+  pub rotate_every: Option<u64>,
 }
 
 impl SecretEntry {
+  /// Date when the secret expires or is due for rotation (whatever comes first).
+  pub fn due_at(&self) -> Option<ZeroizeDateTime> {
+    let rotate_at = self
+      .rotate_every
+      .and_then(|seconds| self.timestamp.checked_add_seconds(seconds));
+
+    match (self.expires_at, rotate_at) {
+      (Some(expires_at), Some(rotate_at)) => Some(expires_at.min(rotate_at)),
+      (expires_at, rotate_at) => expires_at.or(rotate_at),
+    }
+  }
+
   pub fn from_reader(reader: secret_entry::Reader) -> capnp::Result<Self> {
     Ok(SecretEntry {
       id: reader.get_id()?.to_string(),
-      timestamp: Utc.timestamp_millis(reader.get_timestamp()).into(),
+      timestamp: read_timestamp(reader.get_timestamp())?,
       name: reader.get_name()?.to_string(),
       secret_type: SecretType::from_reader(reader.get_type()?),
       tags: reader
@@ -283,6 +364,9 @@ impl SecretEntry {
         .map(|u| u.map(|u| u.to_string()))
         .collect::<capnp::Result<Vec<String>>>()?,
       deleted: reader.get_deleted(),
+      conflicted: reader.get_conflicted(),
+      expires_at: read_optional_timestamp(reader.get_expires_at()),
+      rotate_every: read_optional_seconds(reader.get_rotate_every()),
     })
   }
 
@@ -300,6 +384,9 @@ impl SecretEntry {
       urls.set(idx as u32, url)
     }
     builder.set_deleted(self.deleted);
+    builder.set_conflicted(self.conflicted);
+    builder.set_expires_at(self.expires_at.map(|e| e.timestamp_millis()).unwrap_or(0));
+    builder.set_rotate_every(self.rotate_every.unwrap_or(0));
   }
 }
 
@@ -341,6 +428,9 @@ pub struct SecretEntryMatch {
   pub url_highlights: Vec<usize>,
   /// Array of matching tags
   pub tags_highlights: Vec<usize>,
+  /// Matches of the full-text search
+  #[serde(default)]
+  pub text_matches: Vec<TextMatch>,
 }
 
 impl SecretEntryMatch {
@@ -351,6 +441,11 @@ impl SecretEntryMatch {
       name_highlights: reader.get_name_highlights()?.into_iter().map(|h| h as usize).collect(),
       url_highlights: reader.get_url_highlights()?.into_iter().map(|h| h as usize).collect(),
       tags_highlights: reader.get_tags_highlights()?.into_iter().map(|h| h as usize).collect(),
+      text_matches: reader
+        .get_text_matches()?
+        .into_iter()
+        .map(TextMatch::from_reader)
+        .collect::<capnp::Result<Vec<TextMatch>>>()?,
     })
   }
   pub fn to_builder(&self, mut builder: secret_entry_match::Builder) {
@@ -362,16 +457,50 @@ impl SecretEntryMatch {
     for (idx, highlight) in self.name_highlights.iter().enumerate() {
       name_highlights.set(idx as u32, *highlight as u64);
     }
-    let mut url_highlights = builder
-      .reborrow()
-      .init_url_highlights(self.name_highlights.len() as u32);
+    let mut url_highlights = builder.reborrow().init_url_highlights(self.url_highlights.len() as u32);
     for (idx, highlight) in self.url_highlights.iter().enumerate() {
       url_highlights.set(idx as u32, *highlight as u64);
     }
-    let mut tags_highlights = builder.init_tags_highlights(self.name_highlights.len() as u32);
+    let mut tags_highlights = builder
+      .reborrow()
+      .init_tags_highlights(self.tags_highlights.len() as u32);
     for (idx, highlight) in self.tags_highlights.iter().enumerate() {
       tags_highlights.set(idx as u32, *highlight as u64);
     }
+    let mut text_matches = builder.init_text_matches(self.text_matches.len() as u32);
+    for (idx, text_match) in self.text_matches.iter().enumerate() {
+      text_match.to_builder(text_matches.reborrow().get(idx as u32));
+    }
+  }
+}
+
+/// Match of a full-text search in a (non-secret) property.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Zeroize)]
+#[zeroize(drop)]
+pub struct TextMatch {
+  pub property: String,
+  /// The line of the property value containing the match
+  pub text: String,
+  /// Array of positions (single chars) to highlight in the text
+  pub highlights: Vec<usize>,
+}
+
+impl TextMatch {
+  pub fn from_reader(reader: text_match::Reader) -> capnp::Result<Self> {
+    Ok(TextMatch {
+      property: reader.get_property()?.to_string(),
+      text: reader.get_text()?.to_string(),
+      highlights: reader.get_highlights()?.into_iter().map(|h| h as usize).collect(),
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: text_match::Builder) {
+    builder.set_property(&self.property);
+    builder.set_text(&self.text);
+    let mut highlights = builder.init_highlights(self.highlights.len() as u32);
+    for (idx, highlight) in self.highlights.iter().enumerate() {
+      highlights.set(idx as u32, *highlight as u64);
+    }
   }
 }
 
@@ -437,6 +566,86 @@ impl SecretList {
   }
 }
 
+/// Match of a secret in one of several stores (see `TrustlessService::list_all`).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreEntryMatch {
+  pub store_name: String,
+  pub entry_match: SecretEntryMatch,
+}
+
+impl StoreEntryMatch {
+  pub fn from_reader(reader: store_entry_match::Reader) -> capnp::Result<Self> {
+    Ok(StoreEntryMatch {
+      store_name: reader.get_store_name()?.to_string(),
+      entry_match: SecretEntryMatch::from_reader(reader.get_entry_match()?)?,
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: store_entry_match::Builder) {
+    builder.set_store_name(&self.store_name);
+    self.entry_match.to_builder(builder.init_entry_match());
+  }
+}
+
+/// Combined list of secrets of all unlocked stores.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StoreSecretList {
+  pub all_tags: Vec<String>,
+  pub entries: Vec<StoreEntryMatch>,
+}
+
+impl StoreSecretList {
+  /// Add the list of a store, the entries are kept sorted by their matching score.
+  pub fn extend(&mut self, store_name: &str, mut list: SecretList) {
+    for tag in list.all_tags.drain(..) {
+      if !self.all_tags.contains(&tag) {
+        self.all_tags.push(tag);
+      }
+    }
+    self.all_tags.sort();
+    self
+      .entries
+      .extend(list.entries.drain(..).map(|entry_match| StoreEntryMatch {
+        store_name: store_name.to_string(),
+        entry_match,
+      }));
+    self.entries.sort_by(|a, b| {
+      a.entry_match
+        .cmp(&b.entry_match)
+        .then_with(|| a.store_name.cmp(&b.store_name))
+    });
+  }
+
+  pub fn from_reader(reader: store_secret_list::Reader) -> capnp::Result<Self> {
+    Ok(StoreSecretList {
+      all_tags: reader
+        .get_all_tags()?
+        .into_iter()
+        .map(|t| t.map(|t| t.to_string()))
+        .collect::<capnp::Result<Vec<String>>>()?,
+      entries: reader
+        .get_entries()?
+        .into_iter()
+        .map(StoreEntryMatch::from_reader)
+        .collect::<capnp::Result<Vec<StoreEntryMatch>>>()?,
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: store_secret_list::Builder) -> capnp::Result<()> {
+    set_text_list(
+      builder.reborrow().init_all_tags(self.all_tags.len() as u32),
+      &self.all_tags,
+    )?;
+    let mut entries = builder.init_entries(self.entries.len() as u32);
+
+    for (idx, entry) in self.entries.iter().enumerate() {
+      entry.to_builder(entries.reborrow().get(idx as u32));
+    }
+
+    Ok(())
+  }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct SecretProperties(BTreeMap<String, String>);
@@ -458,6 +667,20 @@ impl SecretProperties {
     self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
   }
 
+  /// Set the value of a property, the previous value (if any) will be zeroed out.
+  pub fn set(&mut self, name: &str, value: &str) {
+    if let Some(mut previous) = self.0.insert(name.to_string(), value.to_string()) {
+      previous.zeroize();
+    }
+  }
+
+  /// Remove a property, the removed value will be zeroed out.
+  pub fn remove(&mut self, name: &str) {
+    if let Some(mut previous) = self.0.remove(name) {
+      previous.zeroize();
+    }
+  }
+
   pub fn from_reader(reader: struct_list::Reader<secret_version::property::Owned>) -> capnp::Result<Self> {
     let mut properties = BTreeMap::new();
     for property in reader {
@@ -495,27 +718,116 @@ impl Zeroize for SecretProperties {
 /// secure document store. Nevertheless, sometimes it might be convenient added some
 /// sort of (small) document to a password.
 ///
+/// The content of an attachment is stored in separate (encrypted) chunk blocks, a secret version
+/// just references these by their ids. Only attachments that have not been stored yet (or versions
+/// created by older releases) contain the content itself.
+///
 #[derive(Clone, Debug, Serialize, Deserialize, Zeroize)]
 #[zeroize(drop)]
 pub struct SecretAttachment {
   name: String,
   mime_type: String,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
   content: Vec<u8>,
+  /// SHA-256 of the content (hex), empty if the content has not been stored in chunks
+  #[serde(default, skip_serializing_if = "String::is_empty")]
+  content_hash: String,
+  #[serde(default)]
+  size: u64,
+  /// Block ids of the chunks of the content
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  chunks: Vec<String>,
 }
 
 impl SecretAttachment {
+  pub fn new(name: String, mime_type: String, content: Vec<u8>) -> SecretAttachment {
+    SecretAttachment {
+      name,
+      mime_type,
+      size: content.len() as u64,
+      content,
+      content_hash: String::new(),
+      chunks: vec![],
+    }
+  }
+
+  /// Attachment with its content stored in chunk blocks.
+  pub fn stored(name: String, mime_type: String, content_hash: String, size: u64, chunks: Vec<String>) -> Self {
+    SecretAttachment {
+      name,
+      mime_type,
+      content: vec![],
+      content_hash,
+      size,
+      chunks,
+    }
+  }
+
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  pub fn mime_type(&self) -> &str {
+    &self.mime_type
+  }
+
+  /// Inline content, this is empty if the content is stored in chunks.
+  pub fn content(&self) -> &[u8] {
+    &self.content
+  }
+
+  pub fn content_hash(&self) -> &str {
+    &self.content_hash
+  }
+
+  pub fn size(&self) -> u64 {
+    if self.is_stored() {
+      self.size
+    } else {
+      self.content.len() as u64
+    }
+  }
+
+  pub fn chunks(&self) -> &[String] {
+    &self.chunks
+  }
+
+  /// Check if the content is stored in chunk blocks (i.e. is not inline).
+  pub fn is_stored(&self) -> bool {
+    !self.content_hash.is_empty()
+  }
+
+  /// Check if both attachments have the same content.
+  pub fn same_content(&self, other: &SecretAttachment) -> bool {
+    match (self.is_stored(), other.is_stored()) {
+      (true, true) => self.content_hash == other.content_hash,
+      (false, false) => self.content == other.content,
+      _ => false,
+    }
+  }
+
   pub fn from_reader(reader: secret_version::attachment::Reader) -> capnp::Result<Self> {
     Ok(SecretAttachment {
       name: reader.get_name()?.to_string(),
       mime_type: reader.get_mime_type()?.to_string(),
       content: reader.get_content()?.to_vec(),
+      content_hash: reader.get_content_hash()?.to_string(),
+      size: reader.get_size(),
+      chunks: reader
+        .get_chunks()?
+        .into_iter()
+        .map(|chunk| chunk.map(ToString::to_string))
+        .collect::<capnp::Result<Vec<String>>>()?,
     })
   }
 
-  pub fn to_builder(&self, mut builder: secret_version::attachment::Builder) {
+  pub fn to_builder(&self, mut builder: secret_version::attachment::Builder) -> capnp::Result<()> {
     builder.set_name(&self.name);
     builder.set_mime_type(&self.mime_type);
     builder.set_content(&self.content);
+    builder.set_content_hash(&self.content_hash);
+    builder.set_size(self.size);
+    set_text_list(builder.init_chunks(self.chunks.len() as u32), &self.chunks)
   }
 }
 
@@ -569,14 +881,72 @@ pub struct SecretVersion {
   /// to change the Secret and create a new version without the recipient.
   #[serde(default)]
   pub recipients: Vec<String>,
+  /// Block id of the version this one is based on (i.e. the current version at the time of the change).
+  /// Two versions with the same parent have been created concurrently (e.g. offline on different nodes)
+  /// and the secret is considered conflicted until they are merged.
+  /// Versions without a parent (i.e. created by older clients) are based on their predecessor by timestamp.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub parent_block_id: Option<String>,
+  /// Block ids of competing versions that have been merged into this one.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub merged_block_ids: Vec<String>,
+  /// Optional date after which the secret should no longer be used.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub expires_at: Option<ZeroizeDateTime>,
+  /// Optional interval (in seconds) in which the secret has to be changed, i.e. the secret is due
+  /// for rotation `rotate_every` seconds after this version has been created.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub rotate_every: Option<u64>,
+  /// Id of the custom type of the secret (see `SecretTypeSchema`). The `secret_type` is still used
+  /// for everything that is not covered by the schema.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub custom_type: Option<String>,
 }
 
 impl SecretVersion {
+  /// Create a version of a secret without any tags, urls, properties, attachments or recipients.
+  pub fn new(secret_id: &str, secret_type: SecretType, name: &str, timestamp: ZeroizeDateTime) -> Self {
+    SecretVersion {
+      secret_id: secret_id.to_string(),
+      secret_type,
+      timestamp,
+      name: name.to_string(),
+      tags: vec![],
+      urls: vec![],
+      properties: Default::default(),
+      attachments: vec![],
+      deleted: false,
+      recipients: vec![],
+      parent_block_id: None,
+      merged_block_ids: vec![],
+      expires_at: None,
+      rotate_every: None,
+      custom_type: None,
+    }
+  }
+
+  /// Get the properties that may contain a password.
+  ///
+  /// For a custom type these are the secret fields of its schema, otherwise the common properties of the `secret_type`.
+  pub fn password_properties<'a>(&self, schemas: &'a [SecretTypeSchema]) -> Vec<&'a str> {
+    match self.schema(schemas) {
+      Some(schema) => schema.properties_of_kind(FieldKind::Secret),
+      None => self.secret_type.password_properties().to_vec(),
+    }
+  }
+
+  /// Find the schema of the custom type (if there is one).
+  pub fn schema<'a>(&self, schemas: &'a [SecretTypeSchema]) -> Option<&'a SecretTypeSchema> {
+    let custom_type = self.custom_type.as_ref()?;
+
+    schemas.iter().find(|schema| &schema.id == custom_type)
+  }
+
   pub fn from_reader(reader: secret_version::Reader) -> capnp::Result<Self> {
     Ok(SecretVersion {
       secret_id: reader.get_secret_id()?.to_string(),
       secret_type: SecretType::from_reader(reader.get_type()?),
-      timestamp: Utc.timestamp_millis(reader.get_timestamp()).into(),
+      timestamp: read_timestamp(reader.get_timestamp())?,
       name: reader.get_name()?.to_string(),
       tags: reader
         .get_tags()?
@@ -600,6 +970,21 @@ impl SecretVersion {
         .into_iter()
         .map(|u| u.map(|u| u.to_string()))
         .collect::<capnp::Result<Vec<String>>>()?,
+      parent_block_id: match reader.get_parent_block_id()? {
+        "" => None,
+        parent_block_id => Some(parent_block_id.to_string()),
+      },
+      merged_block_ids: reader
+        .get_merged_block_ids()?
+        .into_iter()
+        .map(|b| b.map(|b| b.to_string()))
+        .collect::<capnp::Result<Vec<String>>>()?,
+      expires_at: read_optional_timestamp(reader.get_expires_at()),
+      rotate_every: read_optional_seconds(reader.get_rotate_every()),
+      custom_type: match reader.get_custom_type()? {
+        "" => None,
+        custom_type => Some(custom_type.to_string()),
+      },
     })
   }
 
@@ -615,13 +1000,27 @@ impl SecretVersion {
       .to_builder(builder.reborrow().init_properties(self.properties.len() as u32));
     let mut attachments = builder.reborrow().init_attachments(self.attachments.len() as u32);
     for (idx, attachment) in self.attachments.iter().enumerate() {
-      attachment.to_builder(attachments.reborrow().get(idx as u32));
+      attachment.to_builder(attachments.reborrow().get(idx as u32))?;
     }
     builder.set_deleted(self.deleted);
     set_text_list(
       builder.reborrow().init_recipients(self.recipients.len() as u32),
       &self.recipients,
     )?;
+    if let Some(parent_block_id) = &self.parent_block_id {
+      builder.set_parent_block_id(parent_block_id);
+    }
+    set_text_list(
+      builder
+        .reborrow()
+        .init_merged_block_ids(self.merged_block_ids.len() as u32),
+      &self.merged_block_ids,
+    )?;
+    builder.set_expires_at(self.expires_at.map(|e| e.timestamp_millis()).unwrap_or(0));
+    builder.set_rotate_every(self.rotate_every.unwrap_or(0));
+    if let Some(custom_type) = &self.custom_type {
+      builder.set_custom_type(custom_type);
+    }
 
     Ok(())
   }
@@ -634,6 +1033,8 @@ impl SecretVersion {
     set_text_list(builder.reborrow().init_tags(self.tags.len() as u32), &self.tags)?;
     set_text_list(builder.reborrow().init_urls(self.urls.len() as u32), &self.urls)?;
     builder.set_deleted(self.deleted);
+    builder.set_expires_at(self.expires_at.map(|e| e.timestamp_millis()).unwrap_or(0));
+    builder.set_rotate_every(self.rotate_every.unwrap_or(0));
     Ok(())
   }
 }
@@ -652,6 +1053,9 @@ pub struct PasswordStrength {
   pub crack_time: f64,
   pub crack_time_display: String,
   pub score: u8,
+  /// Number of times the password appears in the breached passwords file (0 if not found or not checked)
+  #[serde(default)]
+  pub breach_count: u64,
 }
 
 impl PasswordStrength {
@@ -661,6 +1065,7 @@ impl PasswordStrength {
       crack_time: reader.get_crack_time(),
       crack_time_display: reader.get_crack_time_display()?.to_string(),
       score: reader.get_score(),
+      breach_count: reader.get_breach_count(),
     })
   }
   pub fn to_builder(&self, mut builder: password_strength::Builder) {
@@ -668,6 +1073,7 @@ impl PasswordStrength {
     builder.set_crack_time(self.crack_time);
     builder.set_crack_time_display(&self.crack_time_display);
     builder.set_score(self.score);
+    builder.set_breach_count(self.breach_count);
   }
 }
 
@@ -676,19 +1082,48 @@ impl PasswordStrength {
 pub struct SecretVersionRef {
   pub block_id: String,
   pub timestamp: ZeroizeDateTime,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub parent_block_id: Option<String>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub merged_block_ids: Vec<String>,
 }
 
 impl SecretVersionRef {
+  pub fn new(block_id: &str, version: &SecretVersion) -> Self {
+    SecretVersionRef {
+      block_id: block_id.to_string(),
+      timestamp: version.timestamp,
+      parent_block_id: version.parent_block_id.clone(),
+      merged_block_ids: version.merged_block_ids.clone(),
+    }
+  }
+
   pub fn from_reader(reader: secret::version_ref::Reader) -> capnp::Result<Self> {
     Ok(SecretVersionRef {
       block_id: reader.get_block_id()?.to_string(),
-      timestamp: Utc.timestamp_millis(reader.get_timestamp()).into(),
+      timestamp: read_timestamp(reader.get_timestamp())?,
+      parent_block_id: match reader.get_parent_block_id()? {
+        "" => None,
+        parent_block_id => Some(parent_block_id.to_string()),
+      },
+      merged_block_ids: reader
+        .get_merged_block_ids()?
+        .into_iter()
+        .map(|b| b.map(|b| b.to_string()))
+        .collect::<capnp::Result<Vec<String>>>()?,
     })
   }
 
-  pub fn to_builder(&self, mut builder: secret::version_ref::Builder) {
+  pub fn to_builder(&self, mut builder: secret::version_ref::Builder) -> capnp::Result<()> {
     builder.set_block_id(&self.block_id);
     builder.set_timestamp(self.timestamp.timestamp_millis());
+    if let Some(parent_block_id) = &self.parent_block_id {
+      builder.set_parent_block_id(parent_block_id);
+    }
+    set_text_list(
+      builder.init_merged_block_ids(self.merged_block_ids.len() as u32),
+      &self.merged_block_ids,
+    )
   }
 }
 
@@ -710,6 +1145,9 @@ pub struct Secret {
   pub current_block_id: String,
   pub versions: Vec<SecretVersionRef>,
   pub password_strengths: HashMap<String, PasswordStrength>,
+  /// Versions competing with the current one (i.e. concurrent changes that have not been merged yet).
+  #[serde(default)]
+  pub competing_versions: Vec<SecretVersionRef>,
 }
 
 impl Secret {
@@ -734,6 +1172,11 @@ impl Secret {
           ))
         })
         .collect::<capnp::Result<HashMap<String, PasswordStrength>>>()?,
+      competing_versions: reader
+        .get_competing_versions()?
+        .into_iter()
+        .map(SecretVersionRef::from_reader)
+        .collect::<capnp::Result<Vec<SecretVersionRef>>>()?,
     })
   }
 
@@ -744,7 +1187,7 @@ impl Secret {
     builder.set_current_block_id(&self.current_block_id);
     let mut versions = builder.reborrow().init_versions(self.versions.len() as u32);
     for (idx, version) in self.versions.iter().enumerate() {
-      version.to_builder(versions.reborrow().get(idx as u32));
+      version.to_builder(versions.reborrow().get(idx as u32))?;
     }
     let mut password_strengths = builder
       .reborrow()
@@ -754,6 +1197,12 @@ impl Secret {
       password_strength.set_key(&key);
       strength.to_builder(password_strength.init_strength());
     }
+    let mut competing_versions = builder
+      .reborrow()
+      .init_competing_versions(self.competing_versions.len() as u32);
+    for (idx, version) in self.competing_versions.iter().enumerate() {
+      version.to_builder(competing_versions.reborrow().get(idx as u32))?;
+    }
 
     Ok(())
   }
@@ -776,6 +1225,137 @@ impl Drop for Secret {
   }
 }
 
+/// Thresholds of the password health report.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HealthReportParams {
+  /// Passwords with a strength score (0-4) below this are reported as weak.
+  pub min_score: u8,
+  /// Passwords that have not been changed for this number of days are reported as old (0 to disable).
+  pub max_age_days: u64,
+}
+
+impl Default for HealthReportParams {
+  fn default() -> Self {
+    HealthReportParams {
+      min_score: 3,
+      max_age_days: 365,
+    }
+  }
+}
+
+impl HealthReportParams {
+  pub fn from_reader(reader: health_report_params::Reader) -> capnp::Result<Self> {
+    Ok(HealthReportParams {
+      min_score: reader.get_min_score(),
+      max_age_days: reader.get_max_age_days(),
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: health_report_params::Builder) -> capnp::Result<()> {
+    builder.set_min_score(self.min_score);
+    builder.set_max_age_days(self.max_age_days);
+    Ok(())
+  }
+}
+
+/// Health issues of a single secret.
+///
+/// Passwords itself are never part of the report, reused passwords are only reported by the ids of
+/// the other secrets using them.
+#[derive(Clone, Debug, Serialize, Deserialize, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretHealth {
+  pub secret_id: String,
+  pub name: String,
+  /// Lowest strength score of the passwords of the secret.
+  pub score: u8,
+  /// Strength score is below the `min_score` threshold.
+  pub weak: bool,
+  /// Ids of other secrets using the same password.
+  pub reused_with: Vec<String>,
+  /// Time of the last password change if it is older than `max_age_days`.
+  pub password_changed_at: Option<ZeroizeDateTime>,
+  /// Login with urls but without a one-time password.
+  pub missing_totp: bool,
+  /// At least one of the passwords appears in the breached passwords file.
+  #[serde(default)]
+  pub breached: bool,
+}
+
+impl SecretHealth {
+  pub fn has_issues(&self) -> bool {
+    self.weak
+      || !self.reused_with.is_empty()
+      || self.password_changed_at.is_some()
+      || self.missing_totp
+      || self.breached
+  }
+
+  pub fn from_reader(reader: secret_health::Reader) -> capnp::Result<Self> {
+    Ok(SecretHealth {
+      secret_id: reader.get_secret_id()?.to_string(),
+      name: reader.get_name()?.to_string(),
+      score: reader.get_score(),
+      weak: reader.get_weak(),
+      reused_with: reader
+        .get_reused_with()?
+        .into_iter()
+        .map(|s| s.map(|s| s.to_string()))
+        .collect::<capnp::Result<Vec<String>>>()?,
+      password_changed_at: read_optional_timestamp(reader.get_password_changed_at()),
+      missing_totp: reader.get_missing_totp(),
+      breached: reader.get_breached(),
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: secret_health::Builder) -> capnp::Result<()> {
+    builder.set_secret_id(&self.secret_id);
+    builder.set_name(&self.name);
+    builder.set_score(self.score);
+    builder.set_weak(self.weak);
+    if let Some(password_changed_at) = &self.password_changed_at {
+      builder.set_password_changed_at(password_changed_at.timestamp_millis());
+    }
+    builder.set_missing_totp(self.missing_totp);
+    builder.set_breached(self.breached);
+    set_text_list(
+      builder.init_reused_with(self.reused_with.len() as u32),
+      &self.reused_with,
+    )
+  }
+}
+
+/// Result of a health check of all the secrets of the unlocked user.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HealthReport {
+  /// Number of (not deleted) secrets that have been checked.
+  pub checked: u64,
+  /// All secrets with at least one issue.
+  pub secrets: Vec<SecretHealth>,
+}
+
+impl HealthReport {
+  pub fn from_reader(reader: health_report::Reader) -> capnp::Result<Self> {
+    Ok(HealthReport {
+      checked: reader.get_checked(),
+      secrets: reader
+        .get_secrets()?
+        .into_iter()
+        .map(SecretHealth::from_reader)
+        .collect::<capnp::Result<Vec<SecretHealth>>>()?,
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: health_report::Builder) -> capnp::Result<()> {
+    builder.set_checked(self.checked);
+    let mut secrets = builder.init_secrets(self.secrets.len() as u32);
+    for (idx, secret) in self.secrets.iter().enumerate() {
+      secret.to_builder(secrets.reborrow().get(idx as u32))?;
+    }
+    Ok(())
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Zeroize)]
 #[zeroize(drop)]
 pub struct PasswordGeneratorCharsParam {
@@ -886,6 +1466,29 @@ where
   }
 }
 
+fn read_timestamp(millis: i64) -> capnp::Result<ZeroizeDateTime> {
+  Utc
+    .timestamp_millis_opt(millis)
+    .single()
+    .map(Into::into)
+    .ok_or_else(|| capnp::Error::failed(format!("Invalid timestamp: {}", millis)))
+}
+
+/// Optional timestamps are represented by 0 in capnp.
+fn read_optional_timestamp(millis: i64) -> Option<ZeroizeDateTime> {
+  match millis {
+    0 => None,
+    millis => Utc.timestamp_millis_opt(millis).single().map(Into::into),
+  }
+}
+
+fn read_optional_seconds(seconds: u64) -> Option<u64> {
+  match seconds {
+    0 => None,
+    seconds => Some(seconds),
+  }
+}
+
 pub fn set_text_list<I, S>(mut text_list: text_list::Builder, texts: I) -> capnp::Result<()>
 where
   I: IntoIterator<Item = S>,
@@ -0,0 +1,227 @@
+use crate::api::SecretType;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A single condition of a query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QueryTerm {
+  /// Fuzzy match of the name (i.e. a plain word or quoted string)
+  Name(String),
+  /// Secret has exactly this tag
+  Tag(String),
+  /// Secret is of this type
+  Type(SecretType),
+  /// One of the urls of the secret contains the value (case insensitive)
+  Url(String),
+  /// Full-text search (only if the full-text index of the store is enabled)
+  Text(String),
+}
+
+/// Parsed query to filter the secrets of a store.
+///
+/// The syntax is a list of terms that all have to match, e.g.
+/// `tag:prod tag:db -tag:legacy type:login url:example.com "fuzzy name"`.
+/// Alternatives are separated by `OR` (or `|`), `-` negates a term and terms may be grouped in parentheses.
+/// Plain words or quoted strings are fuzzy matched against the name of a secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecretQuery {
+  Term(QueryTerm),
+  Not(Box<SecretQuery>),
+  And(Vec<SecretQuery>),
+  Or(Vec<SecretQuery>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct QueryError(pub String);
+
+impl fmt::Display for QueryError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+  Open,
+  Close,
+  Or,
+  Not,
+  Term(QueryTerm),
+}
+
+impl SecretQuery {
+  /// Check if the query contains a full-text search.
+  pub fn has_text_term(&self) -> bool {
+    match self {
+      SecretQuery::Term(term) => matches!(term, QueryTerm::Text(_)),
+      SecretQuery::Not(negated) => negated.has_text_term(),
+      SecretQuery::And(terms) | SecretQuery::Or(terms) => terms.iter().any(SecretQuery::has_text_term),
+    }
+  }
+
+  pub fn parse(query: &str) -> Result<SecretQuery, QueryError> {
+    let tokens = tokenize(query)?;
+    if tokens.is_empty() {
+      return Ok(SecretQuery::And(vec![]));
+    }
+    let mut tokens = tokens.into_iter().peekable();
+    let parsed = parse_or(&mut tokens)?;
+
+    match tokens.next() {
+      None => Ok(parsed),
+      Some(token) => Err(QueryError(format!("Unexpected {:?}", token))),
+    }
+  }
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, QueryError> {
+  let mut tokens = Vec::new();
+  let mut chars = query.chars().peekable();
+
+  while let Some(ch) = chars.peek().cloned() {
+    match ch {
+      _ if ch.is_whitespace() => {
+        chars.next();
+      }
+      '(' => {
+        chars.next();
+        tokens.push(Token::Open);
+      }
+      ')' => {
+        chars.next();
+        tokens.push(Token::Close);
+      }
+      '|' => {
+        chars.next();
+        tokens.push(Token::Or);
+      }
+      '-' => {
+        chars.next();
+        tokens.push(Token::Not);
+      }
+      '"' => tokens.push(Token::Term(QueryTerm::Name(read_quoted(&mut chars)?))),
+      _ => {
+        let word = read_word(&mut chars);
+
+        if word == "OR" {
+          tokens.push(Token::Or);
+          continue;
+        }
+        let term = match word.find(':') {
+          Some(idx) => {
+            let mut value = word[idx + 1..].to_string();
+            if value.is_empty() && chars.peek() == Some(&'"') {
+              value = read_quoted(&mut chars)?;
+            }
+            make_term(&word[..idx], value)?
+          }
+          None => QueryTerm::Name(word),
+        };
+        tokens.push(Token::Term(term));
+      }
+    }
+  }
+
+  Ok(tokens)
+}
+
+fn read_word(chars: &mut Peekable<Chars>) -> String {
+  let mut word = String::new();
+
+  while let Some(ch) = chars.peek() {
+    if ch.is_whitespace() || *ch == '(' || *ch == ')' || *ch == '"' {
+      break;
+    }
+    word.push(*ch);
+    chars.next();
+  }
+
+  word
+}
+
+fn read_quoted(chars: &mut Peekable<Chars>) -> Result<String, QueryError> {
+  let mut value = String::new();
+
+  chars.next();
+  for ch in chars {
+    if ch == '"' {
+      return Ok(value);
+    }
+    value.push(ch);
+  }
+
+  Err(QueryError("Missing closing quote".to_string()))
+}
+
+fn make_term(key: &str, value: String) -> Result<QueryTerm, QueryError> {
+  if value.is_empty() {
+    return Err(QueryError(format!("Missing value for {}", key)));
+  }
+  match key {
+    "tag" => Ok(QueryTerm::Tag(value)),
+    "url" => Ok(QueryTerm::Url(value)),
+    "name" => Ok(QueryTerm::Name(value)),
+    "text" => Ok(QueryTerm::Text(value)),
+    "type" => serde_json::from_value(serde_json::Value::String(value.to_lowercase()))
+      .ok()
+      .filter(|secret_type| *secret_type != SecretType::Other || value.to_lowercase() == "other")
+      .map(QueryTerm::Type)
+      .ok_or_else(|| QueryError(format!("Unknown secret type {}", value))),
+    // Anything else is just a word with a colon (e.g. a time)
+    _ => Ok(QueryTerm::Name(format!("{}:{}", key, value))),
+  }
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+fn parse_or(tokens: &mut Tokens) -> Result<SecretQuery, QueryError> {
+  let mut alternatives = vec![parse_and(tokens)?];
+
+  while tokens.peek() == Some(&Token::Or) {
+    tokens.next();
+    alternatives.push(parse_and(tokens)?);
+  }
+
+  Ok(if alternatives.len() == 1 {
+    alternatives.pop().unwrap()
+  } else {
+    SecretQuery::Or(alternatives)
+  })
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<SecretQuery, QueryError> {
+  let mut terms = Vec::new();
+
+  while let Some(token) = tokens.peek() {
+    if *token == Token::Or || *token == Token::Close {
+      break;
+    }
+    terms.push(parse_unary(tokens)?);
+  }
+
+  match terms.len() {
+    0 => Err(QueryError("Empty expression".to_string())),
+    1 => Ok(terms.pop().unwrap()),
+    _ => Ok(SecretQuery::And(terms)),
+  }
+}
+
+fn parse_unary(tokens: &mut Tokens) -> Result<SecretQuery, QueryError> {
+  match tokens.next() {
+    Some(Token::Not) => Ok(SecretQuery::Not(Box::new(parse_unary(tokens)?))),
+    Some(Token::Open) => {
+      let group = parse_or(tokens)?;
+
+      match tokens.next() {
+        Some(Token::Close) => Ok(group),
+        _ => Err(QueryError("Missing closing parenthesis".to_string())),
+      }
+    }
+    Some(Token::Term(term)) => Ok(SecretQuery::Term(term)),
+    Some(token) => Err(QueryError(format!("Unexpected {:?}", token))),
+    None => Err(QueryError("Unexpected end of query".to_string())),
+  }
+}
@@ -0,0 +1,46 @@
+use crate::api::{QueryTerm, SecretQuery, SecretType};
+use spectral::prelude::*;
+
+fn term(term: QueryTerm) -> SecretQuery {
+  SecretQuery::Term(term)
+}
+
+#[test]
+fn test_parse_query() {
+  assert_that(&SecretQuery::parse("")).is_equal_to(Ok(SecretQuery::And(vec![])));
+  assert_that(&SecretQuery::parse("github")).is_equal_to(Ok(term(QueryTerm::Name("github".to_string()))));
+  assert_that(&SecretQuery::parse(
+    r#"tag:prod tag:db -tag:legacy type:login url:example.com "fuzzy name""#,
+  ))
+  .is_equal_to(Ok(SecretQuery::And(vec![
+    term(QueryTerm::Tag("prod".to_string())),
+    term(QueryTerm::Tag("db".to_string())),
+    SecretQuery::Not(Box::new(term(QueryTerm::Tag("legacy".to_string())))),
+    term(QueryTerm::Type(SecretType::Login)),
+    term(QueryTerm::Url("example.com".to_string())),
+    term(QueryTerm::Name("fuzzy name".to_string())),
+  ])));
+  assert_that(&SecretQuery::parse(
+    r#"(tag:a | tag:"b c") OR -(type:note text:backup) 12:30"#,
+  ))
+  .is_equal_to(Ok(SecretQuery::Or(vec![
+    SecretQuery::Or(vec![
+      term(QueryTerm::Tag("a".to_string())),
+      term(QueryTerm::Tag("b c".to_string())),
+    ]),
+    SecretQuery::And(vec![
+      SecretQuery::Not(Box::new(SecretQuery::And(vec![
+        term(QueryTerm::Type(SecretType::Note)),
+        term(QueryTerm::Text("backup".to_string())),
+      ]))),
+      term(QueryTerm::Name("12:30".to_string())),
+    ]),
+  ])));
+
+  assert_that(&SecretQuery::parse(r#""unterminated"#)).is_err();
+  assert_that(&SecretQuery::parse("(tag:a")).is_err();
+  assert_that(&SecretQuery::parse("tag:a)")).is_err();
+  assert_that(&SecretQuery::parse("tag:a OR")).is_err();
+  assert_that(&SecretQuery::parse("tag:")).is_err();
+  assert_that(&SecretQuery::parse("type:unknown")).is_err();
+}
@@ -0,0 +1,198 @@
+use crate::api::SecretVersion;
+use crate::api_capnp::{self, schema_field, secret_type_schema};
+use crate::otp::OTPAuthUrl;
+use chrono::NaiveDate;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+
+/// Kind of a field of a custom secret type.
+///
+/// This defines how the value of the field is validated and displayed.
+///
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldKind {
+  /// Single line of text
+  Text,
+  /// Password or any other kind of secret, these are checked for their strength
+  Secret,
+  Url,
+  /// otpauth url
+  Totp,
+  /// Plain date (`YYYY-MM-DD`)
+  Date,
+  /// Text with multiple lines (e.g. notes)
+  Multiline,
+}
+
+impl FieldKind {
+  pub fn from_reader(api: api_capnp::FieldKind) -> Self {
+    match api {
+      api_capnp::FieldKind::Text => FieldKind::Text,
+      api_capnp::FieldKind::Secret => FieldKind::Secret,
+      api_capnp::FieldKind::Url => FieldKind::Url,
+      api_capnp::FieldKind::Totp => FieldKind::Totp,
+      api_capnp::FieldKind::Date => FieldKind::Date,
+      api_capnp::FieldKind::Multiline => FieldKind::Multiline,
+    }
+  }
+
+  pub fn to_builder(self) -> api_capnp::FieldKind {
+    match self {
+      FieldKind::Text => api_capnp::FieldKind::Text,
+      FieldKind::Secret => api_capnp::FieldKind::Secret,
+      FieldKind::Url => api_capnp::FieldKind::Url,
+      FieldKind::Totp => api_capnp::FieldKind::Totp,
+      FieldKind::Date => api_capnp::FieldKind::Date,
+      FieldKind::Multiline => api_capnp::FieldKind::Multiline,
+    }
+  }
+}
+
+impl fmt::Display for FieldKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      FieldKind::Text => write!(f, "text"),
+      FieldKind::Secret => write!(f, "secret"),
+      FieldKind::Url => write!(f, "url"),
+      FieldKind::Totp => write!(f, "totp"),
+      FieldKind::Date => write!(f, "date"),
+      FieldKind::Multiline => write!(f, "multiline"),
+    }
+  }
+}
+
+/// A field of a custom secret type, i.e. a property of the secret.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchemaField {
+  pub property: String,
+  pub kind: FieldKind,
+  #[serde(default)]
+  pub required: bool,
+}
+
+impl SchemaField {
+  pub fn from_reader(reader: schema_field::Reader) -> capnp::Result<Self> {
+    Ok(SchemaField {
+      property: reader.get_property()?.to_string(),
+      kind: FieldKind::from_reader(reader.get_kind()?),
+      required: reader.get_required(),
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: schema_field::Builder) {
+    builder.set_property(&self.property);
+    builder.set_kind(self.kind.to_builder());
+    builder.set_required(self.required);
+  }
+
+  fn validate(&self, value: &str) -> Result<(), SchemaError> {
+    let valid = match self.kind {
+      FieldKind::Text => !value.contains('\n'),
+      FieldKind::Url => url::Url::parse(value).is_ok(),
+      FieldKind::Totp => OTPAuthUrl::parse(value).is_ok(),
+      FieldKind::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok(),
+      FieldKind::Secret | FieldKind::Multiline => true,
+    };
+
+    if valid {
+      Ok(())
+    } else {
+      Err(SchemaError(format!("{} is not a valid {}", self.property, self.kind)))
+    }
+  }
+}
+
+/// Schema of a custom secret type (e.g. "Database" or "API Token").
+///
+/// Secrets referring to a schema via `SecretVersion::custom_type` are validated against its fields.
+/// Properties that are not part of the schema are allowed as well.
+///
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SecretTypeSchema {
+  /// Identifier of the schema, may only contain lower case letters, digits, `-` and `_`.
+  pub id: String,
+  /// Display name of the type.
+  pub name: String,
+  pub fields: Vec<SchemaField>,
+}
+
+impl SecretTypeSchema {
+  pub fn from_reader(reader: secret_type_schema::Reader) -> capnp::Result<Self> {
+    Ok(SecretTypeSchema {
+      id: reader.get_id()?.to_string(),
+      name: reader.get_name()?.to_string(),
+      fields: reader
+        .get_fields()?
+        .into_iter()
+        .map(SchemaField::from_reader)
+        .collect::<capnp::Result<Vec<SchemaField>>>()?,
+    })
+  }
+
+  pub fn to_builder(&self, mut builder: secret_type_schema::Builder) {
+    builder.set_id(&self.id);
+    builder.set_name(&self.name);
+    let mut fields = builder.init_fields(self.fields.len() as u32);
+    for (idx, field) in self.fields.iter().enumerate() {
+      field.to_builder(fields.reborrow().get(idx as u32));
+    }
+  }
+
+  /// Properties of all the fields of a kind.
+  pub fn properties_of_kind(&self, kind: FieldKind) -> Vec<&str> {
+    self
+      .fields
+      .iter()
+      .filter(|field| field.kind == kind)
+      .map(|field| field.property.as_str())
+      .collect()
+  }
+
+  /// Check if the schema itself is well-formed.
+  pub fn check(&self) -> Result<(), SchemaError> {
+    if self.id.is_empty()
+      || !self
+        .id
+        .chars()
+        .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-' || ch == '_')
+    {
+      return Err(SchemaError(format!("Invalid id: '{}'", self.id)));
+    }
+    if self.name.is_empty() {
+      return Err(SchemaError("Name must not be empty".to_string()));
+    }
+    for (idx, field) in self.fields.iter().enumerate() {
+      if field.property.is_empty() {
+        return Err(SchemaError("Property of field must not be empty".to_string()));
+      }
+      if self.fields[..idx].iter().any(|other| other.property == field.property) {
+        return Err(SchemaError(format!("Duplicate field {}", field.property)));
+      }
+    }
+    Ok(())
+  }
+
+  /// Validate the properties of a secret version against the fields of the schema.
+  pub fn validate(&self, version: &SecretVersion) -> Result<(), SchemaError> {
+    for field in &self.fields {
+      match version.properties.get(&field.property) {
+        Some(value) if !value.is_empty() => field.validate(value)?,
+        _ if field.required => return Err(SchemaError(format!("{} is required", field.property))),
+        _ => (),
+      }
+    }
+    Ok(())
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaError(pub String);
+
+impl fmt::Display for SchemaError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.0)
+  }
+}
+
+impl std::error::Error for SchemaError {}
@@ -0,0 +1,122 @@
+use crate::api::{FieldKind, SchemaError, SchemaField, SecretType, SecretTypeSchema, SecretVersion, PROPERTY_PASSWORD};
+use chrono::Utc;
+use spectral::prelude::*;
+
+fn field(property: &str, kind: FieldKind, required: bool) -> SchemaField {
+  SchemaField {
+    property: property.to_string(),
+    kind,
+    required,
+  }
+}
+
+fn api_token_schema() -> SecretTypeSchema {
+  SecretTypeSchema {
+    id: "api-token".to_string(),
+    name: "API Token".to_string(),
+    fields: vec![
+      field("token", FieldKind::Secret, true),
+      field("endpoint", FieldKind::Url, false),
+      field("validUntil", FieldKind::Date, false),
+      field("account", FieldKind::Text, false),
+      field("otp", FieldKind::Totp, false),
+    ],
+  }
+}
+
+fn secret_version(properties: &[(&str, &str)]) -> SecretVersion {
+  let mut version = SecretVersion {
+    secret_id: "secret".to_string(),
+    secret_type: SecretType::Other,
+    timestamp: Utc::now().into(),
+    name: "Token".to_string(),
+    tags: vec![],
+    urls: vec![],
+    properties: Default::default(),
+    attachments: vec![],
+    deleted: false,
+    recipients: vec![],
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: Some("api-token".to_string()),
+  };
+  for (property, value) in properties {
+    version.properties.set(property, value);
+  }
+  version
+}
+
+#[test]
+fn test_check_schema() {
+  let schema = api_token_schema();
+
+  assert_that(&schema.check()).is_ok();
+  assert_that(
+    &SecretTypeSchema {
+      id: "API Token".to_string(),
+      ..schema.clone()
+    }
+    .check(),
+  )
+  .is_err();
+  assert_that(
+    &SecretTypeSchema {
+      name: "".to_string(),
+      ..schema.clone()
+    }
+    .check(),
+  )
+  .is_err();
+  assert_that(
+    &SecretTypeSchema {
+      fields: vec![
+        field("token", FieldKind::Secret, true),
+        field("token", FieldKind::Text, false),
+      ],
+      ..schema
+    }
+    .check(),
+  )
+  .is_err_containing(SchemaError("Duplicate field token".to_string()));
+}
+
+#[test]
+fn test_validate_secret_version() {
+  let schema = api_token_schema();
+
+  assert_that(&schema.validate(&secret_version(&[("token", "abcd")]))).is_ok();
+  assert_that(&schema.validate(&secret_version(&[
+    ("token", "abcd"),
+    ("endpoint", "https://api.example.com/v1"),
+    ("validUntil", "2030-12-31"),
+    ("account", "service user"),
+    (
+      "otp",
+      "otpauth://totp/Example:someone@example.com?secret=JBSWY3DPEHPK3PXP",
+    ),
+    ("other", "anything\ngoes"),
+  ])))
+  .is_ok();
+
+  assert_that(&schema.validate(&secret_version(&[("endpoint", "https://api.example.com/v1")])))
+    .is_err_containing(SchemaError("token is required".to_string()));
+  assert_that(&schema.validate(&secret_version(&[("token", "abcd"), ("endpoint", "not an url")]))).is_err();
+  assert_that(&schema.validate(&secret_version(&[("token", "abcd"), ("validUntil", "31.12.2030")]))).is_err();
+  assert_that(&schema.validate(&secret_version(&[("token", "abcd"), ("account", "two\nlines")]))).is_err();
+  assert_that(&schema.validate(&secret_version(&[("token", "abcd"), ("otp", "https://example.com")]))).is_err();
+}
+
+#[test]
+fn test_password_properties() {
+  let schemas = vec![api_token_schema()];
+  let mut version = secret_version(&[("token", "abcd"), (PROPERTY_PASSWORD, "secret")]);
+
+  assert_that(&version.password_properties(&schemas)).is_equal_to(vec!["token"]);
+  assert_that(&version.password_properties(&[])).is_equal_to(Vec::<&str>::new());
+
+  version.custom_type = None;
+  version.secret_type = SecretType::Login;
+  assert_that(&version.password_properties(&schemas)).is_equal_to(vec![PROPERTY_PASSWORD]);
+}
@@ -15,11 +15,18 @@ impl ZeroizeDateTime {
   pub fn format(&self, fmt: &str) -> String {
     self.0.format(fmt).to_string()
   }
+
+  /// Add a number of seconds, `None` if the result is not representable.
+  pub fn checked_add_seconds(&self, seconds: u64) -> Option<ZeroizeDateTime> {
+    let duration = Duration::from_std(std::time::Duration::from_secs(seconds)).ok()?;
+
+    self.0.checked_add_signed(duration).map(ZeroizeDateTime)
+  }
 }
 
 impl Zeroize for ZeroizeDateTime {
   fn zeroize(&mut self) {
-    self.0 = Utc.timestamp_millis(0)
+    self.0 = Utc.timestamp_millis_opt(0).unwrap()
   }
 }
 
@@ -35,6 +42,14 @@ impl From<std::time::SystemTime> for ZeroizeDateTime {
   }
 }
 
+impl ops::Add<Duration> for ZeroizeDateTime {
+  type Output = ZeroizeDateTime;
+
+  fn add(self, rhs: Duration) -> Self::Output {
+    ZeroizeDateTime(self.0 + rhs)
+  }
+}
+
 impl<T: Into<ZeroizeDateTime>> ops::Sub<T> for ZeroizeDateTime {
   type Output = Duration;
 
@@ -399,6 +399,22 @@ pub mod store_config {
     pub fn has_default_identity_id(&self) -> bool {
       !self.reader.get_pointer_field(3).is_null()
     }
+    #[inline]
+    pub fn get_full_text_index(self) -> bool {
+      self.reader.get_bool_field(64)
+    }
+    #[inline]
+    pub fn get_max_attachment_size(self) -> u64 {
+      self.reader.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn get_compress_blocks(self) -> bool {
+      self.reader.get_bool_field(65)
+    }
+    #[inline]
+    pub fn get_capnp_payload(self) -> bool {
+      self.reader.get_bool_field(66)
+    }
   }
 
   pub struct Builder<'a> {
@@ -555,6 +571,38 @@ pub mod store_config {
     pub fn has_default_identity_id(&self) -> bool {
       !self.builder.get_pointer_field(3).is_null()
     }
+    #[inline]
+    pub fn get_full_text_index(self) -> bool {
+      self.builder.get_bool_field(64)
+    }
+    #[inline]
+    pub fn set_full_text_index(&mut self, value: bool) {
+      self.builder.set_bool_field(64, value);
+    }
+    #[inline]
+    pub fn get_max_attachment_size(self) -> u64 {
+      self.builder.get_data_field::<u64>(2)
+    }
+    #[inline]
+    pub fn set_max_attachment_size(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(2, value);
+    }
+    #[inline]
+    pub fn get_compress_blocks(self) -> bool {
+      self.builder.get_bool_field(65)
+    }
+    #[inline]
+    pub fn set_compress_blocks(&mut self, value: bool) {
+      self.builder.set_bool_field(65, value);
+    }
+    #[inline]
+    pub fn get_capnp_payload(self) -> bool {
+      self.builder.get_bool_field(66)
+    }
+    #[inline]
+    pub fn set_capnp_payload(&mut self, value: bool) {
+      self.builder.set_bool_field(66, value);
+    }
   }
 
   pub struct Pipeline {
@@ -577,7 +625,7 @@ pub mod store_config {
       capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
     ];
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 4 };
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 3, pointers: 4 };
     pub const TYPE_ID: u64 = 0xde1d_89e9_9999_f92e;
   }
 }
@@ -2041,6 +2089,8 @@ pub enum EventType {
   IdentityAdded = 4,
   ClipboardProviding = 5,
   ClipboardDone = 6,
+  SecretExpiring = 7,
+  ConfigChanged = 8,
 }
 impl ::capnp::traits::FromU16 for EventType {
   #[inline]
@@ -2053,6 +2103,8 @@ impl ::capnp::traits::FromU16 for EventType {
       4 => ::core::result::Result::Ok(EventType::IdentityAdded),
       5 => ::core::result::Result::Ok(EventType::ClipboardProviding),
       6 => ::core::result::Result::Ok(EventType::ClipboardDone),
+      7 => ::core::result::Result::Ok(EventType::SecretExpiring),
+      8 => ::core::result::Result::Ok(EventType::ConfigChanged),
       n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
     }
   }
@@ -2187,6 +2239,10 @@ pub mod event {
     pub fn has_block_id(&self) -> bool {
       !self.reader.get_pointer_field(4).is_null()
     }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.reader.get_data_field::<i64>(1)
+    }
   }
 
   pub struct Builder<'a> {
@@ -2354,6 +2410,14 @@ pub mod event {
     pub fn has_block_id(&self) -> bool {
       !self.builder.get_pointer_field(4).is_null()
     }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.builder.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn set_expires_at(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(1, value);
+    }
   }
 
   pub struct Pipeline {
@@ -2371,7 +2435,7 @@ pub mod event {
   }
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 5 };
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 5 };
     pub const TYPE_ID: u64 = 0x8e7d_8c32_5abe_4062;
   }
 }
@@ -3674,6 +3738,10 @@ pub mod service {
   pub type GeneratePasswordResults =
     ::capnp::capability::Results<crate::api_capnp::service::generate_password_results::Owned>;
 
+  pub type ListAllParams = ::capnp::capability::Params<crate::api_capnp::service::list_all_params::Owned>;
+  pub type ListAllResults = ::capnp::capability::Results<crate::api_capnp::service::list_all_results::Owned>;
+  pub type LockAllParams = ::capnp::capability::Params<crate::api_capnp::service::lock_all_params::Owned>;
+  pub type LockAllResults = ::capnp::capability::Results<crate::api_capnp::service::lock_all_results::Owned>;
   pub struct Client {
     pub client: ::capnp::capability::Client,
   }
@@ -3817,6 +3885,22 @@ pub mod service {
     > {
       self.client.new_call(_private::TYPE_ID, 9, None)
     }
+    pub fn list_all_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::service::list_all_params::Owned,
+      crate::api_capnp::service::list_all_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 10, None)
+    }
+    pub fn lock_all_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::service::lock_all_params::Owned,
+      crate::api_capnp::service::lock_all_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 11, None)
+    }
   }
   pub trait Server {
     fn list_stores(
@@ -3889,6 +3973,12 @@ pub mod service {
     ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
       ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
     }
+    fn list_all(&mut self, _: ListAllParams, _: ListAllResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn lock_all(&mut self, _: LockAllParams, _: LockAllResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
   }
   pub struct ServerDispatch<_T> {
     pub server: _T,
@@ -3972,6 +4062,14 @@ pub mod service {
           ::capnp::private::capability::internal_get_typed_params(params),
           ::capnp::private::capability::internal_get_typed_results(results),
         ),
+        10 => server.list_all(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        11 => server.lock_all(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
         _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented("Method not implemented.".to_string())),
       }
     }
@@ -7461,660 +7559,760 @@ pub mod service {
       pub const TYPE_ID: u64 = 0xa7d1_7a6c_acf2_3f30;
     }
   }
-}
-
-pub mod identity {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
 
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
-
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+  pub mod list_all_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
     }
-  }
 
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
     }
-  }
 
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-  }
-
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
     }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.reader.total_size()
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
     }
-    #[inline]
-    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
     }
-    pub fn has_id(&self) -> bool {
-      !self.reader.get_pointer_field(0).is_null()
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
     }
-    #[inline]
-    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_filter(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
-    pub fn has_name(&self) -> bool {
-      !self.reader.get_pointer_field(1).is_null()
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    #[inline]
-    pub fn get_email(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
     }
-    pub fn has_email(&self) -> bool {
-      !self.reader.get_pointer_field(2).is_null()
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    #[inline]
-    pub fn get_hidden(self) -> bool {
-      self.reader.get_bool_field(0)
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
     }
-  }
 
-  pub struct Builder<'a> {
-    builder: ::capnp::private::layout::StructBuilder<'a>,
-  }
-  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-    #[inline]
-    fn struct_size() -> ::capnp::private::layout::StructSize {
-      _private::STRUCT_SIZE
-    }
-  }
-  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
     }
-  }
-  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-      Builder { builder }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-      self
-        .builder
-        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
-    }
-    fn get_from_pointer(
-      builder: ::capnp::private::layout::PointerBuilder<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Builder<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-        builder.get_struct(_private::STRUCT_SIZE, default)?,
-      ))
-    }
-  }
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
 
-  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-    fn set_pointer_builder<'b>(
-      pointer: ::capnp::private::layout::PointerBuilder<'b>,
-      value: Reader<'a>,
-      canonicalize: bool,
-    ) -> ::capnp::Result<()> {
-      pointer.set_struct(&value.reader, canonicalize)
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_filter<'b>(&mut self, value: crate::api_capnp::secret_list_filter::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_filter(self) -> crate::api_capnp::secret_list_filter::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_filter(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
-  }
 
-  impl<'a> Builder<'a> {
-    pub fn into_reader(self) -> Reader<'a> {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
     }
-    pub fn reborrow(&mut self) -> Builder {
-      Builder { ..*self }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
     }
-    pub fn reborrow_as_reader(&self) -> Reader {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xc64f_45fb_c098_8567;
     }
+  }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.builder.into_reader().total_size()
+  pub mod list_all_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-    #[inline]
-    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-    #[inline]
-    pub fn set_id(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(0).set_text(value);
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
     }
-    #[inline]
-    pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(0).init_text(size)
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
     }
-    pub fn has_id(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    #[inline]
-    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
     }
-    #[inline]
-    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(1).set_text(value);
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
     }
-    #[inline]
-    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(1).init_text(size)
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
     }
-    pub fn has_name(&self) -> bool {
-      !self.builder.get_pointer_field(1).is_null()
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
     }
-    #[inline]
-    pub fn get_email(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::store_secret_list::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_list(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
-    #[inline]
-    pub fn set_email(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(2).set_text(value);
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    #[inline]
-    pub fn init_email(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(2).init_text(size)
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
     }
-    pub fn has_email(&self) -> bool {
-      !self.builder.get_pointer_field(2).is_null()
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    #[inline]
-    pub fn get_hidden(self) -> bool {
-      self.builder.get_bool_field(0)
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
     }
-    #[inline]
-    pub fn set_hidden(&mut self, value: bool) {
-      self.builder.set_bool_field(0, value);
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
     }
-  }
 
-  pub struct Pipeline {
-    _typeless: ::capnp::any_pointer::Pipeline,
-  }
-  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-      Pipeline { _typeless: typeless }
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
     }
-  }
-  impl Pipeline {}
-  mod _private {
-    use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 3 };
-    pub const TYPE_ID: u64 = 0xdecb_1959_2b3c_494a;
-  }
-}
 
-pub mod status {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
-
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
-
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
-    }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
-    }
-  }
-
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-    }
-  }
-
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-    }
-  }
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
 
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::store_secret_list::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_list<'b>(&mut self, value: crate::api_capnp::store_secret_list::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_list(self) -> crate::api_capnp::store_secret_list::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_list(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.reader.total_size()
-    }
-    #[inline]
-    pub fn get_locked(self) -> bool {
-      self.reader.get_bool_field(0)
-    }
-    #[inline]
-    pub fn get_unlocked_by(
-      self,
-    ) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, crate::api_capnp::identity::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
-    }
-    pub fn has_unlocked_by(&self) -> bool {
-      !self.reader.get_pointer_field(0).is_null()
-    }
-    #[inline]
-    pub fn get_autolock_at(self) -> i64 {
-      self.reader.get_data_field::<i64>(1)
-    }
-    #[inline]
-    pub fn get_version(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
     }
-    pub fn has_version(&self) -> bool {
-      !self.reader.get_pointer_field(1).is_null()
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
     }
-    #[inline]
-    pub fn get_autolock_timeout(self) -> u64 {
-      self.reader.get_data_field::<u64>(2)
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xb0c5_c230_35be_a7c7;
     }
   }
 
-  pub struct Builder<'a> {
-    builder: ::capnp::private::layout::StructBuilder<'a>,
-  }
-  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-    #[inline]
-    fn struct_size() -> ::capnp::private::layout::StructSize {
-      _private::STRUCT_SIZE
+  pub mod lock_all_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-      Builder { builder }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
     }
-  }
 
-  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-      self
-        .builder
-        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
     }
-  }
 
-  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    fn get_from_pointer(
-      builder: ::capnp::private::layout::PointerBuilder<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Builder<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-        builder.get_struct(_private::STRUCT_SIZE, default)?,
-      ))
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-    fn set_pointer_builder<'b>(
-      pointer: ::capnp::private::layout::PointerBuilder<'b>,
-      value: Reader<'a>,
-      canonicalize: bool,
-    ) -> ::capnp::Result<()> {
-      pointer.set_struct(&value.reader, canonicalize)
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
     }
-  }
 
-  impl<'a> Builder<'a> {
-    pub fn into_reader(self) -> Reader<'a> {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
     }
-    pub fn reborrow(&mut self) -> Builder {
-      Builder { ..*self }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
     }
-    pub fn reborrow_as_reader(&self) -> Reader {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
     }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.builder.into_reader().total_size()
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    #[inline]
-    pub fn get_locked(self) -> bool {
-      self.builder.get_bool_field(0)
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
     }
-    #[inline]
-    pub fn set_locked(&mut self, value: bool) {
-      self.builder.set_bool_field(0, value);
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    #[inline]
-    pub fn get_unlocked_by(
-      self,
-    ) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, crate::api_capnp::identity::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
     }
-    #[inline]
-    pub fn set_unlocked_by<'b>(
-      &mut self,
-      value: crate::api_capnp::option::Reader<'b, crate::api_capnp::identity::Owned>,
-    ) -> ::capnp::Result<()> {
-      <crate::api_capnp::option::Reader<'b, crate::api_capnp::identity::Owned> as ::capnp::traits::SetPointerBuilder<
-        crate::api_capnp::option::Builder<'b, crate::api_capnp::identity::Owned>,
-      >>::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
-    }
-    #[inline]
-    pub fn init_unlocked_by(self) -> crate::api_capnp::option::Builder<'a, crate::api_capnp::identity::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
-    }
-    pub fn has_unlocked_by(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
-    }
-    #[inline]
-    pub fn get_autolock_at(self) -> i64 {
-      self.builder.get_data_field::<i64>(1)
-    }
-    #[inline]
-    pub fn set_autolock_at(&mut self, value: i64) {
-      self.builder.set_data_field::<i64>(1, value);
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
     }
-    #[inline]
-    pub fn get_version(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
     }
-    #[inline]
-    pub fn set_version(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(1).set_text(value);
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
     }
-    #[inline]
-    pub fn init_version(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(1).init_text(size)
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
     }
-    pub fn has_version(&self) -> bool {
-      !self.builder.get_pointer_field(1).is_null()
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
     }
-    #[inline]
-    pub fn get_autolock_timeout(self) -> u64 {
-      self.builder.get_data_field::<u64>(2)
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
     }
-    #[inline]
-    pub fn set_autolock_timeout(&mut self, value: u64) {
-      self.builder.set_data_field::<u64>(2, value);
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xed47_89ff_c11d_61ba;
     }
   }
 
-  pub struct Pipeline {
-    _typeless: ::capnp::any_pointer::Pipeline,
-  }
-  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-      Pipeline { _typeless: typeless }
+  pub mod lock_all_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-  impl Pipeline {
-    pub fn get_unlocked_by(&self) -> crate::api_capnp::option::Pipeline<crate::api_capnp::identity::Owned> {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-  mod _private {
-    use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 3, pointers: 2 };
-    pub const TYPE_ID: u64 = 0xcad9_d02c_6a1e_8838;
-  }
-}
-
-#[repr(u16)]
-#[derive(Clone, Copy, PartialEq)]
-pub enum SecretType {
-  Login = 0,
-  Note = 1,
-  Licence = 2,
-  Wlan = 3,
-  Password = 4,
-  Other = 5,
-}
-impl ::capnp::traits::FromU16 for SecretType {
-  #[inline]
-  fn from_u16(value: u16) -> ::core::result::Result<SecretType, ::capnp::NotInSchema> {
-    match value {
-      0 => ::core::result::Result::Ok(SecretType::Login),
-      1 => ::core::result::Result::Ok(SecretType::Note),
-      2 => ::core::result::Result::Ok(SecretType::Licence),
-      3 => ::core::result::Result::Ok(SecretType::Wlan),
-      4 => ::core::result::Result::Ok(SecretType::Password),
-      5 => ::core::result::Result::Ok(SecretType::Other),
-      n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
     }
-  }
-}
-impl ::capnp::traits::ToU16 for SecretType {
-  #[inline]
-  fn to_u16(self) -> u16 {
-    self as u16
-  }
-}
-impl ::capnp::traits::HasTypeId for SecretType {
-  #[inline]
-  fn type_id() -> u64 {
-    0x9872_ebcd_5881_f40bu64
-  }
-}
-
-pub mod secret_entry {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
 
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
 
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
     }
-  }
 
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
     }
-  }
 
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
-    }
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.reader.total_size()
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
     }
-    #[inline]
-    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    pub fn has_id(&self) -> bool {
-      !self.reader.get_pointer_field(0).is_null()
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
     }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x8629_9bbe_69c9_828b;
+    }
+  }
+}
+
+pub mod identity {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
     #[inline]
-    pub fn get_timestamp(self) -> i64 {
-      self.reader.get_data_field::<i64>(0)
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
     }
     #[inline]
-    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(1),
+        &self.reader.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
-    pub fn has_name(&self) -> bool {
-      !self.reader.get_pointer_field(1).is_null()
-    }
-    #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(4))
+    pub fn has_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(2),
+        &self.reader.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
-    pub fn has_tags(&self) -> bool {
-      !self.reader.get_pointer_field(2).is_null()
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+    pub fn get_email(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(3),
+        &self.reader.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
-    pub fn has_urls(&self) -> bool {
-      !self.reader.get_pointer_field(3).is_null()
+    pub fn has_email(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.reader.get_bool_field(80)
+    pub fn get_hidden(self) -> bool {
+      self.reader.get_bool_field(0)
     }
   }
 
@@ -8204,14 +8402,6 @@ pub mod secret_entry {
       !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_timestamp(self) -> i64 {
-      self.builder.get_data_field::<i64>(0)
-    }
-    #[inline]
-    pub fn set_timestamp(&mut self, value: i64) {
-      self.builder.set_data_field::<i64>(0, value);
-    }
-    #[inline]
     pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(1),
@@ -8230,56 +8420,30 @@ pub mod secret_entry {
       !self.builder.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(4))
-    }
-    #[inline]
-    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
-      self.builder.set_data_field::<u16>(4, value as u16)
-    }
-    #[inline]
-    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+    pub fn get_email(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+    pub fn set_email(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(2).set_text(value);
     }
     #[inline]
-    pub fn init_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    pub fn init_email(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(2).init_text(size)
     }
-    pub fn has_tags(&self) -> bool {
+    pub fn has_email(&self) -> bool {
       !self.builder.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(3),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_urls(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
-    }
-    #[inline]
-    pub fn init_urls(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
-    }
-    pub fn has_urls(&self) -> bool {
-      !self.builder.get_pointer_field(3).is_null()
-    }
-    #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.builder.get_bool_field(80)
+    pub fn get_hidden(self) -> bool {
+      self.builder.get_bool_field(0)
     }
     #[inline]
-    pub fn set_deleted(&mut self, value: bool) {
-      self.builder.set_bool_field(80, value);
+    pub fn set_hidden(&mut self, value: bool) {
+      self.builder.set_bool_field(0, value);
     }
   }
 
@@ -8294,12 +8458,12 @@ pub mod secret_entry {
   impl Pipeline {}
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 4 };
-    pub const TYPE_ID: u64 = 0xe906_1a4b_4d54_1be9;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 3 };
+    pub const TYPE_ID: u64 = 0xdecb_1959_2b3c_494a;
   }
 }
 
-pub mod secret_list_filter {
+pub mod status {
   #[derive(Copy, Clone)]
   pub struct Owned;
   impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -8363,48 +8527,38 @@ pub mod secret_list_filter {
       self.reader.total_size()
     }
     #[inline]
-    pub fn get_url(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
+    pub fn get_locked(self) -> bool {
+      self.reader.get_bool_field(0)
+    }
+    #[inline]
+    pub fn get_unlocked_by(
+      self,
+    ) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, crate::api_capnp::identity::Owned>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
-    pub fn has_url(&self) -> bool {
+    pub fn has_unlocked_by(&self) -> bool {
       !self.reader.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_tag(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
+    pub fn get_autolock_at(self) -> i64 {
+      self.reader.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn get_version(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
-    pub fn has_tag(&self) -> bool {
+    pub fn has_version(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_type(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::option_type::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
-    }
-    pub fn has_type(&self) -> bool {
-      !self.reader.get_pointer_field(2).is_null()
-    }
-    #[inline]
-    pub fn get_name(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(3),
-        ::core::option::Option::None,
-      )
-    }
-    pub fn has_name(&self) -> bool {
-      !self.reader.get_pointer_field(3).is_null()
-    }
-    #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.reader.get_bool_field(0)
+    pub fn get_autolock_timeout(self) -> u64 {
+      self.reader.get_data_field::<u64>(2)
     }
   }
 
@@ -8476,102 +8630,71 @@ pub mod secret_list_filter {
       self.builder.into_reader().total_size()
     }
     #[inline]
-    pub fn get_url(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_url<'b>(
-      &mut self,
-      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
-    ) -> ::capnp::Result<()> {
-      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
-        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
-      >>::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    pub fn get_locked(self) -> bool {
+      self.builder.get_bool_field(0)
     }
     #[inline]
-    pub fn init_url(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
-    }
-    pub fn has_url(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
+    pub fn set_locked(&mut self, value: bool) {
+      self.builder.set_bool_field(0, value);
     }
     #[inline]
-    pub fn get_tag(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
+    pub fn get_unlocked_by(
+      self,
+    ) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, crate::api_capnp::identity::Owned>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(1),
+        self.builder.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_tag<'b>(
+    pub fn set_unlocked_by<'b>(
       &mut self,
-      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
+      value: crate::api_capnp::option::Reader<'b, crate::api_capnp::identity::Owned>,
     ) -> ::capnp::Result<()> {
-      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
-        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
-      >>::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+      <crate::api_capnp::option::Reader<'b, crate::api_capnp::identity::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, crate::api_capnp::identity::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
     }
     #[inline]
-    pub fn init_tag(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
-    }
-    pub fn has_tag(&self) -> bool {
-      !self.builder.get_pointer_field(1).is_null()
+    pub fn init_unlocked_by(self) -> crate::api_capnp::option::Builder<'a, crate::api_capnp::identity::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
     }
-    #[inline]
-    pub fn get_type(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::option_type::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
+    pub fn has_unlocked_by(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn set_type<'b>(
-      &mut self,
-      value: crate::api_capnp::secret_list_filter::option_type::Reader<'b>,
-    ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+    pub fn get_autolock_at(self) -> i64 {
+      self.builder.get_data_field::<i64>(1)
     }
     #[inline]
-    pub fn init_type(self) -> crate::api_capnp::secret_list_filter::option_type::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
-    }
-    pub fn has_type(&self) -> bool {
-      !self.builder.get_pointer_field(2).is_null()
+    pub fn set_autolock_at(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(1, value);
     }
     #[inline]
-    pub fn get_name(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
+    pub fn get_version(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(3),
+        self.builder.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_name<'b>(
-      &mut self,
-      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
-    ) -> ::capnp::Result<()> {
-      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
-        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
-      >>::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
+    pub fn set_version(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
     }
     #[inline]
-    pub fn init_name(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+    pub fn init_version(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
     }
-    pub fn has_name(&self) -> bool {
-      !self.builder.get_pointer_field(3).is_null()
+    pub fn has_version(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.builder.get_bool_field(0)
+    pub fn get_autolock_timeout(self) -> u64 {
+      self.builder.get_data_field::<u64>(2)
     }
     #[inline]
-    pub fn set_deleted(&mut self, value: bool) {
-      self.builder.set_bool_field(0, value);
+    pub fn set_autolock_timeout(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(2, value);
     }
   }
 
@@ -8584,320 +8707,185 @@ pub mod secret_list_filter {
     }
   }
   impl Pipeline {
-    pub fn get_url(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
+    pub fn get_unlocked_by(&self) -> crate::api_capnp::option::Pipeline<crate::api_capnp::identity::Owned> {
       ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
     }
-    pub fn get_tag(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
-    }
-    pub fn get_type(&self) -> crate::api_capnp::secret_list_filter::option_type::Pipeline {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(2))
-    }
-    pub fn get_name(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
-    }
   }
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 4 };
-    pub const TYPE_ID: u64 = 0xb647_6a49_2492_a435;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 3, pointers: 2 };
+    pub const TYPE_ID: u64 = 0xcad9_d02c_6a1e_8838;
   }
+}
 
-  pub mod option_type {
-    pub use self::Which::{None, Some};
-
-    #[derive(Copy, Clone)]
-    pub struct Owned;
-    impl<'a> ::capnp::traits::Owned<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum SecretType {
+  Login = 0,
+  Note = 1,
+  Licence = 2,
+  Wlan = 3,
+  Password = 4,
+  Other = 5,
+  Card = 6,
+  Identity = 7,
+}
+impl ::capnp::traits::FromU16 for SecretType {
+  #[inline]
+  fn from_u16(value: u16) -> ::core::result::Result<SecretType, ::capnp::NotInSchema> {
+    match value {
+      0 => ::core::result::Result::Ok(SecretType::Login),
+      1 => ::core::result::Result::Ok(SecretType::Note),
+      2 => ::core::result::Result::Ok(SecretType::Licence),
+      3 => ::core::result::Result::Ok(SecretType::Wlan),
+      4 => ::core::result::Result::Ok(SecretType::Password),
+      5 => ::core::result::Result::Ok(SecretType::Other),
+      6 => ::core::result::Result::Ok(SecretType::Card),
+      7 => ::core::result::Result::Ok(SecretType::Identity),
+      n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
     }
-    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
+  }
+}
+impl ::capnp::traits::ToU16 for SecretType {
+  #[inline]
+  fn to_u16(self) -> u16 {
+    self as u16
+  }
+}
+impl ::capnp::traits::HasTypeId for SecretType {
+  #[inline]
+  fn type_id() -> u64 {
+    0x9872_ebcd_5881_f40bu64
+  }
+}
+
+pub mod secret_entry {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-    impl ::capnp::traits::Pipelined for Owned {
-      type Pipeline = Pipeline;
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
     }
+  }
 
-    #[derive(Clone, Copy)]
-    pub struct Reader<'a> {
-      reader: ::capnp::private::layout::StructReader<'a>,
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
     }
+  }
 
-    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
-    }
-    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-        Reader { reader }
-      }
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
     }
+  }
 
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-      fn get_from_pointer(
-        reader: &::capnp::private::layout::PointerReader<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Reader<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-      }
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
     }
+  }
 
-    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-        self.reader
-      }
-    }
-
-    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-        self
-          .reader
-          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-      }
-    }
-
-    impl<'a> Reader<'a> {
-      pub fn reborrow(&self) -> Reader {
-        Reader { ..*self }
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn which(self) -> ::core::result::Result<WhichReader, ::capnp::NotInSchema> {
-        match self.reader.get_data_field::<u16>(1) {
-          0 => ::core::result::Result::Ok(Some(::capnp::traits::FromU16::from_u16(
-            self.reader.get_data_field::<u16>(0),
-          ))),
-          1 => ::core::result::Result::Ok(None(())),
-          x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
-        }
-      }
-    }
-
-    pub struct Builder<'a> {
-      builder: ::capnp::private::layout::StructBuilder<'a>,
-    }
-    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-      #[inline]
-      fn struct_size() -> ::capnp::private::layout::StructSize {
-        _private::STRUCT_SIZE
-      }
-    }
-    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
-    }
-    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-        Builder { builder }
-      }
-    }
-
-    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-        self
-          .builder
-          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
-      }
-    }
-
-    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
-      }
-      fn get_from_pointer(
-        builder: ::capnp::private::layout::PointerBuilder<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Builder<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-          builder.get_struct(_private::STRUCT_SIZE, default)?,
-        ))
-      }
-    }
-
-    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-      fn set_pointer_builder<'b>(
-        pointer: ::capnp::private::layout::PointerBuilder<'b>,
-        value: Reader<'a>,
-        canonicalize: bool,
-      ) -> ::capnp::Result<()> {
-        pointer.set_struct(&value.reader, canonicalize)
-      }
-    }
-
-    impl<'a> Builder<'a> {
-      pub fn into_reader(self) -> Reader<'a> {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-      pub fn reborrow(&mut self) -> Builder {
-        Builder { ..*self }
-      }
-      pub fn reborrow_as_reader(&self) -> Reader {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.builder.into_reader().total_size()
-      }
-      #[inline]
-      pub fn set_some(&mut self, value: crate::api_capnp::SecretType) {
-        self.builder.set_data_field::<u16>(1, 0);
-        self.builder.set_data_field::<u16>(0, value as u16)
-      }
-      #[inline]
-      pub fn set_none(&mut self, _value: ()) {
-        self.builder.set_data_field::<u16>(1, 1);
-      }
-      #[inline]
-      pub fn which(self) -> ::core::result::Result<WhichBuilder, ::capnp::NotInSchema> {
-        match self.builder.get_data_field::<u16>(1) {
-          0 => ::core::result::Result::Ok(Some(::capnp::traits::FromU16::from_u16(
-            self.builder.get_data_field::<u16>(0),
-          ))),
-          1 => ::core::result::Result::Ok(None(())),
-          x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
-        }
-      }
-    }
-
-    pub struct Pipeline {
-      _typeless: ::capnp::any_pointer::Pipeline,
-    }
-    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-        Pipeline { _typeless: typeless }
-      }
-    }
-    impl Pipeline {}
-    mod _private {
-      use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xfc5e_7ab6_2a94_57cf;
-    }
-    pub enum Which {
-      Some(::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema>),
-      None(()),
-    }
-    pub type WhichReader = Which;
-    pub type WhichBuilder = Which;
-  }
-}
-
-pub mod secret_entry_match {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
-
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
-
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
-    }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
-    }
-  }
-
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-    }
-  }
-
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
-    }
-  }
-
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-    }
-  }
-
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
     }
 
     pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
       self.reader.total_size()
     }
     #[inline]
-    pub fn get_entry(self) -> ::capnp::Result<crate::api_capnp::secret_entry::Reader<'a>> {
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
-    pub fn has_entry(&self) -> bool {
+    pub fn has_id(&self) -> bool {
       !self.reader.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_name_score(self) -> i64 {
+    pub fn get_timestamp(self) -> i64 {
       self.reader.get_data_field::<i64>(0)
     }
     #[inline]
-    pub fn get_name_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
-    pub fn has_name_highlights(&self) -> bool {
+    pub fn has_name(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_url_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(4))
+    }
+    #[inline]
+    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
-    pub fn has_url_highlights(&self) -> bool {
+    pub fn has_tags(&self) -> bool {
       !self.reader.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_tags_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(3),
         ::core::option::Option::None,
       )
     }
-    pub fn has_tags_highlights(&self) -> bool {
+    pub fn has_urls(&self) -> bool {
       !self.reader.get_pointer_field(3).is_null()
     }
+    #[inline]
+    pub fn get_deleted(self) -> bool {
+      self.reader.get_bool_field(80)
+    }
+    #[inline]
+    pub fn get_conflicted(self) -> bool {
+      self.reader.get_bool_field(81)
+    }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.reader.get_data_field::<i64>(2)
+    }
+    #[inline]
+    pub fn get_rotate_every(self) -> u64 {
+      self.reader.get_data_field::<u64>(3)
+    }
   }
 
   pub struct Builder<'a> {
@@ -8968,85 +8956,125 @@ pub mod secret_entry_match {
       self.builder.into_reader().total_size()
     }
     #[inline]
-    pub fn get_entry(self) -> ::capnp::Result<crate::api_capnp::secret_entry::Builder<'a>> {
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_entry<'b>(&mut self, value: crate::api_capnp::secret_entry::Reader<'b>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
     }
     #[inline]
-    pub fn init_entry(self) -> crate::api_capnp::secret_entry::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
     }
-    pub fn has_entry(&self) -> bool {
+    pub fn has_id(&self) -> bool {
       !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_name_score(self) -> i64 {
+    pub fn get_timestamp(self) -> i64 {
       self.builder.get_data_field::<i64>(0)
     }
     #[inline]
-    pub fn set_name_score(&mut self, value: i64) {
+    pub fn set_timestamp(&mut self, value: i64) {
       self.builder.set_data_field::<i64>(0, value);
     }
     #[inline]
-    pub fn get_name_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_name_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
     }
     #[inline]
-    pub fn init_name_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
     }
-    pub fn has_name_highlights(&self) -> bool {
+    pub fn has_name(&self) -> bool {
       !self.builder.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_url_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(4))
+    }
+    #[inline]
+    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
+      self.builder.set_data_field::<u16>(4, value as u16)
+    }
+    #[inline]
+    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_url_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+    pub fn set_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
       ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
     }
     #[inline]
-    pub fn init_url_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+    pub fn init_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
       ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
     }
-    pub fn has_url_highlights(&self) -> bool {
+    pub fn has_tags(&self) -> bool {
       !self.builder.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_tags_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
+    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(3),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_tags_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+    pub fn set_urls(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
       ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
     }
     #[inline]
-    pub fn init_tags_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+    pub fn init_urls(self, size: u32) -> ::capnp::text_list::Builder<'a> {
       ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
     }
-    pub fn has_tags_highlights(&self) -> bool {
+    pub fn has_urls(&self) -> bool {
       !self.builder.get_pointer_field(3).is_null()
     }
+    #[inline]
+    pub fn get_deleted(self) -> bool {
+      self.builder.get_bool_field(80)
+    }
+    #[inline]
+    pub fn set_deleted(&mut self, value: bool) {
+      self.builder.set_bool_field(80, value);
+    }
+    #[inline]
+    pub fn get_conflicted(self) -> bool {
+      self.builder.get_bool_field(81)
+    }
+    #[inline]
+    pub fn set_conflicted(&mut self, value: bool) {
+      self.builder.set_bool_field(81, value);
+    }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.builder.get_data_field::<i64>(2)
+    }
+    #[inline]
+    pub fn set_expires_at(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(2, value);
+    }
+    #[inline]
+    pub fn get_rotate_every(self) -> u64 {
+      self.builder.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn set_rotate_every(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(3, value);
+    }
   }
 
   pub struct Pipeline {
@@ -9057,19 +9085,15 @@ pub mod secret_entry_match {
       Pipeline { _typeless: typeless }
     }
   }
-  impl Pipeline {
-    pub fn get_entry(&self) -> crate::api_capnp::secret_entry::Pipeline {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-    }
-  }
+  impl Pipeline {}
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 4 };
-    pub const TYPE_ID: u64 = 0xa436_0d13_81d8_d993;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 4, pointers: 4 };
+    pub const TYPE_ID: u64 = 0xe906_1a4b_4d54_1be9;
   }
 }
 
-pub mod secret_list {
+pub mod secret_list_filter {
   #[derive(Copy, Clone)]
   pub struct Owned;
   impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -9133,27 +9157,77 @@ pub mod secret_list {
       self.reader.total_size()
     }
     #[inline]
-    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+    pub fn get_url(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
-    pub fn has_all_tags(&self) -> bool {
+    pub fn has_url(&self) -> bool {
       !self.reader.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_entries(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_entry_match::Owned>> {
+    pub fn get_tag(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
-    pub fn has_entries(&self) -> bool {
+    pub fn has_tag(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
+    #[inline]
+    pub fn get_type(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::option_type::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_type(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_deleted(self) -> bool {
+      self.reader.get_bool_field(0)
+    }
+    #[inline]
+    pub fn get_expired(self) -> bool {
+      self.reader.get_bool_field(1)
+    }
+    #[inline]
+    pub fn get_expiring_within(self) -> u64 {
+      self.reader.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn get_text(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_text(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_query(self) -> ::capnp::Result<crate::api_capnp::option::Reader<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(5),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_query(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
   }
 
   pub struct Builder<'a> {
@@ -9224,572 +9298,291 @@ pub mod secret_list {
       self.builder.into_reader().total_size()
     }
     #[inline]
-    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+    pub fn get_url(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_all_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    pub fn set_url<'b>(
+      &mut self,
+      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
+    ) -> ::capnp::Result<()> {
+      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
     }
     #[inline]
-    pub fn init_all_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    pub fn init_url(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
     }
-    pub fn has_all_tags(&self) -> bool {
+    pub fn has_url(&self) -> bool {
       !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_entries(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_entry_match::Owned>> {
+    pub fn get_tag(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_entries(
+    pub fn set_tag<'b>(
       &mut self,
-      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_entry_match::Owned>,
+      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
     ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
     }
     #[inline]
-    pub fn init_entries(
-      self,
-      size: u32,
-    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_entry_match::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    pub fn init_tag(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
     }
-    pub fn has_entries(&self) -> bool {
+    pub fn has_tag(&self) -> bool {
       !self.builder.get_pointer_field(1).is_null()
     }
-  }
-
-  pub struct Pipeline {
-    _typeless: ::capnp::any_pointer::Pipeline,
-  }
-  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-      Pipeline { _typeless: typeless }
-    }
-  }
-  impl Pipeline {}
-  mod _private {
-    use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
-    pub const TYPE_ID: u64 = 0x8344_d850_fd4f_f685;
-  }
-}
-
-pub mod secret_version {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
-
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
-
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
     #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
-    }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
-    }
-  }
-
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-    }
-  }
-
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
+    pub fn get_type(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::option_type::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
     }
-  }
-
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    #[inline]
+    pub fn set_type<'b>(
+      &mut self,
+      value: crate::api_capnp::secret_list_filter::option_type::Reader<'b>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
     }
-  }
-
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
+    #[inline]
+    pub fn init_type(self) -> crate::api_capnp::secret_list_filter::option_type::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), 0)
     }
-
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.reader.total_size()
+    pub fn has_type(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(0),
+    pub fn get_name(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(3),
         ::core::option::Option::None,
       )
     }
-    pub fn has_secret_id(&self) -> bool {
-      !self.reader.get_pointer_field(0).is_null()
+    #[inline]
+    pub fn set_name<'b>(
+      &mut self,
+      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
+    ) -> ::capnp::Result<()> {
+      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
     }
     #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
+    pub fn init_name(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), 0)
+    }
+    pub fn has_name(&self) -> bool {
+      !self.builder.get_pointer_field(3).is_null()
     }
     #[inline]
-    pub fn get_timestamp(self) -> i64 {
-      self.reader.get_data_field::<i64>(1)
+    pub fn get_deleted(self) -> bool {
+      self.builder.get_bool_field(0)
     }
     #[inline]
-    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
+    pub fn set_deleted(&mut self, value: bool) {
+      self.builder.set_bool_field(0, value);
     }
-    pub fn has_name(&self) -> bool {
-      !self.reader.get_pointer_field(1).is_null()
+    #[inline]
+    pub fn get_expired(self) -> bool {
+      self.builder.get_bool_field(1)
     }
     #[inline]
-    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
+    pub fn set_expired(&mut self, value: bool) {
+      self.builder.set_bool_field(1, value);
     }
-    pub fn has_tags(&self) -> bool {
-      !self.reader.get_pointer_field(2).is_null()
+    #[inline]
+    pub fn get_expiring_within(self) -> u64 {
+      self.builder.get_data_field::<u64>(1)
     }
     #[inline]
-    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(3),
+    pub fn set_expiring_within(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(1, value);
+    }
+    #[inline]
+    pub fn get_text(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(4),
         ::core::option::Option::None,
       )
     }
-    pub fn has_urls(&self) -> bool {
-      !self.reader.get_pointer_field(3).is_null()
+    #[inline]
+    pub fn set_text<'b>(
+      &mut self,
+      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
+    ) -> ::capnp::Result<()> {
+      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
     }
     #[inline]
-    pub fn get_properties(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::property::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(4),
-        ::core::option::Option::None,
-      )
+    pub fn init_text(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), 0)
     }
-    pub fn has_properties(&self) -> bool {
-      !self.reader.get_pointer_field(4).is_null()
+    pub fn has_text(&self) -> bool {
+      !self.builder.get_pointer_field(4).is_null()
     }
     #[inline]
-    pub fn get_attachments(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::attachment::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(5),
+    pub fn get_query(self) -> ::capnp::Result<crate::api_capnp::option::Builder<'a, ::capnp::text::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(5),
         ::core::option::Option::None,
       )
     }
-    pub fn has_attachments(&self) -> bool {
-      !self.reader.get_pointer_field(5).is_null()
-    }
     #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.reader.get_bool_field(16)
+    pub fn set_query<'b>(
+      &mut self,
+      value: crate::api_capnp::option::Reader<'b, ::capnp::text::Owned>,
+    ) -> ::capnp::Result<()> {
+      <crate::api_capnp::option::Reader<'b, ::capnp::text::Owned> as ::capnp::traits::SetPointerBuilder<
+        crate::api_capnp::option::Builder<'b, ::capnp::text::Owned>,
+      >>::set_pointer_builder(self.builder.get_pointer_field(5), value, false)
     }
     #[inline]
-    pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(6),
-        ::core::option::Option::None,
-      )
+    pub fn init_query(self) -> crate::api_capnp::option::Builder<'a, ::capnp::text::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), 0)
     }
-    pub fn has_recipients(&self) -> bool {
-      !self.reader.get_pointer_field(6).is_null()
+    pub fn has_query(&self) -> bool {
+      !self.builder.get_pointer_field(5).is_null()
     }
   }
 
-  pub struct Builder<'a> {
-    builder: ::capnp::private::layout::StructBuilder<'a>,
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
   }
-  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-    #[inline]
-    fn struct_size() -> ::capnp::private::layout::StructSize {
-      _private::STRUCT_SIZE
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
     }
   }
-  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+  impl Pipeline {
+    pub fn get_url(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
     }
-  }
-  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-      Builder { builder }
+    pub fn get_tag(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
     }
-  }
-
-  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-      self
-        .builder
-        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    pub fn get_type(&self) -> crate::api_capnp::secret_list_filter::option_type::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(2))
     }
+    pub fn get_name(&self) -> crate::api_capnp::option::Pipeline<::capnp::text::Owned> {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(3))
+    }
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 6 };
+    pub const TYPE_ID: u64 = 0xb647_6a49_2492_a435;
   }
 
-  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+  pub mod option_type {
+    pub use self::Which::{None, Some};
+
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-    fn get_from_pointer(
-      builder: ::capnp::private::layout::PointerBuilder<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Builder<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-        builder.get_struct(_private::STRUCT_SIZE, default)?,
-      ))
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
     }
-  }
-
-  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-    fn set_pointer_builder<'b>(
-      pointer: ::capnp::private::layout::PointerBuilder<'b>,
-      value: Reader<'a>,
-      canonicalize: bool,
-    ) -> ::capnp::Result<()> {
-      pointer.set_struct(&value.reader, canonicalize)
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
     }
-  }
 
-  impl<'a> Builder<'a> {
-    pub fn into_reader(self) -> Reader<'a> {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
     }
-    pub fn reborrow(&mut self) -> Builder {
-      Builder { ..*self }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    pub fn reborrow_as_reader(&self) -> Reader {
-      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
     }
 
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.builder.into_reader().total_size()
-    }
-    #[inline]
-    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(0),
-        ::core::option::Option::None,
-      )
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
     }
-    #[inline]
-    pub fn set_secret_id(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(0).set_text(value);
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
     }
-    #[inline]
-    pub fn init_secret_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(0).init_text(size)
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
     }
-    pub fn has_secret_id(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn which(self) -> ::core::result::Result<WhichReader, ::capnp::NotInSchema> {
+        match self.reader.get_data_field::<u16>(1) {
+          0 => ::core::result::Result::Ok(Some(::capnp::traits::FromU16::from_u16(
+            self.reader.get_data_field::<u16>(0),
+          ))),
+          1 => ::core::result::Result::Ok(None(())),
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+        }
+      }
     }
-    #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    #[inline]
-    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
-      self.builder.set_data_field::<u16>(0, value as u16)
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
     }
-    #[inline]
-    pub fn get_timestamp(self) -> i64 {
-      self.builder.get_data_field::<i64>(1)
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    #[inline]
-    pub fn set_timestamp(&mut self, value: i64) {
-      self.builder.set_data_field::<i64>(1, value);
-    }
-    #[inline]
-    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(1),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(1).set_text(value);
-    }
-    #[inline]
-    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(1).init_text(size)
-    }
-    pub fn has_name(&self) -> bool {
-      !self.builder.get_pointer_field(1).is_null()
-    }
-    #[inline]
-    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(2),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
-    }
-    #[inline]
-    pub fn init_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
-    }
-    pub fn has_tags(&self) -> bool {
-      !self.builder.get_pointer_field(2).is_null()
-    }
-    #[inline]
-    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(3),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_urls(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
-    }
-    #[inline]
-    pub fn init_urls(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
-    }
-    pub fn has_urls(&self) -> bool {
-      !self.builder.get_pointer_field(3).is_null()
-    }
-    #[inline]
-    pub fn get_properties(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::property::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(4),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_properties(
-      &mut self,
-      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::property::Owned>,
-    ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
-    }
-    #[inline]
-    pub fn init_properties(
-      self,
-      size: u32,
-    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::property::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
-    }
-    pub fn has_properties(&self) -> bool {
-      !self.builder.get_pointer_field(4).is_null()
-    }
-    #[inline]
-    pub fn get_attachments(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::attachment::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(5),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_attachments(
-      &mut self,
-      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::attachment::Owned>,
-    ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(5), value, false)
-    }
-    #[inline]
-    pub fn init_attachments(
-      self,
-      size: u32,
-    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::attachment::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
-    }
-    pub fn has_attachments(&self) -> bool {
-      !self.builder.get_pointer_field(5).is_null()
-    }
-    #[inline]
-    pub fn get_deleted(self) -> bool {
-      self.builder.get_bool_field(16)
-    }
-    #[inline]
-    pub fn set_deleted(&mut self, value: bool) {
-      self.builder.set_bool_field(16, value);
-    }
-    #[inline]
-    pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(6),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_recipients(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(6), value, false)
-    }
-    #[inline]
-    pub fn init_recipients(self, size: u32) -> ::capnp::text_list::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(6), size)
-    }
-    pub fn has_recipients(&self) -> bool {
-      !self.builder.get_pointer_field(6).is_null()
-    }
-  }
-
-  pub struct Pipeline {
-    _typeless: ::capnp::any_pointer::Pipeline,
-  }
-  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-      Pipeline { _typeless: typeless }
-    }
-  }
-  impl Pipeline {}
-  mod _private {
-    use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 7 };
-    pub const TYPE_ID: u64 = 0x9cc9_80f1_0099_83c6;
-  }
-
-  pub mod property {
-    #[derive(Copy, Clone)]
-    pub struct Owned;
-    impl<'a> ::capnp::traits::Owned<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
-    }
-    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
-    }
-    impl ::capnp::traits::Pipelined for Owned {
-      type Pipeline = Pipeline;
-    }
-
-    #[derive(Clone, Copy)]
-    pub struct Reader<'a> {
-      reader: ::capnp::private::layout::StructReader<'a>,
-    }
-
-    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
-    }
-    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-        Reader { reader }
-      }
-    }
-
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-      fn get_from_pointer(
-        reader: &::capnp::private::layout::PointerReader<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Reader<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-      }
-    }
-
-    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-        self.reader
-      }
-    }
-
-    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-        self
-          .reader
-          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-      }
-    }
-
-    impl<'a> Reader<'a> {
-      pub fn reborrow(&self) -> Reader {
-        Reader { ..*self }
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_key(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_value(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_value(&self) -> bool {
-        !self.reader.get_pointer_field(1).is_null()
-      }
-    }
-
-    pub struct Builder<'a> {
-      builder: ::capnp::private::layout::StructBuilder<'a>,
-    }
-    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-      #[inline]
-      fn struct_size() -> ::capnp::private::layout::StructSize {
-        _private::STRUCT_SIZE
-      }
-    }
-    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
-    }
-    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-        Builder { builder }
-      }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
     }
 
     impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
@@ -9839,40 +9632,23 @@ pub mod secret_version {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_key(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
-      }
-      #[inline]
-      pub fn init_key(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
-      }
-      pub fn has_key(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_value(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
+      pub fn set_some(&mut self, value: crate::api_capnp::SecretType) {
+        self.builder.set_data_field::<u16>(1, 0);
+        self.builder.set_data_field::<u16>(0, value as u16)
       }
       #[inline]
-      pub fn set_value(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(1).set_text(value);
+      pub fn set_none(&mut self, _value: ()) {
+        self.builder.set_data_field::<u16>(1, 1);
       }
       #[inline]
-      pub fn init_value(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(1).init_text(size)
-      }
-      pub fn has_value(&self) -> bool {
-        !self.builder.get_pointer_field(1).is_null()
+      pub fn which(self) -> ::core::result::Result<WhichBuilder, ::capnp::NotInSchema> {
+        match self.builder.get_data_field::<u16>(1) {
+          0 => ::core::result::Result::Ok(Some(::capnp::traits::FromU16::from_u16(
+            self.builder.get_data_field::<u16>(0),
+          ))),
+          1 => ::core::result::Result::Ok(None(())),
+          x => ::core::result::Result::Err(::capnp::NotInSchema(x)),
+        }
       }
     }
 
@@ -9887,330 +9663,136 @@ pub mod secret_version {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
-      pub const TYPE_ID: u64 = 0xd3a4_d2a9_74e8_2093;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xfc5e_7ab6_2a94_57cf;
+    }
+    pub enum Which {
+      Some(::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema>),
+      None(()),
     }
+    pub type WhichReader = Which;
+    pub type WhichBuilder = Which;
   }
+}
 
-  pub mod attachment {
-    #[derive(Copy, Clone)]
-    pub struct Owned;
-    impl<'a> ::capnp::traits::Owned<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
-    }
-    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
-    }
-    impl ::capnp::traits::Pipelined for Owned {
-      type Pipeline = Pipeline;
-    }
+pub mod secret_entry_match {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
 
-    #[derive(Clone, Copy)]
-    pub struct Reader<'a> {
-      reader: ::capnp::private::layout::StructReader<'a>,
-    }
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
 
-    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-        Reader { reader }
-      }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
     }
+  }
 
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-      fn get_from_pointer(
-        reader: &::capnp::private::layout::PointerReader<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Reader<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-      }
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
     }
+  }
 
-    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-        self.reader
-      }
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
     }
+  }
 
-    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-        self
-          .reader
-          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-      }
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
     }
+  }
 
-    impl<'a> Reader<'a> {
-      pub fn reborrow(&self) -> Reader {
-        Reader { ..*self }
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_name(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_mime_type(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_mime_type(&self) -> bool {
-        !self.reader.get_pointer_field(1).is_null()
-      }
-      #[inline]
-      pub fn get_content(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(2),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_content(&self) -> bool {
-        !self.reader.get_pointer_field(2).is_null()
-      }
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
     }
 
-    pub struct Builder<'a> {
-      builder: ::capnp::private::layout::StructBuilder<'a>,
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
     }
-    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-      #[inline]
-      fn struct_size() -> ::capnp::private::layout::StructSize {
-        _private::STRUCT_SIZE
-      }
+    #[inline]
+    pub fn get_entry(self) -> ::capnp::Result<crate::api_capnp::secret_entry::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
-    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
+    pub fn has_entry(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
     }
-    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-        Builder { builder }
-      }
+    #[inline]
+    pub fn get_name_score(self) -> i64 {
+      self.reader.get_data_field::<i64>(0)
     }
-
-    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-        self
-          .builder
-          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
-      }
+    #[inline]
+    pub fn get_name_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
     }
-
-    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
-      }
-      fn get_from_pointer(
-        builder: ::capnp::private::layout::PointerBuilder<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Builder<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-          builder.get_struct(_private::STRUCT_SIZE, default)?,
-        ))
-      }
+    pub fn has_name_highlights(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
     }
-
-    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-      fn set_pointer_builder<'b>(
-        pointer: ::capnp::private::layout::PointerBuilder<'b>,
-        value: Reader<'a>,
-        canonicalize: bool,
-      ) -> ::capnp::Result<()> {
-        pointer.set_struct(&value.reader, canonicalize)
-      }
+    #[inline]
+    pub fn get_url_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
     }
-
-    impl<'a> Builder<'a> {
-      pub fn into_reader(self) -> Reader<'a> {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-      pub fn reborrow(&mut self) -> Builder {
-        Builder { ..*self }
-      }
-      pub fn reborrow_as_reader(&self) -> Reader {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.builder.into_reader().total_size()
-      }
-      #[inline]
-      pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_name(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
-      }
-      #[inline]
-      pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
-      }
-      pub fn has_name(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_mime_type(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_mime_type(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(1).set_text(value);
-      }
-      #[inline]
-      pub fn init_mime_type(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(1).init_text(size)
-      }
-      pub fn has_mime_type(&self) -> bool {
-        !self.builder.get_pointer_field(1).is_null()
-      }
-      #[inline]
-      pub fn get_content(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(2),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_content(&mut self, value: ::capnp::data::Reader) {
-        self.builder.get_pointer_field(2).set_data(value);
-      }
-      #[inline]
-      pub fn init_content(self, size: u32) -> ::capnp::data::Builder<'a> {
-        self.builder.get_pointer_field(2).init_data(size)
-      }
-      pub fn has_content(&self) -> bool {
-        !self.builder.get_pointer_field(2).is_null()
-      }
-    }
-
-    pub struct Pipeline {
-      _typeless: ::capnp::any_pointer::Pipeline,
-    }
-    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-        Pipeline { _typeless: typeless }
-      }
-    }
-    impl Pipeline {}
-    mod _private {
-      use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 3 };
-      pub const TYPE_ID: u64 = 0x855d_d661_fc7b_18e6;
-    }
-  }
-}
-
-pub mod password_strength {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
-
-  #[derive(Clone, Copy)]
-  pub struct Reader<'a> {
-    reader: ::capnp::private::layout::StructReader<'a>,
-  }
-
-  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
-    }
-  }
-  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-      Reader { reader }
-    }
-  }
-
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Reader<'a>> {
-      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-    }
-  }
-
-  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-      self.reader
-    }
-  }
-
-  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-      self
-        .reader
-        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-    }
-  }
-
-  impl<'a> Reader<'a> {
-    pub fn reborrow(&self) -> Reader {
-      Reader { ..*self }
-    }
-
-    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-      self.reader.total_size()
+    pub fn has_url_highlights(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
     }
     #[inline]
-    pub fn get_entropy(self) -> f64 {
-      self.reader.get_data_field::<f64>(0)
+    pub fn get_tags_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
     }
-    #[inline]
-    pub fn get_crack_time(self) -> f64 {
-      self.reader.get_data_field::<f64>(1)
+    pub fn has_tags_highlights(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
     }
     #[inline]
-    pub fn get_crack_time_display(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+    pub fn get_text_matches(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::text_match::Owned>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(0),
+        &self.reader.get_pointer_field(4),
         ::core::option::Option::None,
       )
     }
-    pub fn has_crack_time_display(&self) -> bool {
-      !self.reader.get_pointer_field(0).is_null()
-    }
-    #[inline]
-    pub fn get_score(self) -> u8 {
-      self.reader.get_data_field::<u8>(16)
+    pub fn has_text_matches(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
     }
   }
 
@@ -10282,79 +9864,147 @@ pub mod password_strength {
       self.builder.into_reader().total_size()
     }
     #[inline]
-    pub fn get_entropy(self) -> f64 {
-      self.builder.get_data_field::<f64>(0)
+    pub fn get_entry(self) -> ::capnp::Result<crate::api_capnp::secret_entry::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
     #[inline]
-    pub fn set_entropy(&mut self, value: f64) {
-      self.builder.set_data_field::<f64>(0, value);
+    pub fn set_entry<'b>(&mut self, value: crate::api_capnp::secret_entry::Reader<'b>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
     }
     #[inline]
-    pub fn get_crack_time(self) -> f64 {
-      self.builder.get_data_field::<f64>(1)
+    pub fn init_entry(self) -> crate::api_capnp::secret_entry::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+    }
+    pub fn has_entry(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn set_crack_time(&mut self, value: f64) {
-      self.builder.set_data_field::<f64>(1, value);
+    pub fn get_name_score(self) -> i64 {
+      self.builder.get_data_field::<i64>(0)
     }
     #[inline]
-    pub fn get_crack_time_display(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+    pub fn set_name_score(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(0, value);
+    }
+    #[inline]
+    pub fn get_name_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(0),
+        self.builder.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_crack_time_display(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(0).set_text(value);
+    pub fn set_name_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
     }
     #[inline]
-    pub fn init_crack_time_display(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(0).init_text(size)
+    pub fn init_name_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
     }
-    pub fn has_crack_time_display(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
+    pub fn has_name_highlights(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_score(self) -> u8 {
-      self.builder.get_data_field::<u8>(16)
+    pub fn get_url_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
     }
     #[inline]
-    pub fn set_score(&mut self, value: u8) {
-      self.builder.set_data_field::<u8>(16, value);
+    pub fn set_url_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
     }
-  }
-
-  pub struct Pipeline {
-    _typeless: ::capnp::any_pointer::Pipeline,
-  }
-  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-      Pipeline { _typeless: typeless }
+    #[inline]
+    pub fn init_url_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
     }
-  }
-  impl Pipeline {}
-  mod _private {
-    use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 3, pointers: 1 };
-    pub const TYPE_ID: u64 = 0xa994_a4b4_4f21_4077;
-  }
-}
-
-pub mod secret {
-  #[derive(Copy, Clone)]
-  pub struct Owned;
-  impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-    type Reader = Reader<'a>;
-    type Builder = Builder<'a>;
-  }
-  impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Pipeline;
-  }
+    pub fn has_url_highlights(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_tags_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_tags_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
+    }
+    #[inline]
+    pub fn init_tags_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+    }
+    pub fn has_tags_highlights(&self) -> bool {
+      !self.builder.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_text_matches(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::text_match::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_text_matches(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::text_match::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
+    }
+    #[inline]
+    pub fn init_text_matches(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::text_match::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+    }
+    pub fn has_text_matches(&self) -> bool {
+      !self.builder.get_pointer_field(4).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {
+    pub fn get_entry(&self) -> crate::api_capnp::secret_entry::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+    }
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 5 };
+    pub const TYPE_ID: u64 = 0xa436_0d13_81d8_d993;
+  }
+}
+
+pub mod text_match {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
 
   #[derive(Clone, Copy)]
   pub struct Reader<'a> {
@@ -10405,63 +10055,35 @@ pub mod secret {
       self.reader.total_size()
     }
     #[inline]
-    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
-    pub fn has_id(&self) -> bool {
+    pub fn has_property(&self) -> bool {
       !self.reader.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
-    }
-    #[inline]
-    pub fn get_current(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
+    pub fn get_text(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
-    pub fn has_current(&self) -> bool {
+    pub fn has_text(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_current_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+    pub fn get_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a, u64>> {
       ::capnp::traits::FromPointerReader::get_from_pointer(
         &self.reader.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
-    pub fn has_current_block_id(&self) -> bool {
+    pub fn has_highlights(&self) -> bool {
       !self.reader.get_pointer_field(2).is_null()
     }
-    #[inline]
-    pub fn get_versions(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(3),
-        ::core::option::Option::None,
-      )
-    }
-    pub fn has_versions(&self) -> bool {
-      !self.reader.get_pointer_field(3).is_null()
-    }
-    #[inline]
-    pub fn get_password_strengths(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret::estimate::Owned>> {
-      ::capnp::traits::FromPointerReader::get_from_pointer(
-        &self.reader.get_pointer_field(4),
-        ::core::option::Option::None,
-      )
-    }
-    pub fn has_password_strengths(&self) -> bool {
-      !self.reader.get_pointer_field(4).is_null()
-    }
   }
 
   pub struct Builder<'a> {
@@ -10532,119 +10154,59 @@ pub mod secret {
       self.builder.into_reader().total_size()
     }
     #[inline]
-    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(0),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+    pub fn set_property(&mut self, value: ::capnp::text::Reader) {
       self.builder.get_pointer_field(0).set_text(value);
     }
     #[inline]
-    pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+    pub fn init_property(self, size: u32) -> ::capnp::text::Builder<'a> {
       self.builder.get_pointer_field(0).init_text(size)
     }
-    pub fn has_id(&self) -> bool {
+    pub fn has_property(&self) -> bool {
       !self.builder.get_pointer_field(0).is_null()
     }
     #[inline]
-    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
-      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
-    }
-    #[inline]
-    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
-      self.builder.set_data_field::<u16>(0, value as u16)
-    }
-    #[inline]
-    pub fn get_current(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
+    pub fn get_text(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(1),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_current<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+    pub fn set_text(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
     }
     #[inline]
-    pub fn init_current(self) -> crate::api_capnp::secret_version::Builder<'a> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+    pub fn init_text(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
     }
-    pub fn has_current(&self) -> bool {
+    pub fn has_text(&self) -> bool {
       !self.builder.get_pointer_field(1).is_null()
     }
     #[inline]
-    pub fn get_current_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+    pub fn get_highlights(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a, u64>> {
       ::capnp::traits::FromPointerBuilder::get_from_pointer(
         self.builder.get_pointer_field(2),
         ::core::option::Option::None,
       )
     }
     #[inline]
-    pub fn set_current_block_id(&mut self, value: ::capnp::text::Reader) {
-      self.builder.get_pointer_field(2).set_text(value);
+    pub fn set_highlights(&mut self, value: ::capnp::primitive_list::Reader<'a, u64>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
     }
     #[inline]
-    pub fn init_current_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-      self.builder.get_pointer_field(2).init_text(size)
+    pub fn init_highlights(self, size: u32) -> ::capnp::primitive_list::Builder<'a, u64> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
     }
-    pub fn has_current_block_id(&self) -> bool {
+    pub fn has_highlights(&self) -> bool {
       !self.builder.get_pointer_field(2).is_null()
     }
-    #[inline]
-    pub fn get_versions(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(3),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_versions(
-      &mut self,
-      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>,
-    ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
-    }
-    #[inline]
-    pub fn init_versions(
-      self,
-      size: u32,
-    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
-    }
-    pub fn has_versions(&self) -> bool {
-      !self.builder.get_pointer_field(3).is_null()
-    }
-    #[inline]
-    pub fn get_password_strengths(
-      self,
-    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret::estimate::Owned>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(
-        self.builder.get_pointer_field(4),
-        ::core::option::Option::None,
-      )
-    }
-    #[inline]
-    pub fn set_password_strengths(
-      &mut self,
-      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret::estimate::Owned>,
-    ) -> ::capnp::Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
-    }
-    #[inline]
-    pub fn init_password_strengths(
-      self,
-      size: u32,
-    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret::estimate::Owned> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
-    }
-    pub fn has_password_strengths(&self) -> bool {
-      !self.builder.get_pointer_field(4).is_null()
-    }
   }
 
   pub struct Pipeline {
@@ -10655,753 +10217,6346 @@ pub mod secret {
       Pipeline { _typeless: typeless }
     }
   }
-  impl Pipeline {
-    pub fn get_current(&self) -> crate::api_capnp::secret_version::Pipeline {
-      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
-    }
-  }
+  impl Pipeline {}
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 5 };
-    pub const TYPE_ID: u64 = 0x83d4_6332_ba1a_4ae3;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 3 };
+    pub const TYPE_ID: u64 = 0xbff5_3620_3f41_bf05;
   }
+}
 
-  pub mod estimate {
-    #[derive(Copy, Clone)]
-    pub struct Owned;
-    impl<'a> ::capnp::traits::Owned<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
-    }
-    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
+pub mod secret_list {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-    impl ::capnp::traits::Pipelined for Owned {
-      type Pipeline = Pipeline;
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
     }
+  }
 
-    #[derive(Clone, Copy)]
-    pub struct Reader<'a> {
-      reader: ::capnp::private::layout::StructReader<'a>,
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
     }
+  }
 
-    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
-    }
-    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-        Reader { reader }
-      }
-    }
-
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-      fn get_from_pointer(
-        reader: &::capnp::private::layout::PointerReader<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Reader<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-      }
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
     }
+  }
 
-    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-        self.reader
-      }
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
     }
+  }
 
-    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-        self
-          .reader
-          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-      }
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
     }
 
-    impl<'a> Reader<'a> {
-      pub fn reborrow(&self) -> Reader {
-        Reader { ..*self }
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_key(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_strength(self) -> ::capnp::Result<crate::api_capnp::password_strength::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_strength(&self) -> bool {
-        !self.reader.get_pointer_field(1).is_null()
-      }
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
     }
-
-    pub struct Builder<'a> {
-      builder: ::capnp::private::layout::StructBuilder<'a>,
+    #[inline]
+    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
-    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-      #[inline]
-      fn struct_size() -> ::capnp::private::layout::StructSize {
-        _private::STRUCT_SIZE
-      }
+    pub fn has_all_tags(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
     }
-    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
+    #[inline]
+    pub fn get_entries(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_entry_match::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
     }
-    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-        Builder { builder }
-      }
+    pub fn has_entries(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
     }
+  }
 
-    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-        self
-          .builder
-          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
-      }
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
     }
-
-    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
-      }
-      fn get_from_pointer(
-        builder: ::capnp::private::layout::PointerBuilder<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Builder<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-          builder.get_struct(_private::STRUCT_SIZE, default)?,
-        ))
-      }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-
-    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-      fn set_pointer_builder<'b>(
-        pointer: ::capnp::private::layout::PointerBuilder<'b>,
-        value: Reader<'a>,
-        canonicalize: bool,
-      ) -> ::capnp::Result<()> {
-        pointer.set_struct(&value.reader, canonicalize)
-      }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
     }
+  }
 
-    impl<'a> Builder<'a> {
-      pub fn into_reader(self) -> Reader<'a> {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-      pub fn reborrow(&mut self) -> Builder {
-        Builder { ..*self }
-      }
-      pub fn reborrow_as_reader(&self) -> Reader {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.builder.into_reader().total_size()
-      }
-      #[inline]
-      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_key(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
-      }
-      #[inline]
-      pub fn init_key(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
-      }
-      pub fn has_key(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_strength(self) -> ::capnp::Result<crate::api_capnp::password_strength::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_strength<'b>(
-        &mut self,
-        value: crate::api_capnp::password_strength::Reader<'b>,
-      ) -> ::capnp::Result<()> {
-        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
-      }
-      #[inline]
-      pub fn init_strength(self) -> crate::api_capnp::password_strength::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
-      }
-      pub fn has_strength(&self) -> bool {
-        !self.builder.get_pointer_field(1).is_null()
-      }
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
     }
+  }
 
-    pub struct Pipeline {
-      _typeless: ::capnp::any_pointer::Pipeline,
-    }
-    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-        Pipeline { _typeless: typeless }
-      }
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
     }
-    impl Pipeline {
-      pub fn get_strength(&self) -> crate::api_capnp::password_strength::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
-      }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
     }
-    mod _private {
-      use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
-      pub const TYPE_ID: u64 = 0xeb21_0708_91d3_9f04;
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
     }
   }
 
-  pub mod version_ref {
-    #[derive(Copy, Clone)]
-    pub struct Owned;
-    impl<'a> ::capnp::traits::Owned<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
-    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
-      type Reader = Reader<'a>;
-      type Builder = Builder<'a>;
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
     }
-    impl ::capnp::traits::Pipelined for Owned {
-      type Pipeline = Pipeline;
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
 
-    #[derive(Clone, Copy)]
-    pub struct Reader<'a> {
-      reader: ::capnp::private::layout::StructReader<'a>,
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
     }
-
-    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
+    #[inline]
+    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
-    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
-      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
-        Reader { reader }
-      }
+    #[inline]
+    pub fn set_all_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_all_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_all_tags(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_entries(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_entry_match::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_entries(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_entry_match::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_entries(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_entry_match::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    pub fn has_entries(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
     }
+  }
 
-    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
-      fn get_from_pointer(
-        reader: &::capnp::private::layout::PointerReader<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Reader<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
-      }
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
     }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const TYPE_ID: u64 = 0x8344_d850_fd4f_f685;
+  }
+}
 
-    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
-      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
-        self.reader
-      }
+pub mod store_entry_match {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
     }
+  }
 
-    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
-      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
-        self
-          .reader
-          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
-      }
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
     }
+  }
 
-    impl<'a> Reader<'a> {
-      pub fn reborrow(&self) -> Reader {
-        Reader { ..*self }
-      }
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
 
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_block_id(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_timestamp(self) -> i64 {
-        self.reader.get_data_field::<i64>(0)
-      }
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
     }
+  }
 
-    pub struct Builder<'a> {
-      builder: ::capnp::private::layout::StructBuilder<'a>,
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
     }
-    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
-      #[inline]
-      fn struct_size() -> ::capnp::private::layout::StructSize {
-        _private::STRUCT_SIZE
-      }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
     }
-    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
-      #[inline]
-      fn type_id() -> u64 {
-        _private::TYPE_ID
-      }
+    #[inline]
+    pub fn get_store_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
-    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
-      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
-        Builder { builder }
-      }
+    pub fn has_store_name(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
     }
-
-    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
-      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
-        self
-          .builder
-          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
-      }
+    #[inline]
+    pub fn get_entry_match(self) -> ::capnp::Result<crate::api_capnp::secret_entry_match::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_entry_match(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
     }
+  }
 
-    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
-      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
-        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
-      }
-      fn get_from_pointer(
-        builder: ::capnp::private::layout::PointerBuilder<'a>,
-        default: ::core::option::Option<&'a [capnp::Word]>,
-      ) -> ::capnp::Result<Builder<'a>> {
-        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
-          builder.get_struct(_private::STRUCT_SIZE, default)?,
-        ))
-      }
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
     }
+  }
 
-    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
-      fn set_pointer_builder<'b>(
-        pointer: ::capnp::private::layout::PointerBuilder<'b>,
-        value: Reader<'a>,
-        canonicalize: bool,
-      ) -> ::capnp::Result<()> {
-        pointer.set_struct(&value.reader, canonicalize)
-      }
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
     }
+  }
 
-    impl<'a> Builder<'a> {
-      pub fn into_reader(self) -> Reader<'a> {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
-      pub fn reborrow(&mut self) -> Builder {
-        Builder { ..*self }
-      }
-      pub fn reborrow_as_reader(&self) -> Reader {
-        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
-      }
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
 
-      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.builder.into_reader().total_size()
-      }
-      #[inline]
-      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
-      }
-      #[inline]
-      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
-      }
-      pub fn has_block_id(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
-      #[inline]
-      pub fn get_timestamp(self) -> i64 {
-        self.builder.get_data_field::<i64>(0)
-      }
-      #[inline]
-      pub fn set_timestamp(&mut self, value: i64) {
-        self.builder.set_data_field::<i64>(0, value);
-      }
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
     }
+  }
 
-    pub struct Pipeline {
-      _typeless: ::capnp::any_pointer::Pipeline,
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
-    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
-      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
-        Pipeline { _typeless: typeless }
-      }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
     }
-    impl Pipeline {}
-    mod _private {
-      use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xd0d1_ef7f_b6b6_dd69;
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
-  }
-}
 
-pub mod secrets_store {
-  #![allow(unused_variables)]
-  pub type StatusParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::status_params::Owned>;
-  pub type StatusResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::status_results::Owned>;
-  pub type LockParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::lock_params::Owned>;
-  pub type LockResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::lock_results::Owned>;
-  pub type UnlockParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::unlock_params::Owned>;
-  pub type UnlockResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::unlock_results::Owned>;
-  pub type IdentitiesParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::identities_params::Owned>;
-  pub type IdentitiesResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::identities_results::Owned>;
-  pub type AddIdentityParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::add_identity_params::Owned>;
-  pub type AddIdentityResults =
-    ::capnp::capability::Results<crate::api_capnp::secrets_store::add_identity_results::Owned>;
-  pub type ChangePassphraseParams =
-    ::capnp::capability::Params<crate::api_capnp::secrets_store::change_passphrase_params::Owned>;
-  pub type ChangePassphraseResults =
-    ::capnp::capability::Results<crate::api_capnp::secrets_store::change_passphrase_results::Owned>;
-  pub type ListParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::list_params::Owned>;
-  pub type ListResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::list_results::Owned>;
-  pub type UpdateIndexParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::update_index_params::Owned>;
-  pub type UpdateIndexResults =
-    ::capnp::capability::Results<crate::api_capnp::secrets_store::update_index_results::Owned>;
-  pub type AddParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::add_params::Owned>;
-  pub type AddResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::add_results::Owned>;
-  pub type GetParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::get_params::Owned>;
-  pub type GetResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::get_results::Owned>;
-  pub type GetVersionParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::get_version_params::Owned>;
-  pub type GetVersionResults =
-    ::capnp::capability::Results<crate::api_capnp::secrets_store::get_version_results::Owned>;
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_store_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_store_name(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_store_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_store_name(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_entry_match(self) -> ::capnp::Result<crate::api_capnp::secret_entry_match::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_entry_match<'b>(
+      &mut self,
+      value: crate::api_capnp::secret_entry_match::Reader<'b>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_entry_match(self) -> crate::api_capnp::secret_entry_match::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+    }
+    pub fn has_entry_match(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+  }
 
-  pub struct Client {
-    pub client: ::capnp::capability::Client,
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
   }
-  impl ::capnp::capability::FromClientHook for Client {
-    fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
-      Client {
-        client: ::capnp::capability::Client::new(hook),
-      }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
     }
   }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const TYPE_ID: u64 = 0xda80_6d1a_8a8e_35cd;
+  }
+}
+
+pub mod store_secret_list {
   #[derive(Copy, Clone)]
   pub struct Owned;
   impl<'a> ::capnp::traits::Owned<'a> for Owned {
-    type Reader = Client;
-    type Builder = Client;
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
   }
   impl ::capnp::traits::Pipelined for Owned {
-    type Pipeline = Client;
+    type Pipeline = Pipeline;
   }
-  impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
-    fn get_from_pointer(
-      reader: &::capnp::private::layout::PointerReader<'a>,
-      _default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Client> {
-      ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(reader.get_capability()?))
-    }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
   }
-  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
-    fn init_pointer(_builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Client {
-      unimplemented!()
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-    fn get_from_pointer(
-      builder: ::capnp::private::layout::PointerBuilder<'a>,
-      _default: ::core::option::Option<&'a [capnp::Word]>,
-    ) -> ::capnp::Result<Client> {
-      ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(builder.get_capability()?))
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
     }
   }
 
-  impl ::capnp::traits::SetPointerBuilder<Client> for Client {
-    fn set_pointer_builder(
-      pointer: ::capnp::private::layout::PointerBuilder,
-      from: Client,
-      _canonicalize: bool,
-    ) -> ::capnp::Result<()> {
-      pointer.set_capability(from.client.hook);
-      ::core::result::Result::Ok(())
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
     }
   }
-  impl ::capnp::traits::HasTypeId for Client {
-    #[inline]
-    fn type_id() -> u64 {
-      _private::TYPE_ID
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
     }
   }
-  impl Clone for Client {
-    fn clone(&self) -> Client {
-      Client {
-        client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
-      }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
     }
   }
-  impl Client {
-    pub fn status_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::status_params::Owned,
-      crate::api_capnp::secrets_store::status_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 0, None)
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
     }
-    pub fn lock_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::lock_params::Owned,
-      crate::api_capnp::secrets_store::lock_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 1, None)
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
     }
-    pub fn unlock_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::unlock_params::Owned,
-      crate::api_capnp::secrets_store::unlock_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 2, None)
+    #[inline]
+    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
     }
-    pub fn identities_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::identities_params::Owned,
-      crate::api_capnp::secrets_store::identities_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 3, None)
+    pub fn has_all_tags(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
     }
-    pub fn add_identity_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::add_identity_params::Owned,
-      crate::api_capnp::secrets_store::add_identity_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 4, None)
+    #[inline]
+    pub fn get_entries(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::store_entry_match::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
     }
-    pub fn change_passphrase_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::change_passphrase_params::Owned,
-      crate::api_capnp::secrets_store::change_passphrase_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 5, None)
+    pub fn has_entries(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
     }
-    pub fn list_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::list_params::Owned,
-      crate::api_capnp::secrets_store::list_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 6, None)
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
     }
-    pub fn update_index_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::update_index_params::Owned,
-      crate::api_capnp::secrets_store::update_index_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 7, None)
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
     }
-    pub fn add_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::add_params::Owned,
-      crate::api_capnp::secrets_store::add_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 8, None)
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
     }
-    pub fn get_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::get_params::Owned,
-      crate::api_capnp::secrets_store::get_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 9, None)
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
     }
-    pub fn get_version_request(
-      &self,
-    ) -> ::capnp::capability::Request<
-      crate::api_capnp::secrets_store::get_version_params::Owned,
-      crate::api_capnp::secrets_store::get_version_results::Owned,
-    > {
-      self.client.new_call(_private::TYPE_ID, 10, None)
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
     }
   }
-  pub trait Server {
-    fn status(&mut self, _: StatusParams, _: StatusResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
     }
-    fn lock(&mut self, _: LockParams, _: LockResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
-    fn unlock(&mut self, _: UnlockParams, _: UnlockResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
     }
-    fn identities(
-      &mut self,
-      _: IdentitiesParams,
-      _: IdentitiesResults,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
     }
-    fn add_identity(
-      &mut self,
-      _: AddIdentityParams,
-      _: AddIdentityResults,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
     }
-    fn change_passphrase(
+    #[inline]
+    pub fn get_all_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_all_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_all_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_all_tags(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_entries(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::store_entry_match::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_entries(
       &mut self,
-      _: ChangePassphraseParams,
-      _: ChangePassphraseResults,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::store_entry_match::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
     }
-    fn list(&mut self, _: ListParams, _: ListResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    #[inline]
+    pub fn init_entries(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::store_entry_match::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    pub fn has_entries(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const TYPE_ID: u64 = 0xf268_afe7_3828_a137;
+  }
+}
+
+pub mod secret_version {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_secret_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn get_timestamp(self) -> i64 {
+      self.reader.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_tags(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_urls(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_properties(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::property::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_properties(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_attachments(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::attachment::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(5),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_attachments(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_deleted(self) -> bool {
+      self.reader.get_bool_field(16)
+    }
+    #[inline]
+    pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(6),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_recipients(&self) -> bool {
+      !self.reader.get_pointer_field(6).is_null()
+    }
+    #[inline]
+    pub fn get_parent_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(7),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_parent_block_id(&self) -> bool {
+      !self.reader.get_pointer_field(7).is_null()
+    }
+    #[inline]
+    pub fn get_merged_block_ids(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(8),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_merged_block_ids(&self) -> bool {
+      !self.reader.get_pointer_field(8).is_null()
+    }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.reader.get_data_field::<i64>(2)
+    }
+    #[inline]
+    pub fn get_rotate_every(self) -> u64 {
+      self.reader.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn get_custom_type(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(9),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_custom_type(&self) -> bool {
+      !self.reader.get_pointer_field(9).is_null()
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_secret_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_secret_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_secret_id(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
+      self.builder.set_data_field::<u16>(0, value as u16)
+    }
+    #[inline]
+    pub fn get_timestamp(self) -> i64 {
+      self.builder.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn set_timestamp(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(1, value);
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    pub fn has_name(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_tags(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_tags(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_tags(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    pub fn has_tags(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_urls(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_urls(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
+    }
+    #[inline]
+    pub fn init_urls(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+    }
+    pub fn has_urls(&self) -> bool {
+      !self.builder.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_properties(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::property::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_properties(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::property::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
+    }
+    #[inline]
+    pub fn init_properties(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::property::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+    }
+    pub fn has_properties(&self) -> bool {
+      !self.builder.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_attachments(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::attachment::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(5),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_attachments(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_version::attachment::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(5), value, false)
+    }
+    #[inline]
+    pub fn init_attachments(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_version::attachment::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
+    }
+    pub fn has_attachments(&self) -> bool {
+      !self.builder.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_deleted(self) -> bool {
+      self.builder.get_bool_field(16)
+    }
+    #[inline]
+    pub fn set_deleted(&mut self, value: bool) {
+      self.builder.set_bool_field(16, value);
+    }
+    #[inline]
+    pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(6),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_recipients(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(6), value, false)
+    }
+    #[inline]
+    pub fn init_recipients(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(6), size)
+    }
+    pub fn has_recipients(&self) -> bool {
+      !self.builder.get_pointer_field(6).is_null()
+    }
+    #[inline]
+    pub fn get_parent_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(7),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_parent_block_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(7).set_text(value);
+    }
+    #[inline]
+    pub fn init_parent_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(7).init_text(size)
+    }
+    pub fn has_parent_block_id(&self) -> bool {
+      !self.builder.get_pointer_field(7).is_null()
+    }
+    #[inline]
+    pub fn get_merged_block_ids(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(8),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_merged_block_ids(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(8), value, false)
+    }
+    #[inline]
+    pub fn init_merged_block_ids(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(8), size)
+    }
+    pub fn has_merged_block_ids(&self) -> bool {
+      !self.builder.get_pointer_field(8).is_null()
+    }
+    #[inline]
+    pub fn get_expires_at(self) -> i64 {
+      self.builder.get_data_field::<i64>(2)
+    }
+    #[inline]
+    pub fn set_expires_at(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(2, value);
+    }
+    #[inline]
+    pub fn get_rotate_every(self) -> u64 {
+      self.builder.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn set_rotate_every(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(3, value);
+    }
+    #[inline]
+    pub fn get_custom_type(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(9),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_custom_type(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(9).set_text(value);
+    }
+    #[inline]
+    pub fn init_custom_type(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(9).init_text(size)
+    }
+    pub fn has_custom_type(&self) -> bool {
+      !self.builder.get_pointer_field(9).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 4, pointers: 10 };
+    pub const TYPE_ID: u64 = 0x9cc9_80f1_0099_83c6;
+  }
+
+  pub mod property {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_key(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_value(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_value(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_key(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_key(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_key(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_value(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_value(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(1).set_text(value);
+      }
+      #[inline]
+      pub fn init_value(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(1).init_text(size)
+      }
+      pub fn has_value(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const TYPE_ID: u64 = 0xd3a4_d2a9_74e8_2093;
+    }
+  }
+
+  pub mod attachment {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_name(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_mime_type(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_mime_type(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+      #[inline]
+      pub fn get_content(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_content(&self) -> bool {
+        !self.reader.get_pointer_field(2).is_null()
+      }
+      #[inline]
+      pub fn get_content_hash(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(3),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_content_hash(&self) -> bool {
+        !self.reader.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_size(self) -> u64 {
+        self.reader.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn get_chunks(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(4),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_chunks(&self) -> bool {
+        !self.reader.get_pointer_field(4).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_name(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_name(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_mime_type(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_mime_type(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(1).set_text(value);
+      }
+      #[inline]
+      pub fn init_mime_type(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(1).init_text(size)
+      }
+      pub fn has_mime_type(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+      #[inline]
+      pub fn get_content(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_content(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(2).set_data(value);
+      }
+      #[inline]
+      pub fn init_content(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(2).init_data(size)
+      }
+      pub fn has_content(&self) -> bool {
+        !self.builder.get_pointer_field(2).is_null()
+      }
+      #[inline]
+      pub fn get_content_hash(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(3),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_content_hash(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(3).set_text(value);
+      }
+      #[inline]
+      pub fn init_content_hash(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(3).init_text(size)
+      }
+      pub fn has_content_hash(&self) -> bool {
+        !self.builder.get_pointer_field(3).is_null()
+      }
+      #[inline]
+      pub fn get_size(self) -> u64 {
+        self.builder.get_data_field::<u64>(0)
+      }
+      #[inline]
+      pub fn set_size(&mut self, value: u64) {
+        self.builder.set_data_field::<u64>(0, value);
+      }
+      #[inline]
+      pub fn get_chunks(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(4),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_chunks(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
+      }
+      #[inline]
+      pub fn init_chunks(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+      }
+      pub fn has_chunks(&self) -> bool {
+        !self.builder.get_pointer_field(4).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 5 };
+      pub const TYPE_ID: u64 = 0x855d_d661_fc7b_18e6;
+    }
+  }
+}
+
+pub mod password_strength {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_entropy(self) -> f64 {
+      self.reader.get_data_field::<f64>(0)
+    }
+    #[inline]
+    pub fn get_crack_time(self) -> f64 {
+      self.reader.get_data_field::<f64>(1)
+    }
+    #[inline]
+    pub fn get_crack_time_display(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_crack_time_display(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_score(self) -> u8 {
+      self.reader.get_data_field::<u8>(16)
+    }
+    #[inline]
+    pub fn get_breach_count(self) -> u64 {
+      self.reader.get_data_field::<u64>(3)
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_entropy(self) -> f64 {
+      self.builder.get_data_field::<f64>(0)
+    }
+    #[inline]
+    pub fn set_entropy(&mut self, value: f64) {
+      self.builder.set_data_field::<f64>(0, value);
+    }
+    #[inline]
+    pub fn get_crack_time(self) -> f64 {
+      self.builder.get_data_field::<f64>(1)
+    }
+    #[inline]
+    pub fn set_crack_time(&mut self, value: f64) {
+      self.builder.set_data_field::<f64>(1, value);
+    }
+    #[inline]
+    pub fn get_crack_time_display(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_crack_time_display(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_crack_time_display(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_crack_time_display(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_score(self) -> u8 {
+      self.builder.get_data_field::<u8>(16)
+    }
+    #[inline]
+    pub fn set_score(&mut self, value: u8) {
+      self.builder.set_data_field::<u8>(16, value);
+    }
+    #[inline]
+    pub fn get_breach_count(self) -> u64 {
+      self.builder.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn set_breach_count(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(3, value);
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 4, pointers: 1 };
+    pub const TYPE_ID: u64 = 0xa994_a4b4_4f21_4077;
+  }
+}
+
+pub mod secret {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn get_current(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_current(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_current_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_current_block_id(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_versions(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_versions(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_password_strengths(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret::estimate::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_password_strengths(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_competing_versions(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(5),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_competing_versions(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_id(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_type(self) -> ::core::result::Result<crate::api_capnp::SecretType, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn set_type(&mut self, value: crate::api_capnp::SecretType) {
+      self.builder.set_data_field::<u16>(0, value as u16)
+    }
+    #[inline]
+    pub fn get_current(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_current<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+    }
+    #[inline]
+    pub fn init_current(self) -> crate::api_capnp::secret_version::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+    }
+    pub fn has_current(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_current_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_current_block_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(2).set_text(value);
+    }
+    #[inline]
+    pub fn init_current_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(2).init_text(size)
+    }
+    pub fn has_current_block_id(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_versions(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(3),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_versions(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value, false)
+    }
+    #[inline]
+    pub fn init_versions(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+    }
+    pub fn has_versions(&self) -> bool {
+      !self.builder.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_password_strengths(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret::estimate::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(4),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_password_strengths(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret::estimate::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value, false)
+    }
+    #[inline]
+    pub fn init_password_strengths(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret::estimate::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+    }
+    pub fn has_password_strengths(&self) -> bool {
+      !self.builder.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_competing_versions(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(5),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_competing_versions(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret::version_ref::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(5), value, false)
+    }
+    #[inline]
+    pub fn init_competing_versions(
+      self,
+      size: u32,
+    ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret::version_ref::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
+    }
+    pub fn has_competing_versions(&self) -> bool {
+      !self.builder.get_pointer_field(5).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {
+    pub fn get_current(&self) -> crate::api_capnp::secret_version::Pipeline {
+      ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+    }
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 6 };
+    pub const TYPE_ID: u64 = 0x83d4_6332_ba1a_4ae3;
+  }
+
+  pub mod estimate {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_key(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_strength(self) -> ::capnp::Result<crate::api_capnp::password_strength::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_strength(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_key(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_key(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_key(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_key(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_strength(self) -> ::capnp::Result<crate::api_capnp::password_strength::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_strength<'b>(
+        &mut self,
+        value: crate::api_capnp::password_strength::Reader<'b>,
+      ) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value, false)
+      }
+      #[inline]
+      pub fn init_strength(self) -> crate::api_capnp::password_strength::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), 0)
+      }
+      pub fn has_strength(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {
+      pub fn get_strength(&self) -> crate::api_capnp::password_strength::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(1))
+      }
+    }
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const TYPE_ID: u64 = 0xeb21_0708_91d3_9f04;
+    }
+  }
+
+  pub mod version_ref {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_block_id(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_timestamp(self) -> i64 {
+        self.reader.get_data_field::<i64>(0)
+      }
+      #[inline]
+      pub fn get_parent_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_parent_block_id(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+      #[inline]
+      pub fn get_merged_block_ids(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_merged_block_ids(&self) -> bool {
+        !self.reader.get_pointer_field(2).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_block_id(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_timestamp(self) -> i64 {
+        self.builder.get_data_field::<i64>(0)
+      }
+      #[inline]
+      pub fn set_timestamp(&mut self, value: i64) {
+        self.builder.set_data_field::<i64>(0, value);
+      }
+      #[inline]
+      pub fn get_parent_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_parent_block_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(1).set_text(value);
+      }
+      #[inline]
+      pub fn init_parent_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(1).init_text(size)
+      }
+      pub fn has_parent_block_id(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+      #[inline]
+      pub fn get_merged_block_ids(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_merged_block_ids(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+      }
+      #[inline]
+      pub fn init_merged_block_ids(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+      }
+      pub fn has_merged_block_ids(&self) -> bool {
+        !self.builder.get_pointer_field(2).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 3 };
+      pub const TYPE_ID: u64 = 0xd0d1_ef7f_b6b6_dd69;
+    }
+  }
+}
+
+pub mod health_report_params {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_min_score(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_max_age_days(self) -> u64 {
+      self.reader.get_data_field::<u64>(1)
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_min_score(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_min_score(&mut self, value: u8) {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_max_age_days(self) -> u64 {
+      self.builder.get_data_field::<u64>(1)
+    }
+    #[inline]
+    pub fn set_max_age_days(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(1, value);
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 0 };
+    pub const TYPE_ID: u64 = 0x9457_89e1_8b6f_e1c3;
+  }
+}
+
+pub mod secret_health {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_secret_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_weak(self) -> bool {
+      self.reader.get_bool_field(0)
+    }
+    #[inline]
+    pub fn get_score(self) -> u8 {
+      self.reader.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn get_reused_with(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_reused_with(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_password_changed_at(self) -> i64 {
+      self.reader.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn get_missing_totp(self) -> bool {
+      self.reader.get_bool_field(1)
+    }
+    #[inline]
+    pub fn get_breached(self) -> bool {
+      self.reader.get_bool_field(2)
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_secret_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_secret_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_secret_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_secret_id(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    pub fn has_name(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_weak(self) -> bool {
+      self.builder.get_bool_field(0)
+    }
+    #[inline]
+    pub fn set_weak(&mut self, value: bool) {
+      self.builder.set_bool_field(0, value);
+    }
+    #[inline]
+    pub fn get_score(self) -> u8 {
+      self.builder.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn set_score(&mut self, value: u8) {
+      self.builder.set_data_field::<u8>(1, value);
+    }
+    #[inline]
+    pub fn get_reused_with(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_reused_with(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_reused_with(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    pub fn has_reused_with(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_password_changed_at(self) -> i64 {
+      self.builder.get_data_field::<i64>(1)
+    }
+    #[inline]
+    pub fn set_password_changed_at(&mut self, value: i64) {
+      self.builder.set_data_field::<i64>(1, value);
+    }
+    #[inline]
+    pub fn get_missing_totp(self) -> bool {
+      self.builder.get_bool_field(1)
+    }
+    #[inline]
+    pub fn set_missing_totp(&mut self, value: bool) {
+      self.builder.set_bool_field(1, value);
+    }
+    #[inline]
+    pub fn get_breached(self) -> bool {
+      self.builder.get_bool_field(2)
+    }
+    #[inline]
+    pub fn set_breached(&mut self, value: bool) {
+      self.builder.set_bool_field(2, value);
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 2, pointers: 3 };
+    pub const TYPE_ID: u64 = 0xb73c_ffae_0fc2_a9c4;
+  }
+}
+
+pub mod health_report {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_checked(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_secrets(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_health::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_secrets(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_checked(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_checked(&mut self, value: u64) {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn get_secrets(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_health::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_secrets(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_health::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+    }
+    #[inline]
+    pub fn init_secrets(self, size: u32) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_health::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_secrets(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 1 };
+    pub const TYPE_ID: u64 = 0xebae_9a9c_38df_0194;
+  }
+}
+
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum FieldKind {
+  Text = 0,
+  Secret = 1,
+  Url = 2,
+  Totp = 3,
+  Date = 4,
+  Multiline = 5,
+}
+impl ::capnp::traits::FromU16 for FieldKind {
+  #[inline]
+  fn from_u16(value: u16) -> ::core::result::Result<FieldKind, ::capnp::NotInSchema> {
+    match value {
+      0 => ::core::result::Result::Ok(FieldKind::Text),
+      1 => ::core::result::Result::Ok(FieldKind::Secret),
+      2 => ::core::result::Result::Ok(FieldKind::Url),
+      3 => ::core::result::Result::Ok(FieldKind::Totp),
+      4 => ::core::result::Result::Ok(FieldKind::Date),
+      5 => ::core::result::Result::Ok(FieldKind::Multiline),
+      n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+    }
+  }
+}
+impl ::capnp::traits::ToU16 for FieldKind {
+  #[inline]
+  fn to_u16(self) -> u16 {
+    self as u16
+  }
+}
+impl ::capnp::traits::HasTypeId for FieldKind {
+  #[inline]
+  fn type_id() -> u64 {
+    0xf294_13be_9475_5fceu64
+  }
+}
+
+pub mod schema_field {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_property(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_kind(self) -> ::core::result::Result<crate::api_capnp::FieldKind, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn get_required(self) -> bool {
+      self.reader.get_bool_field(16)
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_property(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_property(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_property(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_kind(self) -> ::core::result::Result<crate::api_capnp::FieldKind, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn set_kind(&mut self, value: crate::api_capnp::FieldKind) {
+      self.builder.set_data_field::<u16>(0, value as u16)
+    }
+    #[inline]
+    pub fn get_required(self) -> bool {
+      self.builder.get_bool_field(16)
+    }
+    #[inline]
+    pub fn set_required(&mut self, value: bool) {
+      self.builder.set_bool_field(16, value);
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 1 };
+    pub const TYPE_ID: u64 = 0xcf2b_7aea_bbfe_013b;
+  }
+}
+
+pub mod secret_type_schema {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_id(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_name(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_fields(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::schema_field::Owned>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_fields(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_id(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_name(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    pub fn has_name(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_fields(
+      self,
+    ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::schema_field::Owned>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(2),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_fields(
+      &mut self,
+      value: ::capnp::struct_list::Reader<'a, crate::api_capnp::schema_field::Owned>,
+    ) -> ::capnp::Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+    }
+    #[inline]
+    pub fn init_fields(self, size: u32) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::schema_field::Owned> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    pub fn has_fields(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 3 };
+    pub const TYPE_ID: u64 = 0xa0cf_247a_b9c4_6e99;
+  }
+}
+
+pub mod secrets_store {
+  #![allow(unused_variables)]
+  pub type StatusParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::status_params::Owned>;
+  pub type StatusResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::status_results::Owned>;
+  pub type LockParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::lock_params::Owned>;
+  pub type LockResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::lock_results::Owned>;
+  pub type UnlockParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::unlock_params::Owned>;
+  pub type UnlockResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::unlock_results::Owned>;
+  pub type IdentitiesParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::identities_params::Owned>;
+  pub type IdentitiesResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::identities_results::Owned>;
+  pub type AddIdentityParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::add_identity_params::Owned>;
+  pub type AddIdentityResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::add_identity_results::Owned>;
+  pub type ChangePassphraseParams =
+    ::capnp::capability::Params<crate::api_capnp::secrets_store::change_passphrase_params::Owned>;
+  pub type ChangePassphraseResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::change_passphrase_results::Owned>;
+  pub type ListParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::list_params::Owned>;
+  pub type ListResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::list_results::Owned>;
+  pub type UpdateIndexParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::update_index_params::Owned>;
+  pub type UpdateIndexResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::update_index_results::Owned>;
+  pub type AddParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::add_params::Owned>;
+  pub type AddResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::add_results::Owned>;
+  pub type GetParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::get_params::Owned>;
+  pub type GetResults = ::capnp::capability::Results<crate::api_capnp::secrets_store::get_results::Owned>;
+  pub type GetVersionParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::get_version_params::Owned>;
+  pub type GetVersionResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::get_version_results::Owned>;
+
+  pub type HealthReportParams =
+    ::capnp::capability::Params<crate::api_capnp::secrets_store::health_report_params::Owned>;
+  pub type HealthReportResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::health_report_results::Owned>;
+  pub type ListSchemasParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::list_schemas_params::Owned>;
+  pub type ListSchemasResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::list_schemas_results::Owned>;
+  pub type StoreSchemaParams = ::capnp::capability::Params<crate::api_capnp::secrets_store::store_schema_params::Owned>;
+  pub type StoreSchemaResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::store_schema_results::Owned>;
+  pub type AddAttachmentChunkParams =
+    ::capnp::capability::Params<crate::api_capnp::secrets_store::add_attachment_chunk_params::Owned>;
+  pub type AddAttachmentChunkResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::add_attachment_chunk_results::Owned>;
+  pub type GetAttachmentChunkParams =
+    ::capnp::capability::Params<crate::api_capnp::secrets_store::get_attachment_chunk_params::Owned>;
+  pub type GetAttachmentChunkResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::get_attachment_chunk_results::Owned>;
+  pub type MigrateVersionsParams =
+    ::capnp::capability::Params<crate::api_capnp::secrets_store::migrate_versions_params::Owned>;
+  pub type MigrateVersionsResults =
+    ::capnp::capability::Results<crate::api_capnp::secrets_store::migrate_versions_results::Owned>;
+  pub struct Client {
+    pub client: ::capnp::capability::Client,
+  }
+  impl ::capnp::capability::FromClientHook for Client {
+    fn new(hook: Box<dyn (::capnp::private::capability::ClientHook)>) -> Client {
+      Client {
+        client: ::capnp::capability::Client::new(hook),
+      }
+    }
+  }
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Client;
+    type Builder = Client;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Client;
+  }
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Client {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      _default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Client> {
+      ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(reader.get_capability()?))
+    }
+  }
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Client {
+    fn init_pointer(_builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Client {
+      unimplemented!()
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      _default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Client> {
+      ::core::result::Result::Ok(::capnp::capability::FromClientHook::new(builder.get_capability()?))
+    }
+  }
+
+  impl ::capnp::traits::SetPointerBuilder<Client> for Client {
+    fn set_pointer_builder(
+      pointer: ::capnp::private::layout::PointerBuilder,
+      from: Client,
+      _canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_capability(from.client.hook);
+      ::core::result::Result::Ok(())
+    }
+  }
+  impl ::capnp::traits::HasTypeId for Client {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl Clone for Client {
+    fn clone(&self) -> Client {
+      Client {
+        client: ::capnp::capability::Client::new(self.client.hook.add_ref()),
+      }
+    }
+  }
+  impl Client {
+    pub fn status_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::status_params::Owned,
+      crate::api_capnp::secrets_store::status_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 0, None)
+    }
+    pub fn lock_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::lock_params::Owned,
+      crate::api_capnp::secrets_store::lock_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 1, None)
+    }
+    pub fn unlock_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::unlock_params::Owned,
+      crate::api_capnp::secrets_store::unlock_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 2, None)
+    }
+    pub fn identities_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::identities_params::Owned,
+      crate::api_capnp::secrets_store::identities_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 3, None)
+    }
+    pub fn add_identity_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::add_identity_params::Owned,
+      crate::api_capnp::secrets_store::add_identity_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 4, None)
+    }
+    pub fn change_passphrase_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::change_passphrase_params::Owned,
+      crate::api_capnp::secrets_store::change_passphrase_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 5, None)
+    }
+    pub fn list_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::list_params::Owned,
+      crate::api_capnp::secrets_store::list_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 6, None)
+    }
+    pub fn update_index_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::update_index_params::Owned,
+      crate::api_capnp::secrets_store::update_index_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 7, None)
+    }
+    pub fn add_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::add_params::Owned,
+      crate::api_capnp::secrets_store::add_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 8, None)
+    }
+    pub fn get_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::get_params::Owned,
+      crate::api_capnp::secrets_store::get_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 9, None)
+    }
+    pub fn get_version_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::get_version_params::Owned,
+      crate::api_capnp::secrets_store::get_version_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 10, None)
+    }
+    pub fn health_report_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::health_report_params::Owned,
+      crate::api_capnp::secrets_store::health_report_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 11, None)
+    }
+    pub fn list_schemas_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::list_schemas_params::Owned,
+      crate::api_capnp::secrets_store::list_schemas_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 12, None)
+    }
+    pub fn store_schema_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::store_schema_params::Owned,
+      crate::api_capnp::secrets_store::store_schema_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 13, None)
+    }
+    pub fn add_attachment_chunk_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::add_attachment_chunk_params::Owned,
+      crate::api_capnp::secrets_store::add_attachment_chunk_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 14, None)
+    }
+    pub fn get_attachment_chunk_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::get_attachment_chunk_params::Owned,
+      crate::api_capnp::secrets_store::get_attachment_chunk_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 15, None)
+    }
+    pub fn migrate_versions_request(
+      &self,
+    ) -> ::capnp::capability::Request<
+      crate::api_capnp::secrets_store::migrate_versions_params::Owned,
+      crate::api_capnp::secrets_store::migrate_versions_results::Owned,
+    > {
+      self.client.new_call(_private::TYPE_ID, 16, None)
+    }
+  }
+  pub trait Server {
+    fn status(&mut self, _: StatusParams, _: StatusResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn lock(&mut self, _: LockParams, _: LockResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn unlock(&mut self, _: UnlockParams, _: UnlockResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn identities(
+      &mut self,
+      _: IdentitiesParams,
+      _: IdentitiesResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn add_identity(
+      &mut self,
+      _: AddIdentityParams,
+      _: AddIdentityResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn change_passphrase(
+      &mut self,
+      _: ChangePassphraseParams,
+      _: ChangePassphraseResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn list(&mut self, _: ListParams, _: ListResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn update_index(
+      &mut self,
+      _: UpdateIndexParams,
+      _: UpdateIndexResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn add(&mut self, _: AddParams, _: AddResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn get(&mut self, _: GetParams, _: GetResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn get_version(
+      &mut self,
+      _: GetVersionParams,
+      _: GetVersionResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn health_report(
+      &mut self,
+      _: HealthReportParams,
+      _: HealthReportResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn list_schemas(
+      &mut self,
+      _: ListSchemasParams,
+      _: ListSchemasResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn store_schema(
+      &mut self,
+      _: StoreSchemaParams,
+      _: StoreSchemaResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn add_attachment_chunk(
+      &mut self,
+      _: AddAttachmentChunkParams,
+      _: AddAttachmentChunkResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn get_attachment_chunk(
+      &mut self,
+      _: GetAttachmentChunkParams,
+      _: GetAttachmentChunkResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+    fn migrate_versions(
+      &mut self,
+      _: MigrateVersionsParams,
+      _: MigrateVersionsResults,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    }
+  }
+  pub struct ServerDispatch<_T> {
+    pub server: _T,
+  }
+  impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
+    type Dispatch = ServerDispatch<_S>;
+    fn from_server(s: _S) -> ServerDispatch<_S> {
+      ServerDispatch { server: s }
+    }
+  }
+  impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
+    type Target = _T;
+    fn deref(&self) -> &_T {
+      &self.server
+    }
+  }
+  impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
+    fn deref_mut(&mut self) -> &mut _T {
+      &mut self.server
+    }
+  }
+  impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
+    fn dispatch_call(
+      &mut self,
+      interface_id: u64,
+      method_id: u16,
+      params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+      results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      match interface_id {
+        _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(&mut self.server, method_id, params, results),
+        _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented("Method not implemented.".to_string())),
+      }
+    }
+  }
+  impl<_T: Server> ServerDispatch<_T> {
+    pub fn dispatch_call_internal(
+      server: &mut _T,
+      method_id: u16,
+      params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
+      results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
+    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
+      match method_id {
+        0 => server.status(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        1 => server.lock(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        2 => server.unlock(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        3 => server.identities(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        4 => server.add_identity(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        5 => server.change_passphrase(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        6 => server.list(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        7 => server.update_index(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        8 => server.add(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        9 => server.get(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        10 => server.get_version(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        11 => server.health_report(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        12 => server.list_schemas(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        13 => server.store_schema(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        14 => server.add_attachment_chunk(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        15 => server.get_attachment_chunk(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        16 => server.migrate_versions(
+          ::capnp::private::capability::internal_get_typed_params(params),
+          ::capnp::private::capability::internal_get_typed_results(results),
+        ),
+        _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented("Method not implemented.".to_string())),
+      }
+    }
+  }
+  pub mod _private {
+    pub const TYPE_ID: u64 = 0x836e_0afc_4388_e275;
+  }
+
+  pub mod status_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xc95b_65f0_b201_86c5;
+    }
+  }
+
+  pub mod status_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_status(self) -> ::capnp::Result<crate::api_capnp::status::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_status(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_status(self) -> ::capnp::Result<crate::api_capnp::status::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_status<'b>(&mut self, value: crate::api_capnp::status::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_status(self) -> crate::api_capnp::status::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_status(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {
+      pub fn get_status(&self) -> crate::api_capnp::status::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xfb2d_51a1_eebf_e7e7;
+    }
+  }
+
+  pub mod lock_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x85f3_c4d9_b630_ed94;
+    }
+  }
+
+  pub mod lock_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xc2d8_4f30_2f50_23a7;
+    }
+  }
+
+  pub mod unlock_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_identity_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_identity_id(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_identity_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_identity_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_identity_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_identity_id(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(1).set_data(value);
+      }
+      #[inline]
+      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(1).init_data(size)
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const TYPE_ID: u64 = 0xf6a7_9a03_9455_2fcc;
+    }
+  }
+
+  pub mod unlock_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xc455_9404_c098_e8dd;
+    }
+  }
+
+  pub mod identities_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x8d9c_f396_3654_d4dd;
+    }
+  }
+
+  pub mod identities_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_identities(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::identity::Owned>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_identities(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_identities(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::identity::Owned>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_identities(
+        &mut self,
+        value: ::capnp::struct_list::Reader<'a, crate::api_capnp::identity::Owned>,
+      ) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_identities(self, size: u32) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::identity::Owned> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+      }
+      pub fn has_identities(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xda99_7ac5_3a32_b83a;
+    }
+  }
+
+  pub mod add_identity_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_identity(self) -> ::capnp::Result<crate::api_capnp::identity::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_identity(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_identity(self) -> ::capnp::Result<crate::api_capnp::identity::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_identity<'b>(&mut self, value: crate::api_capnp::identity::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_identity(self) -> crate::api_capnp::identity::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_identity(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(1).set_data(value);
+      }
+      #[inline]
+      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(1).init_data(size)
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {
+      pub fn get_identity(&self) -> crate::api_capnp::identity::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const TYPE_ID: u64 = 0xdcab_fb07_10a9_b00b;
+    }
+  }
+
+  pub mod add_identity_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x8155_ac5c_76b2_c069;
+    }
+  }
+
+  pub mod change_passphrase_params {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
+    }
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
+    }
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
+    }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
+    }
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
+    }
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
+      }
+      #[inline]
+      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(0).set_data(value);
+      }
+      #[inline]
+      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(0).init_data(size)
+      }
+      pub fn has_passphrase(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
+    }
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
+      }
+    }
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0x9bfe_e38b_3d35_5fe9;
+    }
+  }
+
+  pub mod change_passphrase_results {
+    #[derive(Copy, Clone)]
+    pub struct Owned;
+    impl<'a> ::capnp::traits::Owned<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+      type Reader = Reader<'a>;
+      type Builder = Builder<'a>;
+    }
+    impl ::capnp::traits::Pipelined for Owned {
+      type Pipeline = Pipeline;
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Reader<'a> {
+      reader: ::capnp::private::layout::StructReader<'a>,
+    }
+
+    impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
+    }
+    impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+      fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+        Reader { reader }
+      }
+    }
+
+    impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+      fn get_from_pointer(
+        reader: &::capnp::private::layout::PointerReader<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Reader<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+      }
+    }
+
+    impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+      fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+        self.reader
+      }
+    }
+
+    impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+      fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+        self
+          .reader
+          .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+      }
+    }
+
+    impl<'a> Reader<'a> {
+      pub fn reborrow(&self) -> Reader {
+        Reader { ..*self }
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.reader.total_size()
+      }
     }
-    fn update_index(
-      &mut self,
-      _: UpdateIndexParams,
-      _: UpdateIndexResults,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+
+    pub struct Builder<'a> {
+      builder: ::capnp::private::layout::StructBuilder<'a>,
     }
-    fn add(&mut self, _: AddParams, _: AddResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+      #[inline]
+      fn struct_size() -> ::capnp::private::layout::StructSize {
+        _private::STRUCT_SIZE
+      }
     }
-    fn get(&mut self, _: GetParams, _: GetResults) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+      #[inline]
+      fn type_id() -> u64 {
+        _private::TYPE_ID
+      }
     }
-    fn get_version(
-      &mut self,
-      _: GetVersionParams,
-      _: GetVersionResults,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      ::capnp::capability::Promise::err(::capnp::Error::unimplemented("method not implemented".to_string()))
+    impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+      fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+        Builder { builder }
+      }
     }
-  }
-  pub struct ServerDispatch<_T> {
-    pub server: _T,
-  }
-  impl<_S: Server + 'static> ::capnp::capability::FromServer<_S> for Client {
-    type Dispatch = ServerDispatch<_S>;
-    fn from_server(s: _S) -> ServerDispatch<_S> {
-      ServerDispatch { server: s }
+
+    impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+      fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+        self
+          .builder
+          .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+      }
     }
-  }
-  impl<_T: Server> ::core::ops::Deref for ServerDispatch<_T> {
-    type Target = _T;
-    fn deref(&self) -> &_T {
-      &self.server
+
+    impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+      fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+        ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+      }
+      fn get_from_pointer(
+        builder: ::capnp::private::layout::PointerBuilder<'a>,
+        default: ::core::option::Option<&'a [capnp::Word]>,
+      ) -> ::capnp::Result<Builder<'a>> {
+        ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+          builder.get_struct(_private::STRUCT_SIZE, default)?,
+        ))
+      }
     }
-  }
-  impl<_T: Server> ::core::ops::DerefMut for ServerDispatch<_T> {
-    fn deref_mut(&mut self) -> &mut _T {
-      &mut self.server
+
+    impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+      fn set_pointer_builder<'b>(
+        pointer: ::capnp::private::layout::PointerBuilder<'b>,
+        value: Reader<'a>,
+        canonicalize: bool,
+      ) -> ::capnp::Result<()> {
+        pointer.set_struct(&value.reader, canonicalize)
+      }
     }
-  }
-  impl<_T: Server> ::capnp::capability::Server for ServerDispatch<_T> {
-    fn dispatch_call(
-      &mut self,
-      interface_id: u64,
-      method_id: u16,
-      params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-      results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      match interface_id {
-        _private::TYPE_ID => ServerDispatch::<_T>::dispatch_call_internal(&mut self.server, method_id, params, results),
-        _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented("Method not implemented.".to_string())),
+
+    impl<'a> Builder<'a> {
+      pub fn into_reader(self) -> Reader<'a> {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+      pub fn reborrow(&mut self) -> Builder {
+        Builder { ..*self }
+      }
+      pub fn reborrow_as_reader(&self) -> Reader {
+        ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+      }
+
+      pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+        self.builder.into_reader().total_size()
       }
     }
-  }
-  impl<_T: Server> ServerDispatch<_T> {
-    pub fn dispatch_call_internal(
-      server: &mut _T,
-      method_id: u16,
-      params: ::capnp::capability::Params<::capnp::any_pointer::Owned>,
-      results: ::capnp::capability::Results<::capnp::any_pointer::Owned>,
-    ) -> ::capnp::capability::Promise<(), ::capnp::Error> {
-      match method_id {
-        0 => server.status(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        1 => server.lock(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        2 => server.unlock(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        3 => server.identities(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        4 => server.add_identity(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        5 => server.change_passphrase(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        6 => server.list(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        7 => server.update_index(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        8 => server.add(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        9 => server.get(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        10 => server.get_version(
-          ::capnp::private::capability::internal_get_typed_params(params),
-          ::capnp::private::capability::internal_get_typed_results(results),
-        ),
-        _ => ::capnp::capability::Promise::err(::capnp::Error::unimplemented("Method not implemented.".to_string())),
+
+    pub struct Pipeline {
+      _typeless: ::capnp::any_pointer::Pipeline,
+    }
+    impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+      fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+        Pipeline { _typeless: typeless }
       }
     }
-  }
-  pub mod _private {
-    pub const TYPE_ID: u64 = 0x836e_0afc_4388_e275;
+    impl Pipeline {}
+    mod _private {
+      use capnp::private::layout;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x982d_2f9d_1091_f92d;
+    }
   }
 
-  pub mod status_params {
+  pub mod list_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -11464,6 +16619,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_filter(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -11533,6 +16698,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_filter<'b>(&mut self, value: crate::api_capnp::secret_list_filter::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_filter(self) -> crate::api_capnp::secret_list_filter::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_filter(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -11543,15 +16726,19 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {}
+    impl Pipeline {
+      pub fn get_filter(&self) -> crate::api_capnp::secret_list_filter::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xc95b_65f0_b201_86c5;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0x9e48_0a70_d4f7_4f37;
     }
   }
 
-  pub mod status_results {
+  pub mod list_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -11615,13 +16802,13 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_status(self) -> ::capnp::Result<crate::api_capnp::status::Reader<'a>> {
+      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::secret_list::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_status(&self) -> bool {
+      pub fn has_list(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -11694,21 +16881,21 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_status(self) -> ::capnp::Result<crate::api_capnp::status::Builder<'a>> {
+      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::secret_list::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_status<'b>(&mut self, value: crate::api_capnp::status::Reader<'b>) -> ::capnp::Result<()> {
+      pub fn set_list<'b>(&mut self, value: crate::api_capnp::secret_list::Reader<'b>) -> ::capnp::Result<()> {
         ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
       #[inline]
-      pub fn init_status(self) -> crate::api_capnp::status::Builder<'a> {
+      pub fn init_list(self) -> crate::api_capnp::secret_list::Builder<'a> {
         ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
       }
-      pub fn has_status(&self) -> bool {
+      pub fn has_list(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -11722,18 +16909,18 @@ pub mod secrets_store {
       }
     }
     impl Pipeline {
-      pub fn get_status(&self) -> crate::api_capnp::status::Pipeline {
+      pub fn get_list(&self) -> crate::api_capnp::secret_list::Pipeline {
         ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
       }
     }
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xfb2d_51a1_eebf_e7e7;
+      pub const TYPE_ID: u64 = 0x9b0a_0b2e_d1d9_6885;
     }
   }
 
-  pub mod lock_params {
+  pub mod update_index_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -11879,11 +17066,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0x85f3_c4d9_b630_ed94;
+      pub const TYPE_ID: u64 = 0xcd89_9c2c_f1bb_a6ec;
     }
   }
 
-  pub mod lock_results {
+  pub mod update_index_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12029,11 +17216,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xc2d8_4f30_2f50_23a7;
+      pub const TYPE_ID: u64 = 0xde01_587a_a1ad_dbee;
     }
   }
 
-  pub mod unlock_params {
+  pub mod add_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12094,27 +17281,17 @@ pub mod secrets_store {
       }
 
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
-        self.reader.total_size()
-      }
-      #[inline]
-      pub fn get_identity_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_identity_id(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
+        self.reader.total_size()
       }
       #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(1),
+          &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_passphrase(&self) -> bool {
-        !self.reader.get_pointer_field(1).is_null()
+      pub fn has_version(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
       }
     }
 
@@ -12186,41 +17363,23 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_identity_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_identity_id(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
+      pub fn set_version<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
       #[inline]
-      pub fn init_identity_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
+      pub fn init_version(self) -> crate::api_capnp::secret_version::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
       }
-      pub fn has_identity_id(&self) -> bool {
+      pub fn has_version(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
-      #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
-        self.builder.get_pointer_field(1).set_data(value);
-      }
-      #[inline]
-      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
-        self.builder.get_pointer_field(1).init_data(size)
-      }
-      pub fn has_passphrase(&self) -> bool {
-        !self.builder.get_pointer_field(1).is_null()
-      }
     }
 
     pub struct Pipeline {
@@ -12231,15 +17390,19 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {}
+    impl Pipeline {
+      pub fn get_version(&self) -> crate::api_capnp::secret_version::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
-      pub const TYPE_ID: u64 = 0xf6a7_9a03_9455_2fcc;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xaee1_d7aa_1aa7_077e;
     }
   }
 
-  pub mod unlock_results {
+  pub mod add_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12302,6 +17465,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_block_id(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -12371,6 +17544,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_block_id(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -12384,12 +17575,12 @@ pub mod secrets_store {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xc455_9404_c098_e8dd;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0x8b11_3df9_f35c_af4c;
     }
   }
 
-  pub mod identities_params {
+  pub mod get_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12452,6 +17643,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_id(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -12521,6 +17722,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
+      }
+      #[inline]
+      pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
+      }
+      pub fn has_id(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -12534,12 +17753,12 @@ pub mod secrets_store {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0x8d9c_f396_3654_d4dd;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xb7d2_f857_2c3b_87e0;
     }
   }
 
-  pub mod identities_results {
+  pub mod get_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12603,15 +17822,13 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_identities(
-        self,
-      ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::identity::Owned>> {
+      pub fn get_secret(self) -> ::capnp::Result<crate::api_capnp::secret::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_identities(&self) -> bool {
+      pub fn has_secret(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -12684,26 +17901,21 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_identities(
-        self,
-      ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::identity::Owned>> {
+      pub fn get_secret(self) -> ::capnp::Result<crate::api_capnp::secret::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_identities(
-        &mut self,
-        value: ::capnp::struct_list::Reader<'a, crate::api_capnp::identity::Owned>,
-      ) -> ::capnp::Result<()> {
+      pub fn set_secret<'b>(&mut self, value: crate::api_capnp::secret::Reader<'b>) -> ::capnp::Result<()> {
         ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
       #[inline]
-      pub fn init_identities(self, size: u32) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::identity::Owned> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+      pub fn init_secret(self) -> crate::api_capnp::secret::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
       }
-      pub fn has_identities(&self) -> bool {
+      pub fn has_secret(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -12716,15 +17928,19 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {}
+    impl Pipeline {
+      pub fn get_secret(&self) -> crate::api_capnp::secret::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xda99_7ac5_3a32_b83a;
+      pub const TYPE_ID: u64 = 0xc62a_9865_5e7d_d6e6;
     }
   }
 
-  pub mod add_identity_params {
+  pub mod get_version_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12788,25 +18004,15 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_identity(self) -> ::capnp::Result<crate::api_capnp::identity::Reader<'a>> {
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_identity(&self) -> bool {
+      pub fn has_block_id(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
-      #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_passphrase(&self) -> bool {
-        !self.reader.get_pointer_field(1).is_null()
-      }
     }
 
     pub struct Builder<'a> {
@@ -12877,41 +18083,23 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_identity(self) -> ::capnp::Result<crate::api_capnp::identity::Builder<'a>> {
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_identity<'b>(&mut self, value: crate::api_capnp::identity::Reader<'b>) -> ::capnp::Result<()> {
-        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
+        self.builder.get_pointer_field(0).set_text(value);
       }
       #[inline]
-      pub fn init_identity(self) -> crate::api_capnp::identity::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+        self.builder.get_pointer_field(0).init_text(size)
       }
-      pub fn has_identity(&self) -> bool {
+      pub fn has_block_id(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
-      #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(1),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
-        self.builder.get_pointer_field(1).set_data(value);
-      }
-      #[inline]
-      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
-        self.builder.get_pointer_field(1).init_data(size)
-      }
-      pub fn has_passphrase(&self) -> bool {
-        !self.builder.get_pointer_field(1).is_null()
-      }
     }
 
     pub struct Pipeline {
@@ -12922,19 +18110,15 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_identity(&self) -> crate::api_capnp::identity::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
-      pub const TYPE_ID: u64 = 0xdcab_fb07_10a9_b00b;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xf15e_df0d_f0dc_5d9e;
     }
   }
 
-  pub mod add_identity_results {
+  pub mod get_version_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -12997,6 +18181,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_version(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -13066,6 +18260,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_version<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_version(self) -> crate::api_capnp::secret_version::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_version(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -13076,15 +18288,19 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {}
+    impl Pipeline {
+      pub fn get_version(&self) -> crate::api_capnp::secret_version::Pipeline {
+        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
+      }
+    }
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0x8155_ac5c_76b2_c069;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0x96d4_7324_ae25_64dd;
     }
   }
 
-  pub mod change_passphrase_params {
+  pub mod health_report_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -13148,13 +18364,13 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      pub fn get_params(self) -> ::capnp::Result<crate::api_capnp::health_report_params::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_passphrase(&self) -> bool {
+      pub fn has_params(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -13227,21 +18443,24 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_passphrase(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      pub fn get_params(self) -> ::capnp::Result<crate::api_capnp::health_report_params::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_passphrase(&mut self, value: ::capnp::data::Reader) {
-        self.builder.get_pointer_field(0).set_data(value);
+      pub fn set_params<'b>(
+        &mut self,
+        value: crate::api_capnp::health_report_params::Reader<'b>,
+      ) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
-      #[inline]
-      pub fn init_passphrase(self, size: u32) -> ::capnp::data::Builder<'a> {
-        self.builder.get_pointer_field(0).init_data(size)
+      #[inline]
+      pub fn init_params(self) -> crate::api_capnp::health_report_params::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
       }
-      pub fn has_passphrase(&self) -> bool {
+      pub fn has_params(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -13258,11 +18477,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0x9bfe_e38b_3d35_5fe9;
+      pub const TYPE_ID: u64 = 0xf2b6_1861_dbbc_cdf8;
     }
   }
 
-  pub mod change_passphrase_results {
+  pub mod health_report_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -13325,6 +18544,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_report(self) -> ::capnp::Result<crate::api_capnp::health_report::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_report(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -13394,6 +18623,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_report(self) -> ::capnp::Result<crate::api_capnp::health_report::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_report<'b>(&mut self, value: crate::api_capnp::health_report::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_report(self) -> crate::api_capnp::health_report::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_report(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -13407,12 +18654,12 @@ pub mod secrets_store {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0x982d_2f9d_1091_f92d;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0x9ad1_9d25_3a71_e05c;
     }
   }
 
-  pub mod list_params {
+  pub mod list_schemas_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -13475,16 +18722,6 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
-      #[inline]
-      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_filter(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
     }
 
     pub struct Builder<'a> {
@@ -13554,24 +18791,6 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
-      #[inline]
-      pub fn get_filter(self) -> ::capnp::Result<crate::api_capnp::secret_list_filter::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_filter<'b>(&mut self, value: crate::api_capnp::secret_list_filter::Reader<'b>) -> ::capnp::Result<()> {
-        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
-      }
-      #[inline]
-      pub fn init_filter(self) -> crate::api_capnp::secret_list_filter::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
-      }
-      pub fn has_filter(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
     }
 
     pub struct Pipeline {
@@ -13582,19 +18801,15 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_filter(&self) -> crate::api_capnp::secret_list_filter::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0x9e48_0a70_d4f7_4f37;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xd592_8396_5650_a653;
     }
   }
 
-  pub mod list_results {
+  pub mod list_schemas_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -13658,13 +18873,15 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::secret_list::Reader<'a>> {
+      pub fn get_schemas(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::api_capnp::secret_type_schema::Owned>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_list(&self) -> bool {
+      pub fn has_schemas(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -13737,21 +18954,29 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_list(self) -> ::capnp::Result<crate::api_capnp::secret_list::Builder<'a>> {
+      pub fn get_schemas(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::api_capnp::secret_type_schema::Owned>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_list<'b>(&mut self, value: crate::api_capnp::secret_list::Reader<'b>) -> ::capnp::Result<()> {
+      pub fn set_schemas(
+        &mut self,
+        value: ::capnp::struct_list::Reader<'a, crate::api_capnp::secret_type_schema::Owned>,
+      ) -> ::capnp::Result<()> {
         ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
       #[inline]
-      pub fn init_list(self) -> crate::api_capnp::secret_list::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      pub fn init_schemas(
+        self,
+        size: u32,
+      ) -> ::capnp::struct_list::Builder<'a, crate::api_capnp::secret_type_schema::Owned> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
       }
-      pub fn has_list(&self) -> bool {
+      pub fn has_schemas(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -13764,19 +18989,15 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_list(&self) -> crate::api_capnp::secret_list::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0x9b0a_0b2e_d1d9_6885;
+      pub const TYPE_ID: u64 = 0x8ece_02d4_4817_adba;
     }
   }
 
-  pub mod update_index_params {
+  pub mod store_schema_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -13839,6 +19060,16 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
+      #[inline]
+      pub fn get_schema(self) -> ::capnp::Result<crate::api_capnp::secret_type_schema::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_schema(&self) -> bool {
+        !self.reader.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -13908,6 +19139,24 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
+      #[inline]
+      pub fn get_schema(self) -> ::capnp::Result<crate::api_capnp::secret_type_schema::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(0),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_schema<'b>(&mut self, value: crate::api_capnp::secret_type_schema::Reader<'b>) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      }
+      #[inline]
+      pub fn init_schema(self) -> crate::api_capnp::secret_type_schema::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      }
+      pub fn has_schema(&self) -> bool {
+        !self.builder.get_pointer_field(0).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -13921,12 +19170,12 @@ pub mod secrets_store {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xcd89_9c2c_f1bb_a6ec;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
+      pub const TYPE_ID: u64 = 0xad0d_4922_fc16_1490;
     }
   }
 
-  pub mod update_index_results {
+  pub mod store_schema_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14072,11 +19321,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
-      pub const TYPE_ID: u64 = 0xde01_587a_a1ad_dbee;
+      pub const TYPE_ID: u64 = 0xb426_1807_143c_fe7c;
     }
   }
 
-  pub mod add_params {
+  pub mod add_attachment_chunk_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14140,15 +19389,25 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
+      pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_version(&self) -> bool {
+      pub fn has_recipients(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
+      #[inline]
+      pub fn get_chunk(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_chunk(&self) -> bool {
+        !self.reader.get_pointer_field(1).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -14219,23 +19478,41 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
+      pub fn get_recipients(self) -> ::capnp::Result<::capnp::text_list::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_version<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
+      pub fn set_recipients(&mut self, value: ::capnp::text_list::Reader<'a>) -> ::capnp::Result<()> {
         ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
       }
       #[inline]
-      pub fn init_version(self) -> crate::api_capnp::secret_version::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      pub fn init_recipients(self, size: u32) -> ::capnp::text_list::Builder<'a> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
       }
-      pub fn has_version(&self) -> bool {
+      pub fn has_recipients(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
+      #[inline]
+      pub fn get_chunk(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(1),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_chunk(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(1).set_data(value);
+      }
+      #[inline]
+      pub fn init_chunk(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(1).init_data(size)
+      }
+      pub fn has_chunk(&self) -> bool {
+        !self.builder.get_pointer_field(1).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -14246,19 +19523,15 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_version(&self) -> crate::api_capnp::secret_version::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xaee1_d7aa_1aa7_077e;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const TYPE_ID: u64 = 0xda82_ac2e_64d3_467b;
     }
   }
 
-  pub mod add_results {
+  pub mod add_attachment_chunk_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14432,11 +19705,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0x8b11_3df9_f35c_af4c;
+      pub const TYPE_ID: u64 = 0xc88c_4df7_6a0b_8990;
     }
   }
 
-  pub mod get_params {
+  pub mod get_attachment_chunk_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14500,13 +19773,13 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_id(&self) -> bool {
+      pub fn has_block_id(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -14579,21 +19852,21 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_id(&mut self, value: ::capnp::text::Reader) {
+      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
         self.builder.get_pointer_field(0).set_text(value);
       }
       #[inline]
-      pub fn init_id(self, size: u32) -> ::capnp::text::Builder<'a> {
+      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
         self.builder.get_pointer_field(0).init_text(size)
       }
-      pub fn has_id(&self) -> bool {
+      pub fn has_block_id(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -14610,11 +19883,11 @@ pub mod secrets_store {
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xb7d2_f857_2c3b_87e0;
+      pub const TYPE_ID: u64 = 0xf556_d893_ccae_e3fa;
     }
   }
 
-  pub mod get_results {
+  pub mod get_attachment_chunk_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14678,13 +19951,13 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_secret(self) -> ::capnp::Result<crate::api_capnp::secret::Reader<'a>> {
+      pub fn get_chunk(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
         ::capnp::traits::FromPointerReader::get_from_pointer(
           &self.reader.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
-      pub fn has_secret(&self) -> bool {
+      pub fn has_chunk(&self) -> bool {
         !self.reader.get_pointer_field(0).is_null()
       }
     }
@@ -14757,21 +20030,21 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_secret(self) -> ::capnp::Result<crate::api_capnp::secret::Builder<'a>> {
+      pub fn get_chunk(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
         ::capnp::traits::FromPointerBuilder::get_from_pointer(
           self.builder.get_pointer_field(0),
           ::core::option::Option::None,
         )
       }
       #[inline]
-      pub fn set_secret<'b>(&mut self, value: crate::api_capnp::secret::Reader<'b>) -> ::capnp::Result<()> {
-        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      pub fn set_chunk(&mut self, value: ::capnp::data::Reader) {
+        self.builder.get_pointer_field(0).set_data(value);
       }
       #[inline]
-      pub fn init_secret(self) -> crate::api_capnp::secret::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
+      pub fn init_chunk(self, size: u32) -> ::capnp::data::Builder<'a> {
+        self.builder.get_pointer_field(0).init_data(size)
       }
-      pub fn has_secret(&self) -> bool {
+      pub fn has_chunk(&self) -> bool {
         !self.builder.get_pointer_field(0).is_null()
       }
     }
@@ -14784,19 +20057,15 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_secret(&self) -> crate::api_capnp::secret::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
       pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xc62a_9865_5e7d_d6e6;
+      pub const TYPE_ID: u64 = 0xb905_b678_59b6_4e90;
     }
   }
 
-  pub mod get_version_params {
+  pub mod migrate_versions_params {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -14859,16 +20128,6 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.reader.total_size()
       }
-      #[inline]
-      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_block_id(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
-      }
     }
 
     pub struct Builder<'a> {
@@ -14938,24 +20197,6 @@ pub mod secrets_store {
       pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
         self.builder.into_reader().total_size()
       }
-      #[inline]
-      pub fn get_block_id(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_block_id(&mut self, value: ::capnp::text::Reader) {
-        self.builder.get_pointer_field(0).set_text(value);
-      }
-      #[inline]
-      pub fn init_block_id(self, size: u32) -> ::capnp::text::Builder<'a> {
-        self.builder.get_pointer_field(0).init_text(size)
-      }
-      pub fn has_block_id(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
-      }
     }
 
     pub struct Pipeline {
@@ -14969,12 +20210,12 @@ pub mod secrets_store {
     impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0xf15e_df0d_f0dc_5d9e;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 0 };
+      pub const TYPE_ID: u64 = 0xc0c1_b7f9_ad5f_d111;
     }
   }
 
-  pub mod get_version_results {
+  pub mod migrate_versions_results {
     #[derive(Copy, Clone)]
     pub struct Owned;
     impl<'a> ::capnp::traits::Owned<'a> for Owned {
@@ -15038,14 +20279,8 @@ pub mod secrets_store {
         self.reader.total_size()
       }
       #[inline]
-      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Reader<'a>> {
-        ::capnp::traits::FromPointerReader::get_from_pointer(
-          &self.reader.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      pub fn has_version(&self) -> bool {
-        !self.reader.get_pointer_field(0).is_null()
+      pub fn get_migrated(self) -> u32 {
+        self.reader.get_data_field::<u32>(0)
       }
     }
 
@@ -15117,22 +20352,12 @@ pub mod secrets_store {
         self.builder.into_reader().total_size()
       }
       #[inline]
-      pub fn get_version(self) -> ::capnp::Result<crate::api_capnp::secret_version::Builder<'a>> {
-        ::capnp::traits::FromPointerBuilder::get_from_pointer(
-          self.builder.get_pointer_field(0),
-          ::core::option::Option::None,
-        )
-      }
-      #[inline]
-      pub fn set_version<'b>(&mut self, value: crate::api_capnp::secret_version::Reader<'b>) -> ::capnp::Result<()> {
-        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value, false)
+      pub fn get_migrated(self) -> u32 {
+        self.builder.get_data_field::<u32>(0)
       }
       #[inline]
-      pub fn init_version(self) -> crate::api_capnp::secret_version::Builder<'a> {
-        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), 0)
-      }
-      pub fn has_version(&self) -> bool {
-        !self.builder.get_pointer_field(0).is_null()
+      pub fn set_migrated(&mut self, value: u32) {
+        self.builder.set_data_field::<u32>(0, value);
       }
     }
 
@@ -15144,15 +20369,11 @@ pub mod secrets_store {
         Pipeline { _typeless: typeless }
       }
     }
-    impl Pipeline {
-      pub fn get_version(&self) -> crate::api_capnp::secret_version::Pipeline {
-        ::capnp::capability::FromTypelessPipeline::new(self._typeless.get_pointer_field(0))
-      }
-    }
+    impl Pipeline {}
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 1 };
-      pub const TYPE_ID: u64 = 0x96d4_7324_ae25_64dd;
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 0 };
+      pub const TYPE_ID: u64 = 0x8fdd_8912_f678_6dd5;
     }
   }
 }
@@ -1,10 +1,10 @@
-use super::{BlockStore, Change, ChangeLog, Operation, StoreError, StoreResult};
+use super::{BlockStore, Change, ChangeLog, Operation, SchemaRef, StoreError, StoreResult};
 use crate::memguard::weak::ZeroingWords;
 use data_encoding::HEXLOWER;
 use log::warn;
 use log::{debug, info};
 use sha2::{Digest, Sha256};
-use std::fs::{metadata, read_dir, DirBuilder, File, OpenOptions};
+use std::fs::{metadata, read_dir, remove_file, DirBuilder, File, OpenOptions};
 use std::io::prelude::*;
 use std::io::{self, BufReader, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
@@ -194,6 +194,59 @@ impl BlockStore for LocalDirBlockStore {
     Ok(())
   }
 
+  fn list_schema_refs(&self) -> StoreResult<Vec<SchemaRef>> {
+    let schemas_dir = match read_dir(self.base_dir.read()?.join("schemas")) {
+      Ok(dir) => dir,
+      Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+      Err(err) => return Err(err.into()),
+    };
+    let mut schema_refs = vec![];
+
+    for maybe_node_entry in schemas_dir {
+      let node_entry = maybe_node_entry?;
+
+      if !node_entry.metadata()?.is_dir() {
+        continue;
+      }
+      let node_id = node_entry.file_name().to_string_lossy().to_string();
+
+      for maybe_entry in read_dir(node_entry.path())? {
+        let entry = maybe_entry?;
+
+        if !entry.metadata()?.is_file() {
+          continue;
+        }
+        schema_refs.push(SchemaRef::new(node_id.as_str(), entry.file_name().to_string_lossy()));
+      }
+    }
+
+    Ok(schema_refs)
+  }
+
+  fn get_schema(&self, schema_ref: &SchemaRef) -> StoreResult<ZeroingWords> {
+    let base_dir = self.base_dir.read()?;
+    Self::read_optional_file(
+      base_dir
+        .join("schemas")
+        .join(&schema_ref.node)
+        .join(&schema_ref.schema_id),
+    )?
+    .ok_or_else(|| StoreError::InvalidBlock(schema_ref.schema_id.to_string()))
+  }
+
+  fn store_schema(&self, schema_id: &str, raw: &[u8]) -> StoreResult<()> {
+    debug!("Try storing schema {}", schema_id);
+    let schema_dir = self.base_dir.write()?.join("schemas").join(&self.node_id);
+    DirBuilder::new().recursive(true).create(&schema_dir)?;
+    let mut schema_file = File::create(schema_dir.join(schema_id))?;
+
+    schema_file.write_all(raw)?;
+    schema_file.flush()?;
+    schema_file.sync_all()?;
+
+    Ok(())
+  }
+
   fn add_block(&self, raw: &[u8]) -> StoreResult<String> {
     let base_dir = self.base_dir.write()?;
     let block_id = Self::generate_id(raw);
@@ -218,6 +271,17 @@ impl BlockStore for LocalDirBlockStore {
     Self::read_optional_file(&block_file_path)?.ok_or_else(|| StoreError::InvalidBlock(block.to_string()))
   }
 
+  fn remove_block(&self, block: &str) -> StoreResult<()> {
+    debug!("Try removing block {}", block);
+    let base_dir = self.base_dir.write()?;
+
+    match remove_file(Self::block_file(&base_dir, block)?) {
+      Ok(_) => Ok(()),
+      Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+      Err(err) => Err(err.into()),
+    }
+  }
+
   fn commit(&self, changes: &[Change]) -> StoreResult<()> {
     let base_dir = self.base_dir.write()?;
     DirBuilder::new().recursive(true).create(base_dir.join("logs"))?;
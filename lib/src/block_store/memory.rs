@@ -4,7 +4,7 @@ use std::sync::RwLock;
 use data_encoding::HEXLOWER;
 use sha2::{Digest, Sha256};
 
-use super::{BlockStore, Change, ChangeLog, StoreError, StoreResult};
+use super::{BlockStore, Change, ChangeLog, SchemaRef, StoreError, StoreResult};
 use crate::memguard::weak::ZeroingWords;
 
 /// Memory based reference implementation of a block store.
@@ -16,6 +16,7 @@ pub struct MemoryBlockStore {
   node_id: String,
   rings: RwLock<HashMap<String, ZeroingWords>>,
   indexes: RwLock<HashMap<String, ZeroingWords>>,
+  schemas: RwLock<HashMap<SchemaRef, ZeroingWords>>,
   blocks: RwLock<HashMap<String, ZeroingWords>>,
   changes: RwLock<HashMap<String, Vec<Change>>>,
 }
@@ -26,6 +27,7 @@ impl MemoryBlockStore {
       node_id: node_id.to_string(),
       rings: RwLock::new(HashMap::new()),
       indexes: RwLock::new(HashMap::new()),
+      schemas: RwLock::new(HashMap::new()),
       blocks: RwLock::new(HashMap::new()),
       changes: RwLock::new(HashMap::new()),
     }
@@ -95,6 +97,28 @@ impl BlockStore for MemoryBlockStore {
     Ok(())
   }
 
+  fn list_schema_refs(&self) -> StoreResult<Vec<SchemaRef>> {
+    let schemas = self.schemas.read()?;
+
+    Ok(schemas.keys().cloned().collect())
+  }
+
+  fn get_schema(&self, schema_ref: &SchemaRef) -> StoreResult<ZeroingWords> {
+    let schemas = self.schemas.read()?;
+
+    schemas
+      .get(schema_ref)
+      .cloned()
+      .ok_or_else(|| StoreError::InvalidBlock(schema_ref.schema_id.to_string()))
+  }
+
+  fn store_schema(&self, schema_id: &str, raw: &[u8]) -> StoreResult<()> {
+    let mut schemas = self.schemas.write()?;
+
+    schemas.insert(SchemaRef::new(&self.node_id, schema_id), raw.into());
+    Ok(())
+  }
+
   fn add_block(&self, raw: &[u8]) -> StoreResult<String> {
     let block_id = Self::generate_id(raw);
     let mut blocks = self.blocks.write()?;
@@ -112,6 +136,13 @@ impl BlockStore for MemoryBlockStore {
       .ok_or_else(|| StoreError::InvalidBlock(block.to_string()))
   }
 
+  fn remove_block(&self, block: &str) -> StoreResult<()> {
+    let mut blocks = self.blocks.write()?;
+
+    blocks.remove(block);
+    Ok(())
+  }
+
   fn commit(&self, changes: &[Change]) -> StoreResult<()> {
     let mut stored_changes = self.changes.write()?;
 
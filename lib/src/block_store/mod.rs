@@ -81,6 +81,24 @@ pub trait BlockStore: Send + Sync {
   ///
   fn store_index(&self, index_id: &str, raw: &[u8]) -> StoreResult<()>;
 
+  /// Get the references of all schema blocks.
+  ///
+  /// Schemas define the fields of custom secret types, they are shared by all
+  /// identities/users of the store. Every node writes its own schema blocks, i.e. if a
+  /// schema has been changed on different nodes there will be one block per node. It is
+  /// up to the client to decide which one wins.
+  fn list_schema_refs(&self) -> StoreResult<Vec<SchemaRef>>;
+
+  /// Get/read a schema block of any node.
+  ///
+  /// Like data blocks, schema blocks have to be protected by the keys inside the ring blocks.
+  ///
+  fn get_schema(&self, schema_ref: &SchemaRef) -> StoreResult<ZeroingWords>;
+
+  /// Set/write a schema block of the current node.
+  ///
+  fn store_schema(&self, schema_id: &str, raw: &[u8]) -> StoreResult<()>;
+
   /// Add a new data block to the store.
   ///
   /// Data blocks contain the secret data shared between clients and should be
@@ -93,6 +111,14 @@ pub trait BlockStore: Send + Sync {
   ///
   fn get_block(&self, block: &str) -> StoreResult<ZeroingWords>;
 
+  /// Remove a block that has never been committed or referenced.
+  ///
+  /// Data blocks are immutable as a rule, this is only supposed to clean up blocks that
+  /// have been added but are of no use (e.g. the chunks of an attachment that has never
+  /// been added to a secret). Removing a block that does not exist is not an error.
+  ///
+  fn remove_block(&self, block: &str) -> StoreResult<()>;
+
   /// Commit a set of changes to the store.
   ///
   /// After adding one or more blocks to the store every client has to
@@ -23,6 +23,22 @@ impl Change {
   }
 }
 
+/// Reference to the schema block written by a specific node.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SchemaRef {
+  pub node: String,
+  pub schema_id: String,
+}
+
+impl SchemaRef {
+  pub fn new<S: Into<String>, T: Into<String>>(node: S, schema_id: T) -> SchemaRef {
+    SchemaRef {
+      node: node.into(),
+      schema_id: schema_id.into(),
+    }
+  }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChangeLog {
   pub node: String,
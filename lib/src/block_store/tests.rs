@@ -1,6 +1,6 @@
 use super::{open_block_store, BlockStore, StoreError};
 use crate::block_store::model::Operation;
-use crate::block_store::{Change, ChangeLog};
+use crate::block_store::{Change, ChangeLog, SchemaRef};
 use crate::memguard::weak::ZeroingWords;
 use rand::rngs::ThreadRng;
 use rand::{distributions, thread_rng, Rng};
@@ -12,6 +12,7 @@ fn common_store_tests(store: Arc<dyn BlockStore>) {
   let mut rng = thread_rng();
   common_test_ring(store.as_ref(), &mut rng);
   common_test_index(store.as_ref(), &mut rng);
+  common_test_schemas(store.as_ref(), &mut rng);
   common_test_blocks_commits(store.as_ref(), &mut rng);
 }
 
@@ -88,6 +89,27 @@ fn common_test_index(store: &dyn BlockStore, rng: &mut ThreadRng) {
   assert_that(&store.get_index(&node2)).is_ok_containing(Some(ZeroingWords::from(node2_index2.as_ref())));
 }
 
+fn common_test_schemas(store: &dyn BlockStore, rng: &mut ThreadRng) {
+  let schema1a = rng
+    .sample_iter(&distributions::Standard)
+    .take(100 * 8)
+    .collect::<Vec<u8>>();
+  let schema1b = rng
+    .sample_iter(&distributions::Standard)
+    .take(120 * 8)
+    .collect::<Vec<u8>>();
+
+  let schema_ref = SchemaRef::new(store.node_id(), "database");
+
+  assert_that(&store.list_schema_refs()).is_ok_containing(vec![]);
+  assert_that(&store.get_schema(&schema_ref)).is_err();
+  assert_that(&store.store_schema("database", &schema1a)).is_ok();
+  assert_that(&store.get_schema(&schema_ref)).is_ok_containing(ZeroingWords::from(schema1a.as_ref()));
+  assert_that(&store.store_schema("database", &schema1b)).is_ok();
+  assert_that(&store.get_schema(&schema_ref)).is_ok_containing(ZeroingWords::from(schema1b.as_ref()));
+  assert_that(&store.list_schema_refs()).is_ok_containing(vec![schema_ref]);
+}
+
 fn common_test_blocks_commits(store: &dyn BlockStore, rng: &mut ThreadRng) {
   assert_that(&store.get_block("00000000000")).is_err_containing(StoreError::InvalidBlock("00000000000".to_string()));
 
@@ -175,6 +197,16 @@ fn common_test_blocks_commits(store: &dyn BlockStore, rng: &mut ThreadRng) {
       },
     ],
   }]);
+
+  let unused = rng
+    .sample_iter(&distributions::Standard)
+    .take(100 * 8)
+    .collect::<Vec<u8>>();
+  let unused_id = store.add_block(&unused).unwrap();
+
+  assert_that(&store.remove_block(&unused_id)).is_ok();
+  assert_that(&store.get_block(&unused_id)).is_err_containing(StoreError::InvalidBlock(unused_id.clone()));
+  assert_that(&store.remove_block(&unused_id)).is_ok();
 }
 
 #[test]
@@ -187,6 +219,30 @@ fn test_local_dir_store() {
   common_store_tests(store);
 }
 
+#[test]
+fn test_local_dir_schemas_of_nodes() {
+  let tempdir = TempDir::new("t-rust-less-test").unwrap();
+  let url = format!("file://{}", tempdir.path().to_string_lossy());
+  let node1 = open_block_store(&url, "node1").unwrap();
+  let node2 = open_block_store(&url, "node2").unwrap();
+  let schema1 = vec![1u8; 8 * 8];
+  let schema2 = vec![2u8; 8 * 8];
+
+  assert_that(&node1.store_schema("database", &schema1)).is_ok();
+  assert_that(&node2.store_schema("database", &schema2)).is_ok();
+
+  // Concurrent changes of different nodes do not overwrite each other
+  let mut schema_refs = node1.list_schema_refs().unwrap();
+  schema_refs.sort_by(|a, b| a.node.cmp(&b.node));
+
+  assert_that(&schema_refs).is_equal_to(vec![
+    SchemaRef::new("node1", "database"),
+    SchemaRef::new("node2", "database"),
+  ]);
+  assert_that(&node1.get_schema(&schema_refs[0])).is_ok_containing(ZeroingWords::from(schema1.as_ref()));
+  assert_that(&node1.get_schema(&schema_refs[1])).is_ok_containing(ZeroingWords::from(schema2.as_ref()));
+}
+
 #[test]
 fn test_memory_store() {
   let store = open_block_store("memory://", "node1").unwrap();
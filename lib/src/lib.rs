@@ -6,6 +6,7 @@ pub mod block_store;
 pub mod clipboard;
 pub mod memguard;
 pub mod otp;
+pub mod pinentry;
 pub mod secrets_store;
 pub mod service;
 
@@ -8,6 +8,7 @@ use std::slice;
 use std::sync::atomic::{AtomicIsize, Ordering};
 
 use rand::{CryptoRng, RngCore};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
 
 /// Strictly memory protected bytes contain sensitive data.
 ///
@@ -398,6 +399,42 @@ impl<'a> io::Write for RefMut<'a> {
   }
 }
 
+/// Deserialize from a string.
+///
+/// An owned string (e.g. of a `serde_json::Value`) is zeroed out, a borrowed one is left to the caller.
+impl<'de> Deserialize<'de> for SecretBytes {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_string(SecretBytesVisitor)
+  }
+}
+
+struct SecretBytesVisitor;
+
+impl<'de> Visitor<'de> for SecretBytesVisitor {
+  type Value = SecretBytes;
+
+  fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    formatter.write_str("a string")
+  }
+
+  fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    Ok(SecretBytes::from_secured(value.as_bytes()))
+  }
+
+  fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+  where
+    E: de::Error,
+  {
+    Ok(SecretBytes::from(value))
+  }
+}
+
 impl std::fmt::Debug for SecretBytes {
   fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
     write!(f, "<Secret>")
@@ -415,6 +452,18 @@ mod tests {
     assert!(actual == expected)
   }
 
+  #[test]
+  fn test_deserialize() {
+    let secret: SecretBytes = serde_json::from_str(r#""borrowed secret""#).unwrap();
+
+    assert_slices_equal(&secret.borrow(), b"borrowed secret");
+
+    let secret: SecretBytes = serde_json::from_value(serde_json::json!("owned secret")).unwrap();
+
+    assert_slices_equal(&secret.borrow(), b"owned secret");
+    assert_that(&serde_json::from_str::<SecretBytes>("42").is_err()).is_true();
+  }
+
   #[test]
   fn test_borrow_read_only() {
     let rng = thread_rng();
@@ -0,0 +1,50 @@
+use super::{PassphrasePrompt, PinentryError, PinentryResult};
+use crate::memguard::{SecretBytes, ZeroizeBytesBuffer};
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use zeroize::Zeroize;
+
+/// Ask an SSH_ASKPASS style program for the passphrase.
+///
+/// The program gets the prompt as last argument and is supposed to print the passphrase
+/// to stdout. A non-zero exit code is interpreted as cancel by the user (i.e. `None`).
+pub fn get_pass(program: &str, args: &[String], prompt: &PassphrasePrompt) -> PinentryResult<Option<SecretBytes>> {
+  let prompt_text = match &prompt.error {
+    Some(error) => format!("{}\n{}", error, prompt.description),
+    None => prompt.description.clone(),
+  };
+  let mut child = Command::new(program)
+    .args(args)
+    .arg(prompt_text)
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .spawn()?;
+  let mut stdout = child
+    .stdout
+    .take()
+    .ok_or_else(|| PinentryError::IO("No stdout from askpass".to_string()))?;
+  let mut output = ZeroizeBytesBuffer::with_capacity(1024);
+  let mut chunk = [0u8; 256];
+
+  let result = loop {
+    match stdout.read(&mut chunk) {
+      Ok(0) => break Ok(()),
+      Ok(len) => output.write_all(&chunk[..len])?,
+      Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+      Err(error) => break Err(error),
+    }
+  };
+  chunk.zeroize();
+  result?;
+
+  if !child.wait()?.success() {
+    return Ok(None);
+  }
+
+  let mut end = output.len();
+  while end > 0 && (output[end - 1] == b'\n' || output[end - 1] == b'\r') {
+    end -= 1;
+  }
+
+  Ok(Some(SecretBytes::from_secured(&output[..end])))
+}
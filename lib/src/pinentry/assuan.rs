@@ -0,0 +1,192 @@
+use super::{PassphrasePrompt, PinentryError, PinentryResult};
+use crate::memguard::{SecretBytes, ZeroizeBytesBuffer};
+use std::env;
+use std::io::{self, Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+/// Lines in the Assuan protocol must not exceed 1000 bytes (including the linefeed)
+const MAX_LINE_LENGTH: usize = 1000;
+/// GPG_ERR_CANCELED. The upper bits of an error code contain the error source, which
+/// differs between the various pinentry implementations.
+const GPG_ERR_CANCELED: u32 = 99;
+const GPG_ERR_CODE_MASK: u32 = 0xffff;
+
+/// Ask a pinentry program for the passphrase.
+///
+/// Returns `None` if the user cancelled the dialog.
+pub fn get_pin(program: &str, args: &[String], prompt: &PassphrasePrompt) -> PinentryResult<Option<SecretBytes>> {
+  let mut child = Command::new(program)
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::null())
+    .spawn()?;
+  let result = AssuanConnection::new(&mut child).and_then(|mut connection| {
+    let result = connection.get_pin(prompt);
+    connection.bye();
+    result
+  });
+
+  child.wait()?;
+
+  result
+}
+
+struct AssuanConnection {
+  stdin: ChildStdin,
+  stdout: ChildStdout,
+}
+
+impl AssuanConnection {
+  fn new(child: &mut Child) -> PinentryResult<AssuanConnection> {
+    let stdin = child
+      .stdin
+      .take()
+      .ok_or_else(|| PinentryError::IO("No stdin to pinentry".to_string()))?;
+    let stdout = child
+      .stdout
+      .take()
+      .ok_or_else(|| PinentryError::IO("No stdout from pinentry".to_string()))?;
+    let mut connection = AssuanConnection { stdin, stdout };
+
+    // Server greeting
+    connection.read_response()?;
+
+    Ok(connection)
+  }
+
+  fn get_pin(&mut self, prompt: &PassphrasePrompt) -> PinentryResult<Option<SecretBytes>> {
+    // Options are just hints, a pinentry is free to reject the ones it does not know
+    if let Ok(tty_name) = env::var("GPG_TTY") {
+      self.transact("OPTION", Some(&format!("ttyname={}", tty_name))).ok();
+    }
+    if let Ok(term) = env::var("TERM") {
+      self.transact("OPTION", Some(&format!("ttytype={}", term))).ok();
+    }
+    self.transact("SETTITLE", Some(&prompt.title))?;
+    self.transact("SETDESC", Some(&prompt.description))?;
+    self.transact("SETPROMPT", Some(&prompt.prompt))?;
+    if let Some(error) = &prompt.error {
+      self.transact("SETERROR", Some(error))?;
+    }
+
+    match self.transact("GETPIN", None) {
+      Ok(data) => Ok(Some(SecretBytes::from_secured(&data))),
+      Err(PinentryError::Pinentry(code, _)) if (code & GPG_ERR_CODE_MASK) == GPG_ERR_CANCELED => Ok(None),
+      Err(error) => Err(error),
+    }
+  }
+
+  fn bye(&mut self) {
+    self.transact("BYE", None).ok();
+  }
+
+  /// Send a command and collect all the data lines of the response.
+  fn transact(&mut self, command: &str, argument: Option<&str>) -> PinentryResult<ZeroizeBytesBuffer> {
+    let mut line = command.to_string();
+
+    if let Some(argument) = argument {
+      line.push(' ');
+      line.push_str(&escape(argument));
+    }
+    line.push('\n');
+    if line.len() > MAX_LINE_LENGTH {
+      return Err(PinentryError::Protocol(format!(
+        "{} exceeds maximum line length",
+        command
+      )));
+    }
+    self.stdin.write_all(line.as_bytes())?;
+    self.stdin.flush()?;
+
+    self.read_response()
+  }
+
+  fn read_response(&mut self) -> PinentryResult<ZeroizeBytesBuffer> {
+    let mut data = ZeroizeBytesBuffer::with_capacity(MAX_LINE_LENGTH);
+
+    loop {
+      let line = self.read_line()?;
+
+      if &line[..] == b"OK" || line.starts_with(b"OK ") {
+        return Ok(data);
+      } else if line.starts_with(b"D ") {
+        unescape_into(&line[2..], &mut data)?;
+      } else if line.starts_with(b"ERR ") {
+        let error = String::from_utf8_lossy(&line[4..]);
+        let mut parts = error.splitn(2, ' ');
+        let code = parts
+          .next()
+          .and_then(|code| code.parse::<u32>().ok())
+          .unwrap_or_default();
+        let description = parts.next().unwrap_or_default().to_string();
+
+        return Err(PinentryError::Pinentry(code, description));
+      } else if line.starts_with(b"S ") || line.starts_with(b"#") || line.is_empty() {
+        // Status and comment lines are not of any interest
+      } else {
+        return Err(PinentryError::Protocol(format!(
+          "Unexpected response: {}",
+          String::from_utf8_lossy(&line[..line.len().min(20)])
+        )));
+      }
+    }
+  }
+
+  /// Read a single line byte by byte, so that no part of a data line ends up in some
+  /// (unprotected) read buffer.
+  fn read_line(&mut self) -> PinentryResult<ZeroizeBytesBuffer> {
+    let mut line = ZeroizeBytesBuffer::with_capacity(MAX_LINE_LENGTH + 1);
+    let mut byte = [0u8; 1];
+
+    loop {
+      match self.stdout.read(&mut byte) {
+        Ok(0) => return Err(PinentryError::Protocol("Unexpected end of stream".to_string())),
+        Ok(_) if byte[0] == b'\n' => return Ok(line),
+        Ok(_) if line.len() >= MAX_LINE_LENGTH => return Err(PinentryError::Protocol("Line too long".to_string())),
+        Ok(_) => line.write_all(&byte)?,
+        Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+        Err(error) => return Err(error.into()),
+      }
+      byte[0] = 0;
+    }
+  }
+}
+
+/// Percent-escape an argument, as required for `%`, CR and LF
+pub(super) fn escape(argument: &str) -> String {
+  let mut escaped = String::with_capacity(argument.len());
+
+  for ch in argument.chars() {
+    match ch {
+      '%' => escaped.push_str("%25"),
+      '\n' => escaped.push_str("%0A"),
+      '\r' => escaped.push_str("%0D"),
+      ch => escaped.push(ch),
+    }
+  }
+
+  escaped
+}
+
+pub(super) fn unescape_into<W: Write>(escaped: &[u8], target: &mut W) -> PinentryResult<()> {
+  let mut idx = 0;
+
+  while idx < escaped.len() {
+    if escaped[idx] == b'%' {
+      let decoded = escaped
+        .get(idx + 1..idx + 3)
+        .and_then(|hex| std::str::from_utf8(hex).ok())
+        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| PinentryError::Protocol("Invalid escape sequence".to_string()))?;
+
+      target.write_all(&[decoded])?;
+      idx += 3;
+    } else {
+      target.write_all(&escaped[idx..=idx])?;
+      idx += 1;
+    }
+  }
+
+  Ok(())
+}
@@ -0,0 +1,24 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum PinentryError {
+  IO(String),
+  Protocol(String),
+  Pinentry(u32, String),
+}
+
+impl fmt::Display for PinentryError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      PinentryError::IO(error) => write!(f, "IO: {}", error)?,
+      PinentryError::Protocol(error) => write!(f, "Protocol violation: {}", error)?,
+      PinentryError::Pinentry(code, description) => write!(f, "Pinentry error {}: {}", code, description)?,
+    }
+
+    Ok(())
+  }
+}
+
+pub type PinentryResult<T> = Result<T, PinentryError>;
+
+error_convert_from!(std::io::Error, PinentryError, IO(display));
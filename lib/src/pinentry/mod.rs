@@ -0,0 +1,65 @@
+//! Collect passphrases via external helper programs.
+//!
+//! Two kinds of helpers are supported:
+//! * Any `pinentry-*` program of the GnuPG project (or compatible), talking the Assuan protocol.
+//! * SSH_ASKPASS style programs, that just print the passphrase to stdout.
+use crate::memguard::SecretBytes;
+use serde_derive::{Deserialize, Serialize};
+use std::env;
+
+mod askpass;
+mod assuan;
+mod error;
+
+#[cfg(all(test, unix))]
+mod tests;
+
+pub use self::error::*;
+
+/// Environment variable to configure a pinentry program for a client
+pub const PINENTRY_ENV: &str = "T_RUST_LESS_PINENTRY";
+/// Environment variable used by ssh to configure an askpass program
+pub const ASKPASS_ENV: &str = "SSH_ASKPASS";
+
+#[derive(Clone, Debug, Default)]
+pub struct PassphrasePrompt {
+  pub title: String,
+  pub description: String,
+  pub prompt: String,
+  /// Error to display, e.g. when asking again after an invalid passphrase
+  pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum PassphraseHelper {
+  Pinentry {
+    program: String,
+    #[serde(default)]
+    args: Vec<String>,
+  },
+  Askpass {
+    program: String,
+    #[serde(default)]
+    args: Vec<String>,
+  },
+}
+
+impl PassphraseHelper {
+  /// Passphrase helper configured via environment, `T_RUST_LESS_PINENTRY` takes precedence over `SSH_ASKPASS`.
+  pub fn from_env() -> Option<PassphraseHelper> {
+    match (env::var(PINENTRY_ENV), env::var(ASKPASS_ENV)) {
+      (Ok(program), _) if !program.is_empty() => Some(PassphraseHelper::Pinentry { program, args: vec![] }),
+      (_, Ok(program)) if !program.is_empty() => Some(PassphraseHelper::Askpass { program, args: vec![] }),
+      _ => None,
+    }
+  }
+
+  /// Ask for a passphrase, `None` if the user cancelled.
+  pub fn read_passphrase(&self, prompt: &PassphrasePrompt) -> PinentryResult<Option<SecretBytes>> {
+    match self {
+      PassphraseHelper::Pinentry { program, args } => assuan::get_pin(program, args, prompt),
+      PassphraseHelper::Askpass { program, args } => askpass::get_pass(program, args, prompt),
+    }
+  }
+}
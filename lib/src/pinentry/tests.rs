@@ -0,0 +1,112 @@
+use super::assuan::{escape, unescape_into};
+use super::{PassphraseHelper, PassphrasePrompt};
+use crate::memguard::ZeroizeBytesBuffer;
+use spectral::prelude::*;
+use std::fs;
+use tempdir::TempDir;
+
+const FAKE_PINENTRY: &str = r#"
+echo "OK Pleased to meet you"
+while read -r cmd arg; do
+  case "$cmd" in
+    SETDESC) echo "$arg" > "$(dirname "$0")/desc"; echo "OK" ;;
+    GETPIN) echo "S PASSWORD_FROM_CACHE"; echo "D secret%25pass%0Aword"; echo "OK" ;;
+    BYE) echo "OK closing connection"; exit 0 ;;
+    *) echo "OK" ;;
+  esac
+done
+"#;
+
+const CANCELLING_PINENTRY: &str = r#"
+echo "OK Pleased to meet you"
+while read -r cmd arg; do
+  case "$cmd" in
+    GETPIN) echo "ERR 83886179 Operation cancelled <Pinentry>" ;;
+    *) echo "OK" ;;
+  esac
+done
+"#;
+
+fn script_helper(dir: &TempDir, content: &str, askpass: bool) -> PassphraseHelper {
+  let script = dir.path().join("helper.sh");
+
+  fs::write(&script, content).unwrap();
+
+  // Going through sh avoids any trouble with executable flags on freshly written files
+  let program = "/bin/sh".to_string();
+  let args = vec![script.to_string_lossy().to_string()];
+
+  if askpass {
+    PassphraseHelper::Askpass { program, args }
+  } else {
+    PassphraseHelper::Pinentry { program, args }
+  }
+}
+
+fn prompt() -> PassphrasePrompt {
+  PassphrasePrompt {
+    title: "t-rust-less".to_string(),
+    description: "Unlock store 100%\nsecure".to_string(),
+    prompt: "Passphrase".to_string(),
+    error: None,
+  }
+}
+
+#[test]
+fn test_escape() {
+  assert_that(&escape("plain text")).is_equal_to("plain text".to_string());
+  assert_that(&escape("100%\r\nsure")).is_equal_to("100%25%0D%0Asure".to_string());
+}
+
+#[test]
+fn test_unescape() {
+  let mut target = ZeroizeBytesBuffer::with_capacity(20);
+
+  unescape_into(b"a%25b%0Ac%0d", &mut target).unwrap();
+
+  assert_that(&&target[..]).is_equal_to(&b"a%b\nc\r"[..]);
+  assert_that(&unescape_into(b"broken%2", &mut target)).is_err();
+  assert_that(&unescape_into(b"broken%zz", &mut target)).is_err();
+}
+
+#[test]
+fn test_fake_pinentry() {
+  let dir = TempDir::new("t-rust-less-pinentry").unwrap();
+  let helper = script_helper(&dir, FAKE_PINENTRY, false);
+
+  let passphrase = helper.read_passphrase(&prompt()).unwrap().unwrap();
+
+  assert_that(&passphrase.borrow().as_str()).is_equal_to("secret%pass\nword");
+  assert_that(&fs::read_to_string(dir.path().join("desc")).unwrap())
+    .is_equal_to("Unlock store 100%25%0Asecure\n".to_string());
+}
+
+#[test]
+fn test_cancelled_pinentry() {
+  let dir = TempDir::new("t-rust-less-pinentry").unwrap();
+  let helper = script_helper(&dir, CANCELLING_PINENTRY, false);
+
+  assert_that(&helper.read_passphrase(&prompt()).unwrap()).is_none();
+}
+
+#[test]
+fn test_broken_pinentry() {
+  let dir = TempDir::new("t-rust-less-pinentry").unwrap();
+  let helper = script_helper(&dir, "echo 'OK Pleased to meet you'\nexit 0\n", false);
+
+  assert_that(&helper.read_passphrase(&prompt())).is_err();
+}
+
+#[test]
+fn test_askpass() {
+  let dir = TempDir::new("t-rust-less-pinentry").unwrap();
+  let helper = script_helper(&dir, "echo \"askpass secret\"\n", true);
+
+  let passphrase = helper.read_passphrase(&prompt()).unwrap().unwrap();
+
+  assert_that(&passphrase.borrow().as_str()).is_equal_to("askpass secret");
+
+  let helper = script_helper(&dir, "exit 1\n", true);
+
+  assert_that(&helper.read_passphrase(&prompt()).unwrap()).is_none();
+}
@@ -0,0 +1,111 @@
+use crate::api::SecretAttachment;
+use crate::memguard::SecretBytes;
+use crate::secrets_store::{SecretStoreError, SecretStoreResult};
+use data_encoding::HEXLOWER;
+use sha2::{Digest, Sha256};
+use std::io::{self, Read, Write};
+use zeroize::Zeroizing;
+
+/// Size of the chunks the content of an attachment is split into.
+///
+/// Every chunk is stored as a separate (encrypted) block, so that large attachments neither
+/// have to fit into memory at once nor blow up the padding of the secret version.
+pub const ATTACHMENT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// SHA-256 of the content of an attachment (hex).
+pub fn content_hash(content: &[u8]) -> String {
+  let mut hasher = Sha256::new();
+
+  hasher.update(content);
+
+  HEXLOWER.encode(&hasher.finalize())
+}
+
+/// Read the content of an attachment chunk by chunk and store every chunk via `add_chunk`.
+pub fn store_chunks<F>(
+  name: &str,
+  mime_type: &str,
+  content: &mut dyn Read,
+  max_size: Option<u64>,
+  mut add_chunk: F,
+) -> SecretStoreResult<SecretAttachment>
+where
+  F: FnMut(&[u8]) -> SecretStoreResult<String>,
+{
+  let mut hasher = Sha256::new();
+  let mut buffer = Zeroizing::new(vec![0u8; ATTACHMENT_CHUNK_SIZE]);
+  let mut size = 0u64;
+  let mut chunks = Vec::new();
+
+  loop {
+    let len = read_chunk(content, &mut buffer)?;
+    if len == 0 {
+      break;
+    }
+    size += len as u64;
+    if let Some(max_size) = max_size.filter(|max_size| size > *max_size) {
+      return Err(SecretStoreError::AttachmentTooLarge(max_size));
+    }
+    hasher.update(&buffer[..len]);
+    chunks.push(add_chunk(&buffer[..len])?);
+    if len < buffer.len() {
+      break;
+    }
+  }
+
+  Ok(SecretAttachment::stored(
+    name.to_string(),
+    mime_type.to_string(),
+    HEXLOWER.encode(&hasher.finalize()),
+    size,
+    chunks,
+  ))
+}
+
+/// Write the content of an attachment, chunks are fetched via `get_chunk`.
+///
+/// The content is verified against its hash, be aware though that an error is only reported
+/// after all chunks have been written.
+pub fn write_content<F>(attachment: &SecretAttachment, out: &mut dyn Write, mut get_chunk: F) -> SecretStoreResult<()>
+where
+  F: FnMut(&str) -> SecretStoreResult<SecretBytes>,
+{
+  if !attachment.is_stored() {
+    out.write_all(attachment.content())?;
+    return Ok(());
+  }
+  let mut hasher = Sha256::new();
+  let mut size = 0u64;
+
+  for block_id in attachment.chunks() {
+    let chunk = get_chunk(block_id)?;
+    let borrowed = chunk.borrow();
+
+    hasher.update(&borrowed[..]);
+    size += borrowed.len() as u64;
+    out.write_all(&borrowed)?;
+  }
+  out.flush()?;
+
+  if size != attachment.size() || HEXLOWER.encode(&hasher.finalize()) != attachment.content_hash() {
+    return Err(SecretStoreError::InvalidAttachment(attachment.name().to_string()));
+  }
+
+  Ok(())
+}
+
+/// Fill the buffer as far as possible, i.e. a short read only happens at the end of the content.
+fn read_chunk(content: &mut dyn Read, buffer: &mut [u8]) -> io::Result<usize> {
+  let mut len = 0;
+
+  while len < buffer.len() {
+    match content.read(&mut buffer[len..]) {
+      Ok(0) => break,
+      Ok(read) => len += read,
+      Err(ref error) if error.kind() == io::ErrorKind::Interrupted => continue,
+      Err(error) => return Err(error),
+    }
+  }
+
+  Ok(len)
+}
@@ -0,0 +1,103 @@
+use crate::secrets_store::SecretStoreResult;
+use data_encoding::HEXUPPER;
+use serde_derive::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek, SeekFrom};
+use std::path::PathBuf;
+use zeroize::Zeroize;
+
+/// Configuration of the offline check for breached passwords.
+///
+/// `file` is the "ordered by hash" SHA-1 version of the Pwned Passwords list
+/// (https://haveibeenpwned.com/Passwords), i.e. lines of `HASH:COUNT` sorted by hash.
+/// The file is only searched on disk, so no network access is required.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BreachCheck {
+  pub file: PathBuf,
+  /// Reject new secret versions containing a breached password instead of just reporting it
+  #[serde(default)]
+  pub reject: bool,
+}
+
+impl BreachCheck {
+  pub fn open(&self) -> SecretStoreResult<BreachedPasswords> {
+    let file = File::open(&self.file)?;
+    let size = file.metadata()?.len();
+
+    Ok(BreachedPasswords {
+      reader: BufReader::new(file),
+      size,
+    })
+  }
+}
+
+/// An opened breached passwords file.
+pub struct BreachedPasswords {
+  reader: BufReader<File>,
+  size: u64,
+}
+
+impl BreachedPasswords {
+  /// Number of times the password appears in the breach file, 0 if it was not found.
+  pub fn occurrences(&mut self, password: &str) -> SecretStoreResult<u64> {
+    let mut hash = HEXUPPER.encode(&Sha1::digest(password.as_bytes()));
+    let mut line = String::new();
+    let mut low = 0u64;
+    let mut high = self.size;
+    let mut result = 0;
+
+    // Binary search over byte offsets: Each step looks at the first line starting at or after the middle.
+    while low < high {
+      let middle = low + (high - low) / 2;
+      let line_end = match self.read_line_at(middle, &mut line)? {
+        Some(line_end) => line_end,
+        None => {
+          high = middle;
+          continue;
+        }
+      };
+      let line_hash = line.split(':').next().unwrap_or_default().trim().to_uppercase();
+
+      match line_hash.as_str().cmp(&hash) {
+        Ordering::Equal => {
+          result = line
+            .split(':')
+            .nth(1)
+            .and_then(|count| count.trim().parse().ok())
+            .unwrap_or(1);
+          break;
+        }
+        Ordering::Less => low = line_end,
+        Ordering::Greater => high = middle,
+      }
+    }
+    hash.zeroize();
+
+    Ok(result)
+  }
+
+  /// Read the first line starting at or after `offset`, returns the offset after the line
+  /// or None if there is no such line.
+  fn read_line_at(&mut self, offset: u64, line: &mut String) -> SecretStoreResult<Option<u64>> {
+    let mut position = offset;
+
+    line.clear();
+    if offset > 0 {
+      // Skip the rest of the line the offset might be pointing into
+      self.reader.seek(SeekFrom::Start(offset - 1))?;
+      position = offset - 1 + self.reader.read_line(line)? as u64;
+      line.clear();
+    } else {
+      self.reader.seek(SeekFrom::Start(0))?;
+    }
+    let read = self.reader.read_line(line)?;
+
+    if read == 0 || line.trim().is_empty() {
+      return Ok(None);
+    }
+
+    Ok(Some(position + read as u64))
+  }
+}
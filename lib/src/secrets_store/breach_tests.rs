@@ -0,0 +1,50 @@
+use crate::secrets_store::breach::BreachCheck;
+use data_encoding::HEXUPPER;
+use sha1::{Digest, Sha1};
+use spectral::prelude::*;
+use std::fs;
+use tempdir::TempDir;
+
+fn breach_line(password: &str, count: u64) -> String {
+  format!("{}:{}", HEXUPPER.encode(&Sha1::digest(password.as_bytes())), count)
+}
+
+#[test]
+fn test_breached_passwords() {
+  let dir = TempDir::new("t-rust-less-breach").unwrap();
+  let mut lines: Vec<String> = (0..1000)
+    .map(|i| breach_line(&format!("password{}", i), i + 1))
+    .collect();
+  lines.sort();
+  fs::write(dir.path().join("pwned.txt"), lines.join("\r\n") + "\r\n").unwrap();
+  fs::write(dir.path().join("single.txt"), breach_line("123456", 42)).unwrap();
+
+  let breach_check = BreachCheck {
+    file: dir.path().join("pwned.txt"),
+    reject: false,
+  };
+  let mut breached_passwords = breach_check.open().unwrap();
+
+  for i in 0..1000 {
+    assert_that(&breached_passwords.occurrences(&format!("password{}", i)).unwrap()).is_equal_to(i + 1);
+  }
+  assert_that(&breached_passwords.occurrences("password1000").unwrap()).is_equal_to(0);
+  assert_that(&breached_passwords.occurrences("").unwrap()).is_equal_to(0);
+
+  let mut single = BreachCheck {
+    file: dir.path().join("single.txt"),
+    reject: false,
+  }
+  .open()
+  .unwrap();
+
+  assert_that(&single.occurrences("123456").unwrap()).is_equal_to(42);
+  assert_that(&single.occurrences("1234567").unwrap()).is_equal_to(0);
+
+  let missing = BreachCheck {
+    file: dir.path().join("missing.txt"),
+    reject: true,
+  };
+
+  assert_that(&missing.open().is_err()).is_true();
+}
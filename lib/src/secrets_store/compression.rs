@@ -0,0 +1,95 @@
+use crate::memguard::{SecretBytes, ZeroizeBytesBuffer};
+use crate::secrets_store::padding::{Padding, RandomFrontBack};
+use crate::secrets_store::{SecretStoreError, SecretStoreResult};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use std::io::{self, Read, Write};
+
+/// Smallest size of a padded block with compressed content.
+const MIN_PADDED_SIZE: usize = 512;
+
+/// Largest size of decompressed content, i.e. a protection against decompression bombs.
+///
+/// This is way more than any regular block (including the index of a large store) will ever need.
+const MAX_DECOMPRESSED_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Compress (DEFLATE) and pad the content of a block.
+///
+/// The compressed content is padded to the next power of two. The size of a regular block
+/// only reveals the length of its content (rounded to 512 bytes), the size of compressed
+/// content would also reveal how repetitive it is, which is mitigated by these coarse buckets.
+pub fn compress_and_pad(data: &[u8]) -> SecretStoreResult<SecretBytes> {
+  let mut encoder = DeflateEncoder::new(ZeroizeBytesBuffer::with_capacity(data.len()), Compression::default());
+
+  encoder.write_all(data)?;
+
+  let compressed = encoder.finish()?;
+
+  RandomFrontBack::pad_secret_data(&compressed, padded_size(compressed.len()))
+}
+
+/// Counterpart of `compress_and_pad`.
+pub fn unpad_and_decompress(padded: &[u8]) -> SecretStoreResult<SecretBytes> {
+  unpad_and_decompress_limited(padded, MAX_DECOMPRESSED_SIZE)
+}
+
+fn unpad_and_decompress_limited(padded: &[u8], max_size: u64) -> SecretStoreResult<SecretBytes> {
+  let compressed = RandomFrontBack::unpad_data(padded)?;
+  let mut data = ZeroizeBytesBuffer::with_capacity(4 * compressed.len());
+  // One more byte than allowed is sufficient to tell that the content is too large
+  let size = io::copy(&mut DeflateDecoder::new(compressed).take(max_size + 1), &mut data)?;
+
+  if size > max_size {
+    return Err(SecretStoreError::IO(format!(
+      "Decompressed content exceeds {} bytes",
+      max_size
+    )));
+  }
+
+  Ok(SecretBytes::from_secured(&data))
+}
+
+fn padded_size(compressed_length: usize) -> usize {
+  // RandomFrontBack requires some extra bytes for the separator and the encoded length
+  (compressed_length + 16).next_power_of_two().max(MIN_PADDED_SIZE)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+
+  #[test]
+  fn test_padded_size() {
+    assert_that(&padded_size(0)).is_equal_to(512);
+    assert_that(&padded_size(496)).is_equal_to(512);
+    assert_that(&padded_size(497)).is_equal_to(1024);
+    assert_that(&padded_size(100_000)).is_equal_to(131_072);
+  }
+
+  #[test]
+  fn test_compress_roundtrip() {
+    let data =
+      br#"{"name":"Some note","properties":{"notes":"repetitive repetitive repetitive repetitive"}}"#.repeat(100);
+    let padded = compress_and_pad(&data).unwrap();
+
+    assert_that(&padded.len()).is_equal_to(512);
+
+    let decompressed = unpad_and_decompress(&padded.borrow()).unwrap();
+
+    assert_that(&decompressed.borrow().as_bytes()).is_equal_to(&data[..]);
+  }
+
+  #[test]
+  fn test_decompress_limit() {
+    let data = vec![0u8; 10_000];
+    let padded = compress_and_pad(&data).unwrap();
+
+    assert_that(&unpad_and_decompress_limited(&padded.borrow(), 10_000).map(|decompressed| decompressed.len()))
+      .is_ok_containing(10_000);
+    assert_that(&unpad_and_decompress_limited(&padded.borrow(), 9_999).map(|_| ())).is_err_containing(
+      SecretStoreError::IO("Decompressed content exceeds 9999 bytes".to_string()),
+    );
+  }
+}
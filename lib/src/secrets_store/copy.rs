@@ -0,0 +1,80 @@
+use crate::api::SecretAttachment;
+use crate::secrets_store::{SecretStoreError, SecretStoreResult, SecretsStore};
+
+/// Re-create a secret of one store in another store (both have to be unlocked).
+///
+/// Either only the current version or the full history (oldest first) is copied. The copy
+/// has a linear history, i.e. merges in the source store are not preserved. The recipients are
+/// not copied either, the copy is readable by all identities of the target store.
+///
+/// The result is the block id of the current version in the target store.
+pub fn copy_secret(
+  source: &dyn SecretsStore,
+  target: &dyn SecretsStore,
+  secret_id: &str,
+  with_history: bool,
+) -> SecretStoreResult<String> {
+  match target.get(secret_id) {
+    Err(SecretStoreError::NotFound) => (),
+    Err(error) => return Err(error),
+    Ok(_) => return Err(SecretStoreError::Conflict),
+  }
+  let secret = source.get(secret_id)?;
+  let block_ids: Vec<&str> = if with_history {
+    secret
+      .versions
+      .iter()
+      .rev()
+      .map(|version| version.block_id.as_str())
+      .collect()
+  } else {
+    vec![secret.current_block_id.as_str()]
+  };
+  let recipients: Vec<String> = target
+    .identities()?
+    .into_iter()
+    .map(|identity| identity.id.clone())
+    .collect();
+  let mut target_schemas = target.list_schemas()?;
+  let mut parent_block_id = None;
+
+  for block_id in block_ids {
+    let mut version = source.get_version(block_id)?;
+
+    if let Some(custom_type) = version.custom_type.as_ref() {
+      if !target_schemas.iter().any(|schema| &schema.id == custom_type) {
+        if let Some(schema) = source
+          .list_schemas()?
+          .into_iter()
+          .find(|schema| &schema.id == custom_type)
+        {
+          target.store_schema(schema.clone())?;
+          target_schemas.push(schema);
+        }
+      }
+    }
+    // Chunks of attachments are blocks of the source store, the target store has to add its own
+    for attachment in version
+      .attachments
+      .iter_mut()
+      .filter(|attachment| attachment.is_stored())
+    {
+      let mut content = Vec::with_capacity(attachment.size() as usize);
+
+      source.get_attachment(attachment, &mut content)?;
+      *attachment = SecretAttachment::new(
+        attachment.name().to_string(),
+        attachment.mime_type().to_string(),
+        content,
+      );
+    }
+    version.recipients = recipients.clone();
+    version.parent_block_id = parent_block_id.take();
+    version.merged_block_ids.clear();
+
+    parent_block_id = Some(target.add(version)?);
+  }
+  target.update_index()?;
+
+  parent_block_id.ok_or(SecretStoreError::NotFound)
+}
@@ -21,6 +21,13 @@ pub enum SecretStoreError {
   InvalidRecipient(String),
   MissingPrivateKey(String),
   NotFound,
+  BreachedPassword(String),
+  InvalidQuery(String),
+  Schema(String),
+  AttachmentTooLarge(u64),
+  InvalidAttachment(String),
+  UnsupportedPayload(u8),
+  FullTextDisabled,
 }
 
 impl fmt::Display for SecretStoreError {
@@ -43,6 +50,15 @@ impl fmt::Display for SecretStoreError {
       SecretStoreError::InvalidRecipient(error) => write!(f, "Invalid recipient: {}", error)?,
       SecretStoreError::MissingPrivateKey(cipher) => write!(f, "Missing private key for cipher: {}", cipher)?,
       SecretStoreError::NotFound => write!(f, "Secret not found")?,
+      SecretStoreError::BreachedPassword(property) => write!(f, "Password {} appears in breached passwords", property)?,
+      SecretStoreError::InvalidQuery(error) => write!(f, "Invalid query: {}", error)?,
+      SecretStoreError::Schema(error) => write!(f, "Schema error: {}", error)?,
+      SecretStoreError::AttachmentTooLarge(max_size) => {
+        write!(f, "Attachment exceeds the maximum size of {} bytes", max_size)?
+      }
+      SecretStoreError::InvalidAttachment(name) => write!(f, "Content of attachment {} is corrupted", name)?,
+      SecretStoreError::UnsupportedPayload(version) => write!(f, "Unsupported payload version: {}", version)?,
+      SecretStoreError::FullTextDisabled => write!(f, "Full-text index is not enabled for this store")?,
     }
     Ok(())
   }
@@ -58,6 +74,8 @@ error_convert_from!(std::io::Error, SecretStoreError, IO(display));
 error_convert_from!(chacha20_poly1305_aead::DecryptError, SecretStoreError, Cipher(display));
 error_convert_from!(capnp::NotInSchema, SecretStoreError, IO(display));
 error_convert_from!(serde_json::Error, SecretStoreError, Json(display));
+error_convert_from!(crate::api::QueryError, SecretStoreError, InvalidQuery(display));
+error_convert_from!(crate::api::SchemaError, SecretStoreError, Schema(display));
 error_convert_from!(StoreError, SecretStoreError, BlockStore(direct));
 
 impl<T> From<std::sync::PoisonError<T>> for SecretStoreError {
@@ -16,12 +16,14 @@ impl PasswordEstimator for ZxcvbnEstimator {
         },
         crack_time_display: format!("{}", entropy.crack_times().offline_fast_hashing_1e10_per_second()),
         score: entropy.score(),
+        breach_count: 0,
       },
       Err(ZxcvbnError::BlankPassword) => PasswordStrength {
         entropy: 0.0,
         crack_time: 0.0,
         crack_time_display: "Instant".to_string(),
         score: 0,
+        breach_count: 0,
       },
     }
   }
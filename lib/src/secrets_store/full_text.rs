@@ -0,0 +1,113 @@
+use crate::api::{FieldKind, SecretTypeSchema, SecretVersion, TextMatch, PROPERTY_TOTP_URL};
+use crate::secrets_store::SecretStoreResult;
+use crate::secrets_store_capnp::indexed_text;
+
+/// Pseudo property for the names of the attachments of a secret.
+pub const PROPERTY_ATTACHMENTS: &str = "attachments";
+
+struct Token {
+  position: usize,
+  length: usize,
+  word: String,
+}
+
+/// Split a text into lower case words consisting of alphanumeric chars.
+///
+/// Positions and lengths are in chars of the original text, so that they can be used as highlights.
+fn tokenize(text: &str) -> Vec<Token> {
+  let mut tokens = Vec::new();
+  let mut current: Option<Token> = None;
+
+  for (position, ch) in text.chars().enumerate() {
+    if ch.is_alphanumeric() {
+      let token = current.get_or_insert_with(|| Token {
+        position,
+        length: 0,
+        word: String::new(),
+      });
+      token.length += 1;
+      token.word.extend(ch.to_lowercase());
+    } else if let Some(token) = current.take() {
+      tokens.push(token);
+    }
+  }
+  tokens.extend(current);
+
+  tokens
+}
+
+/// All the texts of a secret version that are supposed to be searchable.
+///
+/// This includes all properties that are not a password (or a TOTP secret) and the names of the attachments.
+/// For custom types the kinds of the fields in the schema decide what is a password or TOTP secret.
+pub fn searchable_texts<'a>(version: &'a SecretVersion, schemas: &[SecretTypeSchema]) -> Vec<(&'a str, &'a str)> {
+  let password_properties = version.password_properties(schemas);
+  let mut totp_properties = version
+    .schema(schemas)
+    .map(|schema| schema.properties_of_kind(FieldKind::Totp))
+    .unwrap_or_default();
+  totp_properties.push(PROPERTY_TOTP_URL);
+  let mut texts: Vec<(&str, &str)> = version
+    .properties
+    .iter()
+    .filter(|(property, value)| {
+      !value.is_empty() && !totp_properties.contains(property) && !password_properties.contains(property)
+    })
+    .collect();
+
+  texts.extend(
+    version
+      .attachments
+      .iter()
+      .map(|attachment| (PROPERTY_ATTACHMENTS, attachment.name())),
+  );
+
+  texts
+}
+
+/// Match the indexed texts of a secret against a query.
+///
+/// Every word of the query has to be the prefix of a word in one of the texts. For every text with
+/// a match the first matching line is returned (with highlights).
+pub fn match_texts(
+  texts: capnp::struct_list::Reader<indexed_text::Owned>,
+  query: &str,
+) -> SecretStoreResult<Option<Vec<TextMatch>>> {
+  let query_words: Vec<String> = tokenize(query).into_iter().map(|token| token.word).collect();
+  let mut found = vec![false; query_words.len()];
+  let mut text_matches = Vec::new();
+
+  for text in texts {
+    let property = text.get_property()?;
+    let mut first_match = None;
+
+    for line in text.get_value()?.lines() {
+      let mut highlights = Vec::new();
+
+      for token in tokenize(line) {
+        for (idx, query_word) in query_words.iter().enumerate() {
+          if token.word.starts_with(query_word.as_str()) {
+            found[idx] = true;
+            highlights.extend(token.position..token.position + query_word.chars().count().min(token.length));
+          }
+        }
+      }
+      if !highlights.is_empty() && first_match.is_none() {
+        highlights.sort_unstable();
+        highlights.dedup();
+        first_match = Some(TextMatch {
+          property: property.to_string(),
+          text: line.to_string(),
+          highlights,
+        });
+      }
+    }
+    text_matches.extend(first_match);
+  }
+
+  if found.into_iter().all(|found| found) {
+    Ok(Some(text_matches))
+  } else {
+    Ok(None)
+  }
+}
@@ -0,0 +1,192 @@
+use crate::api::{
+  HealthReport, HealthReportParams, SecretHealth, SecretType, SecretTypeSchema, SecretVersion, SecretVersionRef,
+  ZeroizeDateTime, PROPERTY_TOTP_URL,
+};
+use crate::secrets_store::breach::BreachedPasswords;
+use crate::secrets_store::estimate::{PasswordEstimator, ZxcvbnEstimator};
+use crate::secrets_store::index::Index;
+use crate::secrets_store::SecretStoreResult;
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac, NewMac};
+use rand::{thread_rng, RngCore};
+use sha2::Sha256;
+use std::collections::HashMap;
+use zeroize::Zeroize;
+
+/// Collects the password health of all secrets one by one.
+///
+/// To detect reused passwords without keeping them around, all passwords are hashed with a
+/// random key that only lives as long as the check itself.
+pub struct HealthCheck<'a> {
+  params: &'a HealthReportParams,
+  schemas: &'a [SecretTypeSchema],
+  user_inputs: Vec<String>,
+  breached_passwords: Option<BreachedPasswords>,
+  hash_key: Vec<u8>,
+  password_hashes: HashMap<Vec<u8>, Vec<String>>,
+  checked: u64,
+  secrets: Vec<SecretHealth>,
+}
+
+impl<'a> HealthCheck<'a> {
+  pub fn new(
+    params: &'a HealthReportParams,
+    user_inputs: &[&str],
+    breached_passwords: Option<BreachedPasswords>,
+    schemas: &'a [SecretTypeSchema],
+  ) -> Self {
+    let mut hash_key = vec![0u8; 32];
+
+    thread_rng().fill_bytes(&mut hash_key);
+
+    HealthCheck {
+      params,
+      schemas,
+      user_inputs: user_inputs.iter().map(ToString::to_string).collect(),
+      breached_passwords,
+      hash_key,
+      password_hashes: HashMap::new(),
+      checked: 0,
+      secrets: Vec::new(),
+    }
+  }
+
+  /// Check a secret by its versions (newest first).
+  ///
+  /// Only the current version is decrypted, unless the age of the password has to be determined.
+  pub fn check_secret<F>(&mut self, versions: &[SecretVersionRef], get_version: F) -> SecretStoreResult<()>
+  where
+    F: Fn(&str) -> SecretStoreResult<SecretVersion>,
+  {
+    let current_ref = match Index::current_version(versions) {
+      Some(current_ref) => current_ref,
+      None => return Ok(()),
+    };
+    let current = get_version(&current_ref.block_id)?;
+
+    if current.deleted {
+      return Ok(());
+    }
+    self.checked += 1;
+
+    let mut health = SecretHealth {
+      secret_id: current.secret_id.clone(),
+      name: current.name.clone(),
+      score: 4,
+      weak: false,
+      reused_with: vec![],
+      password_changed_at: None,
+      missing_totp: current.secret_type == SecretType::Login
+        && !current.urls.is_empty()
+        && current.properties.get(PROPERTY_TOTP_URL).is_none(),
+      breached: false,
+    };
+    let passwords = Self::passwords(&current, self.schemas);
+
+    if !passwords.is_empty() {
+      let user_inputs: Vec<&str> = std::iter::once(current.name.as_str())
+        .chain(self.user_inputs.iter().map(String::as_str))
+        .collect();
+
+      for password in &passwords {
+        let strength = ZxcvbnEstimator::estimate_strength(password, &user_inputs);
+
+        health.score = health.score.min(strength.score);
+
+        if let Some(breached_passwords) = self.breached_passwords.as_mut() {
+          health.breached |= breached_passwords.occurrences(password)? > 0;
+        }
+
+        let hash = self.keyed_hash(password);
+        let secret_ids = self.password_hashes.entry(hash).or_default();
+        if !secret_ids.contains(&current.secret_id) {
+          secret_ids.push(current.secret_id.clone());
+        }
+      }
+      health.weak = health.score < self.params.min_score;
+
+      if self.params.max_age_days > 0 {
+        let changed_at = Self::password_changed_at(&current, &passwords, &versions[1..], self.schemas, get_version)?;
+
+        if changed_at + Duration::days(self.params.max_age_days as i64) < Utc::now().into() {
+          health.password_changed_at = Some(changed_at);
+        }
+      }
+    }
+
+    self.secrets.push(health);
+
+    Ok(())
+  }
+
+  pub fn finish(mut self) -> HealthReport {
+    for health in self.secrets.iter_mut() {
+      let mut reused_with: Vec<String> = self
+        .password_hashes
+        .values()
+        .filter(|secret_ids| secret_ids.contains(&health.secret_id))
+        .flatten()
+        .filter(|secret_id| **secret_id != health.secret_id)
+        .cloned()
+        .collect();
+
+      reused_with.sort();
+      reused_with.dedup();
+      health.reused_with = reused_with;
+    }
+
+    HealthReport {
+      checked: self.checked,
+      secrets: self.secrets.drain(..).filter(SecretHealth::has_issues).collect(),
+    }
+  }
+
+  fn passwords<'v>(version: &'v SecretVersion, schemas: &[SecretTypeSchema]) -> Vec<&'v str> {
+    version
+      .password_properties(schemas)
+      .into_iter()
+      .filter_map(|property| version.properties.get(property))
+      .map(String::as_str)
+      .filter(|password| !password.is_empty())
+      .collect()
+  }
+
+  /// Walk back the versions as long as the passwords did not change.
+  fn password_changed_at<F>(
+    current: &SecretVersion,
+    passwords: &[&str],
+    previous_versions: &[SecretVersionRef],
+    schemas: &[SecretTypeSchema],
+    get_version: F,
+  ) -> SecretStoreResult<ZeroizeDateTime>
+  where
+    F: Fn(&str) -> SecretStoreResult<SecretVersion>,
+  {
+    let mut changed_at = current.timestamp;
+
+    for version_ref in previous_versions {
+      let version = get_version(&version_ref.block_id)?;
+
+      if Self::passwords(&version, schemas).as_slice() != passwords {
+        break;
+      }
+      changed_at = version.timestamp;
+    }
+
+    Ok(changed_at)
+  }
+
+  fn keyed_hash(&self, password: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_varkey(&self.hash_key).unwrap();
+
+    mac.update(password.as_bytes());
+
+    mac.finalize().into_bytes().to_vec()
+  }
+}
+
+impl<'a> Drop for HealthCheck<'a> {
+  fn drop(&mut self) {
+    self.hash_key.zeroize();
+  }
+}
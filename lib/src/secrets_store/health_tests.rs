@@ -0,0 +1,110 @@
+use crate::api::{
+  HealthReportParams, SecretType, SecretVersion, SecretVersionRef, PROPERTY_PASSWORD, PROPERTY_TOTP_URL,
+};
+use crate::secrets_store::health::HealthCheck;
+use crate::secrets_store::{SecretStoreError, SecretStoreResult};
+use chrono::{Duration, Utc};
+use spectral::prelude::*;
+use std::collections::HashMap;
+
+fn secret_version(secret_id: &str, password: &str, age_days: i64) -> SecretVersion {
+  let mut version = SecretVersion {
+    secret_id: secret_id.to_string(),
+    secret_type: SecretType::Login,
+    timestamp: (Utc::now() - Duration::days(age_days)).into(),
+    name: secret_id.to_string(),
+    tags: vec![],
+    urls: vec![],
+    properties: Default::default(),
+    attachments: vec![],
+    deleted: false,
+    recipients: vec![],
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: None,
+  };
+  version.properties.set(PROPERTY_PASSWORD, password);
+  version
+}
+
+#[derive(Default)]
+struct TestVersions {
+  versions: HashMap<String, SecretVersion>,
+}
+
+impl TestVersions {
+  /// Add versions of a secret (newest first) and return their references.
+  fn add(&mut self, versions: Vec<SecretVersion>) -> Vec<SecretVersionRef> {
+    versions
+      .into_iter()
+      .map(|version| {
+        let block_id = format!("{}-{}", version.secret_id, version.timestamp.timestamp_millis());
+        let version_ref = SecretVersionRef::new(&block_id, &version);
+
+        self.versions.insert(block_id, version);
+        version_ref
+      })
+      .collect()
+  }
+
+  fn get(&self, block_id: &str) -> SecretStoreResult<SecretVersion> {
+    self.versions.get(block_id).cloned().ok_or(SecretStoreError::NotFound)
+  }
+}
+
+#[test]
+fn test_health_report() {
+  let params = HealthReportParams {
+    min_score: 3,
+    max_age_days: 100,
+  };
+  let mut test_versions = TestVersions::default();
+  let mut secure = secret_version("secure", "hQ7#vLp2$Zr9!kWm", 10);
+  secure.urls.push("https://example.com".to_string());
+  secure.properties.set(PROPERTY_TOTP_URL, "otpauth://totp/example");
+  let mut no_totp = secret_version("no_totp", "Lr8!sK3@nB6#vZ1q", 10);
+  no_totp.urls.push("https://example.com".to_string());
+  let mut deleted = secret_version("deleted", "123456", 10);
+  deleted.deleted = true;
+  let secrets = [
+    test_versions.add(vec![secure]),
+    test_versions.add(vec![secret_version("weak", "123456", 10)]),
+    test_versions.add(vec![secret_version("reuse1", "Xq9!mB4@tR7#pL2z", 10)]),
+    test_versions.add(vec![secret_version("reuse2", "Xq9!mB4@tR7#pL2z", 10)]),
+    // Renamed recently, but the password has not been changed for a long time
+    test_versions.add(vec![
+      secret_version("old", "Vn3$kD8&wQ5!jF1s", 1),
+      secret_version("old", "Vn3$kD8&wQ5!jF1s", 200),
+      secret_version("old", "Pz6@cH2#yM9$gT4x", 300),
+    ]),
+    test_versions.add(vec![no_totp]),
+    test_versions.add(vec![deleted]),
+  ];
+
+  let mut health_check = HealthCheck::new(&params, &["User"], None, &[]);
+  for versions in secrets.iter() {
+    assert_that(&health_check.check_secret(versions, |block_id| test_versions.get(block_id))).is_ok();
+  }
+  let report = health_check.finish();
+
+  assert_that(&report.checked).is_equal_to(6);
+
+  let issues: HashMap<String, _> = report
+    .secrets
+    .iter()
+    .map(|health| (health.secret_id.clone(), health))
+    .collect();
+
+  assert_that(&issues.contains_key("secure")).is_false();
+  assert_that(&issues.contains_key("deleted")).is_false();
+  assert_that(&issues["weak"].weak).is_true();
+  assert_that(&issues["weak"].reused_with).is_empty();
+  assert_that(&issues["reuse1"].reused_with).is_equal_to(vec!["reuse2".to_string()]);
+  assert_that(&issues["reuse2"].reused_with).is_equal_to(vec!["reuse1".to_string()]);
+  assert_that(&issues["old"].password_changed_at).is_some();
+  assert_that(&issues["old"].weak).is_false();
+  assert_that(&issues["no_totp"].missing_totp).is_true();
+  assert_that(&issues["no_totp"].password_changed_at).is_none();
+}
@@ -1,11 +1,16 @@
-use crate::api::{SecretEntry, SecretEntryMatch, SecretList, SecretListFilter, SecretVersion, SecretVersionRef};
-use crate::api_capnp::secret_entry;
+use crate::api::{
+  SecretEntry, SecretEntryMatch, SecretList, SecretListFilter, SecretQuery, SecretTypeSchema, SecretVersion,
+  SecretVersionRef, ZeroizeDateTime,
+};
 use crate::block_store::{Change, ChangeLog, Operation};
 use crate::memguard::weak::ZeroingHeapAllocator;
 use crate::memguard::SecretWords;
+use crate::secrets_store::full_text::{match_texts, searchable_texts};
+use crate::secrets_store::query::{match_query, QueryMatch};
 use crate::secrets_store::{SecretStoreError, SecretStoreResult};
 use crate::secrets_store_capnp::index;
 use capnp::{message, serialize};
+use chrono::Utc;
 use itertools::Itertools;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
@@ -24,15 +29,39 @@ impl EffectiveChanges {
 #[derive(Clone)]
 pub struct Index {
   heads: HashMap<String, Change>,
+  full_text: bool,
   pub(super) data: SecretWords,
 }
 
 impl Index {
+  /// Create an empty index, with `full_text` all searchable texts of the secrets are indexed as well.
+  pub fn new(full_text: bool) -> Index {
+    let mut index_message = message::Builder::new(ZeroingHeapAllocator::default());
+    index_message.init_root::<index::Builder>().set_full_text(full_text);
+    let index_data = serialize::write_message_to_words(&index_message);
+
+    Index {
+      data: index_data.into(),
+      heads: HashMap::new(),
+      full_text,
+    }
+  }
+
   pub fn from_secured_raw(raw: &[u8]) -> SecretStoreResult<Index> {
     let data = SecretWords::from_secured(raw);
     let heads = Self::read_heads(&data)?;
+    let full_text = {
+      let mut data_borrow: &[u8] = &data.borrow();
+      let reader = serialize::read_message_from_flat_slice(&mut data_borrow, message::ReaderOptions::new())?;
+
+      reader.get_root::<index::Reader>()?.get_full_text()
+    };
+
+    Ok(Index { data, heads, full_text })
+  }
 
-    Ok(Index { data, heads })
+  pub fn is_full_text(&self) -> bool {
+    self.full_text
   }
 
   pub fn find_versions(&self, secret_id: &str) -> SecretStoreResult<Vec<SecretVersionRef>> {
@@ -60,6 +89,12 @@ impl Index {
     let index = reader.get_root::<index::Reader>()?;
     let mut entries = Vec::new();
     let mut all_tags = BTreeSet::new();
+    let query = filter.query.as_deref().map(SecretQuery::parse).transpose()?;
+
+    // Without the full-text index there are no texts to search in, i.e. nothing would match
+    if !self.full_text && (filter.text.is_some() || query.as_ref().is_some_and(SecretQuery::has_text_term)) {
+      return Err(SecretStoreError::FullTextDisabled);
+    }
 
     for index_entry in index.get_entries()? {
       let entry = index_entry.get_entry()?;
@@ -69,7 +104,7 @@ impl Index {
           all_tags.insert(tag.to_string());
         }
       }
-      if let Some(entry_match) = Self::match_entry(entry, &filter)? {
+      if let Some(entry_match) = Self::match_entry(index_entry, filter, query.as_ref())? {
         entries.push(entry_match);
       }
     }
@@ -81,7 +116,15 @@ impl Index {
     })
   }
 
-  pub fn process_change_logs<F>(&mut self, change_logs: &[ChangeLog], version_accessor: F) -> SecretStoreResult<bool>
+  /// Update the index by all the changes that have not been processed yet.
+  ///
+  /// The `schemas` of the custom types are required to exclude their secret fields from the full-text index.
+  pub fn process_change_logs<F>(
+    &mut self,
+    change_logs: &[ChangeLog],
+    schemas: &[SecretTypeSchema],
+    version_accessor: F,
+  ) -> SecretStoreResult<bool>
   where
     F: Fn(&str) -> SecretStoreResult<Option<SecretVersion>>,
   {
@@ -104,6 +147,7 @@ impl Index {
       let old_index = reader.get_root::<index::Reader>()?;
       let mut new_index = index_message.init_root::<index::Builder>();
 
+      new_index.set_full_text(self.full_text);
       Self::update_heads(new_index.reborrow(), &effective_changes.new_heads);
       let mut entry_pos = 0;
       let mut new_entries = new_index.init_entries((to_keep.len() + additions) as u32);
@@ -126,6 +170,8 @@ impl Index {
           effective_changes.added_versions.get(secret_id),
           &effective_changes.deleted_blocks,
           &version_accessor,
+          self.full_text,
+          schemas,
         )?;
         entry_pos += 1;
       }
@@ -139,6 +185,8 @@ impl Index {
           Some(&added_version),
           &effective_changes.deleted_blocks,
           &version_accessor,
+          self.full_text,
+          schemas,
         )?;
         entry_pos += 1;
       }
@@ -264,11 +312,13 @@ impl Index {
     maybe_added_versions: Option<&HashMap<String, SecretVersion>>,
     deleted_blocks: &HashSet<String>,
     version_accessor: F,
+    full_text: bool,
+    schemas: &[SecretTypeSchema],
   ) -> SecretStoreResult<()>
   where
     F: Fn(&str) -> SecretStoreResult<Option<SecretVersion>>,
   {
-    let current_block_id = old_version_refs.first().map(|v| v.block_id.clone());
+    let current_block_id = Self::current_version(&old_version_refs).map(|v| v.block_id.clone());
     let mut version_refs =
       Vec::with_capacity(old_version_refs.len() + maybe_added_versions.map(HashMap::len).unwrap_or(0));
 
@@ -280,10 +330,7 @@ impl Index {
     if let Some(added_versions) = maybe_added_versions {
       for (block_id, added_version) in added_versions {
         if !deleted_blocks.contains(block_id) {
-          version_refs.push(SecretVersionRef {
-            block_id: block_id.clone(),
-            timestamp: added_version.timestamp,
-          })
+          version_refs.push(SecretVersionRef::new(block_id, added_version))
         }
       }
     }
@@ -291,32 +338,115 @@ impl Index {
 
     assert!(!version_refs.is_empty());
 
-    let new_current_block_id = version_refs.first().unwrap().block_id.clone();
+    let new_current_block_id = Self::current_version(&version_refs).unwrap().block_id.clone();
     if current_block_id.is_none() || current_block_id.unwrap() != new_current_block_id {
-      match maybe_added_versions.and_then(|added| added.get(&new_current_block_id)) {
-        Some(added_version) => added_version.to_entry_builder(new_entry.reborrow().init_entry())?,
-        None => version_accessor(&new_current_block_id)?
-          .unwrap()
-          .to_entry_builder(new_entry.reborrow().init_entry())?,
+      let accessed_version;
+      let current = match maybe_added_versions.and_then(|added| added.get(&new_current_block_id)) {
+        Some(added_version) => added_version,
+        None => {
+          accessed_version = version_accessor(&new_current_block_id)?.unwrap();
+          &accessed_version
+        }
       };
+      current.to_entry_builder(new_entry.reborrow().init_entry())?;
+
+      if full_text {
+        let texts = searchable_texts(current, schemas);
+        let mut new_texts = new_entry.reborrow().init_texts(texts.len() as u32);
+
+        for (idx, (property, value)) in texts.into_iter().enumerate() {
+          let mut new_text = new_texts.reborrow().get(idx as u32);
+
+          new_text.set_property(property);
+          new_text.set_value(value);
+        }
+      }
     }
+    new_entry
+      .reborrow()
+      .get_entry()?
+      .set_conflicted(Self::find_heads(&version_refs).len() > 1);
 
     let mut entry_version_refs = new_entry.init_version_refs(version_refs.len() as u32);
     for (idx, version_ref) in version_refs.iter().enumerate() {
-      version_ref.to_builder(entry_version_refs.reborrow().get(idx as u32));
+      version_ref.to_builder(entry_version_refs.reborrow().get(idx as u32))?;
     }
 
     Ok(())
   }
 
+  /// The current version of a secret, i.e. the newest version that is not superseded.
+  ///
+  /// Usually this is just the newest version, but a version might be superseded by one with the
+  /// same timestamp (e.g. a migrated version).
+  pub fn current_version(version_refs: &[SecretVersionRef]) -> Option<&SecretVersionRef> {
+    Self::find_heads(version_refs)
+      .into_iter()
+      .next()
+      .or_else(|| version_refs.first())
+  }
+
+  /// Find all versions that are not the base of another version (i.e. not superseded).
+  ///
+  /// `version_refs` have to be ordered newest first. Versions without an explicit parent are
+  /// considered to be based on their predecessor by timestamp. If there is more than one head,
+  /// the secret has been changed concurrently.
+  pub fn find_heads(version_refs: &[SecretVersionRef]) -> Vec<&SecretVersionRef> {
+    let mut superseded = HashSet::with_capacity(version_refs.len());
+
+    for (idx, version_ref) in version_refs.iter().enumerate() {
+      match &version_ref.parent_block_id {
+        Some(parent_block_id) => {
+          superseded.insert(parent_block_id.as_str());
+        }
+        None => {
+          // A predecessor with the same timestamp might be explicitly based on this version
+          if let Some(predecessor) = version_refs
+            .get(idx + 1)
+            .filter(|predecessor| predecessor.parent_block_id.as_ref() != Some(&version_ref.block_id))
+          {
+            superseded.insert(predecessor.block_id.as_str());
+          }
+        }
+      }
+      superseded.extend(version_ref.merged_block_ids.iter().map(String::as_str));
+    }
+
+    version_refs
+      .iter()
+      .filter(|version_ref| !superseded.contains(version_ref.block_id.as_str()))
+      .collect()
+  }
+
   fn match_entry(
-    entry_reader: secret_entry::Reader,
+    index_entry: index::entry::Reader,
     filter: &SecretListFilter,
+    query: Option<&SecretQuery>,
   ) -> SecretStoreResult<Option<SecretEntryMatch>> {
-    let entry = SecretEntry::from_reader(entry_reader)?;
+    let entry = SecretEntry::from_reader(index_entry.get_entry()?)?;
     if filter.deleted != entry.deleted {
       return Ok(None);
     }
+    if filter.expired || filter.expiring_within.is_some() {
+      let due_at = match entry.due_at() {
+        Some(due_at) => due_at,
+        None => return Ok(None),
+      };
+      let now = ZeroizeDateTime::from(Utc::now());
+
+      if filter.expired && due_at > now {
+        return Ok(None);
+      }
+      // An interval beyond the representable dates includes everything that is due at all
+      if let Some(limit) = filter
+        .expiring_within
+        .and_then(|seconds| now.checked_add_seconds(seconds))
+      {
+        if due_at > limit {
+          return Ok(None);
+        }
+      }
+    }
 
     let (name_score, name_highlights) = match &filter.name {
       Some(name_filter) => match sublime_fuzzy::best_match(name_filter, &entry.name) {
@@ -346,25 +476,41 @@ impl Index {
       return Ok(None);
     }
 
-    Ok(Some(SecretEntryMatch {
-      entry,
+    let text_matches = match &filter.text {
+      Some(text_filter) => match match_texts(index_entry.get_texts()?, text_filter)? {
+        Some(text_matches) => text_matches,
+        None => return Ok(None),
+      },
+      None => vec![],
+    };
+    let mut entry_match = QueryMatch {
       name_score,
       name_highlights,
       url_highlights,
       tags_highlights,
+      text_matches,
+    };
+
+    if let Some(query) = query {
+      match match_query(query, &entry, index_entry.get_texts()?)? {
+        Some(query_match) => entry_match.merge(query_match),
+        None => return Ok(None),
+      }
+    }
+
+    Ok(Some(SecretEntryMatch {
+      entry,
+      name_score: entry_match.name_score,
+      name_highlights: entry_match.name_highlights,
+      url_highlights: entry_match.url_highlights,
+      tags_highlights: entry_match.tags_highlights,
+      text_matches: entry_match.text_matches,
     }))
   }
 }
 
 impl Default for Index {
   fn default() -> Self {
-    let mut index_message = message::Builder::new(ZeroingHeapAllocator::default());
-    index_message.init_root::<index::Builder>();
-    let index_data = serialize::write_message_to_words(&index_message);
-
-    Index {
-      data: index_data.into(),
-      heads: HashMap::new(),
-    }
+    Index::new(false)
   }
 }
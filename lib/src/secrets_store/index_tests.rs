@@ -1,7 +1,11 @@
-use crate::api::{SecretType, SecretVersion};
+use crate::api::{
+  SecretListFilter, SecretType, SecretVersion, TextMatch, PROPERTY_NOTES, PROPERTY_PASSWORD, PROPERTY_USERNAME,
+};
 use crate::block_store::{Change, ChangeLog, Operation};
 use crate::secrets_store::index::Index;
+use crate::secrets_store::SecretStoreError;
 use chrono::prelude::*;
+use chrono::Duration;
 use data_encoding::HEXLOWER;
 use sha2::{Digest, Sha256};
 use spectral::prelude::*;
@@ -36,7 +40,7 @@ impl TestStore {
     SecretVersion {
       secret_id: secret_id.to_string(),
       secret_type: SecretType::Login,
-      timestamp: Utc.timestamp(1000 + 1000 * version_id, 0).into(),
+      timestamp: Utc.timestamp_opt(1000 + 1000 * version_id, 0).unwrap().into(),
       name: format!("{}_{}", secret_id, version_id),
       properties: Default::default(),
       tags: vec![],
@@ -44,6 +48,11 @@ impl TestStore {
       deleted: false,
       recipients: vec![],
       attachments: vec![],
+      parent_block_id: None,
+      merged_block_ids: vec![],
+      expires_at: None,
+      rotate_every: None,
+      custom_type: None,
     }
   }
 
@@ -69,7 +78,7 @@ fn test_process_change_logs() {
   }
 
   assert_that(
-    &index.process_change_logs(&[test_store.make_changelog("test_node")], |block_id| {
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
       Ok(test_store.versions.get(block_id).cloned())
     }),
   )
@@ -89,7 +98,7 @@ fn test_process_change_logs() {
   }
 
   assert_that(
-    &index.process_change_logs(&[test_store.make_changelog("test_node")], |block_id| {
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
       Ok(test_store.versions.get(block_id).cloned())
     }),
   )
@@ -99,3 +108,293 @@ fn test_process_change_logs() {
 
   assert_that(&all_matches.entries).has_length(15);
 }
+
+#[test]
+fn test_conflicting_versions() {
+  let mut test_store: TestStore = Default::default();
+  let mut index: Index = Default::default();
+
+  test_store.add_secret_version("Secret", 0);
+  let base_block_id = TestStore::generate_block_id("Secret", 0);
+
+  assert_that(
+    &index.process_change_logs(&[test_store.make_changelog("node1")], &[], |block_id| {
+      Ok(test_store.versions.get(block_id).cloned())
+    }),
+  )
+  .is_ok();
+
+  // Two nodes change the same version concurrently
+  let mut node1_store: TestStore = Default::default();
+  let mut node2_store: TestStore = Default::default();
+
+  node1_store.changes = test_store.changes.clone();
+  for (store, version_id) in [(&mut node1_store, 1), (&mut node2_store, 2)] {
+    store.versions = test_store.versions.clone();
+    store.add_secret_version("Secret", version_id);
+    let block_id = TestStore::generate_block_id("Secret", version_id);
+    store.versions.get_mut(&block_id).unwrap().parent_block_id = Some(base_block_id.clone());
+  }
+  let mut all_versions = node1_store.versions.clone();
+  all_versions.extend(node2_store.versions.clone());
+
+  assert_that(&index.process_change_logs(
+    &[node1_store.make_changelog("node1"), node2_store.make_changelog("node2")],
+    &[],
+    |block_id| Ok(all_versions.get(block_id).cloned()),
+  ))
+  .is_ok();
+
+  let list = index.filter_entries(&Default::default()).unwrap();
+
+  assert_that(&list.entries).has_length(1);
+  assert_that(&list.entries[0].entry.conflicted).is_true();
+  assert_that(&list.entries[0].entry.name).is_equal_to("Secret_2".to_string());
+
+  let versions = index.find_versions("Secret").unwrap();
+  let heads: Vec<String> = Index::find_heads(&versions)
+    .into_iter()
+    .map(|v| v.block_id.clone())
+    .collect();
+
+  assert_that(&heads).has_length(2);
+  assert_that(&heads).contains(TestStore::generate_block_id("Secret", 1));
+  assert_that(&heads).contains(TestStore::generate_block_id("Secret", 2));
+
+  // Merge the competing version into a new one
+  node1_store.add_secret_version("Secret", 3);
+  {
+    let merged = node1_store
+      .versions
+      .get_mut(&TestStore::generate_block_id("Secret", 3))
+      .unwrap();
+    merged.parent_block_id = Some(TestStore::generate_block_id("Secret", 1));
+    merged.merged_block_ids = vec![TestStore::generate_block_id("Secret", 2)];
+  }
+  all_versions.extend(node1_store.versions.clone());
+
+  assert_that(&index.process_change_logs(
+    &[node1_store.make_changelog("node1"), node2_store.make_changelog("node2")],
+    &[],
+    |block_id| Ok(all_versions.get(block_id).cloned()),
+  ))
+  .is_ok();
+
+  let list = index.filter_entries(&Default::default()).unwrap();
+
+  assert_that(&list.entries[0].entry.conflicted).is_false();
+  assert_that(&list.entries[0].entry.name).is_equal_to("Secret_3".to_string());
+}
+
+#[test]
+fn test_expiring_secrets() {
+  let mut test_store: TestStore = Default::default();
+  let mut index: Index = Default::default();
+
+  for secret_id in &["Expiring", "Rotation", "Unlimited", "Later"] {
+    test_store.add_secret_version(secret_id, 0);
+  }
+  let now = Utc::now();
+  let mut set_version = |secret_id: &str, modify: &dyn Fn(&mut SecretVersion)| {
+    modify(
+      test_store
+        .versions
+        .get_mut(&TestStore::generate_block_id(secret_id, 0))
+        .unwrap(),
+    )
+  };
+  set_version("Expiring", &|version| {
+    version.expires_at = Some((now + Duration::days(3)).into())
+  });
+  set_version("Rotation", &|version| version.rotate_every = Some(24 * 3600));
+  // Way beyond any representable date, i.e. never due
+  set_version("Unlimited", &|version| version.rotate_every = Some(u64::MAX));
+  set_version("Later", &|version| {
+    version.expires_at = Some((now + Duration::days(30)).into())
+  });
+
+  assert_that(
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
+      Ok(test_store.versions.get(block_id).cloned())
+    }),
+  )
+  .is_ok();
+
+  let mut filter = SecretListFilter::default();
+  filter.expired = true;
+  let expired = index.filter_entries(&filter).unwrap();
+
+  assert_that(&expired.entries).has_length(1);
+  assert_that(&expired.entries[0].entry.name).is_equal_to("Rotation_0".to_string());
+
+  let mut filter = SecretListFilter::default();
+  filter.expiring_within = Some(7 * 24 * 3600);
+  let mut expiring: Vec<String> = index
+    .filter_entries(&filter)
+    .unwrap()
+    .entries
+    .iter()
+    .map(|entry_match| entry_match.entry.name.clone())
+    .collect();
+  expiring.sort();
+
+  assert_that(&expiring).is_equal_to(vec!["Expiring_0".to_string(), "Rotation_0".to_string()]);
+
+  let mut filter = SecretListFilter::default();
+  filter.expiring_within = Some(u64::MAX);
+
+  assert_that(&index.filter_entries(&filter).unwrap().entries).has_length(3);
+}
+
+#[test]
+fn test_full_text_search() {
+  let mut test_store: TestStore = Default::default();
+
+  for secret_id in &["John", "Alice"] {
+    test_store.add_secret_version(secret_id, 0);
+  }
+  for (secret_id, username) in &[("John", "john.doe"), ("Alice", "alice")] {
+    let version = test_store
+      .versions
+      .get_mut(&TestStore::generate_block_id(secret_id, 0))
+      .unwrap();
+    version.properties.set(PROPERTY_USERNAME, username);
+    version.properties.set(PROPERTY_PASSWORD, "secret");
+  }
+  test_store
+    .versions
+    .get_mut(&TestStore::generate_block_id("John", 0))
+    .unwrap()
+    .properties
+    .set(PROPERTY_NOTES, "Recovery codes\nBackup phone: Nokia");
+
+  let search = |index: &Index, text: &str| -> Vec<(String, Vec<TextMatch>)> {
+    let mut filter = SecretListFilter::default();
+    filter.text = Some(text.to_string());
+    index
+      .filter_entries(&filter)
+      .unwrap()
+      .entries
+      .iter()
+      .map(|entry_match| (entry_match.entry.name.clone(), entry_match.text_matches.clone()))
+      .collect()
+  };
+
+  let mut index = Index::new(true);
+
+  assert_that(
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
+      Ok(test_store.versions.get(block_id).cloned())
+    }),
+  )
+  .is_ok();
+
+  assert_that(&search(&index, "JOHN")).is_equal_to(vec![(
+    "John_0".to_string(),
+    vec![TextMatch {
+      property: PROPERTY_USERNAME.to_string(),
+      text: "john.doe".to_string(),
+      highlights: vec![0, 1, 2, 3],
+    }],
+  )]);
+  assert_that(&search(&index, "nok back")).is_equal_to(vec![(
+    "John_0".to_string(),
+    vec![TextMatch {
+      property: PROPERTY_NOTES.to_string(),
+      text: "Backup phone: Nokia".to_string(),
+      highlights: vec![0, 1, 2, 3, 14, 15, 16],
+    }],
+  )]);
+  assert_that(&search(&index, "nokia alice")).is_empty();
+  assert_that(&search(&index, "secret")).is_empty();
+
+  let mut index = Index::new(false);
+
+  assert_that(
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
+      Ok(test_store.versions.get(block_id).cloned())
+    }),
+  )
+  .is_ok();
+
+  let mut filter = SecretListFilter::default();
+  filter.text = Some("john".to_string());
+  assert_that(&index.filter_entries(&filter).map(|list| list.entries.len()))
+    .is_err_containing(SecretStoreError::FullTextDisabled);
+
+  let mut filter = SecretListFilter::default();
+  filter.query = Some("tag:a OR -(type:note text:john)".to_string());
+  assert_that(&index.filter_entries(&filter).map(|list| list.entries.len()))
+    .is_err_containing(SecretStoreError::FullTextDisabled);
+}
+
+#[test]
+fn test_query_filter() {
+  let mut test_store: TestStore = Default::default();
+  let mut index: Index = Default::default();
+
+  for (secret_id, tags, secret_type, url) in &[
+    (
+      "Prod_db",
+      vec!["prod", "db"],
+      SecretType::Login,
+      "https://db.example.com",
+    ),
+    (
+      "Prod_legacy",
+      vec!["prod", "db", "legacy"],
+      SecretType::Login,
+      "https://legacy.local",
+    ),
+    ("Dev_note", vec!["dev"], SecretType::Note, ""),
+  ] {
+    test_store.add_secret_version(secret_id, 0);
+
+    let version = test_store
+      .versions
+      .get_mut(&TestStore::generate_block_id(secret_id, 0))
+      .unwrap();
+    version.tags = tags.iter().map(ToString::to_string).collect();
+    version.secret_type = *secret_type;
+    version.urls = Some(url.to_string()).into_iter().filter(|u| !u.is_empty()).collect();
+  }
+
+  assert_that(
+    &index.process_change_logs(&[test_store.make_changelog("test_node")], &[], |block_id| {
+      Ok(test_store.versions.get(block_id).cloned())
+    }),
+  )
+  .is_ok();
+
+  let query = |query: &str| -> Vec<String> {
+    let mut filter = SecretListFilter::default();
+    filter.query = Some(query.to_string());
+    let mut names: Vec<String> = index
+      .filter_entries(&filter)
+      .unwrap()
+      .entries
+      .iter()
+      .map(|entry_match| entry_match.entry.name.clone())
+      .collect();
+    names.sort();
+    names
+  };
+
+  assert_that(&query("")).has_length(3);
+  assert_that(&query("tag:prod tag:db -tag:legacy")).is_equal_to(vec!["Prod_db_0".to_string()]);
+  assert_that(&query("type:note OR url:EXAMPLE.com"))
+    .is_equal_to(vec!["Dev_note_0".to_string(), "Prod_db_0".to_string()]);
+  assert_that(&query("(tag:dev | tag:legacy) -type:note")).is_equal_to(vec!["Prod_legacy_0".to_string()]);
+  assert_that(&query("pdb")).is_equal_to(vec!["Prod_db_0".to_string()]);
+
+  let mut filter = SecretListFilter::default();
+  filter.query = Some("tag:db url:example".to_string());
+  let list = index.filter_entries(&filter).unwrap();
+
+  assert_that(&list.entries).has_length(1);
+  assert_that(&list.entries[0].tags_highlights).is_equal_to(vec![1]);
+  assert_that(&list.entries[0].url_highlights).is_equal_to(vec![0]);
+
+  filter.query = Some("(tag:db".to_string());
+  assert_that(&index.filter_entries(&filter)).is_err();
+}
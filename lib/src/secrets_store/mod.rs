@@ -1,19 +1,37 @@
-use crate::api::{EventHub, Identity, Secret, SecretList, SecretListFilter, SecretVersion, Status};
+use crate::api::{
+  EventHub, HealthReport, HealthReportParams, Identity, Secret, SecretAttachment, SecretList, SecretListFilter,
+  SecretTypeSchema, SecretVersion, Status,
+};
+use crate::service::StoreConfig;
+use std::io::{Read, Write};
 use std::sync::Arc;
-use std::time::Duration;
 
+mod attachment;
+mod breach;
 mod cipher;
+mod compression;
+mod copy;
 mod error;
 pub mod estimate;
+mod full_text;
+mod health;
 mod index;
 mod multi_lane;
 mod padding;
+mod query;
 
+#[cfg(test)]
+mod breach_tests;
+#[cfg(test)]
+mod health_tests;
 #[cfg(test)]
 mod index_tests;
 #[cfg(test)]
 mod tests;
 
+pub use self::attachment::ATTACHMENT_CHUNK_SIZE;
+pub use self::breach::{BreachCheck, BreachedPasswords};
+pub use self::copy::copy_secret;
 pub use self::error::{SecretStoreError, SecretStoreResult};
 use crate::block_store::open_block_store;
 use crate::memguard::SecretBytes;
@@ -30,32 +48,75 @@ pub trait SecretsStore: std::fmt::Debug {
 
   fn list(&self, filter: &SecretListFilter) -> SecretStoreResult<SecretList>;
   fn update_index(&self) -> SecretStoreResult<()>;
+  /// Rewrite the current version of all secrets that are not stored in the payload format
+  /// configured for the store (i.e. json or capnp).
+  ///
+  /// The result is the number of migrated secrets, older versions remain readable as they are.
+  fn migrate_versions(&self) -> SecretStoreResult<usize>;
 
   fn add(&self, secret_version: SecretVersion) -> SecretStoreResult<String>;
   fn get(&self, secret_id: &str) -> SecretStoreResult<Secret>;
   fn get_version(&self, block_id: &str) -> SecretStoreResult<SecretVersion>;
+
+  /// Check the passwords of all current secrets of the unlocked user.
+  fn health_report(&self, params: &HealthReportParams) -> SecretStoreResult<HealthReport>;
+
+  /// Get the schemas of all custom secret types of the store.
+  fn list_schemas(&self) -> SecretStoreResult<Vec<SecretTypeSchema>>;
+  /// Add or replace the schema of a custom secret type.
+  fn store_schema(&self, schema: SecretTypeSchema) -> SecretStoreResult<()>;
+
+  /// Add a chunk of the content of an attachment as encrypted block, the result is the id of the block.
+  ///
+  /// The unlocked user is always a recipient of the chunk. Chunks that do not become part of
+  /// a secret version until the store is locked are removed again.
+  fn add_attachment_chunk(&self, recipients: &[String], chunk: &[u8]) -> SecretStoreResult<String>;
+  /// Get the decrypted chunk of the content of an attachment.
+  ///
+  /// Only chunks of secret versions that have been opened (or of pending uploads) are available.
+  fn get_attachment_chunk(&self, block_id: &str) -> SecretStoreResult<SecretBytes>;
+
+  /// Store the content of an attachment, which is read chunk by chunk.
+  ///
+  /// The resulting attachment only references the chunks, it is supposed to be added to a
+  /// `SecretVersion` with the same recipients.
+  fn put_attachment(
+    &self,
+    name: &str,
+    mime_type: &str,
+    recipients: &[String],
+    content: &mut dyn Read,
+  ) -> SecretStoreResult<SecretAttachment> {
+    attachment::store_chunks(name, mime_type, content, None, |chunk| {
+      self.add_attachment_chunk(recipients, chunk)
+    })
+  }
+
+  /// Write the content of an attachment chunk by chunk.
+  fn get_attachment(&self, attachment: &SecretAttachment, out: &mut dyn Write) -> SecretStoreResult<()> {
+    attachment::write_content(attachment, out, |block_id| self.get_attachment_chunk(block_id))
+  }
 }
 
 pub fn open_secrets_store(
-  name: &str,
-  url: &str,
-  node_id: &str,
-  autolock_timeout: Duration,
+  store_config: &StoreConfig,
   event_hub: Arc<dyn EventHub>,
+  breach_check: Option<BreachCheck>,
 ) -> SecretStoreResult<Arc<dyn SecretsStore>> {
+  let url = &store_config.store_url;
   let (scheme, block_store_url) = match url.find('+') {
     Some(idx) => (&url[..idx], &url[idx + 1..]),
     _ => return Err(SecretStoreError::InvalidStoreUrl(url.to_string())),
   };
 
-  let block_store = open_block_store(block_store_url, node_id)?;
+  let block_store = open_block_store(block_store_url, &store_config.client_id)?;
 
   let secrets_store = match scheme {
     "multilane" => Arc::new(multi_lane::MultiLaneSecretsStore::new(
-      name,
+      store_config,
       block_store,
-      autolock_timeout,
       event_hub,
+      breach_check,
     )),
     _ => return Err(SecretStoreError::InvalidStoreUrl(url.to_string())),
   };
@@ -1,29 +1,40 @@
-use std::sync::{Arc, RwLock};
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, SystemTime};
 
 use capnp::{message, serialize};
 
 use crate::memguard::weak::ZeroingHeapAllocator;
-use crate::memguard::SecretBytes;
+use crate::memguard::{SecretBytes, SecretWords};
+use crate::secrets_store::attachment::{content_hash, store_chunks, ATTACHMENT_CHUNK_SIZE};
+use crate::secrets_store::breach::{BreachCheck, BreachedPasswords};
 use crate::secrets_store::cipher::{
   Cipher, KeyDerivation, PrivateKey, PublicKey, OPEN_SSL_RSA_AES_GCM, RUST_ARGON2_ID, RUST_X25519CHA_CHA20POLY1305,
 };
+use crate::secrets_store::compression;
 use crate::secrets_store::estimate::{PasswordEstimator, ZxcvbnEstimator};
+use crate::secrets_store::health::HealthCheck;
 use crate::secrets_store::index::Index;
 use crate::secrets_store::padding::{NonZeroPadding, Padding, RandomFrontBack};
 use crate::secrets_store::{SecretStoreError, SecretStoreResult, SecretsStore};
-use crate::secrets_store_capnp::{block, ring, KeyType};
+use crate::secrets_store_capnp::{block, ring, ContentFormat, KeyType};
+use crate::service::StoreConfig;
 use crate::{
   api::ZeroizeDateTime,
-  block_store::{BlockStore, Change, Operation, StoreError},
+  api_capnp::secret_version,
+  block_store::{BlockStore, Change, Operation, SchemaRef, StoreError},
 };
 use crate::{
-  api::{Event, EventHub, Identity, Secret, SecretList, SecretListFilter, SecretVersion, Status},
+  api::{
+    Event, EventHub, HealthReport, HealthReportParams, Identity, Secret, SecretAttachment, SecretList,
+    SecretListFilter, SecretTypeSchema, SecretVersion, Status,
+  },
   memguard::ZeroizeBytesBuffer,
 };
 use log::{info, warn};
 use rand::{thread_rng, RngCore};
-use std::collections::HashMap;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
 
 struct User {
   identity: Identity,
@@ -31,6 +42,43 @@ struct User {
   private_keys: Vec<(KeyType, PrivateKey)>,
   autolock_at: SystemTime,
   index: Index,
+  schemas: Vec<SecretTypeSchema>,
+  pending_chunks: Mutex<PendingChunks>,
+  /// Chunks of all attachments of the secret versions the user has opened.
+  readable_chunks: Mutex<HashSet<String>>,
+}
+
+/// Attachment chunks the user has stored, that are not referenced by a secret version (yet).
+///
+/// Chunks that are still pending when the store is locked will never be referenced and are removed.
+#[derive(Default)]
+struct PendingChunks {
+  /// Size of the content of each chunk by block id
+  sizes: HashMap<String, u64>,
+  /// Size of the current upload, an upload ends with a chunk that is not full
+  upload_size: u64,
+}
+
+/// Initial encoding of the payload of a block, i.e. json for secret versions and schemas.
+const PAYLOAD_VERSION_INITIAL: u8 = 0;
+/// Secret versions encoded as capnp message.
+const PAYLOAD_VERSION_CAPNP: u8 = 1;
+
+/// Format of a block as stored in its (unencrypted) header.
+#[derive(Clone, Copy)]
+struct BlockFormat {
+  content: ContentFormat,
+  payload_version: u8,
+}
+
+/// Content of a schema block.
+///
+/// Schemas may be changed on different nodes at the same time, in this case the latest change wins.
+#[derive(Serialize, Deserialize)]
+struct StoredSchema {
+  timestamp: ZeroizeDateTime,
+  #[serde(flatten)]
+  schema: SecretTypeSchema,
 }
 
 struct RecipientsForCipher<'a> {
@@ -46,23 +94,33 @@ pub struct MultiLaneSecretsStore {
   block_store: Arc<dyn BlockStore>,
   autolock_timeout: Duration,
   event_hub: Arc<dyn EventHub>,
+  breach_check: Option<BreachCheck>,
+  full_text_index: bool,
+  max_attachment_size: Option<u64>,
+  compress_blocks: bool,
+  capnp_payload: bool,
 }
 
 impl MultiLaneSecretsStore {
   pub fn new(
-    name: &str,
+    store_config: &StoreConfig,
     block_store: Arc<dyn BlockStore>,
-    autolock_timeout: Duration,
     event_hub: Arc<dyn EventHub>,
+    breach_check: Option<BreachCheck>,
   ) -> MultiLaneSecretsStore {
     MultiLaneSecretsStore {
-      name: name.to_string(),
+      name: store_config.name.clone(),
       ciphers: vec![&OPEN_SSL_RSA_AES_GCM, &RUST_X25519CHA_CHA20POLY1305],
       key_derivation: &RUST_ARGON2_ID,
       unlocked_user: RwLock::new(None),
       block_store,
-      autolock_timeout,
+      autolock_timeout: Duration::from_secs(store_config.autolock_timeout_secs),
       event_hub,
+      breach_check,
+      full_text_index: store_config.full_text_index,
+      max_attachment_size: store_config.max_attachment_size,
+      compress_blocks: store_config.compress_blocks,
+      capnp_payload: store_config.capnp_payload,
     }
   }
 }
@@ -83,7 +141,9 @@ impl SecretsStore for MultiLaneSecretsStore {
   fn lock(&self) -> SecretStoreResult<()> {
     info!("Locking store");
     let mut unlocked_user = self.unlocked_user.write()?;
-    unlocked_user.take();
+    if let Some(user) = unlocked_user.take() {
+      self.remove_pending_chunks(&user);
+    }
     self.event_hub.send(Event::StoreLocked {
       store_name: self.name.clone(),
     });
@@ -140,6 +200,10 @@ impl SecretsStore for MultiLaneSecretsStore {
         public_keys,
         autolock_at: SystemTime::now() + self.autolock_timeout,
         index,
+        // Schemas are read by the update of the index below
+        schemas: vec![],
+        pending_chunks: Default::default(),
+        readable_chunks: Default::default(),
       });
 
       identity
@@ -214,6 +278,10 @@ impl SecretsStore for MultiLaneSecretsStore {
     let new_ring_raw = serialize::write_message_to_words(&ring_message);
 
     self.block_store.store_ring(&identity.id, &new_ring_raw)?;
+    // If the store is locked, the schemas will be shared with the new identity on the next unlock
+    if let Some(unlocked_user) = self.unlocked_user.write()?.as_mut() {
+      self.refresh_schemas(unlocked_user)?;
+    }
     self.event_hub.send(Event::IdentityAdded {
       store_name: self.name.clone(),
       identity,
@@ -282,12 +350,16 @@ impl SecretsStore for MultiLaneSecretsStore {
   fn update_index(&self) -> SecretStoreResult<()> {
     let mut maybe_unlocked_user = self.unlocked_user.write()?;
     let unlocked_user = maybe_unlocked_user.as_mut().ok_or(SecretStoreError::Locked)?;
+    // Schemas might have been changed by other nodes as well
+    self.refresh_schemas(unlocked_user)?;
     let change_logs = self.block_store.change_logs()?;
     let identity_id = &unlocked_user.identity.id;
     let private_keys = &unlocked_user.private_keys;
-    let index_updated = unlocked_user.index.process_change_logs(&change_logs, |block_id| {
-      self.get_secret_version(identity_id, private_keys, block_id)
-    })?;
+    let index_updated = unlocked_user
+      .index
+      .process_change_logs(&change_logs, &unlocked_user.schemas, |block_id| {
+        self.get_secret_version(identity_id, private_keys, block_id)
+      })?;
 
     if index_updated {
       info!("Index has been updated");
@@ -309,39 +381,83 @@ impl SecretsStore for MultiLaneSecretsStore {
       // User adding a secret version to the store is always a recipient
       secret_version.recipients.push(unlocked_user.identity.id.clone());
     }
+    if let Some(custom_type) = secret_version.custom_type.as_ref().filter(|_| !secret_version.deleted) {
+      secret_version
+        .schema(&unlocked_user.schemas)
+        .ok_or_else(|| SecretStoreError::Schema(format!("Unknown custom type {}", custom_type)))?
+        .validate(&secret_version)?;
+    }
+    self.check_breached_passwords(unlocked_user, &secret_version)?;
+    self.store_attachments(unlocked_user, &mut secret_version)?;
 
-    let block_content = {
-      let mut buffer = ZeroizeBytesBuffer::with_capacity(1024);
-      serde_json::to_writer(&mut buffer, &secret_version)?;
-
-      self.ecnrypt_block(
-        &secret_version.recipients,
-        NonZeroPadding::pad_secret_data(&buffer, 512)?,
-      )?
-    };
+    let block_id = self.write_version(unlocked_user, &secret_version)?;
 
-    let block_id = self.block_store.add_block(&block_content)?;
-    self.block_store.commit(&[Change {
-      op: Operation::Add,
-      block: block_id.clone(),
-    }])?;
-    self.event_hub.send(Event::SecretVersionAdded {
-      store_name: self.name.clone(),
-      secret_id: secret_version.secret_id.clone(),
-      identity: unlocked_user.identity.clone(),
-    });
+    // The chunks are part of the new version now
+    let mut pending_chunks = unlocked_user.pending_chunks.lock()?;
+    let mut readable_chunks = unlocked_user.readable_chunks.lock()?;
+    for chunk in secret_version.attachments.iter().flat_map(SecretAttachment::chunks) {
+      pending_chunks.sizes.remove(chunk);
+      readable_chunks.insert(chunk.clone());
+    }
+    pending_chunks.upload_size = 0;
 
     Ok(block_id)
   }
 
+  fn migrate_versions(&self) -> SecretStoreResult<usize> {
+    let mut migrated = 0;
+
+    {
+      let maybe_unlocked_user = self.unlocked_user.read()?;
+      let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+
+      for deleted in &[false, true] {
+        let mut filter = SecretListFilter::default();
+        filter.deleted = *deleted;
+        let list = unlocked_user.index.filter_entries(&filter)?;
+
+        for entry_match in &list.entries {
+          let versions = unlocked_user.index.find_versions(&entry_match.entry.id)?;
+          let current_block_id = match Index::current_version(&versions) {
+            Some(current) => &current.block_id,
+            None => continue,
+          };
+          let block_words = self.block_store.get_block(current_block_id)?;
+
+          if let Some((format, padded_content)) =
+            self.decrypt_block(&unlocked_user.identity.id, &unlocked_user.private_keys, &block_words)?
+          {
+            if format.payload_version == self.payload_version() {
+              continue;
+            }
+            // The content (including the timestamp) remains unchanged, the migrated version just supersedes
+            // the current one
+            let mut secret_version = Self::decode_secret_version(format, &padded_content)?;
+
+            secret_version.parent_block_id = Some(current_block_id.clone());
+            secret_version.merged_block_ids = vec![];
+            self.write_version(unlocked_user, &secret_version)?;
+            migrated += 1;
+          }
+        }
+      }
+    }
+    if migrated > 0 {
+      self.update_index()?;
+    }
+
+    Ok(migrated)
+  }
+
   fn get(&self, secret_id: &str) -> SecretStoreResult<Secret> {
     let maybe_unlocked_user = self.unlocked_user.read()?;
     let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
     let versions = unlocked_user.index.find_versions(secret_id)?;
 
-    assert!(!versions.is_empty());
-
-    let current_block_id = versions.first().unwrap().block_id.clone();
+    let current_block_id = Index::current_version(&versions)
+      .ok_or(SecretStoreError::NotFound)?
+      .block_id
+      .clone();
     let current = self
       .get_secret_version(
         &unlocked_user.identity.id,
@@ -349,13 +465,23 @@ impl SecretsStore for MultiLaneSecretsStore {
         &current_block_id,
       )?
       .ok_or(SecretStoreError::NotFound)?;
-    let mut password_strengths = HashMap::with_capacity(current.secret_type.password_properties().len());
+    Self::add_readable_chunks(unlocked_user, &current)?;
+    let password_properties = current.password_properties(&unlocked_user.schemas);
+    let mut password_strengths = HashMap::with_capacity(password_properties.len());
+    let mut breached_passwords = self.open_breached_passwords().unwrap_or_else(|error| {
+      warn!("Unable to open breached passwords file: {}", error);
+      None
+    });
+
+    for property in password_properties {
+      if let Some(value) = current.properties.get(property) {
+        let mut strength = ZxcvbnEstimator::estimate_strength(value, &[&current.name, &unlocked_user.identity.name]);
 
-    for property in current.secret_type.password_properties() {
-      if let Some(value) = current.properties.get(*property) {
-        let strength = ZxcvbnEstimator::estimate_strength(value, &[&current.name, &unlocked_user.identity.name]);
+        if let Some(breached_passwords) = breached_passwords.as_mut() {
+          strength.breach_count = breached_passwords.occurrences(value)?;
+        }
 
-        password_strengths.insert((*property).to_string(), strength);
+        password_strengths.insert(property.to_string(), strength);
       }
     }
     self.event_hub.send(Event::SecretOpened {
@@ -364,6 +490,12 @@ impl SecretsStore for MultiLaneSecretsStore {
       identity: unlocked_user.identity.clone(),
     });
 
+    let competing_versions = Index::find_heads(&versions)
+      .into_iter()
+      .filter(|version_ref| version_ref.block_id != current_block_id)
+      .cloned()
+      .collect();
+
     Ok(Secret {
       id: current.secret_id.clone(),
       secret_type: current.secret_type,
@@ -371,6 +503,7 @@ impl SecretsStore for MultiLaneSecretsStore {
       current_block_id,
       versions,
       password_strengths,
+      competing_versions,
     })
   }
 
@@ -378,13 +511,389 @@ impl SecretsStore for MultiLaneSecretsStore {
     let maybe_unlocked_user = self.unlocked_user.read()?;
     let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
 
-    self
+    let version = self
       .get_secret_version(&unlocked_user.identity.id, &unlocked_user.private_keys, block_id)?
-      .ok_or(SecretStoreError::NotFound)
+      .ok_or(SecretStoreError::NotFound)?;
+    Self::add_readable_chunks(unlocked_user, &version)?;
+
+    Ok(version)
+  }
+
+  fn health_report(&self, params: &HealthReportParams) -> SecretStoreResult<HealthReport> {
+    let maybe_unlocked_user = self.unlocked_user.read()?;
+    let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+    let entries = unlocked_user.index.filter_entries(&Default::default())?;
+    let mut health_check = HealthCheck::new(
+      params,
+      &[&unlocked_user.identity.name],
+      self.open_breached_passwords()?,
+      &unlocked_user.schemas,
+    );
+
+    for entry_match in entries.entries.iter() {
+      let versions = unlocked_user.index.find_versions(&entry_match.entry.id)?;
+
+      health_check.check_secret(&versions, |block_id| {
+        self
+          .get_secret_version(&unlocked_user.identity.id, &unlocked_user.private_keys, block_id)?
+          .ok_or(SecretStoreError::NotFound)
+      })?;
+    }
+
+    Ok(health_check.finish())
+  }
+
+  fn list_schemas(&self) -> SecretStoreResult<Vec<SecretTypeSchema>> {
+    let maybe_unlocked_user = self.unlocked_user.read()?;
+    let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+
+    Ok(unlocked_user.schemas.clone())
+  }
+
+  fn store_schema(&self, schema: SecretTypeSchema) -> SecretStoreResult<()> {
+    let mut maybe_unlocked_user = self.unlocked_user.write()?;
+    let unlocked_user = maybe_unlocked_user.as_mut().ok_or(SecretStoreError::Locked)?;
+
+    schema.check()?;
+
+    let stored_schema = StoredSchema {
+      timestamp: SystemTime::now().into(),
+      schema,
+    };
+    self.write_schema(&stored_schema)?;
+    let schema = stored_schema.schema;
+    unlocked_user.schemas.retain(|existing| existing.id != schema.id);
+    unlocked_user.schemas.push(schema);
+    unlocked_user.schemas.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(())
+  }
+
+  fn add_attachment_chunk(&self, recipients: &[String], chunk: &[u8]) -> SecretStoreResult<String> {
+    let maybe_unlocked_user = self.unlocked_user.read()?;
+    let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+
+    if chunk.len() > ATTACHMENT_CHUNK_SIZE {
+      return Err(SecretStoreError::AttachmentTooLarge(ATTACHMENT_CHUNK_SIZE as u64));
+    }
+    if let Some(max_size) = self.max_attachment_size {
+      let mut pending_chunks = unlocked_user.pending_chunks.lock()?;
+
+      pending_chunks.upload_size += chunk.len() as u64;
+      if pending_chunks.upload_size > max_size {
+        pending_chunks.upload_size = 0;
+        return Err(SecretStoreError::AttachmentTooLarge(max_size));
+      }
+      if chunk.len() < ATTACHMENT_CHUNK_SIZE {
+        pending_chunks.upload_size = 0;
+      }
+    }
+
+    self.store_chunk(unlocked_user, recipients, chunk)
+  }
+
+  fn get_attachment_chunk(&self, block_id: &str) -> SecretStoreResult<SecretBytes> {
+    let maybe_unlocked_user = self.unlocked_user.read()?;
+    let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+
+    // Otherwise any block the user is a recipient of could be read as chunk
+    let pending = unlocked_user.pending_chunks.lock()?.sizes.contains_key(block_id);
+    if !pending && !unlocked_user.readable_chunks.lock()?.contains(block_id) {
+      return Err(SecretStoreError::NotFound);
+    }
+    let block_words = self.block_store.get_block(block_id)?;
+
+    self
+      .decode_block::<RandomFrontBack>(&unlocked_user.identity.id, &unlocked_user.private_keys, &block_words)?
+      .ok_or(SecretStoreError::NoRecipient)
+  }
+
+  fn put_attachment(
+    &self,
+    name: &str,
+    mime_type: &str,
+    recipients: &[String],
+    content: &mut dyn Read,
+  ) -> SecretStoreResult<SecretAttachment> {
+    let maybe_unlocked_user = self.unlocked_user.read()?;
+    let unlocked_user = maybe_unlocked_user.as_ref().ok_or(SecretStoreError::Locked)?;
+
+    store_chunks(name, mime_type, content, self.max_attachment_size, |chunk| {
+      self.store_chunk(unlocked_user, recipients, chunk)
+    })
   }
 }
 
 impl MultiLaneSecretsStore {
+  fn open_breached_passwords(&self) -> SecretStoreResult<Option<BreachedPasswords>> {
+    self.breach_check.as_ref().map(BreachCheck::open).transpose()
+  }
+
+  /// Check all passwords that have changed compared to the parent version against the breached passwords file.
+  ///
+  /// Depending on the configuration a breached password is either rejected or just logged. Versions older
+  /// than the current version of the secret (e.g. the history of an import) are never rejected.
+  fn check_breached_passwords(&self, unlocked_user: &User, secret_version: &SecretVersion) -> SecretStoreResult<()> {
+    let breach_check = match &self.breach_check {
+      Some(breach_check) if !secret_version.deleted => breach_check,
+      _ => return Ok(()),
+    };
+    let mut breached_passwords = match breach_check.open() {
+      Ok(breached_passwords) => breached_passwords,
+      Err(error) if !breach_check.reject => {
+        warn!("Unable to open breached passwords file: {}", error);
+        return Ok(());
+      }
+      Err(error) => return Err(error),
+    };
+    let parent = match &secret_version.parent_block_id {
+      Some(parent_block_id) => {
+        self.get_secret_version(&unlocked_user.identity.id, &unlocked_user.private_keys, parent_block_id)?
+      }
+      None => None,
+    };
+
+    for property in secret_version.password_properties(&unlocked_user.schemas) {
+      let value = match secret_version.properties.get(property) {
+        Some(value) if !value.is_empty() => value,
+        _ => continue,
+      };
+      if parent.as_ref().and_then(|parent| parent.properties.get(property)) == Some(value) {
+        continue;
+      }
+      let count = breached_passwords.occurrences(value)?;
+
+      if count > 0 {
+        if breach_check.reject && !Self::is_outdated(unlocked_user, secret_version)? {
+          return Err(SecretStoreError::BreachedPassword(property.to_string()));
+        }
+        warn!(
+          "Password {} of secret {} appears {} times in breached passwords",
+          property, secret_version.secret_id, count
+        );
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Check if the index already has a newer version of the secret than the given one.
+  fn is_outdated(unlocked_user: &User, secret_version: &SecretVersion) -> SecretStoreResult<bool> {
+    let version_refs = match unlocked_user.index.find_versions(&secret_version.secret_id) {
+      Ok(version_refs) => version_refs,
+      Err(SecretStoreError::NotFound) => return Ok(false),
+      Err(error) => return Err(error),
+    };
+
+    Ok(Index::current_version(&version_refs).is_some_and(|current| current.timestamp > secret_version.timestamp))
+  }
+
+  /// Payload format of new secret versions.
+  fn payload_version(&self) -> u8 {
+    if self.capnp_payload {
+      PAYLOAD_VERSION_CAPNP
+    } else {
+      PAYLOAD_VERSION_INITIAL
+    }
+  }
+
+  /// Write a (validated) secret version as new block and commit it.
+  fn write_version(&self, unlocked_user: &User, secret_version: &SecretVersion) -> SecretStoreResult<String> {
+    let block_content = match self.payload_version() {
+      PAYLOAD_VERSION_CAPNP => {
+        let mut version_message = message::Builder::new(ZeroingHeapAllocator::default());
+        secret_version.to_builder(version_message.init_root::<secret_version::Builder>())?;
+        let payload = SecretBytes::from(serialize::write_message_to_words(&version_message));
+        let payload_ref = payload.borrow();
+
+        self.encode_block::<RandomFrontBack, _>(&secret_version.recipients, PAYLOAD_VERSION_CAPNP, &payload_ref)?
+      }
+      _ => {
+        let mut buffer = ZeroizeBytesBuffer::with_capacity(1024);
+        serde_json::to_writer(&mut buffer, secret_version)?;
+
+        self.encode_block::<NonZeroPadding, _>(&secret_version.recipients, PAYLOAD_VERSION_INITIAL, &buffer)?
+      }
+    };
+
+    let block_id = self.block_store.add_block(&block_content)?;
+    self.block_store.commit(&[Change {
+      op: Operation::Add,
+      block: block_id.clone(),
+    }])?;
+    self.event_hub.send(Event::SecretVersionAdded {
+      store_name: self.name.clone(),
+      secret_id: secret_version.secret_id.clone(),
+      identity: unlocked_user.identity.clone(),
+    });
+
+    Ok(block_id)
+  }
+
+  /// Move the content of all inline attachments to chunk blocks.
+  ///
+  /// Content that has already been stored for the parent version is reused (as long as the recipients
+  /// have not been changed).
+  fn store_attachments(&self, unlocked_user: &User, secret_version: &mut SecretVersion) -> SecretStoreResult<()> {
+    if let Some(max_size) = self.max_attachment_size {
+      if secret_version
+        .attachments
+        .iter()
+        .any(|attachment| attachment.size() > max_size)
+      {
+        return Err(SecretStoreError::AttachmentTooLarge(max_size));
+      }
+    }
+    if secret_version.attachments.is_empty() {
+      return Ok(());
+    }
+    let parent = match &secret_version.parent_block_id {
+      Some(parent_block_id) => self
+        .get_secret_version(&unlocked_user.identity.id, &unlocked_user.private_keys, parent_block_id)?
+        .filter(|parent| {
+          secret_version
+            .recipients
+            .iter()
+            .all(|recipient| parent.recipients.contains(recipient))
+        }),
+      None => None,
+    };
+    let parent_attachments = parent
+      .as_ref()
+      .map(|parent| parent.attachments.as_slice())
+      .unwrap_or_default();
+
+    Self::check_stored_attachments(unlocked_user, &secret_version.attachments, parent_attachments)?;
+
+    let recipients = &secret_version.recipients;
+
+    for attachment in secret_version
+      .attachments
+      .iter_mut()
+      .filter(|attachment| !attachment.is_stored())
+    {
+      let hash = content_hash(attachment.content());
+      let stored = match parent_attachments
+        .iter()
+        .find(|existing| existing.content_hash() == hash)
+      {
+        Some(existing) => SecretAttachment::stored(
+          attachment.name().to_string(),
+          attachment.mime_type().to_string(),
+          hash,
+          existing.size(),
+          existing.chunks().to_vec(),
+        ),
+        None => store_chunks(
+          attachment.name(),
+          attachment.mime_type(),
+          &mut attachment.content(),
+          None,
+          |chunk| self.store_chunk(unlocked_user, recipients, chunk),
+        )?,
+      };
+
+      *attachment = stored;
+    }
+
+    Ok(())
+  }
+
+  /// Check that the chunks of all stored attachments have either been stored by the user or are
+  /// taken over from the parent version.
+  ///
+  /// In the first case the size of the attachment has to match the size of the chunks.
+  fn check_stored_attachments(
+    unlocked_user: &User,
+    attachments: &[SecretAttachment],
+    parent_attachments: &[SecretAttachment],
+  ) -> SecretStoreResult<()> {
+    let pending_chunks = unlocked_user.pending_chunks.lock()?;
+
+    for attachment in attachments.iter().filter(|attachment| attachment.is_stored()) {
+      if parent_attachments.iter().any(|existing| {
+        existing.chunks() == attachment.chunks()
+          && existing.size() == attachment.size()
+          && existing.content_hash() == attachment.content_hash()
+      }) {
+        continue;
+      }
+      let stored_size: Option<u64> = attachment
+        .chunks()
+        .iter()
+        .map(|chunk| pending_chunks.sizes.get(chunk).copied())
+        .sum();
+
+      if stored_size != Some(attachment.size()) {
+        return Err(SecretStoreError::InvalidAttachment(attachment.name().to_string()));
+      }
+    }
+
+    Ok(())
+  }
+
+  fn add_readable_chunks(unlocked_user: &User, secret_version: &SecretVersion) -> SecretStoreResult<()> {
+    let mut readable_chunks = unlocked_user.readable_chunks.lock()?;
+
+    readable_chunks.extend(
+      secret_version
+        .attachments
+        .iter()
+        .flat_map(SecretAttachment::chunks)
+        .cloned(),
+    );
+
+    Ok(())
+  }
+
+  /// Remove all chunks that have been stored by the user but have never become part of a secret version.
+  fn remove_pending_chunks(&self, user: &User) {
+    let pending_chunks = match user.pending_chunks.lock() {
+      Ok(pending_chunks) => pending_chunks,
+      Err(error) => {
+        warn!("Unable to remove unused attachment chunks: {}", error);
+        return;
+      }
+    };
+
+    for block_id in pending_chunks.sizes.keys() {
+      if let Err(error) = self.block_store.remove_block(block_id) {
+        warn!("Unable to remove unused attachment chunk {}: {}", block_id, error);
+      }
+    }
+  }
+
+  /// Store a chunk of an attachment as separate block.
+  ///
+  /// Chunks are not committed to the change log, they are only referenced by the secret versions.
+  /// Until then they are pending, so that they can be removed if this never happens.
+  fn store_chunk(&self, unlocked_user: &User, recipients: &[String], chunk: &[u8]) -> SecretStoreResult<String> {
+    let mut recipients = recipients.to_vec();
+
+    if !recipients.contains(&unlocked_user.identity.id) {
+      recipients.push(unlocked_user.identity.id.clone());
+    }
+    // Chunks are not compressed, most attachments are already (e.g. images or archives)
+    let block_content = self.ecnrypt_block(
+      &recipients,
+      BlockFormat {
+        content: ContentFormat::Plain,
+        payload_version: PAYLOAD_VERSION_INITIAL,
+      },
+      RandomFrontBack::pad_secret_data(chunk, 512)?,
+    )?;
+
+    let block_id = self.block_store.add_block(&block_content)?;
+
+    unlocked_user
+      .pending_chunks
+      .lock()?
+      .sizes
+      .insert(block_id.clone(), chunk.len() as u64);
+
+    Ok(block_id)
+  }
+
   fn generate_nonce(len: usize) -> Vec<u8> {
     let mut rng = thread_rng();
     let mut nonce = vec![0u8; len];
@@ -450,24 +959,103 @@ impl MultiLaneSecretsStore {
 
   fn read_index(&self, identity_id: &str, private_keys: &[(KeyType, PrivateKey)]) -> SecretStoreResult<Index> {
     match self.block_store.get_index(identity_id)? {
-      Some(crypted_index) => match self.decrypt_block(identity_id, private_keys, &crypted_index)? {
-        Some(padded_index_data) => {
-          let borrowed = padded_index_data.borrow();
-          let index_data = RandomFrontBack::unpad_data(&borrowed)?;
-          Ok(Index::from_secured_raw(index_data)?)
+      Some(crypted_index) => match self.decode_block::<RandomFrontBack>(identity_id, private_keys, &crypted_index)? {
+        Some(index_data) => {
+          let index = Index::from_secured_raw(&index_data.borrow())?;
+
+          if index.is_full_text() != self.full_text_index {
+            info!("Full-text index has been enabled or disabled. Will trigger re-index.");
+            return Ok(Index::new(self.full_text_index));
+          }
+          Ok(index)
         }
         None => {
           warn!("User is not allowed recipient for index-data. Will trigger re-index.");
-          Ok(Default::default())
+          Ok(Index::new(self.full_text_index))
         }
       },
-      None => Ok(Default::default()),
+      None => Ok(Index::new(self.full_text_index)),
+    }
+  }
+
+  /// Read all schemas the user is a recipient of.
+  ///
+  /// If a schema has been changed on multiple nodes the latest change wins. Schemas that are not shared with
+  /// all identities of the store (e.g. because an identity has been added later on) are written again.
+  fn refresh_schemas(&self, unlocked_user: &mut User) -> SecretStoreResult<()> {
+    let ring_ids = self.block_store.list_ring_ids()?;
+    // Schema id -> (stored schema, shared with all identities, block reference)
+    let mut latest: HashMap<String, (StoredSchema, bool, SchemaRef)> = HashMap::new();
+
+    for schema_ref in self.block_store.list_schema_refs()? {
+      let block_words = self.block_store.get_schema(&schema_ref)?;
+      let stored_schema: StoredSchema = match self.decode_block::<NonZeroPadding>(
+        &unlocked_user.identity.id,
+        &unlocked_user.private_keys,
+        &block_words,
+      )? {
+        Some(content) => serde_json::from_slice(&content.borrow())?,
+        None => {
+          warn!(
+            "User is not allowed recipient for schema {} of {}",
+            schema_ref.schema_id, schema_ref.node
+          );
+          continue;
+        }
+      };
+      let shared = Self::has_recipients(&ring_ids, &block_words)?;
+      // Prefer blocks shared with everyone on equal timestamps, otherwise they would be written over and over again
+      let wins = match latest.get(&stored_schema.schema.id) {
+        Some((existing, existing_shared, existing_ref)) => {
+          (stored_schema.timestamp, shared, &schema_ref.node)
+            > (existing.timestamp, *existing_shared, &existing_ref.node)
+        }
+        None => true,
+      };
+
+      if wins {
+        latest.insert(stored_schema.schema.id.clone(), (stored_schema, shared, schema_ref));
+      }
+    }
+
+    let mut schemas = Vec::with_capacity(latest.len());
+
+    for (stored_schema, shared, _) in latest.into_values() {
+      if !shared {
+        info!("Sharing schema {} with all identities", stored_schema.schema.id);
+        self.write_schema(&stored_schema)?;
+      }
+      schemas.push(stored_schema.schema);
     }
+    schemas.sort_by(|a, b| a.name.cmp(&b.name));
+    unlocked_user.schemas = schemas;
+
+    Ok(())
+  }
+
+  /// Schemas are shared by all identities of the store.
+  fn write_schema(&self, stored_schema: &StoredSchema) -> SecretStoreResult<()> {
+    let recipients = self.block_store.list_ring_ids()?;
+    let block_content = {
+      let mut buffer = ZeroizeBytesBuffer::with_capacity(1024);
+      serde_json::to_writer(&mut buffer, stored_schema)?;
+
+      self.encode_block::<NonZeroPadding, _>(&recipients, PAYLOAD_VERSION_INITIAL, &buffer)?
+    };
+
+    Ok(
+      self
+        .block_store
+        .store_schema(&stored_schema.schema.id, &block_content)?,
+    )
   }
 
   fn store_index(&self, identity_id: &str, index: &Index) -> SecretStoreResult<()> {
-    let secret_content = RandomFrontBack::pad_secret_data(index.data.borrow().as_bytes(), 512)?;
-    let block_content = self.ecnrypt_block(&[identity_id], secret_content)?;
+    let block_content = self.encode_block::<RandomFrontBack, _>(
+      &[identity_id],
+      PAYLOAD_VERSION_INITIAL,
+      index.data.borrow().as_bytes(),
+    )?;
 
     Ok(self.block_store.store_index(identity_id, &block_content)?)
   }
@@ -480,20 +1068,95 @@ impl MultiLaneSecretsStore {
   ) -> SecretStoreResult<Option<SecretVersion>> {
     let block_words = self.block_store.get_block(block_id)?;
 
-    match self.decrypt_block(identity_id, &private_keys, &block_words)? {
-      Some(padded_content) => {
-        let borrowed = padded_content.borrow();
-        let version = serde_json::from_slice(NonZeroPadding::unpad_data(&borrowed)?)?;
+    match self.decrypt_block(identity_id, private_keys, &block_words)? {
+      Some((format, padded_content)) => Ok(Some(Self::decode_secret_version(format, &padded_content)?)),
+      _ => Ok(None),
+    }
+  }
+
+  /// Secret versions used to be stored as json, which is still supported for reading.
+  fn decode_secret_version(format: BlockFormat, padded_content: &SecretBytes) -> SecretStoreResult<SecretVersion> {
+    match format.payload_version {
+      PAYLOAD_VERSION_INITIAL => {
+        let content = Self::unpad_content::<NonZeroPadding>(format.content, padded_content)?;
 
-        Ok(Some(version))
+        let secret_version = serde_json::from_slice(&content.borrow())?;
+        Ok(secret_version)
       }
-      _ => Ok(None),
+      PAYLOAD_VERSION_CAPNP => {
+        // capnp requires the message to be word aligned
+        let content =
+          SecretWords::from_secured(&Self::unpad_content::<RandomFrontBack>(format.content, padded_content)?.borrow());
+        let mut content_borrow: &[u8] = &content.borrow();
+        let reader = serialize::read_message_from_flat_slice(&mut content_borrow, message::ReaderOptions::new())?;
+
+        Ok(SecretVersion::from_reader(
+          reader.get_root::<secret_version::Reader>()?,
+        )?)
+      }
+      unsupported => Err(SecretStoreError::UnsupportedPayload(unsupported)),
+    }
+  }
+
+  /// Pad and encrypt the content of a block, if enabled the content is compressed first.
+  ///
+  /// `P` is the padding used for uncompressed content.
+  fn encode_block<P: Padding, T: AsRef<str>>(
+    &self,
+    recipients: &[T],
+    payload_version: u8,
+    content: &[u8],
+  ) -> SecretStoreResult<Vec<u8>> {
+    if self.compress_blocks {
+      self.ecnrypt_block(
+        recipients,
+        BlockFormat {
+          content: ContentFormat::Deflate,
+          payload_version,
+        },
+        compression::compress_and_pad(content)?,
+      )
+    } else {
+      self.ecnrypt_block(
+        recipients,
+        BlockFormat {
+          content: ContentFormat::Plain,
+          payload_version,
+        },
+        P::pad_secret_data(content, 512)?,
+      )
+    }
+  }
+
+  /// Decrypt a block and unpad its content (counterpart of `encode_block`).
+  ///
+  /// The format of the content is determined by the block itself, i.e. blocks written before compression
+  /// was enabled (or by older versions) are still readable.
+  fn decode_block<P: Padding>(
+    &self,
+    identity_id: &str,
+    private_keys: &[(KeyType, PrivateKey)],
+    block_words: &[u8],
+  ) -> SecretStoreResult<Option<SecretBytes>> {
+    match self.decrypt_block(identity_id, private_keys, block_words)? {
+      Some((format, padded_content)) => Ok(Some(Self::unpad_content::<P>(format.content, &padded_content)?)),
+      None => Ok(None),
+    }
+  }
+
+  fn unpad_content<P: Padding>(format: ContentFormat, padded_content: &SecretBytes) -> SecretStoreResult<SecretBytes> {
+    let borrowed = padded_content.borrow();
+
+    match format {
+      ContentFormat::Plain => Ok(SecretBytes::from_secured(P::unpad_data(&borrowed)?)),
+      ContentFormat::Deflate => compression::unpad_and_decompress(&borrowed),
     }
   }
 
   fn ecnrypt_block<T: AsRef<str>>(
     &self,
     recipients: &[T],
+    format: BlockFormat,
     mut secret_content: SecretBytes,
   ) -> SecretStoreResult<Vec<u8>> {
     let recipients_for_cipher = self.find_recipients(recipients)?;
@@ -507,6 +1170,8 @@ impl MultiLaneSecretsStore {
       secret_content = SecretBytes::from(content);
     }
     block.set_content(&secret_content.borrow());
+    block.set_format(format.content);
+    block.set_payload_version(format.payload_version);
 
     Ok(serialize::write_message_to_words(&block_message))
   }
@@ -516,7 +1181,7 @@ impl MultiLaneSecretsStore {
     identity_id: &str,
     private_keys: &[(KeyType, PrivateKey)],
     mut block_words: &[u8],
-  ) -> SecretStoreResult<Option<SecretBytes>> {
+  ) -> SecretStoreResult<Option<(BlockFormat, SecretBytes)>> {
     let reader = serialize::read_message_from_flat_slice(&mut block_words, Default::default())?;
     let index_block = reader.get_root::<block::Reader>()?;
     let headers = index_block.reborrow().get_headers()?;
@@ -540,7 +1205,25 @@ impl MultiLaneSecretsStore {
       content = next_content;
     }
 
-    Ok(Some(content))
+    let format = BlockFormat {
+      content: index_block.get_format()?,
+      payload_version: index_block.get_payload_version(),
+    };
+
+    Ok(Some((format, content)))
+  }
+
+  /// Check if a block is encrypted for all of the given identities.
+  fn has_recipients(identity_ids: &[String], mut block_words: &[u8]) -> SecretStoreResult<bool> {
+    let reader = serialize::read_message_from_flat_slice(&mut block_words, Default::default())?;
+    let headers = reader.get_root::<block::Reader>()?.get_headers()?;
+
+    for identity_id in identity_ids {
+      if !Self::check_recipient(identity_id, &headers)? {
+        return Ok(false);
+      }
+    }
+    Ok(true)
   }
 
   fn check_recipient<'a>(
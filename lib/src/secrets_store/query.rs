@@ -0,0 +1,117 @@
+use crate::api::{QueryTerm, SecretEntry, SecretQuery, TextMatch};
+use crate::secrets_store::full_text::match_texts;
+use crate::secrets_store::SecretStoreResult;
+use crate::secrets_store_capnp::indexed_text;
+use itertools::Itertools;
+
+/// Everything of an entry that has been matched by a query (for highlighting).
+#[derive(Debug, Default)]
+pub struct QueryMatch {
+  pub name_score: isize,
+  pub name_highlights: Vec<usize>,
+  pub url_highlights: Vec<usize>,
+  pub tags_highlights: Vec<usize>,
+  pub text_matches: Vec<TextMatch>,
+}
+
+impl QueryMatch {
+  pub fn merge(&mut self, other: QueryMatch) {
+    self.name_score += other.name_score;
+    merge_positions(&mut self.name_highlights, other.name_highlights);
+    merge_positions(&mut self.url_highlights, other.url_highlights);
+    merge_positions(&mut self.tags_highlights, other.tags_highlights);
+    for text_match in other.text_matches {
+      if !self.text_matches.contains(&text_match) {
+        self.text_matches.push(text_match);
+      }
+    }
+  }
+}
+
+fn merge_positions(positions: &mut Vec<usize>, other: Vec<usize>) {
+  positions.extend(other);
+  positions.sort_unstable();
+  positions.dedup();
+}
+
+/// Match an entry against a query, negated terms do not contribute any highlights.
+pub fn match_query(
+  query: &SecretQuery,
+  entry: &SecretEntry,
+  texts: capnp::struct_list::Reader<indexed_text::Owned>,
+) -> SecretStoreResult<Option<QueryMatch>> {
+  match query {
+    SecretQuery::Term(term) => match_term(term, entry, texts),
+    SecretQuery::Not(negated) => match match_query(negated, entry, texts)? {
+      Some(_) => Ok(None),
+      None => Ok(Some(QueryMatch::default())),
+    },
+    SecretQuery::And(terms) => {
+      let mut result = QueryMatch::default();
+
+      for term in terms {
+        match match_query(term, entry, texts)? {
+          Some(term_match) => result.merge(term_match),
+          None => return Ok(None),
+        }
+      }
+
+      Ok(Some(result))
+    }
+    SecretQuery::Or(alternatives) => {
+      let mut result = None;
+
+      for alternative in alternatives {
+        if let Some(alternative_match) = match_query(alternative, entry, texts)? {
+          result.get_or_insert_with(QueryMatch::default).merge(alternative_match);
+        }
+      }
+
+      Ok(result)
+    }
+  }
+}
+
+fn match_term(
+  term: &QueryTerm,
+  entry: &SecretEntry,
+  texts: capnp::struct_list::Reader<indexed_text::Owned>,
+) -> SecretStoreResult<Option<QueryMatch>> {
+  let result = match term {
+    QueryTerm::Name(name) => sublime_fuzzy::best_match(name, &entry.name).map(|fuzzy_match| QueryMatch {
+      name_score: fuzzy_match.score(),
+      name_highlights: fuzzy_match.matches().clone(),
+      ..Default::default()
+    }),
+    QueryTerm::Tag(tag) => {
+      let tags_highlights: Vec<usize> = entry.tags.iter().positions(|t| t == tag).collect();
+
+      Some(QueryMatch {
+        tags_highlights,
+        ..Default::default()
+      })
+      .filter(|query_match| !query_match.tags_highlights.is_empty())
+    }
+    QueryTerm::Type(secret_type) => Some(QueryMatch::default()).filter(|_| entry.secret_type == *secret_type),
+    QueryTerm::Url(url) => {
+      let url = url.to_lowercase();
+      let url_highlights: Vec<usize> = entry
+        .urls
+        .iter()
+        .positions(|u| u.to_lowercase().contains(&url))
+        .collect();
+
+      Some(QueryMatch {
+        url_highlights,
+        ..Default::default()
+      })
+      .filter(|query_match| !query_match.url_highlights.is_empty())
+    }
+    QueryTerm::Text(text) => match_texts(texts, text)?.map(|text_matches| QueryMatch {
+      text_matches,
+      ..Default::default()
+    }),
+  };
+
+  Ok(result)
+}
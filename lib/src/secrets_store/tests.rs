@@ -1,10 +1,25 @@
-use super::{open_secrets_store, SecretStoreError, SecretStoreResult, SecretsStore};
-use crate::api::{Event, EventHub, Identity, SecretType, SecretVersion};
+use super::multi_lane::MultiLaneSecretsStore;
+use super::{
+  copy_secret, open_secrets_store, BreachCheck, SecretStoreError, SecretStoreResult, SecretsStore,
+  ATTACHMENT_CHUNK_SIZE,
+};
+use crate::api::{
+  Event, EventHub, FieldKind, Identity, SchemaField, SecretAttachment, SecretListFilter, SecretType, SecretTypeSchema,
+  SecretVersion, PROPERTY_PASSWORD,
+};
+use crate::block_store::{open_block_store, BlockStore, StoreError};
 use crate::memguard::SecretBytes;
-use chrono::Utc;
+use crate::secrets_store_capnp::block;
+use crate::service::StoreConfig;
+use chrono::{Duration, Utc};
+use data_encoding::HEXUPPER;
+use sha1::{Digest, Sha1};
 use spectral::prelude::*;
+use std::fs;
 use std::sync::Arc;
-use std::time::Duration;
+use tempdir::TempDir;
+
+const MAX_ATTACHMENT_SIZE: u64 = 1024 * 1024;
 
 fn common_secrets_store_tests(secrets_store: Arc<dyn SecretsStore>) {
   let initial_status = secrets_store.status().unwrap();
@@ -19,6 +34,9 @@ fn common_secrets_store_tests(secrets_store: Arc<dyn SecretsStore>) {
   let ids_with_passphrase = add_identities_test(secrets_store.as_ref());
 
   add_secrets_versions(secrets_store.as_ref(), &ids_with_passphrase);
+  custom_types_test(secrets_store.as_ref(), &ids_with_passphrase);
+  attachments_test(secrets_store.as_ref(), &ids_with_passphrase);
+  concurrent_versions_test(secrets_store.as_ref(), &ids_with_passphrase);
 }
 
 fn add_identities_test(secrets_store: &dyn SecretsStore) -> Vec<(Identity, SecretBytes)> {
@@ -89,6 +107,11 @@ fn add_secrets_versions(secrets_store: &dyn SecretsStore, ids_with_passphrase: &
     attachments: vec![],
     deleted: false,
     recipients: ids_with_passphrase.iter().map(|(id, _)| id.id.clone()).collect(),
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: None,
   };
 
   assert_that(&secrets_store.unlock(&ids_with_passphrase[0].0.id, ids_with_passphrase[0].1.clone())).is_ok();
@@ -103,6 +126,228 @@ fn add_secrets_versions(secrets_store: &dyn SecretsStore, ids_with_passphrase: &
   assert_that(&secret.current.name).is_equal_to("First secret".to_string());
 }
 
+fn custom_types_test(secrets_store: &dyn SecretsStore, ids_with_passphrase: &[(Identity, SecretBytes)]) {
+  let schema = SecretTypeSchema {
+    id: "database".to_string(),
+    name: "Database".to_string(),
+    fields: vec![
+      SchemaField {
+        property: "host".to_string(),
+        kind: FieldKind::Text,
+        required: true,
+      },
+      SchemaField {
+        property: "adminPassword".to_string(),
+        kind: FieldKind::Secret,
+        required: false,
+      },
+    ],
+  };
+
+  assert_that(&secrets_store.store_schema(SecretTypeSchema {
+    id: "Data base".to_string(),
+    ..schema.clone()
+  }))
+  .is_err();
+  assert_that(&secrets_store.store_schema(schema.clone())).is_ok();
+  assert_that(&secrets_store.list_schemas()).is_ok_containing(vec![schema.clone()]);
+
+  let mut version = SecretVersion {
+    secret_id: "secret2".to_string(),
+    secret_type: SecretType::Other,
+    timestamp: Utc::now().into(),
+    name: "Database secret".to_string(),
+    tags: vec![],
+    urls: vec![],
+    properties: Default::default(),
+    attachments: vec![],
+    deleted: false,
+    recipients: vec![],
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: Some("database".to_string()),
+  };
+  version.properties.set("adminPassword", "correct horse battery staple");
+  version.properties.set(PROPERTY_PASSWORD, "not a field of the schema");
+
+  assert_that(&secrets_store.add(version.clone()))
+    .is_err_containing(SecretStoreError::Schema("host is required".to_string()));
+
+  version.properties.set("host", "db.example.com");
+  assert_that(&secrets_store.add(version)).is_ok();
+  assert_that(&secrets_store.update_index()).is_ok();
+
+  let secret = secrets_store.get("secret2").unwrap();
+  let mut password_properties: Vec<&String> = secret.password_strengths.keys().collect();
+  password_properties.sort();
+
+  assert_that(&password_properties).is_equal_to(vec![&"adminPassword".to_string()]);
+
+  // Schemas are available to all identities, including the ones added later on
+  assert_that(&add_identity(
+    secrets_store,
+    "identity3",
+    "Name3",
+    "Email3",
+    "Passphrase3",
+  ))
+  .is_ok();
+  assert_that(&secrets_store.lock()).is_ok();
+  assert_that(&add_identity(
+    secrets_store,
+    "identity4",
+    "Name4",
+    "Email4",
+    "Passphrase4",
+  ))
+  .is_ok();
+  assert_that(&secrets_store.unlock(&ids_with_passphrase[1].0.id, ids_with_passphrase[1].1.clone())).is_ok();
+  assert_that(&secrets_store.list_schemas()).is_ok_containing(vec![schema.clone()]);
+  assert_that(&secrets_store.lock()).is_ok();
+
+  for (identity_id, passphrase) in &[("identity3", "Passphrase3"), ("identity4", "Passphrase4")] {
+    assert_that(&secrets_store.unlock(identity_id, secret_from_str(passphrase))).is_ok();
+    assert_that(&secrets_store.list_schemas()).is_ok_containing(vec![schema.clone()]);
+    assert_that(&secrets_store.lock()).is_ok();
+  }
+}
+
+fn attachments_test(secrets_store: &dyn SecretsStore, ids_with_passphrase: &[(Identity, SecretBytes)]) {
+  let recipients: Vec<String> = ids_with_passphrase.iter().map(|(id, _)| id.id.clone()).collect();
+  let content: Vec<u8> = (0..2 * ATTACHMENT_CHUNK_SIZE + 100).map(|i| (i % 251) as u8).collect();
+
+  assert_that(&secrets_store.unlock(&ids_with_passphrase[0].0.id, ids_with_passphrase[0].1.clone())).is_ok();
+
+  let large = secrets_store
+    .put_attachment(
+      "large.bin",
+      "application/octet-stream",
+      &recipients,
+      &mut content.as_slice(),
+    )
+    .unwrap();
+
+  assert_that(&large.size()).is_equal_to(content.len() as u64);
+  assert_that(&large.chunks().len()).is_equal_to(3);
+
+  let too_large = vec![0u8; MAX_ATTACHMENT_SIZE as usize + 1];
+
+  assert_that(&secrets_store.put_attachment(
+    "too_large.bin",
+    "application/octet-stream",
+    &recipients,
+    &mut too_large.as_slice(),
+  ))
+  .is_err_containing(SecretStoreError::AttachmentTooLarge(MAX_ATTACHMENT_SIZE));
+
+  let mut version = SecretVersion {
+    secret_id: "secret3".to_string(),
+    secret_type: SecretType::Note,
+    timestamp: Utc::now().into(),
+    name: "Secret with attachments".to_string(),
+    tags: vec![],
+    urls: vec![],
+    properties: Default::default(),
+    attachments: vec![
+      large,
+      SecretAttachment::new(
+        "small.txt".to_string(),
+        "text/plain".to_string(),
+        b"inline content".to_vec(),
+      ),
+    ],
+    deleted: false,
+    recipients,
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: None,
+  };
+  let block_id = secrets_store.add(version.clone()).unwrap();
+  assert_that(&secrets_store.update_index()).is_ok();
+
+  let secret = secrets_store.get("secret3").unwrap();
+
+  // Inline content has been moved to chunk blocks as well
+  assert_that(&secret.current.attachments.iter().all(SecretAttachment::is_stored)).is_true();
+  for (attachment, expected) in secret
+    .current
+    .attachments
+    .iter()
+    .zip(vec![content, b"inline content".to_vec()])
+  {
+    let mut out = Vec::new();
+
+    assert_that(&secrets_store.get_attachment(attachment, &mut out)).is_ok();
+    assert_that(&out).is_equal_to(expected);
+  }
+
+  // Unchanged content of the parent version is reused
+  version.parent_block_id = Some(block_id);
+  version.timestamp = Utc::now().into();
+  assert_that(&secrets_store.add(version)).is_ok();
+  assert_that(&secrets_store.update_index()).is_ok();
+
+  let next = secrets_store.get("secret3").unwrap();
+
+  assert_that(&next.current.attachments[1].chunks()).is_equal_to(secret.current.attachments[1].chunks());
+  assert_that(&secrets_store.lock()).is_ok();
+}
+
+fn concurrent_versions_test(secrets_store: &dyn SecretsStore, ids_with_passphrase: &[(Identity, SecretBytes)]) {
+  assert_that(&secrets_store.unlock(&ids_with_passphrase[0].0.id, ids_with_passphrase[0].1.clone())).is_ok();
+
+  let now = Utc::now();
+  let mut version = SecretVersion::new("secret4", SecretType::Login, "Concurrent", now.into());
+  version.recipients = ids_with_passphrase.iter().map(|(id, _)| id.id.clone()).collect();
+  let base_block_id = secrets_store.add(version.clone()).unwrap();
+
+  // Two changes based on the same version (e.g. made offline on different nodes)
+  version.parent_block_id = Some(base_block_id);
+  version.timestamp = (now + Duration::seconds(1)).into();
+  version.name = "Concurrent (node1)".to_string();
+  let node1_block_id = secrets_store.add(version.clone()).unwrap();
+  version.timestamp = (now + Duration::seconds(2)).into();
+  version.name = "Concurrent (node2)".to_string();
+  let node2_block_id = secrets_store.add(version.clone()).unwrap();
+
+  assert_that(&secrets_store.update_index()).is_ok();
+
+  let secret = secrets_store.get("secret4").unwrap();
+
+  assert_that(&secret.current_block_id).is_equal_to(&node2_block_id);
+  assert_that(&secret.versions).has_length(3);
+  assert_that(&secret.competing_versions).has_length(1);
+  assert_that(&secret.competing_versions[0].block_id).is_equal_to(&node1_block_id);
+
+  let mut filter = SecretListFilter::default();
+  filter.name = Some("Concurrent".to_string());
+  let list = secrets_store.list(&filter).unwrap();
+
+  assert_that(&list.entries).has_length(1);
+  assert_that(&list.entries[0].entry.conflicted).is_true();
+
+  // Merge the competing version into the current one
+  version.parent_block_id = Some(node2_block_id);
+  version.merged_block_ids = vec![node1_block_id];
+  version.timestamp = (now + Duration::seconds(3)).into();
+  version.name = "Concurrent (merged)".to_string();
+  let merged_block_id = secrets_store.add(version).unwrap();
+
+  assert_that(&secrets_store.update_index()).is_ok();
+
+  let merged = secrets_store.get("secret4").unwrap();
+
+  assert_that(&merged.current_block_id).is_equal_to(&merged_block_id);
+  assert_that(&merged.versions).has_length(4);
+  assert_that(&merged.competing_versions).is_empty();
+  assert_that(&secrets_store.list(&filter).unwrap().entries[0].entry.conflicted).is_false();
+  assert_that(&secrets_store.lock()).is_ok();
+}
+
 fn add_identity(
   secrets_store: &dyn SecretsStore,
   id: &str,
@@ -134,17 +379,334 @@ impl EventHub for TestEventHub {
   fn send(&self, _event: Event) {}
 }
 
+fn test_store_config(compress_blocks: bool) -> StoreConfig {
+  StoreConfig {
+    name: "test".to_string(),
+    store_url: "multilane+memory://".to_string(),
+    client_id: "node1".to_string(),
+    autolock_timeout_secs: 300,
+    default_identity_id: None,
+    full_text_index: false,
+    max_attachment_size: Some(MAX_ATTACHMENT_SIZE),
+    compress_blocks,
+    capnp_payload: false,
+  }
+}
+
 #[test]
 #[cfg_attr(debug_assertions, ignore)]
 fn test_multi_lane_secrets_store() {
-  let secrets_store = open_secrets_store(
-    "test",
-    "multilane+memory://",
-    "node1",
-    Duration::from_secs(300),
+  let secrets_store = open_secrets_store(&test_store_config(false), Arc::new(TestEventHub), None).unwrap();
+
+  common_secrets_store_tests(secrets_store)
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_multi_lane_secrets_store_compressed() {
+  let mut store_config = test_store_config(true);
+  store_config.capnp_payload = true;
+  let secrets_store = open_secrets_store(&store_config, Arc::new(TestEventHub), None).unwrap();
+
+  common_secrets_store_tests(secrets_store)
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_attachment_chunks() {
+  let block_store = open_block_store("memory://", "node1").unwrap();
+  let secrets_store = MultiLaneSecretsStore::new(
+    &test_store_config(false),
+    block_store.clone(),
+    Arc::new(TestEventHub),
+    None,
+  );
+  let recipients = vec!["identity1".to_string()];
+  let full_chunk = vec![1u8; ATTACHMENT_CHUNK_SIZE];
+
+  add_identity(&secrets_store, "identity1", "Name1", "Email1", "Passphrase1").unwrap();
+  secrets_store
+    .unlock("identity1", secret_from_str("Passphrase1"))
+    .unwrap();
+
+  // The size of an upload is limited by the store, not by the client
+  assert_that(&secrets_store.add_attachment_chunk(&recipients, &vec![0u8; ATTACHMENT_CHUNK_SIZE + 1]))
+    .is_err_containing(SecretStoreError::AttachmentTooLarge(ATTACHMENT_CHUNK_SIZE as u64));
+  for _ in 0..MAX_ATTACHMENT_SIZE as usize / ATTACHMENT_CHUNK_SIZE {
+    assert_that(&secrets_store.add_attachment_chunk(&recipients, &full_chunk)).is_ok();
+  }
+  assert_that(&secrets_store.add_attachment_chunk(&recipients, b"one more"))
+    .is_err_containing(SecretStoreError::AttachmentTooLarge(MAX_ATTACHMENT_SIZE));
+
+  let attachment = secrets_store
+    .put_attachment(
+      "data.bin",
+      "application/octet-stream",
+      &recipients,
+      &mut &full_chunk[..],
+    )
+    .unwrap();
+  let mut version = SecretVersion::new("secret1", SecretType::Note, "Attachment", Utc::now().into());
+
+  // The declared size has to match the stored chunks
+  version.attachments = vec![SecretAttachment::stored(
+    "data.bin".to_string(),
+    "application/octet-stream".to_string(),
+    attachment.content_hash().to_string(),
+    10,
+    attachment.chunks().to_vec(),
+  )];
+  assert_that(&secrets_store.add(version.clone()))
+    .is_err_containing(SecretStoreError::InvalidAttachment("data.bin".to_string()));
+
+  version.attachments = vec![attachment.clone()];
+  let block_id = secrets_store.add(version.clone()).unwrap();
+
+  // Blocks that are not chunks of an attachment can not be referenced or read as chunks
+  version.attachments = vec![SecretAttachment::stored(
+    "version.bin".to_string(),
+    "application/octet-stream".to_string(),
+    attachment.content_hash().to_string(),
+    attachment.size(),
+    vec![block_id.clone()],
+  )];
+  assert_that(&secrets_store.add(version))
+    .is_err_containing(SecretStoreError::InvalidAttachment("version.bin".to_string()));
+  assert_that(&secrets_store.get_attachment_chunk(&block_id).map(|_| ())).is_err_containing(SecretStoreError::NotFound);
+  assert_that(&secrets_store.get_attachment_chunk(&attachment.chunks()[0]).map(|_| ())).is_ok();
+
+  // Chunks that are not part of a version are removed on lock
+  let unused = secrets_store.add_attachment_chunk(&recipients, b"unused").unwrap();
+
+  assert_that(&secrets_store.lock()).is_ok();
+  assert_that(&block_store.get_block(&unused).map(|_| ())).is_err_containing(StoreError::InvalidBlock(unused.clone()));
+  assert_that(&block_store.get_block(&attachment.chunks()[0]).map(|_| ())).is_ok();
+}
+
+fn payload_version(block_store: &dyn BlockStore, block_id: &str) -> u8 {
+  let block_words = block_store.get_block(block_id).unwrap();
+  let mut raw: &[u8] = &block_words;
+  let reader = capnp::serialize::read_message_from_flat_slice(&mut raw, Default::default()).unwrap();
+
+  reader.get_root::<block::Reader>().unwrap().get_payload_version()
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_migrate_versions() {
+  let block_store = open_block_store("memory://", "node1").unwrap();
+  let json_store = MultiLaneSecretsStore::new(
+    &test_store_config(false),
+    block_store.clone(),
     Arc::new(TestEventHub),
+    None,
+  );
+  let mut store_config = test_store_config(false);
+  store_config.capnp_payload = true;
+  let capnp_store = MultiLaneSecretsStore::new(&store_config, block_store.clone(), Arc::new(TestEventHub), None);
+
+  add_identity(&json_store, "identity1", "Name1", "Email1", "Passphrase1").unwrap();
+  json_store.unlock("identity1", secret_from_str("Passphrase1")).unwrap();
+
+  let mut version = SecretVersion::new(
+    "secret1",
+    SecretType::Login,
+    "Legacy secret",
+    (Utc::now() - Duration::days(1)).into(),
+  );
+  version.tags = vec!["old".to_string()];
+  version.properties.set("username", "someone");
+  let json_block_id = json_store.add(version.clone()).unwrap();
+
+  // Json is the default format
+  assert_that(&payload_version(block_store.as_ref(), &json_block_id)).is_equal_to(0);
+  assert_that(&json_store.migrate_versions()).is_ok_containing(0);
+  assert_that(&json_store.lock()).is_ok();
+
+  capnp_store.unlock("identity1", secret_from_str("Passphrase1")).unwrap();
+  assert_that(&capnp_store.migrate_versions()).is_ok_containing(1);
+
+  let migrated = capnp_store.get("secret1").unwrap();
+
+  assert_that(&migrated.versions).has_length(2);
+  assert_that(&migrated.current_block_id).is_not_equal_to(&json_block_id);
+  assert_that(&payload_version(block_store.as_ref(), &migrated.current_block_id)).is_equal_to(1);
+  assert_that(&migrated.current.parent_block_id).is_equal_to(Some(json_block_id.clone()));
+  assert_that(&migrated.current.timestamp.timestamp_millis()).is_equal_to(version.timestamp.timestamp_millis());
+  assert_that(&migrated.current.name).is_equal_to(&version.name);
+  assert_that(&migrated.current.tags).is_equal_to(&version.tags);
+  assert_that(&migrated.current.properties.get("username")).is_equal_to(Some(&"someone".to_string()));
+  assert_that(&migrated.competing_versions).is_empty();
+  assert_that(
+    &capnp_store
+      .get_version(&json_block_id)
+      .map(|legacy| legacy.name.clone()),
+  )
+  .is_ok_containing(version.name.clone());
+  assert_that(&capnp_store.migrate_versions()).is_ok_containing(0);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_schema_changes_of_nodes() {
+  let tempdir = TempDir::new("t-rust-less-test").unwrap();
+  let mut store_config = test_store_config(false);
+  store_config.store_url = format!("multilane+file://{}", tempdir.path().to_string_lossy());
+  let node1 = open_secrets_store(&store_config, Arc::new(TestEventHub), None).unwrap();
+  store_config.client_id = "node2".to_string();
+  let node2 = open_secrets_store(&store_config, Arc::new(TestEventHub), None).unwrap();
+  let schema = SecretTypeSchema {
+    id: "database".to_string(),
+    name: "Database".to_string(),
+    fields: vec![SchemaField {
+      property: "host".to_string(),
+      kind: FieldKind::Text,
+      required: true,
+    }],
+  };
+  let mut renamed = schema.clone();
+  renamed.name = "Database server".to_string();
+
+  add_identity(node1.as_ref(), "identity1", "Name1", "Email1", "Passphrase1").unwrap();
+  node1.unlock("identity1", secret_from_str("Passphrase1")).unwrap();
+  node2.unlock("identity1", secret_from_str("Passphrase1")).unwrap();
+
+  // Both nodes change the same schema, the latest change wins on both of them
+  assert_that(&node2.store_schema(schema)).is_ok();
+  std::thread::sleep(std::time::Duration::from_millis(10));
+  assert_that(&node1.store_schema(renamed.clone())).is_ok();
+  assert_that(&node1.update_index()).is_ok();
+  assert_that(&node2.update_index()).is_ok();
+  assert_that(&node1.list_schemas()).is_ok_containing(vec![renamed.clone()]);
+  assert_that(&node2.list_schemas()).is_ok_containing(vec![renamed]);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_copy_secret() {
+  let source = open_secrets_store(&test_store_config(false), Arc::new(TestEventHub), None).unwrap();
+  let target = open_secrets_store(&test_store_config(true), Arc::new(TestEventHub), None).unwrap();
+
+  add_identity(source.as_ref(), "personal", "Name", "Email", "Passphrase1").unwrap();
+  add_identity(target.as_ref(), "team", "Name", "Email", "Passphrase2").unwrap();
+  source.unlock("personal", secret_from_str("Passphrase1")).unwrap();
+  target.unlock("team", secret_from_str("Passphrase2")).unwrap();
+
+  let mut version = SecretVersion {
+    secret_id: "shared".to_string(),
+    secret_type: SecretType::Login,
+    timestamp: Utc::now().into(),
+    name: "Shared secret".to_string(),
+    tags: vec![],
+    urls: vec![],
+    properties: Default::default(),
+    attachments: vec![SecretAttachment::new(
+      "config.txt".to_string(),
+      "text/plain".to_string(),
+      b"some config".to_vec(),
+    )],
+    deleted: false,
+    recipients: vec![],
+    parent_block_id: None,
+    merged_block_ids: vec![],
+    expires_at: None,
+    rotate_every: None,
+    custom_type: None,
+  };
+  let first_block_id = source.add(version.clone()).unwrap();
+  version.timestamp = (Utc::now() + Duration::seconds(1)).into();
+  version.name = "Shared secret (renamed)".to_string();
+  version.parent_block_id = Some(first_block_id);
+  source.add(version).unwrap();
+  source.update_index().unwrap();
+
+  assert_that(&copy_secret(source.as_ref(), target.as_ref(), "shared", false)).is_ok();
+  assert_that(&target.get("shared").unwrap().versions).has_length(1);
+  assert_that(&copy_secret(source.as_ref(), target.as_ref(), "shared", true))
+    .is_err_containing(SecretStoreError::Conflict);
+
+  let target = open_secrets_store(&test_store_config(false), Arc::new(TestEventHub), None).unwrap();
+
+  add_identity(target.as_ref(), "team", "Name", "Email", "Passphrase2").unwrap();
+  add_identity(target.as_ref(), "ops", "Name", "Email", "Passphrase3").unwrap();
+  target.unlock("team", secret_from_str("Passphrase2")).unwrap();
+
+  let block_id = copy_secret(source.as_ref(), target.as_ref(), "shared", true).unwrap();
+  let copy = target.get("shared").unwrap();
+
+  assert_that(&copy.current_block_id).is_equal_to(&block_id);
+  assert_that(&copy.versions).has_length(2);
+  assert_that(&copy.current.name).is_equal_to("Shared secret (renamed)".to_string());
+  let mut recipients = copy.current.recipients.clone();
+  recipients.sort();
+  assert_that(&recipients).is_equal_to(vec!["ops".to_string(), "team".to_string()]);
+
+  let mut content = Vec::new();
+
+  target
+    .get_attachment(&copy.current.attachments[0], &mut content)
+    .unwrap();
+  assert_that(&content).is_equal_to(b"some config".to_vec());
+
+  target.lock().unwrap();
+  target.unlock("ops", secret_from_str("Passphrase3")).unwrap();
+
+  let mut content = Vec::new();
+
+  assert_that(&target.get("shared").map(|secret| secret.current.name.clone()))
+    .is_ok_containing("Shared secret (renamed)".to_string());
+  target
+    .get_attachment(&copy.current.attachments[0], &mut content)
+    .unwrap();
+  assert_that(&content).is_equal_to(b"some config".to_vec());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_reject_breached_passwords() {
+  let dir = TempDir::new("t-rust-less-breach").unwrap();
+  let breach_file = dir.path().join("pwned.txt");
+  fs::write(
+    &breach_file,
+    format!("{}:3\n", HEXUPPER.encode(&Sha1::digest(b"breached"))),
   )
   .unwrap();
+  let breach_check = BreachCheck {
+    file: breach_file,
+    reject: true,
+  };
+  let secrets_store =
+    open_secrets_store(&test_store_config(false), Arc::new(TestEventHub), Some(breach_check)).unwrap();
 
-  common_secrets_store_tests(secrets_store)
+  add_identity(secrets_store.as_ref(), "identity1", "Name1", "Email1", "Passphrase1").unwrap();
+  secrets_store
+    .unlock("identity1", secret_from_str("Passphrase1"))
+    .unwrap();
+
+  let login = |password: &str, age_days: i64| {
+    let mut version = SecretVersion::new(
+      "imported",
+      SecretType::Login,
+      "Imported",
+      (Utc::now() - Duration::days(age_days)).into(),
+    );
+    version.properties.set(PROPERTY_PASSWORD, password);
+    version
+  };
+
+  assert_that(&secrets_store.add(login("breached", 0)))
+    .is_err_containing(SecretStoreError::BreachedPassword(PROPERTY_PASSWORD.to_string()));
+
+  // An import adds the current version first, the history after the index has been updated
+  assert_that(&secrets_store.add(login("safe", 1)).map(|_| ())).is_ok();
+  secrets_store.update_index().unwrap();
+  assert_that(&secrets_store.add(login("breached", 2)).map(|_| ())).is_ok();
+  assert_that(&secrets_store.add(login("breached", 0)))
+    .is_err_containing(SecretStoreError::BreachedPassword(PROPERTY_PASSWORD.to_string()));
+  secrets_store.update_index().unwrap();
+
+  let secret = secrets_store.get("imported").unwrap();
+
+  assert_that(&secret.versions).has_length(2);
+  assert_that(&secret.current.properties.get(PROPERTY_PASSWORD)).is_equal_to(Some(&"safe".to_string()));
 }
@@ -58,6 +58,35 @@ impl ::capnp::traits::HasTypeId for KeyType {
   }
 }
 
+#[repr(u16)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum ContentFormat {
+  Plain = 0,
+  Deflate = 1,
+}
+impl ::capnp::traits::FromU16 for ContentFormat {
+  #[inline]
+  fn from_u16(value: u16) -> ::core::result::Result<ContentFormat, ::capnp::NotInSchema> {
+    match value {
+      0 => ::core::result::Result::Ok(ContentFormat::Plain),
+      1 => ::core::result::Result::Ok(ContentFormat::Deflate),
+      n => ::core::result::Result::Err(::capnp::NotInSchema(n)),
+    }
+  }
+}
+impl ::capnp::traits::ToU16 for ContentFormat {
+  #[inline]
+  fn to_u16(self) -> u16 {
+    self as u16
+  }
+}
+impl ::capnp::traits::HasTypeId for ContentFormat {
+  #[inline]
+  fn type_id() -> u64 {
+    0xd2e4_7c1b_58a3_906fu64
+  }
+}
+
 pub mod ring {
   #[derive(Copy, Clone)]
   pub struct Owned;
@@ -901,6 +930,14 @@ pub mod block {
     pub fn has_content(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
+    #[inline]
+    pub fn get_format(self) -> ::core::result::Result<crate::secrets_store_capnp::ContentFormat, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.reader.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn get_payload_version(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
   }
 
   pub struct Builder<'a> {
@@ -1014,6 +1051,22 @@ pub mod block {
     pub fn has_content(&self) -> bool {
       !self.builder.get_pointer_field(1).is_null()
     }
+    #[inline]
+    pub fn get_format(self) -> ::core::result::Result<crate::secrets_store_capnp::ContentFormat, ::capnp::NotInSchema> {
+      ::capnp::traits::FromU16::from_u16(self.builder.get_data_field::<u16>(0))
+    }
+    #[inline]
+    pub fn set_format(&mut self, value: crate::secrets_store_capnp::ContentFormat) {
+      self.builder.set_data_field::<u16>(0, value as u16)
+    }
+    #[inline]
+    pub fn get_payload_version(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_payload_version(&mut self, value: u8) {
+      self.builder.set_data_field::<u8>(2, value);
+    }
   }
 
   pub struct Pipeline {
@@ -1027,7 +1080,7 @@ pub mod block {
   impl Pipeline {}
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 2 };
     pub const TYPE_ID: u64 = 0x8d3d_18b2_169e_f291;
   }
 
@@ -1468,6 +1521,212 @@ pub mod block {
   }
 }
 
+pub mod indexed_text {
+  #[derive(Copy, Clone)]
+  pub struct Owned;
+  impl<'a> ::capnp::traits::Owned<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl<'a> ::capnp::traits::OwnedStruct<'a> for Owned {
+    type Reader = Reader<'a>;
+    type Builder = Builder<'a>;
+  }
+  impl ::capnp::traits::Pipelined for Owned {
+    type Pipeline = Pipeline;
+  }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> {
+    reader: ::capnp::private::layout::StructReader<'a>,
+  }
+
+  impl<'a> ::capnp::traits::HasTypeId for Reader<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructReader<'a> for Reader<'a> {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a> {
+      Reader { reader }
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerReader<'a> for Reader<'a> {
+    fn get_from_pointer(
+      reader: &::capnp::private::layout::PointerReader<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Reader<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructReader::new(reader.get_struct(default)?))
+    }
+  }
+
+  impl<'a> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a> {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl<'a> ::capnp::traits::Imbue<'a> for Reader<'a> {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self
+        .reader
+        .imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl<'a> Reader<'a> {
+    pub fn reborrow(&self) -> Reader {
+      Reader { ..*self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_property(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_value(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(
+        &self.reader.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    pub fn has_value(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> {
+    builder: ::capnp::private::layout::StructBuilder<'a>,
+  }
+  impl<'a> ::capnp::traits::HasStructSize for Builder<'a> {
+    #[inline]
+    fn struct_size() -> ::capnp::private::layout::StructSize {
+      _private::STRUCT_SIZE
+    }
+  }
+  impl<'a> ::capnp::traits::HasTypeId for Builder<'a> {
+    #[inline]
+    fn type_id() -> u64 {
+      _private::TYPE_ID
+    }
+  }
+  impl<'a> ::capnp::traits::FromStructBuilder<'a> for Builder<'a> {
+    fn new(builder: ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a> {
+      Builder { builder }
+    }
+  }
+
+  impl<'a> ::capnp::traits::ImbueMut<'a> for Builder<'a> {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self
+        .builder
+        .imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl<'a> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a> {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Builder<'a> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(
+      builder: ::capnp::private::layout::PointerBuilder<'a>,
+      default: ::core::option::Option<&'a [capnp::Word]>,
+    ) -> ::capnp::Result<Builder<'a>> {
+      ::core::result::Result::Ok(::capnp::traits::FromStructBuilder::new(
+        builder.get_struct(_private::STRUCT_SIZE, default)?,
+      ))
+    }
+  }
+
+  impl<'a> ::capnp::traits::SetPointerBuilder<Builder<'a>> for Reader<'a> {
+    fn set_pointer_builder<'b>(
+      pointer: ::capnp::private::layout::PointerBuilder<'b>,
+      value: Reader<'a>,
+      canonicalize: bool,
+    ) -> ::capnp::Result<()> {
+      pointer.set_struct(&value.reader, canonicalize)
+    }
+  }
+
+  impl<'a> Builder<'a> {
+    pub fn into_reader(self) -> Reader<'a> {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+    pub fn reborrow(&mut self) -> Builder {
+      Builder { ..*self }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader {
+      ::capnp::traits::FromStructReader::new(self.builder.into_reader())
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.into_reader().total_size()
+    }
+    #[inline]
+    pub fn get_property(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(0),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_property(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(0).set_text(value);
+    }
+    #[inline]
+    pub fn init_property(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(0).init_text(size)
+    }
+    pub fn has_property(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_value(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(
+        self.builder.get_pointer_field(1),
+        ::core::option::Option::None,
+      )
+    }
+    #[inline]
+    pub fn set_value(&mut self, value: ::capnp::text::Reader) {
+      self.builder.get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_value(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    pub fn has_value(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Pipeline {
+    _typeless: ::capnp::any_pointer::Pipeline,
+  }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless: typeless }
+    }
+  }
+  impl Pipeline {}
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const TYPE_ID: u64 = 0xd3c1_eb64_1f25_50b4;
+  }
+}
+
 pub mod index {
   #[derive(Copy, Clone)]
   pub struct Owned;
@@ -1555,6 +1814,10 @@ pub mod index {
     pub fn has_entries(&self) -> bool {
       !self.reader.get_pointer_field(1).is_null()
     }
+    #[inline]
+    pub fn get_full_text(self) -> bool {
+      self.reader.get_bool_field(0)
+    }
   }
 
   pub struct Builder<'a> {
@@ -1676,6 +1939,14 @@ pub mod index {
     pub fn has_entries(&self) -> bool {
       !self.builder.get_pointer_field(1).is_null()
     }
+    #[inline]
+    pub fn get_full_text(self) -> bool {
+      self.builder.get_bool_field(0)
+    }
+    #[inline]
+    pub fn set_full_text(&mut self, value: bool) {
+      self.builder.set_bool_field(0, value);
+    }
   }
 
   pub struct Pipeline {
@@ -1689,7 +1960,7 @@ pub mod index {
   impl Pipeline {}
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+    pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 1, pointers: 2 };
     pub const TYPE_ID: u64 = 0xedcd_7dbb_0bd9_f5b9;
   }
 
@@ -2029,6 +2300,18 @@ pub mod index {
       pub fn has_version_refs(&self) -> bool {
         !self.reader.get_pointer_field(1).is_null()
       }
+      #[inline]
+      pub fn get_texts(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Reader<'a, crate::secrets_store_capnp::indexed_text::Owned>> {
+        ::capnp::traits::FromPointerReader::get_from_pointer(
+          &self.reader.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      pub fn has_texts(&self) -> bool {
+        !self.reader.get_pointer_field(2).is_null()
+      }
     }
 
     pub struct Builder<'a> {
@@ -2142,6 +2425,32 @@ pub mod index {
       pub fn has_version_refs(&self) -> bool {
         !self.builder.get_pointer_field(1).is_null()
       }
+      #[inline]
+      pub fn get_texts(
+        self,
+      ) -> ::capnp::Result<::capnp::struct_list::Builder<'a, crate::secrets_store_capnp::indexed_text::Owned>> {
+        ::capnp::traits::FromPointerBuilder::get_from_pointer(
+          self.builder.get_pointer_field(2),
+          ::core::option::Option::None,
+        )
+      }
+      #[inline]
+      pub fn set_texts(
+        &mut self,
+        value: ::capnp::struct_list::Reader<'a, crate::secrets_store_capnp::indexed_text::Owned>,
+      ) -> ::capnp::Result<()> {
+        ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value, false)
+      }
+      #[inline]
+      pub fn init_texts(
+        self,
+        size: u32,
+      ) -> ::capnp::struct_list::Builder<'a, crate::secrets_store_capnp::indexed_text::Owned> {
+        ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+      }
+      pub fn has_texts(&self) -> bool {
+        !self.builder.get_pointer_field(2).is_null()
+      }
     }
 
     pub struct Pipeline {
@@ -2159,7 +2468,7 @@ pub mod index {
     }
     mod _private {
       use capnp::private::layout;
-      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 2 };
+      pub const STRUCT_SIZE: layout::StructSize = layout::StructSize { data: 0, pointers: 3 };
       pub const TYPE_ID: u64 = 0xfb4e_df40_ef58_a280;
     }
   }
@@ -0,0 +1,376 @@
+use crate::api::{
+  HealthReport, HealthReportParams, Identity, Secret, SecretAttachment, SecretList, SecretListFilter, SecretTypeSchema,
+  SecretVersion, Status,
+};
+use crate::memguard::SecretBytes;
+use crate::pinentry::{PassphraseHelper, PassphrasePrompt};
+use crate::secrets_store::{SecretStoreError, SecretStoreResult, SecretsStore};
+use crate::service::ServiceResult;
+use log::{error, info};
+use std::io::{Read, Write};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const MAX_UNLOCK_ATTEMPTS: usize = 3;
+
+/// Unlock a store with a passphrase collected by a helper program.
+///
+/// The default identity is used if configured, otherwise the first visible identity of the store.
+/// Returns `false` if the store has no identity or the user cancelled.
+pub fn unlock_with_helper(
+  secrets_store: &dyn SecretsStore,
+  store_name: &str,
+  default_identity_id: Option<&str>,
+  helper: &PassphraseHelper,
+) -> ServiceResult<bool> {
+  let identities = secrets_store.identities()?;
+  let identity = match select_identity(&identities, default_identity_id) {
+    Some(identity) => identity,
+    None => return Ok(false),
+  };
+  let mut prompt = unlock_prompt(store_name, identity);
+
+  for _ in 0..MAX_UNLOCK_ATTEMPTS {
+    let passphrase = match helper.read_passphrase(&prompt)? {
+      Some(passphrase) => passphrase,
+      None => return Ok(false),
+    };
+
+    match secrets_store.unlock(&identity.id, passphrase) {
+      Ok(()) => return Ok(true),
+      Err(SecretStoreError::InvalidPassphrase) => prompt.error = Some("Invalid passphrase".to_string()),
+      Err(SecretStoreError::AlreadyUnlocked) => return Ok(true),
+      Err(error) => return Err(error.into()),
+    }
+  }
+
+  Ok(false)
+}
+
+fn unlock_prompt(store_name: &str, identity: &Identity) -> PassphrasePrompt {
+  PassphrasePrompt {
+    title: "t-rust-less".to_string(),
+    description: format!(
+      "Please enter the passphrase to unlock store {}\n\nIdentity: {} <{}>",
+      store_name, identity.name, identity.email
+    ),
+    prompt: "Passphrase:".to_string(),
+    error: None,
+  }
+}
+
+fn select_identity<'a>(identities: &'a [Identity], default_identity_id: Option<&str>) -> Option<&'a Identity> {
+  default_identity_id
+    .and_then(|id| identities.iter().find(|identity| identity.id == id))
+    .or_else(|| identities.iter().find(|identity| !identity.hidden))
+}
+
+/// Wrapper of a secrets store that asks for the passphrase (via pinentry or askpass) as soon as
+/// a locked store is accessed.
+///
+/// The helper runs in a thread of its own, so that the daemon keeps serving other requests in the
+/// meantime. Until the passphrase is entered the store stays locked, i.e. the access that triggered
+/// the prompt (and every access while it is pending) fails with `Locked`. The collected passphrase
+/// is used on the next access of the store, which includes polling its status.
+#[derive(Debug)]
+pub struct AutoUnlockSecretsStore {
+  name: String,
+  default_identity_id: Option<String>,
+  helper: PassphraseHelper,
+  inner: Arc<dyn SecretsStore>,
+  // Only one prompt at a time
+  pending: Mutex<Option<PendingUnlock>>,
+}
+
+/// Prompt running in its own thread.
+#[derive(Debug)]
+struct PendingUnlock {
+  identity_id: String,
+  /// Passphrases entered by the user, `None` if the user cancelled
+  passphrases: Receiver<Option<SecretBytes>>,
+  /// Errors to display when asking again
+  retries: Sender<String>,
+}
+
+impl AutoUnlockSecretsStore {
+  pub fn new(
+    name: &str,
+    default_identity_id: Option<String>,
+    helper: PassphraseHelper,
+    inner: Arc<dyn SecretsStore>,
+  ) -> AutoUnlockSecretsStore {
+    AutoUnlockSecretsStore {
+      name: name.to_string(),
+      default_identity_id,
+      helper,
+      inner,
+      pending: Mutex::new(None),
+    }
+  }
+
+  fn ensure_unlocked(&self) -> SecretStoreResult<()> {
+    let mut pending = self.pending.lock()?;
+
+    self.poll_prompt(&mut pending)?;
+    if !self.inner.status()?.locked {
+      // Not interested in the outcome of the prompt any more
+      *pending = None;
+      return Ok(());
+    }
+    if pending.is_none() {
+      *pending = self.start_prompt()?;
+    }
+
+    Err(SecretStoreError::Locked)
+  }
+
+  fn start_prompt(&self) -> SecretStoreResult<Option<PendingUnlock>> {
+    let identities = self.inner.identities()?;
+    let identity = match select_identity(&identities, self.default_identity_id.as_deref()) {
+      Some(identity) => identity,
+      None => return Ok(None),
+    };
+    let (passphrase_sender, passphrases) = mpsc::channel();
+    let (retries, retry_receiver) = mpsc::channel::<String>();
+    let name = self.name.clone();
+    let helper = self.helper.clone();
+    let mut prompt = unlock_prompt(&self.name, identity);
+
+    info!("Store {} is locked, asking for passphrase", self.name);
+    thread::spawn(move || {
+      for _ in 0..MAX_UNLOCK_ATTEMPTS {
+        let passphrase = match helper.read_passphrase(&prompt) {
+          Ok(passphrase) => passphrase,
+          Err(error) => {
+            error!("Unlock of store {} via passphrase helper failed: {}", name, error);
+            None
+          }
+        };
+        let cancelled = passphrase.is_none();
+
+        if passphrase_sender.send(passphrase).is_err() || cancelled {
+          return;
+        }
+        // Only asked again if the passphrase was invalid
+        match retry_receiver.recv() {
+          Ok(error) => prompt.error = Some(error),
+          Err(_) => return,
+        }
+      }
+    });
+
+    Ok(Some(PendingUnlock {
+      identity_id: identity.id.clone(),
+      passphrases,
+      retries,
+    }))
+  }
+
+  /// Unlock with the passphrase of a pending prompt, if the user has entered one in the meantime.
+  fn poll_prompt(&self, pending: &mut Option<PendingUnlock>) -> SecretStoreResult<()> {
+    let unlock = match pending.take() {
+      Some(unlock) => unlock,
+      None => return Ok(()),
+    };
+
+    match unlock.passphrases.try_recv() {
+      Ok(Some(passphrase)) => match self.inner.unlock(&unlock.identity_id, passphrase) {
+        Ok(()) | Err(SecretStoreError::AlreadyUnlocked) => (),
+        Err(SecretStoreError::InvalidPassphrase) => {
+          if unlock.retries.send("Invalid passphrase".to_string()).is_ok() {
+            *pending = Some(unlock);
+          }
+        }
+        Err(error) => return Err(error),
+      },
+      Ok(None) | Err(TryRecvError::Disconnected) => info!("Unlock of store {} cancelled", self.name),
+      Err(TryRecvError::Empty) => *pending = Some(unlock),
+    }
+
+    Ok(())
+  }
+}
+
+impl SecretsStore for AutoUnlockSecretsStore {
+  fn status(&self) -> SecretStoreResult<Status> {
+    self.poll_prompt(&mut *self.pending.lock()?)?;
+    self.inner.status()
+  }
+
+  fn lock(&self) -> SecretStoreResult<()> {
+    self.inner.lock()
+  }
+
+  fn unlock(&self, identity_id: &str, passphrase: SecretBytes) -> SecretStoreResult<()> {
+    self.inner.unlock(identity_id, passphrase)
+  }
+
+  fn identities(&self) -> SecretStoreResult<Vec<Identity>> {
+    self.inner.identities()
+  }
+
+  fn add_identity(&self, identity: Identity, passphrase: SecretBytes) -> SecretStoreResult<()> {
+    self.inner.add_identity(identity, passphrase)
+  }
+
+  fn change_passphrase(&self, passphrase: SecretBytes) -> SecretStoreResult<()> {
+    self.ensure_unlocked()?;
+    self.inner.change_passphrase(passphrase)
+  }
+
+  fn list(&self, filter: &SecretListFilter) -> SecretStoreResult<SecretList> {
+    self.ensure_unlocked()?;
+    self.inner.list(filter)
+  }
+
+  fn update_index(&self) -> SecretStoreResult<()> {
+    self.ensure_unlocked()?;
+    self.inner.update_index()
+  }
+
+  fn migrate_versions(&self) -> SecretStoreResult<usize> {
+    self.ensure_unlocked()?;
+    self.inner.migrate_versions()
+  }
+
+  fn add(&self, secret_version: SecretVersion) -> SecretStoreResult<String> {
+    self.ensure_unlocked()?;
+    self.inner.add(secret_version)
+  }
+
+  fn get(&self, secret_id: &str) -> SecretStoreResult<Secret> {
+    self.ensure_unlocked()?;
+    self.inner.get(secret_id)
+  }
+
+  fn get_version(&self, block_id: &str) -> SecretStoreResult<SecretVersion> {
+    self.ensure_unlocked()?;
+    self.inner.get_version(block_id)
+  }
+
+  fn health_report(&self, params: &HealthReportParams) -> SecretStoreResult<HealthReport> {
+    self.ensure_unlocked()?;
+    self.inner.health_report(params)
+  }
+
+  fn list_schemas(&self) -> SecretStoreResult<Vec<SecretTypeSchema>> {
+    self.ensure_unlocked()?;
+    self.inner.list_schemas()
+  }
+
+  fn store_schema(&self, schema: SecretTypeSchema) -> SecretStoreResult<()> {
+    self.ensure_unlocked()?;
+    self.inner.store_schema(schema)
+  }
+
+  fn add_attachment_chunk(&self, recipients: &[String], chunk: &[u8]) -> SecretStoreResult<String> {
+    self.ensure_unlocked()?;
+    self.inner.add_attachment_chunk(recipients, chunk)
+  }
+
+  fn get_attachment_chunk(&self, block_id: &str) -> SecretStoreResult<SecretBytes> {
+    self.ensure_unlocked()?;
+    self.inner.get_attachment_chunk(block_id)
+  }
+
+  fn put_attachment(
+    &self,
+    name: &str,
+    mime_type: &str,
+    recipients: &[String],
+    content: &mut dyn Read,
+  ) -> SecretStoreResult<SecretAttachment> {
+    self.ensure_unlocked()?;
+    self.inner.put_attachment(name, mime_type, recipients, content)
+  }
+
+  fn get_attachment(&self, attachment: &SecretAttachment, out: &mut dyn Write) -> SecretStoreResult<()> {
+    self.ensure_unlocked()?;
+    self.inner.get_attachment(attachment, out)
+  }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+  use super::*;
+  use crate::api::{Event, EventHub};
+  use crate::secrets_store::open_secrets_store;
+  use crate::service::StoreConfig;
+  use spectral::prelude::*;
+  use std::fs;
+  use std::time::{Duration, Instant};
+  use tempdir::TempDir;
+
+  // Does not answer GETPIN before the `entered` file shows up
+  const WAITING_PINENTRY: &str = r#"
+echo "OK Pleased to meet you"
+while read -r cmd arg; do
+  case "$cmd" in
+    GETPIN)
+      while [ ! -f "$(dirname "$0")/entered" ]; do sleep 0.01; done
+      echo "D Passphrase1"; echo "OK" ;;
+    BYE) echo "OK closing connection"; exit 0 ;;
+    *) echo "OK" ;;
+  esac
+done
+"#;
+
+  struct TestEventHub;
+
+  impl EventHub for TestEventHub {
+    fn send(&self, _event: Event) {}
+  }
+
+  #[test]
+  #[cfg_attr(debug_assertions, ignore)]
+  fn test_access_while_prompt_pending() {
+    let dir = TempDir::new("t-rust-less-auto-unlock").unwrap();
+    let script = dir.path().join("pinentry.sh");
+    fs::write(&script, WAITING_PINENTRY).unwrap();
+    let helper = PassphraseHelper::Pinentry {
+      program: "/bin/sh".to_string(),
+      args: vec![script.to_string_lossy().to_string()],
+    };
+    let store_config = StoreConfig {
+      name: "test".to_string(),
+      store_url: "multilane+memory://".to_string(),
+      client_id: "node1".to_string(),
+      autolock_timeout_secs: 300,
+      default_identity_id: None,
+      full_text_index: false,
+      max_attachment_size: None,
+      compress_blocks: false,
+      capnp_payload: false,
+    };
+    let inner = open_secrets_store(&store_config, Arc::new(TestEventHub), None).unwrap();
+    let identity = Identity {
+      id: "identity1".to_string(),
+      name: "Name1".to_string(),
+      email: "Email1".to_string(),
+      hidden: false,
+    };
+    inner
+      .add_identity(identity, SecretBytes::from(b"Passphrase1".to_vec()))
+      .unwrap();
+    let secrets_store = AutoUnlockSecretsStore::new("test", None, helper, inner);
+    let filter = SecretListFilter::default();
+
+    // Starts the prompt
+    assert_that(&secrets_store.list(&filter).map(|_| ())).is_err_containing(SecretStoreError::Locked);
+    // Further requests are answered right away, while the prompt is still waiting for the user
+    assert_that(&secrets_store.list(&filter).map(|_| ())).is_err_containing(SecretStoreError::Locked);
+    assert_that(&secrets_store.status().unwrap().locked).is_true();
+    assert_that(&secrets_store.pending.lock().unwrap().is_some()).is_true();
+
+    fs::write(dir.path().join("entered"), "").unwrap();
+
+    let start = Instant::now();
+    while secrets_store.status().unwrap().locked {
+      assert_that(&(start.elapsed() < Duration::from_secs(10))).is_true();
+      thread::sleep(Duration::from_millis(10));
+    }
+    assert_that(&secrets_store.list(&filter)).is_ok();
+    assert_that(&secrets_store.pending.lock().unwrap().is_none()).is_true();
+  }
+}
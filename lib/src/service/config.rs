@@ -1,13 +1,23 @@
-use crate::service::ServiceResult;
+use crate::pinentry::PassphraseHelper;
+use crate::secrets_store::BreachCheck;
+use crate::service::{ServiceError, ServiceResult};
 use crate::{api::read_option, api_capnp::store_config};
+use log::error;
 use serde_derive::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::fs::{self, File};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use url::Url;
 use zeroize::Zeroize;
 
-#[derive(Clone, Debug, Serialize, Deserialize, Zeroize)]
+/// Maximum time to wait for another client to finish its change of the configuration.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Zeroize)]
 #[zeroize(drop)]
 pub struct StoreConfig {
   pub name: String,
@@ -15,6 +25,18 @@ pub struct StoreConfig {
   pub client_id: String,
   pub autolock_timeout_secs: u64,
   pub default_identity_id: Option<String>,
+  /// Maintain an (encrypted) full-text index of all non-secret properties
+  #[serde(default)]
+  pub full_text_index: bool,
+  /// Maximum size of an attachment in bytes (unlimited if not set)
+  #[serde(default)]
+  pub max_attachment_size: Option<u64>,
+  /// Compress secrets and index before encryption (blocks written before remain readable)
+  #[serde(default)]
+  pub compress_blocks: bool,
+  /// Encode secrets as capnp message instead of json (not readable by older versions)
+  #[serde(default)]
+  pub capnp_payload: bool,
 }
 
 impl StoreConfig {
@@ -25,6 +47,10 @@ impl StoreConfig {
       client_id: reader.get_client_id()?.to_string(),
       autolock_timeout_secs: reader.get_autolock_timeout_secs(),
       default_identity_id: read_option(reader.get_default_identity_id()?)?.map(ToString::to_string),
+      full_text_index: reader.get_full_text_index(),
+      max_attachment_size: Some(reader.get_max_attachment_size()).filter(|size| *size > 0),
+      compress_blocks: reader.get_compress_blocks(),
+      capnp_payload: reader.get_capnp_payload(),
     })
   }
 
@@ -33,6 +59,10 @@ impl StoreConfig {
     builder.set_store_url(&self.store_url);
     builder.set_client_id(&self.client_id);
     builder.set_autolock_timeout_secs(self.autolock_timeout_secs);
+    builder.set_full_text_index(self.full_text_index);
+    builder.set_max_attachment_size(self.max_attachment_size.unwrap_or(0));
+    builder.set_compress_blocks(self.compress_blocks);
+    builder.set_capnp_payload(self.capnp_payload);
     match &self.default_identity_id {
       Some(default_identity_id) => builder
         .reborrow()
@@ -43,12 +73,103 @@ impl StoreConfig {
 
     Ok(())
   }
+
+  pub fn validate(&self) -> ServiceResult<()> {
+    if self.name.is_empty() {
+      return Err(ServiceError::InvalidConfig("Store without name".to_string()));
+    }
+    // The store url is <secrets store scheme>+<block store url> (e.g. multilane+file:///...)
+    let block_store_url = match self.store_url.find('+') {
+      Some(idx) => &self.store_url[idx + 1..],
+      None => {
+        return Err(ServiceError::InvalidConfig(format!(
+          "Store {} has invalid url {}",
+          self.name, self.store_url
+        )))
+      }
+    };
+    if let Err(error) = Url::parse(block_store_url) {
+      return Err(ServiceError::InvalidConfig(format!(
+        "Store {} has invalid url {}: {}",
+        self.name, self.store_url, error
+      )));
+    }
+    if self.client_id.is_empty() {
+      return Err(ServiceError::InvalidConfig(format!(
+        "Store {} has no client id",
+        self.name
+      )));
+    }
+    if self.autolock_timeout_secs == 0 {
+      return Err(ServiceError::InvalidConfig(format!(
+        "Store {} has no autolock timeout",
+        self.name
+      )));
+    }
+
+    Ok(())
+  }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Config {
   pub default_store: Option<String>,
+  /// Start the daemon if it is not running yet (instead of falling back to a service within the client)
+  #[serde(default)]
+  pub spawn_daemon: bool,
   pub stores: HashMap<String, StoreConfig>,
+  /// Helper to ask for the passphrase when a locked store is accessed
+  #[serde(default)]
+  pub passphrase_helper: Option<PassphraseHelper>,
+  /// Offline check of passwords against a local copy of the Pwned Passwords list
+  #[serde(default)]
+  pub breach_check: Option<BreachCheck>,
+}
+
+impl Config {
+  pub fn validate(&self) -> ServiceResult<()> {
+    let mut client_ids = HashSet::new();
+
+    for (name, store_config) in self.stores.iter() {
+      validate_store(name, store_config, &mut client_ids)?;
+    }
+
+    Ok(())
+  }
+
+  /// Remove (and log) all stores that do not pass validation, e.g. if the file has been edited by hand.
+  pub fn retain_valid_stores(&mut self) {
+    let mut client_ids = HashSet::new();
+    let mut names: Vec<String> = self.stores.keys().cloned().collect();
+
+    // Of two stores sharing a client id the first one is kept
+    names.sort();
+    for name in names {
+      if let Err(error) = validate_store(&name, &self.stores[&name], &mut client_ids) {
+        error!("Ignoring store {}: {}", name, error);
+        self.stores.remove(&name);
+      }
+    }
+  }
+}
+
+fn validate_store(name: &str, store_config: &StoreConfig, client_ids: &mut HashSet<String>) -> ServiceResult<()> {
+  if name != store_config.name {
+    return Err(ServiceError::InvalidConfig(format!(
+      "Store {} is configured with name {}",
+      name, store_config.name
+    )));
+  }
+  store_config.validate()?;
+  // Each client writes its own lane, two stores sharing a client id would mess up each other
+  if !client_ids.insert(store_config.client_id.clone()) {
+    return Err(ServiceError::InvalidConfig(format!(
+      "Client id {} is used by more than one store",
+      store_config.client_id
+    )));
+  }
+
+  Ok(())
 }
 
 pub fn config_file() -> PathBuf {
@@ -59,9 +180,21 @@ pub fn config_file() -> PathBuf {
     .join("config.toml")
 }
 
+/// Last modification of the configuration file (if there is one).
+pub fn config_modified() -> Option<SystemTime> {
+  file_modified(&config_file())
+}
+
+fn file_modified(file: &Path) -> Option<SystemTime> {
+  fs::metadata(file).and_then(|metadata| metadata.modified()).ok()
+}
+
 pub fn read_config() -> ServiceResult<Option<Config>> {
-  let config_file = config_file();
-  match File::open(&config_file) {
+  read_config_file(&config_file())
+}
+
+fn read_config_file(config_file: &Path) -> ServiceResult<Option<Config>> {
+  match File::open(config_file) {
     Ok(mut index_file) => {
       let mut content = vec![];
 
@@ -74,15 +207,302 @@ pub fn read_config() -> ServiceResult<Option<Config>> {
   }
 }
 
-pub fn write_config(config: &Config) -> io::Result<()> {
+/// Modify the configuration file.
+///
+/// The file is re-read while holding the lock file, so that changes of other clients are not lost.
+/// The result is the validated configuration as written and the new modification time of the file.
+pub fn update_config<F>(update: F) -> ServiceResult<(Config, Option<SystemTime>)>
+where
+  F: FnOnce(&mut Config) -> ServiceResult<()>,
+{
+  update_config_file(&config_file(), update)
+}
+
+fn update_config_file<F>(config_file: &Path, update: F) -> ServiceResult<(Config, Option<SystemTime>)>
+where
+  F: FnOnce(&mut Config) -> ServiceResult<()>,
+{
+  fs::create_dir_all(config_file.parent().unwrap())?;
+
+  let _lock = ConfigLock::acquire(&config_file.with_extension("toml.lock"))?;
+  let mut config = read_config_file(config_file)?.unwrap_or_default();
+
+  update(&mut config)?;
+  config.validate()?;
+  write_config(&config, config_file)?;
+
+  Ok((config, file_modified(config_file)))
+}
+
+/// Replace the configuration file by writing a temporary file first, so that readers never
+/// see a partially written configuration.
+fn write_config(config: &Config, config_file: &Path) -> io::Result<()> {
   let content = toml::to_string_pretty(config).unwrap();
-  let config_file = config_file();
+  let tmp_file = config_file.with_extension("toml.tmp");
 
-  fs::create_dir_all(&config_file.parent().unwrap())?;
+  {
+    let mut file = File::create(&tmp_file)?;
 
-  let mut file = File::create(&config_file)?;
+    file.write_all(content.as_bytes())?;
+    file.sync_all()?;
+  }
 
-  file.write_all(content.as_bytes())?;
+  fs::rename(&tmp_file, config_file)
+}
 
-  Ok(())
+/// Exclusive access to the configuration file (as long as it is not dropped).
+///
+/// The lock is held by the operating system, i.e. it is released even if the holder crashes.
+struct ConfigLock {
+  _file: File,
+}
+
+impl ConfigLock {
+  fn acquire(path: &Path) -> io::Result<ConfigLock> {
+    let start = Instant::now();
+
+    loop {
+      match Self::try_acquire(path)? {
+        Some(lock) => return Ok(lock),
+        None if start.elapsed() < LOCK_TIMEOUT => thread::sleep(LOCK_RETRY_INTERVAL),
+        None => {
+          return Err(io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("{} is locked by another process", path.to_string_lossy()),
+          ))
+        }
+      }
+    }
+  }
+
+  #[cfg(unix)]
+  fn try_acquire(path: &Path) -> io::Result<Option<ConfigLock>> {
+    use std::os::unix::io::AsRawFd;
+
+    let file = OpenOptions::new().write(true).create(true).truncate(false).open(path)?;
+
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } < 0 {
+      let error = io::Error::last_os_error();
+
+      if error.kind() == io::ErrorKind::WouldBlock {
+        return Ok(None);
+      }
+      return Err(error);
+    }
+
+    Ok(Some(ConfigLock { _file: file }))
+  }
+
+  #[cfg(windows)]
+  fn try_acquire(path: &Path) -> io::Result<Option<ConfigLock>> {
+    use std::os::windows::fs::OpenOptionsExt;
+
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+
+    // A file opened without sharing can not be opened again until it is closed
+    match OpenOptions::new()
+      .write(true)
+      .create(true)
+      .truncate(false)
+      .share_mode(0)
+      .open(path)
+    {
+      Ok(file) => Ok(Some(ConfigLock { _file: file })),
+      Err(error) if error.raw_os_error() == Some(ERROR_SHARING_VIOLATION) => Ok(None),
+      Err(error) => Err(error),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use std::sync::mpsc;
+  use tempdir::TempDir;
+
+  fn store_config(name: &str, client_id: &str) -> StoreConfig {
+    StoreConfig {
+      name: name.to_string(),
+      store_url: "multilane+memory://".to_string(),
+      client_id: client_id.to_string(),
+      autolock_timeout_secs: 300,
+      default_identity_id: None,
+      full_text_index: false,
+      max_attachment_size: None,
+      compress_blocks: false,
+      capnp_payload: false,
+    }
+  }
+
+  fn config(store_configs: Vec<StoreConfig>) -> Config {
+    Config {
+      stores: store_configs
+        .into_iter()
+        .map(|store_config| (store_config.name.clone(), store_config))
+        .collect(),
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn test_validate_store_config() {
+    assert_that(&store_config("store", "client").validate()).is_ok();
+
+    let mut no_scheme = store_config("store", "client");
+    no_scheme.store_url = "file:///tmp/store".to_string();
+    assert_that(&no_scheme.validate()).is_err();
+
+    let mut invalid_url = store_config("store", "client");
+    invalid_url.store_url = "multilane+not a url".to_string();
+    assert_that(&invalid_url.validate()).is_err();
+
+    let mut no_autolock = store_config("store", "client");
+    no_autolock.autolock_timeout_secs = 0;
+    assert_that(&no_autolock.validate()).is_err();
+
+    assert_that(&store_config("store", "").validate()).is_err();
+  }
+
+  #[test]
+  fn test_validate_config() {
+    assert_that(&Config::default().validate()).is_ok();
+    assert_that(
+      &config(vec![
+        store_config("store1", "client1"),
+        store_config("store2", "client2"),
+      ])
+      .validate(),
+    )
+    .is_ok();
+    assert_that(
+      &config(vec![
+        store_config("store1", "client1"),
+        store_config("store2", "client1"),
+      ])
+      .validate(),
+    )
+    .is_err();
+
+    let mut misnamed = config(vec![store_config("store1", "client1")]);
+    misnamed.stores.get_mut("store1").unwrap().name = "store2".to_string();
+    assert_that(&misnamed.validate()).is_err();
+  }
+
+  #[test]
+  fn test_retain_valid_stores() {
+    let mut config = config(vec![
+      store_config("store1", "client1"),
+      store_config("store2", "client1"),
+      store_config("store3", "client3"),
+      store_config("store4", ""),
+    ]);
+
+    config.retain_valid_stores();
+
+    let mut names: Vec<&String> = config.stores.keys().collect();
+    names.sort();
+    assert_that(&names).is_equal_to(vec![&"store1".to_string(), &"store3".to_string()]);
+    assert_that(&config.validate()).is_ok();
+  }
+
+  #[test]
+  fn test_update_config() {
+    let tmp_dir = TempDir::new("t-rust-less-config").unwrap();
+    let config_file = tmp_dir.path().join("t-rust-less").join("config.toml");
+
+    assert_that(&read_config_file(&config_file).map(|config| config.is_none())).is_ok_containing(true);
+
+    let (config, modified) = update_config_file(&config_file, |config| {
+      config
+        .stores
+        .insert("store1".to_string(), store_config("store1", "client1"));
+      Ok(())
+    })
+    .unwrap();
+
+    assert_that(&config.stores.contains_key("store1")).is_true();
+    assert_that(&modified).is_equal_to(file_modified(&config_file));
+    assert_that(&config_file.with_extension("toml.tmp").exists()).is_false();
+
+    let content = fs::read(&config_file).unwrap();
+
+    // Neither a failing update nor an invalid result touch the file
+    assert_that(&update_config_file(&config_file, |_| Err(ServiceError::IO("failed".to_string()))).is_err()).is_true();
+    assert_that(
+      &update_config_file(&config_file, |config| {
+        config
+          .stores
+          .insert("store2".to_string(), store_config("store2", "client1"));
+        Ok(())
+      })
+      .is_err(),
+    )
+    .is_true();
+    assert_that(&fs::read(&config_file).unwrap()).is_equal_to(content);
+
+    // Changes are always based on the current file
+    update_config_file(&config_file, |config| {
+      config
+        .stores
+        .insert("store2".to_string(), store_config("store2", "client2"));
+      Ok(())
+    })
+    .unwrap();
+
+    let config = read_config_file(&config_file).unwrap().unwrap();
+    let mut names: Vec<&String> = config.stores.keys().collect();
+    names.sort();
+    assert_that(&names).is_equal_to(vec![&"store1".to_string(), &"store2".to_string()]);
+  }
+
+  #[test]
+  fn test_config_lock() {
+    let tmp_dir = TempDir::new("t-rust-less-config").unwrap();
+    let config_file = tmp_dir.path().join("config.toml");
+    let lock = ConfigLock::acquire(&config_file.with_extension("toml.lock")).unwrap();
+    let (done_sender, done_receiver) = mpsc::channel();
+    let config_file_cloned = config_file.clone();
+
+    thread::spawn(move || {
+      let result = update_config_file(&config_file_cloned, |config| {
+        config
+          .stores
+          .insert("store1".to_string(), store_config("store1", "client1"));
+        Ok(())
+      });
+      done_sender.send(result.is_ok()).unwrap();
+    });
+
+    assert_that(&done_receiver.recv_timeout(Duration::from_millis(300)).is_err()).is_true();
+    assert_that(&config_file.exists()).is_false();
+
+    drop(lock);
+
+    assert_that(&done_receiver.recv_timeout(LOCK_TIMEOUT)).is_ok_containing(true);
+    assert_that(&config_file.exists()).is_true();
+  }
+
+  #[test]
+  fn test_serialize_config() {
+    let mut config = config(vec![store_config("store1", "client1")]);
+    config.default_store = Some("store1".to_string());
+    config.spawn_daemon = true;
+    config.passphrase_helper = Some(PassphraseHelper::Askpass {
+      program: "ssh-askpass".to_string(),
+      args: vec![],
+    });
+    config.breach_check = Some(BreachCheck {
+      file: PathBuf::from("pwned-passwords.txt"),
+      reject: false,
+    });
+
+    let content = toml::to_string_pretty(&config).unwrap();
+    let deserialized = toml::from_str::<Config>(&content).unwrap();
+
+    assert_that(&deserialized.stores).is_equal_to(config.stores.clone());
+    assert_that(&deserialized.spawn_daemon).is_true();
+    assert_that(&deserialized.passphrase_helper).is_equal_to(config.passphrase_helper.clone());
+    assert_that(&deserialized.breach_check).is_equal_to(config.breach_check.clone());
+  }
 }
@@ -1,4 +1,5 @@
 use crate::clipboard::ClipboardError;
+use crate::pinentry::PinentryError;
 use crate::secrets_store::SecretStoreError;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
@@ -9,6 +10,7 @@ pub enum ServiceError {
   IO(String),
   Mutex(String),
   StoreNotFound(String),
+  InvalidConfig(String),
   NotAvailable,
 }
 
@@ -21,6 +23,7 @@ impl fmt::Display for ServiceError {
       ServiceError::IO(error) => write!(f, "IO: {}", error)?,
       ServiceError::Mutex(error) => write!(f, "Mutex: {}", error)?,
       ServiceError::StoreNotFound(name) => write!(f, "Store with name {} not found", name)?,
+      ServiceError::InvalidConfig(error) => write!(f, "Invalid configuration: {}", error)?,
       ServiceError::NotAvailable => write!(f, "Functionality not available (on your platform)")?,
     }
     Ok(())
@@ -34,6 +37,7 @@ error_convert_from!(toml::de::Error, ServiceError, IO(display));
 error_convert_from!(SecretStoreError, ServiceError, SecretsStore(direct));
 error_convert_from!(ClipboardError, ServiceError, IO(display));
 error_convert_from!(futures::task::SpawnError, ServiceError, IO(display));
+error_convert_from!(PinentryError, ServiceError, IO(display));
 
 impl<T> From<std::sync::PoisonError<T>> for ServiceError {
   fn from(error: std::sync::PoisonError<T>) -> Self {
@@ -1,18 +1,26 @@
 use super::pw_generator::{generate_chars, generate_words};
-use crate::api::{Event, EventHandler, EventHub, EventSubscription, PasswordGeneratorParam};
+use crate::api::{
+  Event, EventHandler, EventHub, EventSubscription, PasswordGeneratorParam, SecretListFilter, StoreSecretList,
+};
 use crate::clipboard::Clipboard;
 use crate::secrets_store::{open_secrets_store, SecretsStore};
-use crate::service::config::{read_config, write_config, Config};
+use crate::service::auto_unlock::AutoUnlockSecretsStore;
+use crate::service::config::{config_modified, read_config, update_config, Config};
 use crate::service::error::{ServiceError, ServiceResult};
 #[cfg(unix)]
 use crate::service::secrets_provider::SecretsProvider;
 use crate::service::{ClipboardControl, StoreConfig, TrustlessService};
-use chrono::Utc;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use log::{error, info};
 use rand::{distributions, thread_rng, Rng};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
+use std::time::SystemTime;
+
+/// Secrets expiring (or due for rotation) within this number of seconds are reported via `Event::SecretExpiring`.
+const EXPIRY_WARNING_SECS: u64 = 7 * 24 * 3600;
+/// Each expiring secret is reported at most once within this number of seconds.
+const EXPIRY_REPORT_INTERVAL_SECS: i64 = 24 * 3600;
 
 enum ClipboardHolder {
   Empty,
@@ -126,22 +134,89 @@ impl EventSubscription for LocalEventSubscription {}
 
 pub struct LocalTrustlessService {
   config: RwLock<Config>,
+  config_modified: RwLock<Option<SystemTime>>,
   opened_stores: RwLock<HashMap<String, Arc<dyn SecretsStore>>>,
   clipboard: RwLock<Arc<ClipboardHolder>>,
   event_hub: Arc<LocalEventHub>,
+  expiry_reported: RwLock<HashMap<(String, String), DateTime<Utc>>>,
 }
 
 impl LocalTrustlessService {
   pub fn new() -> ServiceResult<LocalTrustlessService> {
-    let config = read_config()?.unwrap_or_default();
+    let modified = config_modified();
+    // An invalid configuration should not lock the user out of all (other) stores
+    let mut config = match read_config() {
+      Ok(config) => config.unwrap_or_default(),
+      Err(error) => {
+        error!("Unable to read configuration: {}", error);
+        Config::default()
+      }
+    };
+
+    config.retain_valid_stores();
 
     Ok(LocalTrustlessService {
       config: RwLock::new(config),
+      config_modified: RwLock::new(modified),
       opened_stores: RwLock::new(HashMap::new()),
       clipboard: RwLock::new(Arc::new(ClipboardHolder::Empty)),
       event_hub: Arc::new(LocalEventHub::new()),
+      expiry_reported: RwLock::new(HashMap::new()),
     })
   }
+
+  /// Check if all opened stores are locked.
+  pub fn all_locked(&self) -> ServiceResult<bool> {
+    let opened_stores = self.opened_stores.read()?;
+
+    for secrets_store in opened_stores.values() {
+      if !secrets_store.status()?.locked {
+        return Ok(false);
+      }
+    }
+
+    Ok(true)
+  }
+
+  fn change_config<F>(&self, change: F) -> ServiceResult<()>
+  where
+    F: FnOnce(&mut Config) -> ServiceResult<()>,
+  {
+    let mut config_modified = self.config_modified.write()?;
+    let (next_config, modified) = update_config(change)?;
+
+    *config_modified = modified;
+    drop(config_modified);
+
+    self.apply_config(next_config)
+  }
+
+  fn apply_config(&self, next_config: Config) -> ServiceResult<()> {
+    let mut opened_stores = self.opened_stores.write()?;
+    let mut config = self.config.write()?;
+    // Opened stores are bound to their configuration, they have to be re-opened on change
+    let changed_stores: Vec<String> = opened_stores
+      .keys()
+      .filter(|name| config.stores.get(*name) != next_config.stores.get(*name))
+      .cloned()
+      .collect();
+
+    for name in changed_stores {
+      if let Some(secrets_store) = opened_stores.remove(&name) {
+        info!("Closing {} due to configuration change", name);
+        if let Err(error) = secrets_store.lock() {
+          error!("Unable to lock store {}: {}", name, error);
+        }
+      }
+    }
+    *config = next_config;
+    drop(config);
+    drop(opened_stores);
+
+    self.event_hub.send(Event::ConfigChanged);
+
+    Ok(())
+  }
 }
 
 impl TrustlessService for LocalTrustlessService {
@@ -152,25 +227,25 @@ impl TrustlessService for LocalTrustlessService {
   }
 
   fn upsert_store_config(&self, store_config: StoreConfig) -> ServiceResult<()> {
-    let mut config = self.config.write()?;
+    store_config.validate()?;
 
-    if config.default_store.is_none() {
-      config.default_store = Some(store_config.name.to_string());
-    }
-    config.stores.insert(store_config.name.to_string(), store_config);
-    write_config(&config)?;
+    self.change_config(|config| {
+      if config.default_store.is_none() {
+        config.default_store = Some(store_config.name.to_string());
+      }
+      config.stores.insert(store_config.name.to_string(), store_config);
 
-    Ok(())
+      Ok(())
+    })
   }
 
   fn delete_store_config(&self, name: &str) -> ServiceResult<()> {
-    let mut config = self.config.write()?;
-
-    if config.stores.remove(name).is_some() {
-      write_config(&config)?;
-    }
+    // The store might have been added by another client, i.e. only the re-read configuration is relevant
+    self.change_config(|config| {
+      config.stores.remove(name);
 
-    Ok(())
+      Ok(())
+    })
   }
 
   fn open_store(&self, name: &str) -> ServiceResult<Arc<dyn SecretsStore>> {
@@ -187,13 +262,16 @@ impl TrustlessService for LocalTrustlessService {
       .stores
       .get(name)
       .ok_or_else(|| ServiceError::StoreNotFound(name.to_string()))?;
-    let store = open_secrets_store(
-      name,
-      &store_config.store_url,
-      &store_config.client_id,
-      Duration::from_secs(store_config.autolock_timeout_secs),
-      self.event_hub.clone(),
-    )?;
+    let mut store = open_secrets_store(store_config, self.event_hub.clone(), config.breach_check.clone())?;
+
+    if let Some(passphrase_helper) = &config.passphrase_helper {
+      store = Arc::new(AutoUnlockSecretsStore::new(
+        name,
+        store_config.default_identity_id.clone(),
+        passphrase_helper.clone(),
+        store,
+      ));
+    }
 
     opened_stores.insert(name.to_string(), store.clone());
 
@@ -207,16 +285,55 @@ impl TrustlessService for LocalTrustlessService {
   }
 
   fn set_default_store(&self, name: &str) -> ServiceResult<()> {
-    let mut config = self.config.write()?;
+    self.change_config(|config| {
+      if !config.stores.contains_key(name) {
+        return Err(ServiceError::StoreNotFound(name.to_string()));
+      }
+      config.default_store = Some(name.to_string());
 
-    if !config.stores.contains_key(name) {
-      return Err(ServiceError::StoreNotFound(name.to_string()));
+      Ok(())
+    })
+  }
+
+  fn lock_all(&self) -> ServiceResult<()> {
+    let opened_stores = self.opened_stores.read()?;
+    let mut result = Ok(());
+
+    // Every store should be locked, even if some of them fail
+    for (name, secrets_store) in opened_stores.iter() {
+      info!("Locking {}", name);
+      if let Err(error) = secrets_store.lock() {
+        error!("Unable to lock store {}: {}", name, error);
+        if result.is_ok() {
+          result = Err(error.into());
+        }
+      }
     }
 
-    config.default_store = Some(name.to_string());
-    write_config(&config)?;
+    result
+  }
 
-    Ok(())
+  fn list_all(&self, filter: &SecretListFilter) -> ServiceResult<StoreSecretList> {
+    let opened_stores = self.opened_stores.read()?;
+    let mut list = StoreSecretList::default();
+
+    // Only opened stores can be unlocked, a failing store should not hide the others
+    for (name, secrets_store) in opened_stores.iter() {
+      match secrets_store.status() {
+        Ok(status) if status.locked => continue,
+        Ok(_) => (),
+        Err(error) => {
+          error!("Unable to get status of store {}: {}", name, error);
+          continue;
+        }
+      }
+      match secrets_store.list(filter) {
+        Ok(secret_list) => list.extend(name, secret_list),
+        Err(error) => error!("Unable to list store {}: {}", name, error),
+      }
+    }
+
+    Ok(list)
   }
 
   fn secret_to_clipboard(
@@ -270,9 +387,9 @@ impl TrustlessService for LocalTrustlessService {
   }
 
   fn generate_password(&self, param: PasswordGeneratorParam) -> ServiceResult<String> {
-    match param {
-      PasswordGeneratorParam::Chars(params) => Ok(generate_chars(params)),
-      PasswordGeneratorParam::Words(params) => Ok(generate_words(params)),
+    match &param {
+      PasswordGeneratorParam::Chars(params) => Ok(generate_chars(params.clone())),
+      PasswordGeneratorParam::Words(params) => Ok(generate_words(params.clone())),
     }
   }
 
@@ -304,6 +421,100 @@ impl TrustlessService for LocalTrustlessService {
       }
     }
   }
+
+  fn check_config(&self) {
+    let modified = config_modified();
+    let mut config_modified = match self.config_modified.write() {
+      Ok(config_modified) => config_modified,
+      Err(err) => {
+        error!("Failed locking config modification: {}", err);
+        return;
+      }
+    };
+
+    if *config_modified == modified {
+      return;
+    }
+    // Even if the change is invalid, there is no point in reporting it over and over again
+    *config_modified = modified;
+
+    let next_config = match read_config() {
+      Ok(next_config) => next_config.unwrap_or_default(),
+      Err(error) => {
+        error!("Unable to read changed configuration: {}", error);
+        return;
+      }
+    };
+    if let Err(error) = next_config.validate() {
+      error!("Ignoring changed configuration: {}", error);
+      return;
+    }
+    info!("Reloading configuration");
+    if let Err(error) = self.apply_config(next_config) {
+      error!("Unable to apply changed configuration: {}", error);
+    }
+  }
+
+  fn check_expiring(&self) {
+    let opened_stores = match self.opened_stores.read() {
+      Ok(opened_stores) => opened_stores,
+      Err(err) => {
+        error!("Failed locking opened stores: {}", err);
+        return;
+      }
+    };
+    let mut expiry_reported = match self.expiry_reported.write() {
+      Ok(expiry_reported) => expiry_reported,
+      Err(err) => {
+        error!("Failed locking expiry reports: {}", err);
+        return;
+      }
+    };
+    let now = Utc::now();
+    let mut filter = SecretListFilter::default();
+    filter.expiring_within = Some(EXPIRY_WARNING_SECS);
+
+    for (name, secrets_store) in opened_stores.iter() {
+      // Only unlocked stores can be checked, we certainly do not want to ask for a passphrase here
+      match secrets_store.status() {
+        Ok(status) if !status.locked => (),
+        Ok(_) => continue,
+        Err(error) => {
+          error!("Expiry check was unable to query status: {}", error);
+          continue;
+        }
+      }
+      let list = match secrets_store.list(&filter) {
+        Ok(list) => list,
+        Err(error) => {
+          error!("Expiry check was unable to list secrets: {}", error);
+          continue;
+        }
+      };
+
+      for entry_match in list.entries.iter() {
+        let entry = &entry_match.entry;
+        let key = (name.to_string(), entry.id.clone());
+        let expires_at = match entry.due_at() {
+          Some(expires_at) => expires_at,
+          None => continue,
+        };
+
+        if let Some(reported_at) = expiry_reported.get(&key) {
+          if now - *reported_at < ChronoDuration::seconds(EXPIRY_REPORT_INTERVAL_SECS) {
+            continue;
+          }
+        }
+        info!("Secret {} in {} is expiring", entry.id, name);
+        self.event_hub.send(Event::SecretExpiring {
+          store_name: name.to_string(),
+          secret_id: entry.id.clone(),
+          expires_at,
+        });
+        expiry_reported.insert(key, now);
+      }
+    }
+  }
 }
 
 impl std::fmt::Debug for LocalTrustlessService {
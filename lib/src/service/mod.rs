@@ -1,6 +1,8 @@
-use crate::api::{EventHandler, EventSubscription, PasswordGeneratorParam};
+use crate::api::{EventHandler, EventSubscription, PasswordGeneratorParam, SecretListFilter, StoreSecretList};
+use log::warn;
 use std::sync::Arc;
 
+mod auto_unlock;
 mod config;
 mod error;
 pub mod local;
@@ -11,6 +13,7 @@ mod secrets_provider;
 #[cfg(unix)]
 pub mod unix;
 
+pub use self::auto_unlock::unlock_with_helper;
 pub use self::config::{config_file, StoreConfig};
 pub use self::error::*;
 
@@ -47,6 +50,14 @@ pub trait TrustlessService: std::fmt::Debug {
   /// Set the name of the store that should be opened by default
   fn set_default_store(&self, name: &str) -> ServiceResult<()>;
 
+  /// Lock all opened stores
+  /// (There is no counterpart to unlock all stores, every store has identities and passphrases of its own)
+  fn lock_all(&self) -> ServiceResult<()>;
+
+  /// List the secrets of all unlocked stores
+  /// (Locked stores are skipped, this will never ask for a passphrase, failing stores are logged and skipped)
+  fn list_all(&self, filter: &SecretListFilter) -> ServiceResult<StoreSecretList>;
+
   fn secret_to_clipboard(
     &self,
     store_name: &str,
@@ -62,12 +73,28 @@ pub trait TrustlessService: std::fmt::Debug {
   fn generate_password(&self, param: PasswordGeneratorParam) -> ServiceResult<String>;
 
   fn check_autolock(&self);
+
+  /// Reload the configuration if it has been changed (e.g. by hand or another client).
+  /// Changes are announced via `Event::ConfigChanged`.
+  fn check_config(&self);
+
+  /// Send `Event::SecretExpiring` for all secrets of unlocked stores that expire (or are due for rotation) soon.
+  fn check_expiring(&self);
 }
 
 pub fn create_service() -> ServiceResult<Arc<dyn TrustlessService>> {
   #[cfg(unix)]
   {
-    if let Some(remote) = self::unix::try_remote_service()? {
+    // A running daemon is fine even if the config can not be read (only the local service requires it)
+    let spawn_daemon = match self::config::read_config() {
+      Ok(config) => config.is_some_and(|config| config.spawn_daemon),
+      Err(error) => {
+        warn!("Not spawning daemon: {}", error);
+        false
+      }
+    };
+
+    if let Some(remote) = self::unix::try_remote_service(spawn_daemon)? {
       return Ok(Arc::new(remote));
     }
   }
@@ -1,4 +1,7 @@
-use crate::api::{read_option, set_text_list, Identity, Secret, SecretList, SecretListFilter, SecretVersion, Status};
+use crate::api::{
+  read_option, set_text_list, HealthReport, HealthReportParams, Identity, Secret, SecretList, SecretListFilter,
+  SecretTypeSchema, SecretVersion, Status, StoreSecretList,
+};
 use crate::api::{Event, EventHandler, EventSubscription, PasswordGeneratorParam};
 use crate::api_capnp::{clipboard_control, event_handler, event_subscription, secrets_store, service};
 use crate::memguard::SecretBytes;
@@ -119,6 +122,35 @@ impl TrustlessService for RemoteTrustlessService {
     )
   }
 
+  fn lock_all(&self) -> ServiceResult<()> {
+    let mut rt = self.runtime.borrow_mut();
+    let request = self.client.lock_all_request();
+
+    self.local_set.block_on(
+      &mut rt,
+      request.send().promise.map(|response| {
+        response?.get()?;
+
+        Ok(())
+      }),
+    )
+  }
+
+  fn list_all(&self, filter: &SecretListFilter) -> ServiceResult<StoreSecretList> {
+    let mut rt = self.runtime.borrow_mut();
+    let mut request = self.client.list_all_request();
+    filter.to_builder(request.get().init_filter())?;
+
+    self.local_set.block_on(
+      &mut rt,
+      request.send().promise.map(|response| {
+        let list = StoreSecretList::from_reader(response?.get()?.get_list()?)?;
+
+        Ok(list)
+      }),
+    )
+  }
+
   fn secret_to_clipboard(
     &self,
     store_name: &str,
@@ -197,6 +229,14 @@ impl TrustlessService for RemoteTrustlessService {
   fn check_autolock(&self) {
     // This is done by the daemon itself
   }
+
+  fn check_config(&self) {
+    // This is done by the daemon itself
+  }
+
+  fn check_expiring(&self) {
+    // This is done by the daemon itself
+  }
 }
 
 impl std::fmt::Debug for RemoteTrustlessService {
@@ -345,6 +385,19 @@ impl SecretsStore for RemoteSecretsStore {
     )
   }
 
+  fn migrate_versions(&self) -> SecretStoreResult<usize> {
+    let mut rt = self.runtime.borrow_mut();
+    let request = self.client.migrate_versions_request();
+
+    self.local_set.block_on(
+      &mut rt,
+      request
+        .send()
+        .promise
+        .map(|response| Ok(response?.get()?.get_migrated() as usize)),
+    )
+  }
+
   fn add(&self, secret_version: SecretVersion) -> SecretStoreResult<String> {
     let mut rt = self.runtime.borrow_mut();
     let mut request = self.client.add_request();
@@ -388,6 +441,84 @@ impl SecretsStore for RemoteSecretsStore {
       }),
     )
   }
+
+  fn health_report(&self, params: &HealthReportParams) -> SecretStoreResult<HealthReport> {
+    let mut rt = self.runtime.borrow_mut();
+    let mut request = self.client.health_report_request();
+    params.to_builder(request.get().init_params())?;
+
+    self.local_set.block_on(
+      &mut rt,
+      request.send().promise.map(|response| {
+        let report = HealthReport::from_reader(response?.get()?.get_report()?)?;
+
+        Ok(report)
+      }),
+    )
+  }
+
+  fn list_schemas(&self) -> SecretStoreResult<Vec<SecretTypeSchema>> {
+    let mut rt = self.runtime.borrow_mut();
+    let request = self.client.list_schemas_request();
+
+    self.local_set.block_on(
+      &mut rt,
+      request.send().promise.map(|response| {
+        let schemas = response?
+          .get()?
+          .get_schemas()?
+          .into_iter()
+          .map(SecretTypeSchema::from_reader)
+          .collect::<capnp::Result<Vec<SecretTypeSchema>>>()?;
+
+        Ok(schemas)
+      }),
+    )
+  }
+
+  fn store_schema(&self, schema: SecretTypeSchema) -> SecretStoreResult<()> {
+    let mut rt = self.runtime.borrow_mut();
+    let mut request = self.client.store_schema_request();
+    schema.to_builder(request.get().init_schema());
+
+    self.local_set.block_on(
+      &mut rt,
+      request.send().promise.map(|response| {
+        response?.get()?;
+
+        Ok(())
+      }),
+    )
+  }
+
+  fn add_attachment_chunk(&self, recipients: &[String], chunk: &[u8]) -> SecretStoreResult<String> {
+    let mut rt = self.runtime.borrow_mut();
+    let mut request = self.client.add_attachment_chunk_request();
+    set_text_list(request.get().init_recipients(recipients.len() as u32), recipients)?;
+    request.get().set_chunk(chunk);
+
+    self.local_set.block_on(
+      &mut rt,
+      request
+        .send()
+        .promise
+        .map(|response| Ok(response?.get()?.get_block_id()?.to_string())),
+    )
+  }
+
+  fn get_attachment_chunk(&self, block_id: &str) -> SecretStoreResult<SecretBytes> {
+    let mut rt = self.runtime.borrow_mut();
+    let mut request = self.client.get_attachment_chunk_request();
+    request.get().set_block_id(block_id);
+
+    self.local_set.block_on(
+      &mut rt,
+      request
+        .send()
+        .promise
+        .map(|response| Ok(SecretBytes::from_secured(response?.get()?.get_chunk()?))),
+    )
+  }
 }
 
 impl std::fmt::Debug for RemoteSecretsStore {
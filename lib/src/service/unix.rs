@@ -1,13 +1,27 @@
 use crate::api_capnp::service;
 use crate::service::remote::RemoteTrustlessService;
-use crate::service::ServiceResult;
+use crate::service::{ServiceError, ServiceResult};
 use capnp_rpc::{rpc_twoparty_capnp, twoparty, RpcSystem};
 use futures::{AsyncReadExt, FutureExt};
-use std::path::PathBuf;
+use log::{info, warn};
+use std::env;
+use std::fs;
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 use tokio::net::UnixStream;
 use tokio::runtime::Builder;
 use tokio::task::{self, LocalSet};
 
+const DAEMON_EXECUTABLE: &str = "t-rust-less-daemon";
+/// Maximum time to wait for a spawned daemon to accept connections.
+const SPAWN_TIMEOUT: Duration = Duration::from_secs(5);
+
 pub fn daemon_socket_path() -> PathBuf {
   dirs::runtime_dir()
     .map(|r| r.join("t-rust-less.socket"))
@@ -18,11 +32,30 @@ pub fn daemon_socket_path() -> PathBuf {
     })
 }
 
-pub fn try_remote_service() -> ServiceResult<Option<RemoteTrustlessService>> {
+/// Socket of the JSON-RPC interface of the daemon (if enabled).
+pub fn json_rpc_socket_path() -> PathBuf {
+  dirs::runtime_dir()
+    .map(|r| r.join("t-rust-less-json.socket"))
+    .unwrap_or_else(|| {
+      dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".t-rust-less-json-socket")
+    })
+}
+
+/// Connect to the daemon.
+///
+/// If the daemon is not running it is either spawned (`spawn_daemon`) or `None` is returned, so that the
+/// caller may fall back to a local service. A socket left behind by a daemon that died is removed.
+pub fn try_remote_service(spawn_daemon: bool) -> ServiceResult<Option<RemoteTrustlessService>> {
   let socket_path = daemon_socket_path();
 
+  remove_stale_socket(&socket_path)?;
   if !socket_path.exists() {
-    return Ok(None);
+    if !spawn_daemon {
+      return Ok(None);
+    }
+    start_daemon(&socket_path)?;
   }
 
   let mut rt = Builder::new().basic_scheduler().enable_all().build().unwrap();
@@ -45,3 +78,153 @@ pub fn try_remote_service() -> ServiceResult<Option<RemoteTrustlessService>> {
 
   Ok(Some(RemoteTrustlessService::new(client?, rt, local_set)))
 }
+
+/// Remove a socket nobody is listening on anymore (e.g. because the daemon has been killed).
+///
+/// Anything else than a socket is left alone.
+fn remove_stale_socket(socket_path: &Path) -> ServiceResult<()> {
+  match fs::symlink_metadata(socket_path) {
+    Ok(metadata) if !metadata.file_type().is_socket() => {
+      return Err(ServiceError::IO(format!(
+        "{} is not a socket",
+        socket_path.to_string_lossy()
+      )))
+    }
+    Ok(_) => (),
+    Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+    Err(error) => return Err(error.into()),
+  }
+  if !is_listening(socket_path)? {
+    warn!("Removing stale socket {}", socket_path.to_string_lossy());
+    fs::remove_file(socket_path)?;
+  }
+  Ok(())
+}
+
+/// Check if there is a daemon accepting connections on the socket.
+fn is_listening(socket_path: &Path) -> ServiceResult<bool> {
+  match net::UnixStream::connect(socket_path) {
+    Ok(_) => Ok(true),
+    Err(error) if error.kind() == io::ErrorKind::ConnectionRefused => Ok(false),
+    Err(error) => Err(error.into()),
+  }
+}
+
+/// Start the daemon detached (i.e. in a session of its own) and wait until it accepts connections.
+///
+/// Fails right away if the daemon exits before (e.g. because of an invalid configuration).
+fn start_daemon(socket_path: &Path) -> ServiceResult<()> {
+  let daemon = daemon_executable();
+
+  info!("Starting {}", daemon.to_string_lossy());
+
+  let mut command = Command::new(&daemon);
+  command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+  unsafe {
+    command.pre_exec(|| {
+      if libc::setsid() < 0 {
+        return Err(io::Error::last_os_error());
+      }
+      Ok(())
+    });
+  }
+  let mut child = command.spawn()?;
+
+  wait_for_daemon(&mut child, &daemon, socket_path)
+}
+
+/// Wait until there is a daemon accepting connections on the socket.
+///
+/// If another client has spawned a daemon at the same time, the child exits because the socket is already
+/// in use, so the socket is checked once more before giving up.
+fn wait_for_daemon(child: &mut Child, daemon: &Path, socket_path: &Path) -> ServiceResult<()> {
+  let deadline = Instant::now() + SPAWN_TIMEOUT;
+
+  while !(socket_path.exists() && is_listening(socket_path)?) {
+    if let Some(status) = child.try_wait()? {
+      if socket_path.exists() && is_listening(socket_path)? {
+        return Ok(());
+      }
+      return Err(ServiceError::IO(format!(
+        "{} exited without listening on {}: {}",
+        daemon.to_string_lossy(),
+        socket_path.to_string_lossy(),
+        status
+      )));
+    }
+    if Instant::now() > deadline {
+      return Err(ServiceError::IO(format!(
+        "{} did not start listening on {}",
+        daemon.to_string_lossy(),
+        socket_path.to_string_lossy()
+      )));
+    }
+    thread::sleep(Duration::from_millis(50));
+  }
+
+  Ok(())
+}
+
+/// The daemon is expected next to the current executable, otherwise it has to be in the `PATH`.
+fn daemon_executable() -> PathBuf {
+  env::current_exe()
+    .ok()
+    .map(|current| current.with_file_name(DAEMON_EXECUTABLE))
+    .filter(|daemon| daemon.is_file())
+    .unwrap_or_else(|| PathBuf::from(DAEMON_EXECUTABLE))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use spectral::prelude::*;
+  use tempdir::TempDir;
+
+  #[test]
+  fn test_stale_socket() {
+    let tmp_dir = TempDir::new("t-rust-less-socket").unwrap();
+    let socket_path = tmp_dir.path().join("daemon.socket");
+
+    assert_that(&remove_stale_socket(&socket_path)).is_ok();
+
+    let listener = net::UnixListener::bind(&socket_path).unwrap();
+
+    assert_that(&is_listening(&socket_path)).is_ok_containing(true);
+    assert_that(&remove_stale_socket(&socket_path)).is_ok();
+    assert_that(&socket_path.exists()).is_true();
+
+    drop(listener);
+
+    assert_that(&is_listening(&socket_path)).is_ok_containing(false);
+    assert_that(&remove_stale_socket(&socket_path)).is_ok();
+    assert_that(&socket_path.exists()).is_false();
+  }
+
+  #[test]
+  fn test_not_a_socket() {
+    let tmp_dir = TempDir::new("t-rust-less-socket").unwrap();
+    let socket_path = tmp_dir.path().join("daemon.socket");
+
+    fs::write(&socket_path, b"no socket").unwrap();
+
+    assert_that(&remove_stale_socket(&socket_path).is_err()).is_true();
+    assert_that(&socket_path.exists()).is_true();
+  }
+
+  #[test]
+  fn test_wait_for_exited_daemon() {
+    let tmp_dir = TempDir::new("t-rust-less-socket").unwrap();
+    let socket_path = tmp_dir.path().join("daemon.socket");
+    let daemon = PathBuf::from("true");
+    let mut child = Command::new(&daemon).spawn().unwrap();
+
+    child.wait().unwrap();
+
+    assert_that(&wait_for_daemon(&mut child, &daemon, &socket_path).is_err()).is_true();
+
+    // Another daemon has won the race for the socket
+    let _listener = net::UnixListener::bind(&socket_path).unwrap();
+
+    assert_that(&wait_for_daemon(&mut child, &daemon, &socket_path)).is_ok();
+  }
+}
@@ -26,6 +26,7 @@ pub enum Command {
   Lock {
     store_name: String,
   },
+  LockAll,
   Unlock {
     store_name: String,
     identity_id: String,
@@ -191,7 +192,7 @@ pub struct Request {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Response {
-  Command { id: u64, result: CommandResult },
+  Command { id: u64, result: Box<CommandResult> },
   Event(Event),
 }
 
@@ -217,5 +218,11 @@ mod tests {
       .is_equal_to(r#"{"id":12,"command":"list_stores"}"#.to_string());
     assert_that(&serde_json::to_string(&request2).unwrap())
       .is_equal_to(r#"{"id":13,"command":{"status":{"store_name":"bla"}}}"#.to_string());
+    assert_that(
+      &serde_json::from_str::<Request>(r#"{"id":14,"command":"lock_all"}"#)
+        .unwrap()
+        .command,
+    )
+    .matches(|command| matches!(command, Command::LockAll));
   }
 }
@@ -52,7 +52,7 @@ where
           error!("Invalid request");
           Response::Command {
             id: 0,
-            result: CommandResult::Invalid,
+            result: Box::new(CommandResult::Invalid),
           }
         }
       };
@@ -94,6 +94,7 @@ where
         .and_then(|store| Ok(store.status()?))
         .into(),
       Command::Lock { store_name } => self.open_store(&store_name).and_then(|store| Ok(store.lock()?)).into(),
+      Command::LockAll => self.service.lock_all().into(),
       Command::Unlock {
         store_name,
         identity_id,
@@ -161,7 +162,10 @@ where
       _ => CommandResult::Invalid,
     };
 
-    Response::Command { id: request.id, result }
+    Response::Command {
+      id: request.id,
+      result: Box::new(result),
+    }
   }
 
   fn open_store(&mut self, store_name: &str) -> ServiceResult<Arc<dyn SecretsStore>> {